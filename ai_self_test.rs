@@ -0,0 +1,75 @@
+// AI Self-Test Runner
+// Headless AI-vs-AI match from a fixed seed, for balance regression checks
+
+use argue_the_toss::components::soldier::Faction;
+use argue_the_toss::config::battlefield_config::BattlefieldGenerationConfig;
+use argue_the_toss::config::game_config::GameConfig;
+use argue_the_toss::simulation::headless_match::{run_headless_match, SideTally};
+use clap::Parser;
+use std::time::Instant;
+
+#[derive(Parser, Debug)]
+#[command(name = "ai_self_test")]
+#[command(about = "Play a deterministic AI-vs-AI match and report the result", long_about = None)]
+struct Args {
+    /// Match seed (terrain, spawns and every in-game roll)
+    #[arg(short, long, default_value = "12345")]
+    seed: u64,
+
+    /// Map size (square, NxN)
+    #[arg(long, default_value = "100")]
+    size: usize,
+
+    /// Soldiers per side
+    #[arg(long, default_value = "10")]
+    soldiers: usize,
+
+    /// Turn limit before the match is called a draw
+    #[arg(long, default_value = "100")]
+    max_turns: u32,
+
+    /// Enable random events (mines, duds, misfires)
+    #[arg(long)]
+    random_events: bool,
+}
+
+fn print_side(name: &str, tally: &SideTally) {
+    println!(
+        "  {:<15} fielded {:>3}  killed {:>3}  surrendered {:>3}  standing {:>3}",
+        name,
+        tally.fielded,
+        tally.killed,
+        tally.surrendered,
+        tally.standing()
+    );
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let battlefield_config = BattlefieldGenerationConfig::default()
+        .with_dimensions(args.size, args.size)
+        .with_seed(args.seed);
+    let config = GameConfig::default().with_random_events(args.random_events);
+
+    println!(
+        "AI self-test: seed {}, {}x{}, {} soldiers per side, up to {} turns",
+        args.seed, args.size, args.size, args.soldiers, args.max_turns
+    );
+
+    let start = Instant::now();
+    let report = run_headless_match(battlefield_config, &config, args.soldiers, args.max_turns);
+    let elapsed = start.elapsed();
+
+    let winner = match report.winner {
+        Some(Faction::Allies) => "Allies",
+        Some(Faction::CentralPowers) => "Central Powers",
+        None => "none",
+    };
+    println!("Winner:   {} ({:?})", winner, report.ended_by);
+    println!("Turns:    {}", report.turns);
+    println!("Casualties:");
+    print_side("Allies", &report.allies);
+    print_side("Central Powers", &report.central_powers);
+    println!("Finished in {:.2?}", elapsed);
+}
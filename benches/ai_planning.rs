@@ -0,0 +1,49 @@
+// Benchmark for the utility AI planner
+// Measures one AIActionPlannerSystem pass for varying soldier counts
+
+use argue_the_toss::{
+    components::{action::QueuedAction, pathfinding::PlannedPath, time_budget::TimeBudget},
+    config::{battlefield_config::BattlefieldGenerationConfig, game_config::GameConfig},
+    game_logic::{turn_state::TurnOrderMode, world_setup::build_skirmish_world},
+    systems::ai_action_planner::AIActionPlannerSystem,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use specs::{Join, RunNow, World, WorldExt};
+
+const SOLDIERS_PER_SIDE: &[usize] = &[5, 20, 50, 100];
+
+/// Clear planner output so every iteration plans from the same starting state
+fn reset_planning_state(world: &mut World) {
+    world.write_storage::<QueuedAction>().clear();
+    world.write_storage::<PlannedPath>().clear();
+    for budget in (&mut world.write_storage::<TimeBudget>()).join() {
+        budget.reset_for_new_turn();
+    }
+}
+
+fn bench_planning(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ai_planning");
+    group.sample_size(10);
+
+    let config = GameConfig::default().with_turn_order_mode(TurnOrderMode::Simultaneous);
+
+    for &count in SOLDIERS_PER_SIDE {
+        let battlefield_config = BattlefieldGenerationConfig::default()
+            .with_dimensions(150, 150)
+            .with_seed(42);
+        let mut world = build_skirmish_world(battlefield_config, &config, count);
+        let mut planner = AIActionPlannerSystem::new();
+
+        group.bench_function(BenchmarkId::from_parameter(count * 2), |b| {
+            b.iter(|| {
+                planner.run_now(&world);
+                reset_planning_state(&mut world);
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_planning);
+criterion_main!(benches);
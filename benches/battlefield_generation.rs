@@ -0,0 +1,50 @@
+// Benchmark for procedural battlefield generation
+// Measures BattlefieldGenerator::generate across presets and map sizes
+
+use argue_the_toss::{
+    config::battlefield_config::BattlefieldGenerationConfig,
+    game_logic::terrain_generation::BattlefieldGenerator,
+};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const MAP_SIZES: &[usize] = &[50, 100, 200];
+
+fn presets() -> Vec<(&'static str, BattlefieldGenerationConfig)> {
+    vec![
+        ("verdun", BattlefieldGenerationConfig::verdun()),
+        ("somme", BattlefieldGenerationConfig::somme()),
+        ("village", BattlefieldGenerationConfig::village()),
+        ("open_field", BattlefieldGenerationConfig::open_field()),
+    ]
+}
+
+fn bench_generation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("battlefield_generation");
+    group.sample_size(20);
+
+    for (name, preset) in presets() {
+        for &size in MAP_SIZES {
+            let config = BattlefieldGenerationConfig {
+                width: size,
+                height: size,
+                ..preset.clone()
+            };
+
+            group.bench_with_input(
+                BenchmarkId::new(name, format!("{}x{}", size, size)),
+                &config,
+                |b, config| {
+                    b.iter(|| {
+                        let mut generator = BattlefieldGenerator::new(config.clone());
+                        black_box(generator.generate())
+                    })
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_generation);
+criterion_main!(benches);
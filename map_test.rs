@@ -4,9 +4,21 @@
 use argue_the_toss::config::battlefield_config::{
     BattlefieldGenerationConfig, FortificationLevel, Side, TrenchDensity,
 };
-use argue_the_toss::game_logic::battlefield::{Position, TerrainType};
+use argue_the_toss::config::glyph_theme::{GlyphSet, GlyphTheme, GLYPH_THEME_PATH};
+use argue_the_toss::game_logic::approach_lanes::{analyze_approach_lanes, ApproachAnalysis, LaneReport};
+use argue_the_toss::game_logic::battlefield::{Battlefield, Position, TerrainType};
+use argue_the_toss::game_logic::map_editor::{export_map, import_map, TerrainBrush, TERRAIN_PALETTE};
 use argue_the_toss::game_logic::terrain_generation::BattlefieldGenerator;
 use clap::{Parser, ValueEnum};
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 #[derive(Parser, Debug)]
@@ -56,6 +68,34 @@ struct Args {
     /// Allies spawn side
     #[arg(long, value_enum, default_value = "south")]
     allies_side: SideArg,
+
+    /// Analyze cover and sightlines along approach lanes between the spawns
+    #[arg(long)]
+    lanes: bool,
+
+    /// Approach lanes sampled per side (with --lanes)
+    #[arg(long, default_value = "5")]
+    lane_count: usize,
+
+    /// Draw the map with plain ASCII glyphs
+    #[arg(long)]
+    ascii: bool,
+
+    /// Glyph theme file (defaults to data/glyphs.toml)
+    #[arg(long)]
+    glyphs: Option<PathBuf>,
+
+    /// Paint terrain onto the generated map interactively
+    #[arg(long)]
+    edit: bool,
+
+    /// Where the editor writes the map file (with --edit)
+    #[arg(long, default_value = "map_export.txt")]
+    export: PathBuf,
+
+    /// Start from a saved map file instead of generating one
+    #[arg(long)]
+    load: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -133,6 +173,16 @@ impl From<SideArg> for Side {
 fn main() {
     let args = Args::parse();
 
+    let glyphs = if args.ascii {
+        GlyphTheme::ascii()
+    } else {
+        let path = args.glyphs.clone().unwrap_or_else(|| PathBuf::from(GLYPH_THEME_PATH));
+        GlyphTheme::load(&path).unwrap_or_else(|e| {
+            eprintln!("{} - using default glyphs", e);
+            GlyphTheme::default()
+        })
+    };
+
     println!("╔═══════════════════════════════════════════════════════════════════╗");
     println!("║          ARGUE THE TOSS - Map Generation Test Tool               ║");
     println!("╚═══════════════════════════════════════════════════════════════════╝\n");
@@ -196,80 +246,202 @@ fn main() {
     println!("  Allies Side: {:?}", config.allies_side);
     println!();
 
-    // Generate battlefield
-    println!("Generating battlefield...");
-    let start = Instant::now();
-    let mut generator = BattlefieldGenerator::new(config.clone());
-    let battlefield = generator.generate();
-    let duration = start.elapsed();
-    println!("Generation time: {}ms\n", duration.as_millis());
+    let mut battlefield = if let Some(path) = &args.load {
+        println!("Loading map from {}...\n", path.display());
+        let loaded = std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| import_map(&contents).map_err(|e| e.to_string()));
+        match loaded {
+            Ok(battlefield) => battlefield,
+            Err(e) => {
+                eprintln!("Could not load {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        // Generate battlefield
+        println!("Generating battlefield...");
+        let start = Instant::now();
+        let mut generator = BattlefieldGenerator::new(config.clone());
+        let battlefield = generator.generate();
+        let duration = start.elapsed();
+        println!("Generation time: {}ms\n", duration.as_millis());
+        battlefield
+    };
+    let (map_width, map_height) = (battlefield.width(), battlefield.height());
+
+    if args.edit {
+        match run_editor(&mut battlefield, &glyphs, &args.export) {
+            Ok(true) => println!("Map exported to {}\n", args.export.display()),
+            Ok(false) => println!("Editor closed without exporting\n"),
+            Err(e) => eprintln!("Editor failed: {}\n", e),
+        }
+    }
 
     // Render ASCII map
     println!("Map Preview:");
-    println!("┌{}┐", "─".repeat(config.width));
-    render_battlefield(&battlefield, config.width, config.height);
-    println!("└{}┘\n", "─".repeat(config.width));
+    let ascii_frame = glyphs.base == GlyphSet::Ascii;
+    let (corner_top, corner_bottom, edge) = if ascii_frame {
+        (("+", "+"), ("+", "+"), "-")
+    } else {
+        (("┌", "┐"), ("└", "┘"), "─")
+    };
+    println!("{}{}{}", corner_top.0, edge.repeat(map_width), corner_top.1);
+    render_battlefield(&battlefield, map_width, map_height, &glyphs);
+    println!("{}{}{}\n", corner_bottom.0, edge.repeat(map_width), corner_bottom.1);
 
     // Calculate statistics
-    let stats = calculate_terrain_stats(&battlefield, config.width, config.height);
-    print_statistics(&stats, config.width * config.height);
+    let stats = calculate_terrain_stats(&battlefield, map_width, map_height);
+    print_statistics(&stats, map_width * map_height);
+
+    if args.lanes {
+        print_lane_analysis(&analyze_approach_lanes(&battlefield, args.lane_count));
+    }
 
     // Print legend
-    print_legend();
+    print_legend(&glyphs);
 }
 
-fn render_battlefield(battlefield: &argue_the_toss::game_logic::battlefield::Battlefield, width: usize, height: usize) {
+/// Paint terrain with the keyboard until the user quits; returns whether the map was exported
+///
+/// Arrows/hjkl move the cursor, [ and ] pick the terrain, Space paints,
+/// e writes the map file to `export_path`, and q or Esc leaves. The view
+/// scrolls to keep the cursor on screen.
+fn run_editor(
+    battlefield: &mut Battlefield,
+    glyphs: &GlyphTheme,
+    export_path: &Path,
+) -> io::Result<bool> {
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+
+    let result = editor_loop(&mut stdout, battlefield, glyphs, export_path);
+
+    execute!(stdout, Show, LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn editor_loop(
+    stdout: &mut io::Stdout,
+    battlefield: &mut Battlefield,
+    glyphs: &GlyphTheme,
+    export_path: &Path,
+) -> io::Result<bool> {
+    let mut brush = TerrainBrush::new();
+    let mut exported = false;
+    let mut status = String::from("Space paints, [ ] picks terrain, e exports, q quits");
+
+    loop {
+        draw_editor(stdout, battlefield, glyphs, &brush, &status)?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(exported),
+            KeyCode::Up | KeyCode::Char('k') => brush.move_cursor(0, -1, battlefield),
+            KeyCode::Down | KeyCode::Char('j') => brush.move_cursor(0, 1, battlefield),
+            KeyCode::Left | KeyCode::Char('h') => brush.move_cursor(-1, 0, battlefield),
+            KeyCode::Right | KeyCode::Char('l') => brush.move_cursor(1, 0, battlefield),
+            KeyCode::Char('[') => brush.cycle_terrain(-1),
+            KeyCode::Char(']') => brush.cycle_terrain(1),
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                brush.paint(battlefield);
+            }
+            KeyCode::Char('e') => {
+                status = match std::fs::write(export_path, export_map(battlefield)) {
+                    Ok(()) => {
+                        exported = true;
+                        format!("Exported to {}", export_path.display())
+                    }
+                    Err(e) => format!("Export failed: {}", e),
+                };
+            }
+            _ => {}
+        }
+    }
+}
+
+fn draw_editor(
+    stdout: &mut io::Stdout,
+    battlefield: &Battlefield,
+    glyphs: &GlyphTheme,
+    brush: &TerrainBrush,
+    status: &str,
+) -> io::Result<()> {
+    // Leave the bottom two rows for the brush line and the status
+    let (columns, rows) = terminal::size()?;
+    let view_width = (columns as usize).min(battlefield.width());
+    let view_height = (rows.saturating_sub(2) as usize).min(battlefield.height());
+    let origin = brush.view_origin(battlefield, view_width, view_height);
+
+    queue!(stdout, Clear(ClearType::All))?;
+    for row in 0..view_height as i32 {
+        queue!(stdout, MoveTo(0, row as u16))?;
+        for column in 0..view_width as i32 {
+            let pos = Position::new(origin.x + column, origin.y + row);
+            let ch = battlefield
+                .get_tile(&pos)
+                .map_or(' ', |tile| glyphs.terrain(tile.terrain));
+            if pos == brush.cursor {
+                queue!(
+                    stdout,
+                    SetAttribute(Attribute::Reverse),
+                    Print(ch),
+                    SetAttribute(Attribute::Reset)
+                )?;
+            } else {
+                queue!(stdout, Print(ch))?;
+            }
+        }
+    }
+
+    let info_row = view_height as u16;
+    queue!(
+        stdout,
+        MoveTo(0, info_row),
+        Print(format!(
+            "Brush: {} {:?}   Cursor: ({}, {})",
+            glyphs.terrain(brush.terrain()),
+            brush.terrain(),
+            brush.cursor.x,
+            brush.cursor.y
+        )),
+        MoveTo(0, info_row + 1),
+        Print(status)
+    )?;
+    stdout.flush()
+}
+
+fn render_battlefield(
+    battlefield: &Battlefield,
+    width: usize,
+    height: usize,
+    glyphs: &GlyphTheme,
+) {
+    let side = if glyphs.base == GlyphSet::Ascii { '|' } else { '│' };
     for y in 0..height {
-        print!("│");
+        print!("{}", side);
         for x in 0..width {
             let pos = Position::new(x as i32, y as i32);
             if let Some(tile) = battlefield.get_tile(&pos) {
-                let ch = terrain_to_char(tile.terrain);
+                let ch = glyphs.terrain(tile.terrain);
                 print!("{}", ch);
             } else {
                 print!(" ");
             }
         }
-        println!("│");
-    }
-}
-
-fn terrain_to_char(terrain: TerrainType) -> char {
-    match terrain {
-        TerrainType::NoMansLand => '.',
-        TerrainType::Grass => ',',
-        TerrainType::Mud => '~',
-        TerrainType::Water => '≈',
-        TerrainType::DeepWater => '≋',
-        TerrainType::TrenchFloor => '═',
-        TerrainType::TrenchParapet => '║',
-        TerrainType::TrenchRamp => '╬',
-        TerrainType::Trench => '╠',
-        TerrainType::Sandbags => 's',
-        TerrainType::Bunker => '■',
-        TerrainType::MgNest => 'M',
-        TerrainType::BarbedWire => 'x',
-        TerrainType::Tree => '♣',
-        TerrainType::Forest => '♠',
-        TerrainType::Hedge => '#',
-        TerrainType::Rubble => '%',
-        TerrainType::ShellCrater => 'o',
-        TerrainType::CraterWater => 'O',
-        TerrainType::BuildingWall => '█',
-        TerrainType::BuildingFloor => '·',
-        TerrainType::BuildingDoor => '▒',
-        TerrainType::BuildingWindow => '▓',
-        TerrainType::Ruins => '▄',
-        TerrainType::Road => '─',
-        TerrainType::Path => '┄',
-        TerrainType::CommTrench => '┼',
-        TerrainType::Fortification => 'F',
-        TerrainType::CivilianBuilding => 'B',
+        println!("{}", side);
     }
 }
 
 fn calculate_terrain_stats(
-    battlefield: &argue_the_toss::game_logic::battlefield::Battlefield,
+    battlefield: &Battlefield,
     width: usize,
     height: usize,
 ) -> Vec<(TerrainType, usize)> {
@@ -318,17 +490,50 @@ fn print_statistics(stats: &[(TerrainType, usize)], total_tiles: usize) {
     println!();
 }
 
-fn print_legend() {
+fn print_lane_analysis(analysis: &ApproachAnalysis) {
+    println!("Approach Lanes:");
+    if analysis.allies.is_empty() {
+        println!("  (no spawn zones to analyze)");
+        println!();
+        return;
+    }
+
+    for (side, lanes) in [("Allies", &analysis.allies), ("Central Powers", &analysis.central_powers)] {
+        println!("  {} advancing:", side);
+        for lane in lanes {
+            print_lane(lane);
+        }
+        println!(
+            "    average cover coverage: {:.1}%  average sightline: {:.1}",
+            ApproachAnalysis::average_coverage(lanes) * 100.0,
+            ApproachAnalysis::average_sightline(lanes)
+        );
+    }
+    println!();
+}
+
+fn print_lane(lane: &LaneReport) {
+    println!(
+        "    ({:3},{:3}) -> ({:3},{:3})  {:3} tiles  cover {:5.1}%  mean {:.2}  sightline {:3}",
+        lane.start.x,
+        lane.start.y,
+        lane.end.x,
+        lane.end.y,
+        lane.length,
+        lane.cover_coverage * 100.0,
+        lane.mean_cover,
+        lane.sightline
+    );
+}
+
+fn print_legend(glyphs: &GlyphTheme) {
     println!("Legend:");
-    println!("  .  = NoMansLand       ,  = Grass             ~  = Mud");
-    println!("  ≈  = Water            ≋  = DeepWater        ═  = TrenchFloor");
-    println!("  ║  = TrenchParapet    ╬  = TrenchRamp        ╠  = Trench (legacy)");
-    println!("  s  = Sandbags         ■  = Bunker            M  = MgNest");
-    println!("  x  = BarbedWire       ♣  = Tree              ♠  = Forest");
-    println!("  #  = Hedge            %  = Rubble            o  = ShellCrater");
-    println!("  O  = CraterWater      █  = BuildingWall      ·  = BuildingFloor");
-    println!("  ▒  = BuildingDoor     ▓  = BuildingWindow    ▄  = Ruins");
-    println!("  ─  = Road             ┄  = Path              ┼  = CommTrench");
-    println!("  F  = Fortification    B  = CivilianBuilding");
+    for row in TERRAIN_PALETTE.chunks(3) {
+        let entries: Vec<String> = row
+            .iter()
+            .map(|terrain| format!("{}  = {:<16}", glyphs.terrain(*terrain), format!("{:?}", terrain)))
+            .collect();
+        println!("  {}", entries.join(" ").trim_end());
+    }
     println!();
 }
@@ -1,7 +1,13 @@
 use crate::components::{
-    action::ActionType, facing::Facing, position::Position, soldier::Soldier, weapon::Weapon,
+    action::ActionType,
+    facing::Facing,
+    position::Position,
+    soldier::Soldier,
+    weapon::{SecondaryWeapon, Weapon},
 };
+use crate::config::game_config::CorpseRule;
 use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos};
+use crate::game_logic::combat::{in_weapon_range, range_in_tiles};
 use crate::game_logic::objectives::Objectives;
 use specs::{Entity, ReadStorage};
 use std::collections::HashSet;
@@ -47,18 +53,37 @@ impl PossibleAction {
     }
 }
 
+/// The world as the generator sees it, gathered once per planning pass
+pub struct GenerationContext<'a, 'w> {
+    pub positions: &'a ReadStorage<'w, Position>,
+    pub soldiers: &'a ReadStorage<'w, Soldier>,
+    pub weapons: &'a ReadStorage<'w, Weapon>,
+    pub secondaries: &'a ReadStorage<'w, SecondaryWeapon>,
+    pub battlefield: &'a Battlefield,
+    pub objectives: &'a Objectives,
+    /// Fallen soldiers whose weapons still hold rounds
+    pub lootable_corpses: &'a [(Entity, BattlefieldPos)],
+    pub corpse_rule: CorpseRule,
+}
+
 pub struct ActionGenerator;
 
 impl ActionGenerator {
     pub fn generate_actions(
         actor_entity: Entity,
         visible_enemies: &[Entity],
-        positions: &ReadStorage<Position>,
-        soldiers: &ReadStorage<Soldier>,
-        weapons: &ReadStorage<Weapon>,
-        battlefield: &Battlefield,
-        objectives: &Objectives,
+        context: &GenerationContext,
     ) -> Vec<PossibleAction> {
+        let GenerationContext {
+            positions,
+            soldiers,
+            weapons,
+            secondaries,
+            battlefield,
+            objectives,
+            lootable_corpses,
+            corpse_rule,
+        } = *context;
         let mut actions = Vec::new();
 
         let actor_pos = match positions.get(actor_entity) {
@@ -79,6 +104,22 @@ impl ActionGenerator {
 
         actions.extend(Self::generate_reload_action(actor_weapon));
 
+        actions.extend(Self::generate_swap_action(
+            actor_pos,
+            actor_weapon,
+            secondaries.get(actor_entity),
+            visible_enemies,
+            positions,
+        ));
+
+        actions.extend(Self::generate_loot_action(
+            actor_pos,
+            actor_weapon,
+            lootable_corpses,
+            corpse_rule,
+            battlefield,
+        ));
+
         actions.extend(Self::generate_move_actions(
             actor_pos,
             visible_enemies,
@@ -116,23 +157,22 @@ impl ActionGenerator {
 
         if !weapon.can_fire() {
             if !visible_enemies.is_empty() {
-                debug_log(&format!("[SHOOT] Weapon cannot fire (ammo: {}/{})", weapon.ammo.current, weapon.ammo.max_capacity));
+                debug_log(&format!("[SHOOT] Weapon cannot fire (ammo: {}/{}, heat: {:.0})", weapon.ammo.current, weapon.ammo.max_capacity, weapon.heat));
             }
             return actions;
         }
 
         for &enemy in visible_enemies {
             if let Some(enemy_pos) = positions.get(enemy) {
-                let distance = actor_pos.as_battlefield_pos().distance_to(enemy_pos.as_battlefield_pos());
-
-                if distance <= weapon.stats.max_range as f32 {
+                if in_weapon_range(weapon, actor_pos.as_battlefield_pos(), enemy_pos.as_battlefield_pos()) {
                     actions.push(
                         PossibleAction::new(ActionType::Shoot { target: enemy })
                             .with_target(enemy)
                             .with_position(*enemy_pos.as_battlefield_pos()),
                     );
                 } else {
-                    debug_log(&format!("[SHOOT] Enemy out of range: {:.1} > {}", distance, weapon.stats.max_range));
+                    let distance = range_in_tiles(actor_pos.as_battlefield_pos(), enemy_pos.as_battlefield_pos());
+                    debug_log(&format!("[SHOOT] Enemy out of range: {} > {}", distance, weapon.stats.max_range));
                 }
             }
         }
@@ -148,7 +188,7 @@ impl ActionGenerator {
         let mut actions = Vec::new();
 
         if let Some(weapon) = actor_weapon {
-            if weapon.ammo.current < weapon.ammo.max_capacity {
+            if weapon.ammo.can_reload() {
                 actions.push(PossibleAction::new(ActionType::Reload));
             }
         }
@@ -156,6 +196,63 @@ impl ActionGenerator {
         actions
     }
 
+    /// Draw the sidearm when the primary is empty and an enemy is adjacent
+    ///
+    /// Too close to risk a reload; this is the only case where swapping is offered.
+    fn generate_swap_action(
+        actor_pos: &Position,
+        actor_weapon: Option<&Weapon>,
+        secondary: Option<&SecondaryWeapon>,
+        visible_enemies: &[Entity],
+        positions: &ReadStorage<Position>,
+    ) -> Option<PossibleAction> {
+        let (weapon, secondary) = (actor_weapon?, secondary?);
+        if weapon.can_fire() || !secondary.weapon.can_fire() {
+            return None;
+        }
+
+        let enemy_adjacent = visible_enemies.iter().any(|&enemy| {
+            positions.get(enemy).is_some_and(|enemy_pos| {
+                actor_pos.as_battlefield_pos().distance_to(enemy_pos.as_battlefield_pos()) <= 1.5
+            })
+        });
+
+        enemy_adjacent.then(|| PossibleAction::new(ActionType::SwapWeapon))
+    }
+
+    /// Scavenge ammo when the weapon in hand is empty
+    ///
+    /// Loot a corpse on the actor's own tile, or step onto an adjacent one;
+    /// that step carries the corpse as its target entity. Corpses that block
+    /// their tile can't be stepped onto, so those are looted from beside them.
+    fn generate_loot_action(
+        actor_pos: &Position,
+        actor_weapon: Option<&Weapon>,
+        lootable_corpses: &[(Entity, BattlefieldPos)],
+        corpse_rule: CorpseRule,
+        battlefield: &Battlefield,
+    ) -> Option<PossibleAction> {
+        if !actor_weapon?.ammo.is_empty() {
+            return None;
+        }
+
+        let here = actor_pos.as_battlefield_pos();
+        if let Some((corpse, _)) = lootable_corpses.iter().find(|(_, pos)| pos == here) {
+            return Some(PossibleAction::new(ActionType::Loot).with_target(*corpse));
+        }
+
+        lootable_corpses
+            .iter()
+            .find(|(_, pos)| here.distance_to(pos) <= 1.5)
+            .and_then(|(corpse, pos)| {
+                if corpse_rule.blocks(true) {
+                    return Some(PossibleAction::new(ActionType::Loot).with_target(*corpse));
+                }
+                Self::create_move_action(here, pos, battlefield)
+                    .map(|action| action.with_target(*corpse))
+            })
+    }
+
     fn generate_move_actions(
         actor_pos: &Position,
         visible_enemies: &[Entity],
@@ -165,7 +262,7 @@ impl ActionGenerator {
         actor_faction: Option<crate::components::soldier::Faction>,
     ) -> Vec<PossibleAction> {
         let mut actions = Vec::new();
-        let mut target_positions = HashSet::new();
+        let mut target_positions = Vec::new();
 
         if !visible_enemies.is_empty() {
             if let Some(nearest_enemy_pos) = Self::find_nearest_enemy(actor_pos, visible_enemies, positions) {
@@ -201,6 +298,10 @@ impl ActionGenerator {
             8,
         ));
 
+        // Drop duplicates but keep the order, so truncation is reproducible
+        let mut seen = HashSet::new();
+        target_positions.retain(|pos| seen.insert(*pos));
+
         for target_pos in target_positions {
             if let Some(action) = Self::create_move_action(actor_pos.as_battlefield_pos(), &target_pos, battlefield) {
                 actions.push(action);
@@ -214,6 +315,18 @@ impl ActionGenerator {
         actions
     }
 
+    /// Moves toward where a comrade reported an enemy
+    pub fn generate_intel_moves(
+        actor_pos: &BattlefieldPos,
+        reported: &BattlefieldPos,
+        battlefield: &Battlefield,
+    ) -> Vec<PossibleAction> {
+        Self::sample_positions_toward(actor_pos, reported, battlefield, 3)
+            .iter()
+            .filter_map(|target| Self::create_move_action(actor_pos, target, battlefield))
+            .collect()
+    }
+
     fn generate_rotation_actions() -> Vec<PossibleAction> {
         vec![
             PossibleAction::new(ActionType::Rotate { clockwise: true }),
@@ -360,3 +473,69 @@ impl ActionGenerator {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::{Faction, Rank};
+    use crate::game_logic::world_setup::register_components;
+    use specs::{Builder, World, WorldExt};
+
+    /// Whether the AI generates a shot from (0, 0) at a target on `target`
+    fn ai_offers_shot(target: BattlefieldPos) -> bool {
+        let mut world = World::new();
+        register_components(&mut world);
+        let soldier = |faction| Soldier {
+            name: "Rifleman".to_string(),
+            faction,
+            rank: Rank::Private,
+        };
+        let actor = world
+            .create_entity()
+            .with(Position::new(0, 0))
+            .with(soldier(Faction::Allies))
+            .with(Weapon::rifle())
+            .build();
+        let enemy = world
+            .create_entity()
+            .with(Position::new(target.x, target.y))
+            .with(soldier(Faction::CentralPowers))
+            .build();
+
+        let context = GenerationContext {
+            positions: &world.read_storage(),
+            soldiers: &world.read_storage(),
+            weapons: &world.read_storage(),
+            secondaries: &world.read_storage(),
+            battlefield: &Battlefield::new(40, 40),
+            objectives: &Objectives::new(),
+            lootable_corpses: &[],
+            corpse_rule: CorpseRule::default(),
+        };
+        let actions = ActionGenerator::generate_actions(actor, &[enemy], &context);
+        actions
+            .iter()
+            .any(|action| matches!(action.action_type, ActionType::Shoot { .. }))
+    }
+
+    #[test]
+    fn test_max_range_boundary_matches_targeting_check() {
+        let rifle = Weapon::rifle();
+        let origin = BattlefieldPos::new(0, 0);
+        // Exactly max_range (an 18-24-30 triangle), then just beyond it
+        let boundary = BattlefieldPos::new(18, 24);
+        let beyond = BattlefieldPos::new(19, 24);
+        assert_eq!(range_in_tiles(&origin, &boundary), rifle.stats.max_range);
+
+        for target in [boundary, beyond] {
+            assert_eq!(
+                ai_offers_shot(target),
+                in_weapon_range(&rifle, &origin, &target),
+                "AI and targeting disagree at {:?}",
+                target
+            );
+        }
+        assert!(in_weapon_range(&rifle, &origin, &boundary));
+        assert!(!in_weapon_range(&rifle, &origin, &beyond));
+    }
+}
@@ -1,14 +1,19 @@
 use crate::ai::{
     considerations::{
-        ActionContext, AlliesNearbyConsideration, AmmoLevelConsideration, Consideration,
-        CoverQualityConsideration, DistanceToTargetConsideration, HasLineOfSightConsideration,
+        ActionContext, AlliesNearbyConsideration, AmmoLevelConsideration, ClusteringConsideration,
+        Consideration,
+        CoverQualityConsideration, DistanceToTargetConsideration, EstablishedContactConsideration,
+        HasLineOfSightConsideration,
         HealthLevelConsideration, NearbyOfficerConsideration, ObjectiveProximityConsideration,
         ThreatLevelConsideration,
     },
     response_curves::ResponseCurve,
 };
 use crate::components::action::ActionType;
+use crate::game_logic::battlefield::Position;
+use rand::Rng;
 use specs::Entity;
+use std::cmp::Ordering;
 
 #[derive(Debug, Clone)]
 pub struct ScoredAction {
@@ -44,6 +49,61 @@ impl ScoredAction {
         self.debug_info = Some(info);
         self
     }
+
+    /// Order by score, then by the tie-break keys that don't need the RNG
+    fn rank(&self, other: &Self, origin: &Position) -> Ordering {
+        let distance = |action: &Self| action.position.map_or(0.0, |pos| origin.distance_to(&pos));
+        self.score
+            .total_cmp(&other.score)
+            .then(tiebreak_priority(&self.action_type).cmp(&tiebreak_priority(&other.action_type)))
+            .then(distance(other).total_cmp(&distance(self)))
+    }
+
+    /// A fixed order for actions that tie on every key, independent of generation order
+    fn canonical_key(&self) -> (Option<(i32, i32)>, Option<u32>, String) {
+        (
+            self.position.map(|pos| (pos.x, pos.y)),
+            self.target.map(|target| target.id()),
+            format!("{:?}", self.action_type),
+        )
+    }
+}
+
+/// Which action type wins when two actions score the same (higher wins)
+fn tiebreak_priority(action_type: &ActionType) -> u8 {
+    match action_type {
+        ActionType::Shoot { .. } | ActionType::PanicFire { .. } => 9,
+        ActionType::MoveAndFire { .. } => 8,
+        ActionType::ThrowGrenade { .. } => 7,
+        ActionType::Aim => 6,
+        ActionType::Reload => 5,
+        ActionType::LayWire { .. } => 4,
+        ActionType::SwapWeapon | ActionType::Loot => 2,
+        ActionType::Move { .. } | ActionType::Crawl { .. } => 1,
+        ActionType::Rotate { .. } | ActionType::Wait => 0,
+    }
+}
+
+/// Pick the highest-scoring action, settling ties deterministically
+///
+/// Equal scores go to the higher-priority action type, then to the action
+/// nearer `origin`. Anything still tied is put in a fixed order and one is
+/// drawn with the seeded AI RNG, which is only touched when such a tie occurs.
+pub fn select_best_action<'a>(
+    actions: &'a [ScoredAction],
+    origin: &Position,
+    rng: &mut impl Rng,
+) -> Option<&'a ScoredAction> {
+    let best = actions.iter().max_by(|a, b| a.rank(b, origin))?;
+    let mut tied: Vec<&ScoredAction> = actions
+        .iter()
+        .filter(|action| action.rank(best, origin) == Ordering::Equal)
+        .collect();
+    if tied.len() == 1 {
+        return Some(best);
+    }
+    tied.sort_by_cached_key(|action| action.canonical_key());
+    Some(tied[rng.random_range(0..tied.len())])
 }
 
 #[derive(Debug, Clone)]
@@ -108,6 +168,8 @@ pub struct ActionEvaluator {
     pub name: String,
     pub base_score: f32,
     pub considerations: Vec<Box<dyn Consideration>>,
+    /// Considerations that scale the combined score, so a 0.0 vetoes the action
+    pub gates: Vec<Box<dyn Consideration>>,
     pub combiner: ScoreCombiner,
 }
 
@@ -117,6 +179,7 @@ impl ActionEvaluator {
             name: name.into(),
             base_score,
             considerations: Vec::new(),
+            gates: Vec::new(),
             combiner: ScoreCombiner::Multiplicative,
         }
     }
@@ -126,6 +189,11 @@ impl ActionEvaluator {
         self
     }
 
+    pub fn with_gate(mut self, gate: Box<dyn Consideration>) -> Self {
+        self.gates.push(gate);
+        self
+    }
+
     pub fn with_combiner(mut self, combiner: ScoreCombiner) -> Self {
         self.combiner = combiner;
         self
@@ -138,7 +206,29 @@ impl ActionEvaluator {
             .map(|c| c.evaluate_with_timing(context))  // Use timing wrapper
             .collect();
 
-        self.combiner.combine(self.base_score, &scores)
+        let gate: f32 = self
+            .gates
+            .iter()
+            .map(|g| g.evaluate_with_timing(context))
+            .product();
+
+        self.combiner.combine(self.base_score, &scores) * gate
+    }
+
+    /// Like `evaluate`, but also returns each consideration's (and gate's) score
+    pub fn evaluate_breakdown(&self, context: &ActionContext) -> (f32, Vec<(String, f32)>) {
+        let breakdown: Vec<(String, f32)> = self
+            .considerations
+            .iter()
+            .chain(self.gates.iter())
+            .map(|c| (c.name().to_string(), c.evaluate(context)))
+            .collect();
+
+        let (scores, gates) = breakdown.split_at(self.considerations.len());
+        let scores: Vec<f32> = scores.iter().map(|(_, s)| *s).collect();
+        let gate: f32 = gates.iter().map(|(_, s)| s).product();
+
+        (self.combiner.combine(self.base_score, &scores) * gate, breakdown)
     }
 }
 
@@ -147,6 +237,9 @@ pub fn create_shoot_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(HasLineOfSightConsideration::new(
             ResponseCurve::Boolean { threshold: 0.5 },
         )))
+        .with_gate(Box::new(EstablishedContactConsideration::new(
+            ResponseCurve::Boolean { threshold: 0.5 },
+        )))
         .with_consideration(Box::new(DistanceToTargetConsideration::new(
             ResponseCurve::Inverse,
         )))
@@ -181,6 +274,9 @@ pub fn create_move_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(NearbyOfficerConsideration::new(
             ResponseCurve::Inverse,
         )))
+        .with_consideration(Box::new(ClusteringConsideration::new(
+            ResponseCurve::Linear,
+        )))
         .with_combiner(ScoreCombiner::Average)
 }
 
@@ -216,3 +312,60 @@ pub fn create_wait_evaluator() -> ActionEvaluator {
         )))
         .with_combiner(ScoreCombiner::Minimum)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::game_rng::AiRng;
+    use specs::{Builder, World, WorldExt};
+
+    #[test]
+    fn test_tied_top_scores_resolve_the_same_way_every_pass() {
+        let mut world = World::new();
+        let first = world.create_entity().build();
+        let second = world.create_entity().build();
+        let origin = Position::new(5, 5);
+
+        // Two equally good shots and an equally scored move: the shot wins on
+        // type, and the two shots are settled by the seeded draw
+        let pass = |order: &[usize]| {
+            let candidates = [
+                ScoredAction::new(ActionType::Shoot { target: first }, 0.8)
+                    .with_target(first)
+                    .with_position(Position::new(9, 5)),
+                ScoredAction::new(ActionType::Shoot { target: second }, 0.8)
+                    .with_target(second)
+                    .with_position(Position::new(5, 9)),
+                ScoredAction::new(
+                    ActionType::Move {
+                        dx: 1,
+                        dy: 0,
+                        terrain_cost: 1.0,
+                    },
+                    0.8,
+                )
+                .with_position(Position::new(6, 5)),
+            ];
+            let actions: Vec<_> = order.iter().map(|&i| candidates[i].clone()).collect();
+            let mut rng = AiRng::new(42);
+            select_best_action(&actions, &origin, &mut rng).map(|best| best.target)
+        };
+
+        let picked = pass(&[0, 1, 2]).expect("an action is chosen");
+        assert!(picked.is_some(), "a shot should beat a move on an equal score");
+        assert_eq!(pass(&[0, 1, 2]), Some(picked));
+        assert_eq!(pass(&[2, 1, 0]), Some(picked), "generation order should not matter");
+    }
+
+    #[test]
+    fn test_nearer_action_wins_a_tie_between_the_same_action_type() {
+        let origin = Position::new(0, 0);
+        let actions = [
+            ScoredAction::new(ActionType::Wait, 0.5).with_position(Position::new(6, 0)),
+            ScoredAction::new(ActionType::Wait, 0.5).with_position(Position::new(2, 0)),
+            ScoredAction::new(ActionType::Wait, 0.4),
+        ];
+        let best = select_best_action(&actions, &origin, &mut AiRng::new(1)).unwrap();
+        assert_eq!(best.position, Some(Position::new(2, 0)));
+    }
+}
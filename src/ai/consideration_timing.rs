@@ -0,0 +1,127 @@
+// Consideration Timing
+// Per-consideration evaluation times, totalled over a planning pass
+//
+// Debug builds time every consideration the planner evaluates. Totals are
+// kept per consideration name so the hot ones stand out in the summary
+// written at the end of the pass; single calls at or over the slow
+// threshold are counted separately. The threshold defaults to
+// DEFAULT_SLOW_MICROS and can be changed with the SLOW_CONSIDERATION_ENV
+// environment variable.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Environment variable holding the slow-call threshold in microseconds
+pub const SLOW_CONSIDERATION_ENV: &str = "ARGUE_AI_SLOW_CONSIDERATION_US";
+/// Slow-call threshold when the environment doesn't set one
+pub const DEFAULT_SLOW_MICROS: u64 = 100;
+
+/// Running totals for one consideration
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ConsiderationTiming {
+    pub calls: u32,
+    pub total: Duration,
+    pub max: Duration,
+    /// Calls at or over the slow threshold
+    pub slow_calls: u32,
+}
+
+/// Evaluation times for every consideration seen in a planning pass
+#[derive(Debug, Clone)]
+pub struct ConsiderationTimings {
+    slow_threshold: Duration,
+    timings: HashMap<String, ConsiderationTiming>,
+}
+
+impl Default for ConsiderationTimings {
+    fn default() -> Self {
+        Self::new(Duration::from_micros(DEFAULT_SLOW_MICROS))
+    }
+}
+
+impl ConsiderationTimings {
+    pub fn new(slow_threshold: Duration) -> Self {
+        Self {
+            slow_threshold,
+            timings: HashMap::new(),
+        }
+    }
+
+    /// Threshold from SLOW_CONSIDERATION_ENV, falling back to the default
+    pub fn from_env() -> Self {
+        let micros = std::env::var(SLOW_CONSIDERATION_ENV)
+            .ok()
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or(DEFAULT_SLOW_MICROS);
+        Self::new(Duration::from_micros(micros))
+    }
+
+    pub fn slow_threshold(&self) -> Duration {
+        self.slow_threshold
+    }
+
+    /// Add one evaluation of `name` that took `elapsed`
+    pub fn record(&mut self, name: &str, elapsed: Duration) {
+        let timing = self.timings.entry(name.to_string()).or_default();
+        timing.calls += 1;
+        timing.total += elapsed;
+        timing.max = timing.max.max(elapsed);
+        if elapsed >= self.slow_threshold {
+            timing.slow_calls += 1;
+        }
+    }
+
+    /// Totals for `name`, if it was evaluated this pass
+    pub fn get(&self, name: &str) -> Option<&ConsiderationTiming> {
+        self.timings.get(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timings.is_empty()
+    }
+
+    /// One line per consideration, most total time first
+    pub fn summary(&self) -> Vec<String> {
+        let mut rows: Vec<_> = self.timings.iter().collect();
+        rows.sort_by(|a, b| b.1.total.cmp(&a.1.total).then_with(|| a.0.cmp(b.0)));
+        rows.into_iter()
+            .map(|(name, timing)| {
+                format!(
+                    "{}: {} calls, {}μs total, {}μs max, {} over {}μs",
+                    name,
+                    timing.calls,
+                    timing.total.as_micros(),
+                    timing.max.as_micros(),
+                    timing.slow_calls,
+                    self.slow_threshold.as_micros()
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timings_accumulate_per_consideration() {
+        let mut timings = ConsiderationTimings::new(Duration::from_micros(100));
+        timings.record("HasLineOfSight", Duration::from_micros(40));
+        timings.record("HasLineOfSight", Duration::from_micros(150));
+        timings.record("HasLineOfSight", Duration::from_micros(10));
+        timings.record("Distance", Duration::from_micros(5));
+
+        let los = timings.get("HasLineOfSight").unwrap();
+        assert_eq!(los.calls, 3);
+        assert_eq!(los.total, Duration::from_micros(200));
+        assert_eq!(los.max, Duration::from_micros(150));
+        assert_eq!(los.slow_calls, 1);
+        assert_eq!(timings.get("Distance").unwrap().calls, 1);
+
+        // Hottest consideration leads the summary
+        let summary = timings.summary();
+        assert_eq!(summary.len(), 2);
+        assert!(summary[0].starts_with("HasLineOfSight: 3 calls, 200μs total"));
+    }
+}
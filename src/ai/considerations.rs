@@ -1,12 +1,16 @@
+use crate::ai::consideration_timing::ConsiderationTimings;
+use crate::ai::contact::ContactTracker;
 use crate::ai::response_curves::ResponseCurve;
 use crate::components::{
     facing::Facing, health::Health, position::Position, soldier::{Faction, Rank, Soldier},
     vision::Vision, weapon::Weapon,
 };
 use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos};
+use crate::game_logic::blast::{in_blast, GRENADE_BLAST_RADIUS};
 use crate::game_logic::line_of_sight::calculate_fov;
 use crate::game_logic::objectives::Objectives;
 use specs::{Entities, Entity, Join, ReadStorage};
+use std::cell::RefCell;
 use std::time::Instant;
 
 pub struct ActionContext<'a> {
@@ -26,28 +30,29 @@ pub struct ActionContext<'a> {
     pub entities: &'a Entities<'a>,
 
     pub visible_enemies: &'a Vec<Entity>,
+
+    pub contacts: &'a ContactTracker,
+    pub current_turn: u32,
+
+    /// Where evaluation times are totalled, when the planner is profiling
+    pub timings: Option<&'a RefCell<ConsiderationTimings>>,
 }
 
 pub trait Consideration: Send + Sync {
     fn evaluate(&self, context: &ActionContext) -> f32;
     fn name(&self) -> &str;
 
-    // Wrapper with timing for debugging
+    // Wrapper with timing for debugging; times are totalled into the
+    // context's timings rather than printed, which would break TUI rendering
     fn evaluate_with_timing(&self, context: &ActionContext) -> f32 {
-        if cfg!(debug_assertions) {
-            let start = Instant::now();
-            let result = self.evaluate(context);
-            let elapsed = start.elapsed();
-
-            // Performance logging removed - it breaks TUI rendering
-            // Re-enable manually if needed for debugging specific considerations
-            // if elapsed.as_micros() > 100 {
-            //     eprintln!("[PERF] {} took {}μs", self.name(), elapsed.as_micros());
-            // }
-
-            result
-        } else {
-            self.evaluate(context)
+        match context.timings {
+            Some(timings) if cfg!(debug_assertions) => {
+                let start = Instant::now();
+                let result = self.evaluate(context);
+                timings.borrow_mut().record(self.name(), start.elapsed());
+                result
+            }
+            _ => self.evaluate(context),
         }
     }
 }
@@ -214,6 +219,38 @@ impl Consideration for HasLineOfSightConsideration {
     }
 }
 
+/// Whether the target has been seen long enough to engage (spot then shoot)
+pub struct EstablishedContactConsideration {
+    curve: ResponseCurve,
+}
+
+impl EstablishedContactConsideration {
+    pub fn new(curve: ResponseCurve) -> Self {
+        Self { curve }
+    }
+}
+
+impl Consideration for EstablishedContactConsideration {
+    fn evaluate(&self, context: &ActionContext) -> f32 {
+        let target_entity = match context.target_entity {
+            Some(target) => target,
+            None => return self.curve.evaluate(1.0),
+        };
+
+        let established = context.contacts.is_established(
+            context.actor_entity,
+            target_entity,
+            context.current_turn,
+        );
+
+        self.curve.evaluate(if established { 1.0 } else { 0.0 })
+    }
+
+    fn name(&self) -> &str {
+        "EstablishedContact"
+    }
+}
+
 pub struct ThreatLevelConsideration {
     curve: ResponseCurve,
 }
@@ -530,6 +567,60 @@ impl Consideration for ExposedDangerConsideration {
     }
 }
 
+/// Friendlies around a move target at which it counts as fully crowded
+const CLUSTER_MAX_FRIENDLIES: f32 = 4.0;
+
+/// Evaluates how spread out a move target keeps the squad
+///
+/// Counts living friendlies within a grenade's blast of the target tile;
+/// 1.0 means no one else would be caught by the same grenade. Crowding only
+/// costs half as much while no enemy is in sight.
+pub struct ClusteringConsideration {
+    curve: ResponseCurve,
+}
+
+impl ClusteringConsideration {
+    pub fn new(curve: ResponseCurve) -> Self {
+        Self { curve }
+    }
+}
+
+impl Consideration for ClusteringConsideration {
+    fn evaluate(&self, context: &ActionContext) -> f32 {
+        let actor_faction = match context.soldiers.get(context.actor_entity) {
+            Some(s) => s.faction,
+            None => return 0.5,
+        };
+
+        let target = match context.target_position {
+            Some(pos) => pos,
+            None => match context.positions.get(context.actor_entity) {
+                Some(pos) => *pos.as_battlefield_pos(),
+                None => return 0.5,
+            },
+        };
+
+        let nearby = (context.entities, context.positions, context.soldiers)
+            .join()
+            .filter(|(entity, pos, soldier)| {
+                *entity != context.actor_entity
+                    && soldier.faction == actor_faction
+                    && context.healths.get(*entity).is_none_or(|h| h.is_alive())
+                    && in_blast(target, GRENADE_BLAST_RADIUS, *pos.as_battlefield_pos())
+            })
+            .count();
+
+        let crowding = (nearby as f32 / CLUSTER_MAX_FRIENDLIES).min(1.0);
+        let penalty = if context.visible_enemies.is_empty() { 0.5 } else { 1.0 };
+
+        self.curve.evaluate(1.0 - crowding * penalty)
+    }
+
+    fn name(&self) -> &str {
+        "Clustering"
+    }
+}
+
 /// Evaluates tactical advantage of target position (range, elevation, flanking)
 pub struct TacticalAdvantageConsideration {
     curve: ResponseCurve,
@@ -710,7 +801,7 @@ impl Consideration for ObjectivePressureConsideration {
         let mut objective_found = false;
 
         for flag in context.objectives.flags.values() {
-            if flag.owning_faction != actor_faction {
+            if !flag.is_held_by(actor_faction) {
                 let dist = actor_pos.distance_to(&flag.position);
                 if dist < nearest_obj_dist {
                     nearest_obj_dist = dist;
@@ -726,7 +817,7 @@ impl Consideration for ObjectivePressureConsideration {
         // Calculate if moving toward objective
         let current_dist = nearest_obj_dist;
         let target_obj_dist = context.objectives.flags.values()
-            .filter(|f| f.owning_faction != actor_faction)
+            .filter(|f| !f.is_held_by(actor_faction))
             .map(|f| target_pos.distance_to(&f.position))
             .min_by(|a, b| a.partial_cmp(b).unwrap())
             .unwrap_or(f32::MAX);
@@ -821,3 +912,83 @@ impl Consideration for NoEnemiesVisibleConsideration {
         "NoEnemiesVisible"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::personality::AIPersonality;
+    use crate::game_logic::world_setup::register_components;
+    use specs::{Builder, World, WorldExt};
+
+    #[test]
+    fn test_threatened_move_prefers_tile_with_fewer_friendlies() {
+        let mut world = World::new();
+        register_components(&mut world);
+        let soldier = |faction| Soldier {
+            name: "Soldier".to_string(),
+            faction,
+            rank: Rank::Private,
+        };
+        let actor = world
+            .create_entity()
+            .with(Position::new(10, 10))
+            .with(soldier(Faction::Allies))
+            .with(Health::new(100))
+            .with(Weapon::rifle())
+            .build();
+        let enemy = world
+            .create_entity()
+            .with(Position::new(10, 2))
+            .with(soldier(Faction::CentralPowers))
+            .build();
+        // Bunched up west of the actor; the east is clear
+        for (x, y) in [(7, 9), (7, 10), (7, 11)] {
+            world
+                .create_entity()
+                .with(Position::new(x, y))
+                .with(soldier(Faction::Allies))
+                .with(Health::new(100))
+                .build();
+        }
+
+        let battlefield = Battlefield::new(20, 20);
+        let objectives = Objectives::new();
+        let contacts = ContactTracker::new(0);
+        let visible_enemies = vec![enemy];
+        let positions = world.read_storage::<Position>();
+        let soldiers = world.read_storage::<Soldier>();
+        let healths = world.read_storage::<Health>();
+        let weapons = world.read_storage::<Weapon>();
+        let visions = world.read_storage::<Vision>();
+        let facings = world.read_storage::<Facing>();
+        let entities = world.entities();
+        let context_for = |target| ActionContext {
+            actor_entity: actor,
+            target_entity: None,
+            target_position: Some(target),
+            positions: &positions,
+            soldiers: &soldiers,
+            healths: &healths,
+            weapons: &weapons,
+            visions: &visions,
+            facings: &facings,
+            battlefield: &battlefield,
+            objectives: &objectives,
+            entities: &entities,
+            visible_enemies: &visible_enemies,
+            contacts: &contacts,
+            current_turn: 1,
+            timings: None,
+        };
+
+        let crowded = context_for(BattlefieldPos::new(9, 10));
+        let clear = context_for(BattlefieldPos::new(11, 10));
+        let balanced = AIPersonality::balanced();
+        let move_evaluator = balanced.evaluators.iter().find(|e| e.name == "Move").unwrap();
+
+        assert!(move_evaluator.evaluate(&clear) > move_evaluator.evaluate(&crowded));
+        let clustering = ClusteringConsideration::new(ResponseCurve::Linear);
+        assert_eq!(clustering.evaluate(&clear), 1.0);
+        assert!(clustering.evaluate(&crowded) < 0.5);
+    }
+}
@@ -0,0 +1,110 @@
+// Contact Tracking
+// Remembers how long each AI soldier has continuously seen each enemy
+
+use specs::Entity;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy)]
+struct ContactRecord {
+    first_seen_turn: u32,
+    last_seen_turn: u32,
+}
+
+/// Per (observer, target) contact durations for the "spot then shoot" rule
+///
+/// `required_turns` is how many full turns a target must stay in sight
+/// before it counts as an established contact. 0 disables the rule.
+#[derive(Debug, Clone, Default)]
+pub struct ContactTracker {
+    pub required_turns: u32,
+    contacts: HashMap<(Entity, Entity), ContactRecord>,
+}
+
+impl ContactTracker {
+    pub fn new(required_turns: u32) -> Self {
+        Self {
+            required_turns,
+            contacts: HashMap::new(),
+        }
+    }
+
+    /// Record that `observer` sees every entity in `visible` on `turn`
+    ///
+    /// A contact seen on the previous turn (or earlier this turn) continues;
+    /// one that was lost starts counting again from this turn.
+    pub fn observe(&mut self, observer: Entity, visible: &[Entity], turn: u32) {
+        for &target in visible {
+            let record = self
+                .contacts
+                .entry((observer, target))
+                .or_insert(ContactRecord {
+                    first_seen_turn: turn,
+                    last_seen_turn: turn,
+                });
+
+            if record.last_seen_turn + 1 < turn {
+                record.first_seen_turn = turn;
+            }
+            record.last_seen_turn = turn;
+        }
+    }
+
+    /// Number of turns `observer` has had `target` in continuous view
+    pub fn contact_turns(&self, observer: Entity, target: Entity, turn: u32) -> Option<u32> {
+        self.contacts
+            .get(&(observer, target))
+            .filter(|record| record.last_seen_turn + 1 >= turn)
+            .map(|record| turn.saturating_sub(record.first_seen_turn))
+    }
+
+    /// Whether `observer` may engage `target` under the spot-then-shoot rule
+    pub fn is_established(&self, observer: Entity, target: Entity, turn: u32) -> bool {
+        if self.required_turns == 0 {
+            return true;
+        }
+
+        self.contact_turns(observer, target, turn)
+            .is_some_and(|turns| turns >= self.required_turns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use specs::{Builder, World, WorldExt};
+
+    fn two_entities() -> (Entity, Entity) {
+        let mut world = World::new();
+        (world.create_entity().build(), world.create_entity().build())
+    }
+
+    #[test]
+    fn test_contact_established_after_one_turn() {
+        let (observer, target) = two_entities();
+        let mut tracker = ContactTracker::new(1);
+
+        tracker.observe(observer, &[target], 1);
+        assert!(!tracker.is_established(observer, target, 1));
+
+        tracker.observe(observer, &[target], 2);
+        assert!(tracker.is_established(observer, target, 2));
+    }
+
+    #[test]
+    fn test_lost_contact_resets() {
+        let (observer, target) = two_entities();
+        let mut tracker = ContactTracker::new(1);
+
+        tracker.observe(observer, &[target], 1);
+        tracker.observe(observer, &[target], 4);
+        assert!(!tracker.is_established(observer, target, 4));
+    }
+
+    #[test]
+    fn test_disabled_rule_always_established() {
+        let (observer, target) = two_entities();
+        let tracker = ContactTracker::new(0);
+
+        assert!(tracker.is_established(observer, target, 1));
+    }
+}
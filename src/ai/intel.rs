@@ -0,0 +1,116 @@
+// Faction Intel
+// Enemy sightings each side has pooled, with scout reports kept longer
+//
+// Every AI soldier reports what it sees into its faction's intel, so a
+// soldier with no enemy in its own view can still move on one a comrade
+// spotted. An ordinary sighting is only good for the turn it was made; a
+// soldier planning under the Scout personality files a report that stays
+// on the map for SCOUT_REPORT_TURNS.
+
+use crate::components::soldier::Faction;
+use crate::game_logic::battlefield::Position as BattlefieldPos;
+use specs::Entity;
+use std::collections::HashMap;
+
+/// Turns an ordinary sighting stays in the faction's intel
+pub const SIGHTING_TURNS: u32 = 1;
+/// Turns a scout's report stays in the faction's intel
+pub const SCOUT_REPORT_TURNS: u32 = 4;
+
+/// Where an enemy was reported, and until when the report holds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntelReport {
+    pub position: BattlefieldPos,
+    pub reported_turn: u32,
+    /// First turn the report no longer counts
+    pub expires_turn: u32,
+    pub by_scout: bool,
+}
+
+/// Resource: each faction's pooled enemy sightings
+#[derive(Debug, Clone, Default)]
+pub struct FactionIntel {
+    reports: HashMap<(Faction, Entity), IntelReport>,
+}
+
+impl FactionIntel {
+    /// File a sighting of `enemy` at `position` for the whole of `faction`
+    ///
+    /// The newest position always wins; a scout's longer hold on the report
+    /// is kept even if an ordinary soldier sees the same enemy later.
+    pub fn report(
+        &mut self,
+        faction: Faction,
+        enemy: Entity,
+        position: BattlefieldPos,
+        turn: u32,
+        by_scout: bool,
+    ) {
+        let hold = if by_scout { SCOUT_REPORT_TURNS } else { SIGHTING_TURNS };
+        let entry = self.reports.entry((faction, enemy)).or_insert(IntelReport {
+            position,
+            reported_turn: turn,
+            expires_turn: turn + hold,
+            by_scout,
+        });
+        entry.position = position;
+        entry.reported_turn = turn;
+        if turn + hold > entry.expires_turn {
+            entry.expires_turn = turn + hold;
+            entry.by_scout = by_scout;
+        }
+    }
+
+    /// Enemies `faction` knows about on `turn`
+    pub fn known_enemies(
+        &self,
+        faction: Faction,
+        turn: u32,
+    ) -> impl Iterator<Item = (Entity, &IntelReport)> + '_ {
+        self.reports
+            .iter()
+            .filter(move |((side, _), report)| *side == faction && turn < report.expires_turn)
+            .map(|((_, enemy), report)| (*enemy, report))
+    }
+
+    /// The reported enemy position nearest `from`, if any
+    pub fn nearest(&self, faction: Faction, from: &BattlefieldPos, turn: u32) -> Option<BattlefieldPos> {
+        self.known_enemies(faction, turn)
+            .map(|(_, report)| report.position)
+            .min_by(|a, b| from.distance_to(a).total_cmp(&from.distance_to(b)))
+    }
+
+    /// Drop reports that have run out
+    pub fn prune(&mut self, turn: u32) {
+        self.reports.retain(|_, report| turn < report.expires_turn);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use specs::{Builder, World, WorldExt};
+
+    #[test]
+    fn test_scout_reports_outlast_ordinary_sightings() {
+        let mut world = World::new();
+        let seen = world.create_entity().build();
+        let scouted = world.create_entity().build();
+
+        let mut intel = FactionIntel::default();
+        intel.report(Faction::Allies, seen, BattlefieldPos::new(1, 1), 3, false);
+        intel.report(Faction::Allies, scouted, BattlefieldPos::new(9, 9), 3, true);
+
+        let known = |intel: &FactionIntel, turn| {
+            intel
+                .known_enemies(Faction::Allies, turn)
+                .map(|(enemy, _)| enemy)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(known(&intel, 3).len(), 2);
+        assert_eq!(known(&intel, 4), vec![scouted]);
+        assert!(known(&intel, 3 + SCOUT_REPORT_TURNS).is_empty());
+        // The other side learns nothing
+        assert_eq!(intel.known_enemies(Faction::CentralPowers, 3).count(), 0);
+    }
+}
@@ -3,12 +3,29 @@
 
 pub mod action_generation;
 pub mod actions;
+pub mod consideration_timing;
 pub mod considerations;
+pub mod contact;
+pub mod intel;
+pub mod panic_fire;
 pub mod personality;
+pub mod personality_data;
 pub mod response_curves;
+pub mod retreat;
+pub mod rules_of_engagement;
+pub mod scoring_snapshot;
+pub mod standing_orders;
 
-pub use action_generation::{ActionGenerator, PossibleAction};
+pub use action_generation::{ActionGenerator, GenerationContext, PossibleAction};
 pub use actions::{ActionEvaluator, ScoreCombiner, ScoredAction};
+pub use consideration_timing::ConsiderationTimings;
 pub use considerations::{ActionContext, Consideration, NoEnemiesVisibleConsideration};
+pub use contact::ContactTracker;
+pub use intel::FactionIntel;
 pub use personality::AIPersonality;
+pub use personality_data::PersonalityLibrary;
 pub use response_curves::ResponseCurve;
+pub use retreat::RetreatOrder;
+pub use rules_of_engagement::RulesOfEngagement;
+pub use scoring_snapshot::{ScoringSnapshot, ScoringSnapshots};
+pub use standing_orders::{SideOrders, StandingOrders};
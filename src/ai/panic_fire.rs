@@ -0,0 +1,81 @@
+// Panic fire
+// Suppressed soldiers blazing away instead of fighting sensibly
+//
+// A soldier shot at recently whose morale has sunk below the threshold is
+// suppressed. Each planning pass a suppressed soldier may drop their plan and
+// fire wildly toward the nearest enemy they know of, seen or not: a shot at a
+// fraction of their usual accuracy that burns through PANIC_FIRE_ROUNDS.
+
+use crate::components::{action::ActionType, morale::Morale};
+use crate::config::game_config::PanicFireConfig;
+use crate::game_logic::battlefield::Position as BattlefieldPos;
+use rand::Rng;
+use specs::Entity;
+
+/// Whether a soldier is pinned down badly enough to panic
+pub fn is_suppressed(config: &PanicFireConfig, morale: Option<&Morale>, shot_at: bool) -> bool {
+    config.enabled && shot_at && morale.is_some_and(|morale| morale.current < config.morale_threshold)
+}
+
+/// Roll whether a suppressed soldier at `from` panics, and at whom
+///
+/// `known_enemies` are the enemies the soldier can see or has had reported;
+/// the nearest is fired on. None when the soldier keeps their head.
+pub fn roll_panic_fire(
+    config: &PanicFireConfig,
+    morale: Option<&Morale>,
+    shot_at: bool,
+    from: &BattlefieldPos,
+    known_enemies: &[(Entity, BattlefieldPos)],
+    rng: &mut impl Rng,
+) -> Option<ActionType> {
+    if !is_suppressed(config, morale, shot_at) {
+        return None;
+    }
+    let (target, _) = known_enemies
+        .iter()
+        .min_by(|(_, a), (_, b)| from.distance_to(a).total_cmp(&from.distance_to(b)))?;
+    rng.random_bool(config.chance.clamp(0.0, 1.0) as f64)
+        .then_some(ActionType::PanicFire { target: *target })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::game_rng::AiRng;
+    use specs::{Builder, World, WorldExt};
+
+    #[test]
+    fn test_heavily_suppressed_soldier_sometimes_panic_fires() {
+        let mut world = World::new();
+        let enemy = world.create_entity().build();
+        let far_enemy = world.create_entity().build();
+        let known = [
+            (far_enemy, BattlefieldPos::new(25, 5)),
+            (enemy, BattlefieldPos::new(12, 5)),
+        ];
+        let from = BattlefieldPos::new(5, 5);
+        let config = PanicFireConfig {
+            enabled: true,
+            ..PanicFireConfig::default()
+        };
+        let broken = Morale::new(0.0);
+        let mut rng = AiRng::new(3);
+
+        let panics = (0..100)
+            .filter_map(|_| roll_panic_fire(&config, Some(&broken), true, &from, &known, &mut rng))
+            .collect::<Vec<_>>();
+        assert!(!panics.is_empty(), "a pinned-down soldier should panic now and then");
+        assert!(
+            panics
+                .iter()
+                .all(|action| matches!(action, ActionType::PanicFire { target } if *target == enemy)),
+            "panic fire goes toward the nearest known enemy"
+        );
+
+        let steady = Morale::default();
+        assert!((0..100).all(|_| {
+            roll_panic_fire(&config, Some(&steady), true, &from, &known, &mut rng).is_none()
+        }));
+    }
+}
@@ -6,7 +6,7 @@ use crate::ai::{
         ObjectiveProximityConsideration, ThreatLevelConsideration,
         ExposedDangerConsideration, TacticalAdvantageConsideration, ForceBalanceConsideration,
         SupportProximityConsideration, ObjectivePressureConsideration, RetreatNecessityConsideration,
-        NoEnemiesVisibleConsideration,
+        NoEnemiesVisibleConsideration, EstablishedContactConsideration, ClusteringConsideration,
     },
     response_curves::ResponseCurve,
 };
@@ -24,6 +24,12 @@ impl AIPersonality {
         }
     }
 
+    /// Whether soldiers planning with this personality press an assault,
+    /// stepping toward a distant target as they fire at it
+    pub fn assaults(&self) -> bool {
+        self.name == "Aggressive"
+    }
+
     pub fn balanced() -> Self {
         let evaluators = vec![
             create_balanced_shoot_evaluator(),
@@ -108,6 +114,9 @@ fn create_balanced_shoot_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(HasLineOfSightConsideration::new(
             ResponseCurve::Boolean { threshold: 0.5 },
         )))
+        .with_gate(Box::new(EstablishedContactConsideration::new(
+            ResponseCurve::Boolean { threshold: 0.5 },
+        )))
         .with_consideration(Box::new(DistanceToTargetConsideration::new(
             ResponseCurve::Linear,
         )))
@@ -153,6 +162,9 @@ fn create_balanced_move_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(RetreatNecessityConsideration::new(
             ResponseCurve::Linear,
         )))
+        .with_consideration(Box::new(ClusteringConsideration::new(
+            ResponseCurve::Linear,
+        )))
         .with_combiner(ScoreCombiner::Average)
 }
 
@@ -200,6 +212,9 @@ fn create_aggressive_shoot_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(HasLineOfSightConsideration::new(
             ResponseCurve::Boolean { threshold: 0.5 },
         )))
+        .with_gate(Box::new(EstablishedContactConsideration::new(
+            ResponseCurve::Boolean { threshold: 0.5 },
+        )))
         .with_consideration(Box::new(DistanceToTargetConsideration::new(
             ResponseCurve::Inverse,
         )))
@@ -245,6 +260,9 @@ fn create_aggressive_move_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(RetreatNecessityConsideration::new(
             ResponseCurve::Polynomial { exponent: 2.0 },  // Only retreats when very hurt
         )))
+        .with_consideration(Box::new(ClusteringConsideration::new(
+            ResponseCurve::Linear,
+        )))
         .with_combiner(ScoreCombiner::Average)
 }
 
@@ -292,6 +310,9 @@ fn create_defensive_shoot_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(HasLineOfSightConsideration::new(
             ResponseCurve::Boolean { threshold: 0.5 },
         )))
+        .with_gate(Box::new(EstablishedContactConsideration::new(
+            ResponseCurve::Boolean { threshold: 0.5 },
+        )))
         .with_consideration(Box::new(DistanceToTargetConsideration::new(
             ResponseCurve::Inverse,
         )))
@@ -340,6 +361,9 @@ fn create_defensive_move_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(RetreatNecessityConsideration::new(
             ResponseCurve::Linear,  // Retreats readily when hurt
         )))
+        .with_consideration(Box::new(ClusteringConsideration::new(
+            ResponseCurve::Linear,
+        )))
         .with_combiner(ScoreCombiner::Average)
 }
 
@@ -387,6 +411,9 @@ fn create_objective_shoot_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(HasLineOfSightConsideration::new(
             ResponseCurve::Boolean { threshold: 0.5 },
         )))
+        .with_gate(Box::new(EstablishedContactConsideration::new(
+            ResponseCurve::Boolean { threshold: 0.5 },
+        )))
         .with_consideration(Box::new(DistanceToTargetConsideration::new(
             ResponseCurve::Inverse,
         )))
@@ -432,6 +459,9 @@ fn create_objective_move_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(RetreatNecessityConsideration::new(
             ResponseCurve::Polynomial { exponent: 2.0 },  // Mission-focused, retreats reluctantly
         )))
+        .with_consideration(Box::new(ClusteringConsideration::new(
+            ResponseCurve::Linear,
+        )))
         .with_combiner(ScoreCombiner::Average)
 }
 
@@ -479,6 +509,9 @@ fn create_scout_shoot_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(HasLineOfSightConsideration::new(
             ResponseCurve::Boolean { threshold: 0.5 },
         )))
+        .with_gate(Box::new(EstablishedContactConsideration::new(
+            ResponseCurve::Boolean { threshold: 0.5 },
+        )))
         .with_consideration(Box::new(DistanceToTargetConsideration::new(
             ResponseCurve::Inverse,
         )))
@@ -524,6 +557,9 @@ fn create_scout_move_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(RetreatNecessityConsideration::new(
             ResponseCurve::Polynomial { exponent: 2.0 },
         )))
+        .with_consideration(Box::new(ClusteringConsideration::new(
+            ResponseCurve::Linear,
+        )))
         .with_combiner(ScoreCombiner::Average)
 }
 
@@ -571,6 +607,9 @@ fn create_rearguard_shoot_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(HasLineOfSightConsideration::new(
             ResponseCurve::Boolean { threshold: 0.5 },
         )))
+        .with_gate(Box::new(EstablishedContactConsideration::new(
+            ResponseCurve::Boolean { threshold: 0.5 },
+        )))
         .with_consideration(Box::new(DistanceToTargetConsideration::new(
             ResponseCurve::Inverse,
         )))
@@ -616,6 +655,9 @@ fn create_rearguard_move_evaluator() -> ActionEvaluator {
         .with_consideration(Box::new(RetreatNecessityConsideration::new(
             ResponseCurve::Linear,
         )))
+        .with_consideration(Box::new(ClusteringConsideration::new(
+            ResponseCurve::Linear,
+        )))
         .with_combiner(ScoreCombiner::Average)
 }
 
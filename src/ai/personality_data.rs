@@ -0,0 +1,287 @@
+// AI personality data file
+// Personality definitions loaded from TOML, overriding the compiled personalities by name
+
+use crate::ai::{
+    actions::{ActionEvaluator, ScoreCombiner},
+    considerations::{
+        AlliesNearbyConsideration, AmmoLevelConsideration, ClusteringConsideration, Consideration,
+        CoverQualityConsideration, DistanceToTargetConsideration, EstablishedContactConsideration,
+        ExposedDangerConsideration, ForceBalanceConsideration, HasLineOfSightConsideration,
+        HealthLevelConsideration, NearbyOfficerConsideration, NoEnemiesVisibleConsideration,
+        ObjectivePressureConsideration, ObjectiveProximityConsideration,
+        RetreatNecessityConsideration, SupportProximityConsideration,
+        TacticalAdvantageConsideration, ThreatLevelConsideration,
+    },
+    personality::AIPersonality,
+    response_curves::ResponseCurve,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+/// Default location of the personality file, relative to the working directory
+pub const PERSONALITIES_PATH: &str = "data/ai_personalities.toml";
+
+/// Personality definitions read from a data file
+///
+/// Definitions are validated on load and rebuilt on demand, since evaluators
+/// hold boxed considerations and cannot be cloned. Personalities not listed
+/// in the file keep their compiled definitions.
+#[derive(Debug, Clone, Default)]
+pub struct PersonalityLibrary {
+    definitions: HashMap<String, PersonalityDef>,
+}
+
+/// Error loading or parsing a personality file
+#[derive(Debug)]
+pub enum PersonalityDataError {
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+    UnknownConsideration(String),
+}
+
+impl fmt::Display for PersonalityDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersonalityDataError::Io(e) => write!(f, "could not read AI personalities: {}", e),
+            PersonalityDataError::Parse(e) => write!(f, "invalid AI personality file: {}", e),
+            PersonalityDataError::UnknownConsideration(name) => {
+                write!(f, "unknown AI consideration '{}'", name)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PersonalityFile {
+    #[serde(default)]
+    personality: Vec<PersonalityDef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PersonalityDef {
+    name: String,
+    evaluator: Vec<EvaluatorDef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EvaluatorDef {
+    name: String,
+    base_score: f32,
+    combiner: CombinerDef,
+    #[serde(default)]
+    considerations: Vec<ConsiderationDef>,
+    #[serde(default)]
+    gates: Vec<ConsiderationDef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConsiderationDef {
+    name: String,
+    curve: CurveDef,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", deny_unknown_fields)]
+enum CurveDef {
+    Linear,
+    Polynomial { exponent: f32 },
+    Logistic { midpoint: f32, steepness: f32 },
+    Inverse,
+    InverseSquared,
+    Boolean { threshold: f32 },
+    Step { thresholds: Vec<(f32, f32)> },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", deny_unknown_fields)]
+enum CombinerDef {
+    Multiplicative,
+    Average,
+    WeightedAverage { base_weight: f32 },
+    WeightedSum { weights: Vec<f32> },
+    Minimum,
+}
+
+impl From<CurveDef> for ResponseCurve {
+    fn from(curve: CurveDef) -> Self {
+        match curve {
+            CurveDef::Linear => ResponseCurve::Linear,
+            CurveDef::Polynomial { exponent } => ResponseCurve::Polynomial { exponent },
+            CurveDef::Logistic { midpoint, steepness } => {
+                ResponseCurve::Logistic { midpoint, steepness }
+            }
+            CurveDef::Inverse => ResponseCurve::Inverse,
+            CurveDef::InverseSquared => ResponseCurve::InverseSquared,
+            CurveDef::Boolean { threshold } => ResponseCurve::Boolean { threshold },
+            CurveDef::Step { thresholds } => ResponseCurve::Step { thresholds },
+        }
+    }
+}
+
+impl From<CombinerDef> for ScoreCombiner {
+    fn from(combiner: CombinerDef) -> Self {
+        match combiner {
+            CombinerDef::Multiplicative => ScoreCombiner::Multiplicative,
+            CombinerDef::Average => ScoreCombiner::Average,
+            CombinerDef::WeightedAverage { base_weight } => {
+                ScoreCombiner::WeightedAverage { base_weight }
+            }
+            CombinerDef::WeightedSum { weights } => ScoreCombiner::WeightedSum { weights },
+            CombinerDef::Minimum => ScoreCombiner::Minimum,
+        }
+    }
+}
+
+impl ConsiderationDef {
+    /// Map the consideration's `name()` to its concrete type
+    fn build(&self) -> Result<Box<dyn Consideration>, PersonalityDataError> {
+        let curve = ResponseCurve::from(self.curve.clone());
+        let consideration: Box<dyn Consideration> = match self.name.as_str() {
+            "DistanceToTarget" => Box::new(DistanceToTargetConsideration::new(curve)),
+            "AmmoLevel" => Box::new(AmmoLevelConsideration::new(curve)),
+            "HealthLevel" => Box::new(HealthLevelConsideration::new(curve)),
+            "HasLineOfSight" => Box::new(HasLineOfSightConsideration::new(curve)),
+            "EstablishedContact" => Box::new(EstablishedContactConsideration::new(curve)),
+            "ThreatLevel" => Box::new(ThreatLevelConsideration::new(curve)),
+            "CoverQuality" => Box::new(CoverQualityConsideration::new(curve)),
+            "ObjectiveProximity" => Box::new(ObjectiveProximityConsideration::new(curve)),
+            "AlliesNearby" => Box::new(AlliesNearbyConsideration::new(curve)),
+            "NearbyOfficer" => Box::new(NearbyOfficerConsideration::new(curve)),
+            "ExposedDanger" => Box::new(ExposedDangerConsideration::new(curve)),
+            "TacticalAdvantage" => Box::new(TacticalAdvantageConsideration::new(curve)),
+            "ForceBalance" => Box::new(ForceBalanceConsideration::new(curve)),
+            "SupportProximity" => Box::new(SupportProximityConsideration::new(curve)),
+            "ObjectivePressure" => Box::new(ObjectivePressureConsideration::new(curve)),
+            "RetreatNecessity" => Box::new(RetreatNecessityConsideration::new(curve)),
+            "NoEnemiesVisible" => Box::new(NoEnemiesVisibleConsideration::new(curve)),
+            "Clustering" => Box::new(ClusteringConsideration::new(curve)),
+            other => return Err(PersonalityDataError::UnknownConsideration(other.to_string())),
+        };
+        Ok(consideration)
+    }
+}
+
+impl EvaluatorDef {
+    fn build(&self) -> Result<ActionEvaluator, PersonalityDataError> {
+        let mut evaluator = ActionEvaluator::new(self.name.clone(), self.base_score)
+            .with_combiner(self.combiner.clone().into());
+        for consideration in &self.considerations {
+            evaluator = evaluator.with_consideration(consideration.build()?);
+        }
+        for gate in &self.gates {
+            evaluator = evaluator.with_gate(gate.build()?);
+        }
+        Ok(evaluator)
+    }
+}
+
+impl PersonalityDef {
+    fn build(&self) -> Result<AIPersonality, PersonalityDataError> {
+        let evaluators = self
+            .evaluator
+            .iter()
+            .map(EvaluatorDef::build)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(AIPersonality::new(self.name.clone(), evaluators))
+    }
+}
+
+impl PersonalityLibrary {
+    /// Build the named personality, if the data file defines it
+    pub fn get(&self, name: &str) -> Option<AIPersonality> {
+        // Every definition was built once in from_toml_str, so this cannot fail
+        self.definitions.get(name).and_then(|def| def.build().ok())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+
+    /// Parse a TOML document, rejecting unknown considerations up front
+    pub fn from_toml_str(contents: &str) -> Result<Self, PersonalityDataError> {
+        let file: PersonalityFile =
+            toml::from_str(contents).map_err(PersonalityDataError::Parse)?;
+
+        let mut definitions = HashMap::new();
+        for def in file.personality {
+            def.build()?;
+            definitions.insert(def.name.clone(), def);
+        }
+
+        Ok(Self { definitions })
+    }
+
+    /// Load a personality file; a missing file yields an empty library
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PersonalityDataError> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::from_toml_str(&contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(PersonalityDataError::Io(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn describe(personality: &AIPersonality) -> Vec<(String, f32, Vec<String>, Vec<String>)> {
+        personality
+            .evaluators
+            .iter()
+            .map(|e| {
+                (
+                    e.name.clone(),
+                    e.base_score,
+                    e.considerations.iter().map(|c| c.name().to_string()).collect(),
+                    e.gates.iter().map(|g| g.name().to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_shipped_file_reproduces_balanced_personality() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(PERSONALITIES_PATH);
+        let library = PersonalityLibrary::load(path).unwrap();
+        let loaded = library.get("Balanced").expect("Balanced not defined");
+        let compiled = AIPersonality::balanced();
+
+        assert_eq!(loaded.evaluators.len(), compiled.evaluators.len());
+        assert_eq!(describe(&loaded), describe(&compiled));
+    }
+
+    #[test]
+    fn test_unknown_consideration_is_rejected() {
+        let result = PersonalityLibrary::from_toml_str(
+            r#"
+            [[personality]]
+            name = "Broken"
+
+            [[personality.evaluator]]
+            name = "Shoot"
+            base_score = 1.0
+            combiner = { type = "Average" }
+            considerations = [{ name = "Bravado", curve = { type = "Linear" } }]
+            "#,
+        );
+
+        assert!(matches!(
+            result,
+            Err(PersonalityDataError::UnknownConsideration(name)) if name == "Bravado"
+        ));
+    }
+
+    #[test]
+    fn test_missing_file_yields_empty_library() {
+        let library = PersonalityLibrary::load("does/not/exist.toml").unwrap();
+        assert!(library.is_empty());
+        assert!(library.get("Balanced").is_none());
+    }
+}
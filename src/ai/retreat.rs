@@ -0,0 +1,56 @@
+// Retreat order
+// Player-issued order for the whole friendly side to disengage and fall back
+
+/// Turns a retreat order stays in force once issued
+pub const RETREAT_TURNS: u32 = 5;
+
+/// Resource: timed fall-back order for friendly AI
+///
+/// While active, friendly AI holds its fire and paths back to the friendly
+/// spawn zone instead of weighing objectives and targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RetreatOrder {
+    /// Last turn the order is in force (None = no order)
+    until_turn: Option<u32>,
+}
+
+impl RetreatOrder {
+    /// Order a retreat lasting RETREAT_TURNS turns, starting with this one
+    pub fn issue(&mut self, current_turn: u32) {
+        self.until_turn = Some(current_turn + RETREAT_TURNS - 1);
+    }
+
+    pub fn cancel(&mut self) {
+        self.until_turn = None;
+    }
+
+    pub fn is_active(&self, current_turn: u32) -> bool {
+        self.until_turn.is_some_and(|until| current_turn <= until)
+    }
+
+    /// Turns the order still has to run, counting this one
+    pub fn turns_left(&self, current_turn: u32) -> u32 {
+        self.until_turn
+            .map_or(0, |until| (until + 1).saturating_sub(current_turn))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_expires_after_retreat_turns() {
+        let mut order = RetreatOrder::default();
+        assert!(!order.is_active(3));
+
+        order.issue(3);
+        assert!(order.is_active(3));
+        assert!(order.is_active(3 + RETREAT_TURNS - 1));
+        assert!(!order.is_active(3 + RETREAT_TURNS));
+        assert_eq!(order.turns_left(4), RETREAT_TURNS - 1);
+
+        order.cancel();
+        assert_eq!(order.turns_left(4), 0);
+    }
+}
@@ -0,0 +1,39 @@
+// Rules of Engagement
+// Player-set fire discipline for AI soldiers on the player's side
+
+/// Resource: when friendly AI is allowed to open fire
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RulesOfEngagement {
+    /// Engage any valid target
+    #[default]
+    OpenFire,
+    /// Only return fire, or fire on enemies at arm's length (ambushes)
+    HoldFire,
+}
+
+/// Enemies at or within this distance may always be engaged
+const ADJACENT_DISTANCE: f32 = 1.5;
+
+impl RulesOfEngagement {
+    pub fn toggle(self) -> Self {
+        match self {
+            RulesOfEngagement::OpenFire => RulesOfEngagement::HoldFire,
+            RulesOfEngagement::HoldFire => RulesOfEngagement::OpenFire,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RulesOfEngagement::OpenFire => "Open Fire",
+            RulesOfEngagement::HoldFire => "Hold Fire",
+        }
+    }
+
+    /// Whether a shot at a target `distance` tiles away is allowed
+    pub fn permits_shot(&self, distance: f32, under_fire: bool) -> bool {
+        match self {
+            RulesOfEngagement::OpenFire => true,
+            RulesOfEngagement::HoldFire => under_fire || distance <= ADJACENT_DISTANCE,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// AI Scoring Snapshots
+// Per-entity record of the last planning pass, for the debug tuning overlay
+
+use crate::components::action::ActionType;
+use specs::Entity;
+use std::collections::HashMap;
+
+/// One scored action and how each consideration contributed
+#[derive(Debug, Clone)]
+pub struct ActionScoreEntry {
+    pub action_type: ActionType,
+    pub score: f32,
+    /// Name of the evaluator that produced the winning score
+    pub evaluator: Option<String>,
+    /// (consideration name, score) pairs from that evaluator, gates included
+    pub considerations: Vec<(String, f32)>,
+}
+
+/// Everything an AI soldier weighed in its last planning pass
+#[derive(Debug, Clone, Default)]
+pub struct ScoringSnapshot {
+    pub turn: u32,
+    pub personality: String,
+    pub actions: Vec<ActionScoreEntry>,
+    /// Index into `actions` of the one the planner picked, ties settled
+    pub chosen: Option<usize>,
+}
+
+impl ScoringSnapshot {
+    /// The action the planner picked
+    pub fn best(&self) -> Option<&ActionScoreEntry> {
+        self.chosen.and_then(|index| self.actions.get(index))
+    }
+}
+
+/// Resource: cached scoring snapshots keyed by entity
+///
+/// Recording costs an allocation per action, so it is off until the debug
+/// overlay (or a test) turns it on.
+#[derive(Debug, Clone, Default)]
+pub struct ScoringSnapshots {
+    pub recording: bool,
+    snapshots: HashMap<Entity, ScoringSnapshot>,
+}
+
+impl ScoringSnapshots {
+    pub fn record(&mut self, entity: Entity, snapshot: ScoringSnapshot) {
+        self.snapshots.insert(entity, snapshot);
+    }
+
+    pub fn get(&self, entity: Entity) -> Option<&ScoringSnapshot> {
+        self.snapshots.get(&entity)
+    }
+
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
@@ -0,0 +1,50 @@
+// Standing orders
+// The fire discipline and retreat order each side's AI is under
+//
+// Orders bind only the side they were given to. The player commands their
+// own side; a side nobody commands, as in headless AI-vs-AI matches, keeps
+// the defaults of open fire and no retreat.
+
+use crate::ai::{retreat::RetreatOrder, rules_of_engagement::RulesOfEngagement};
+use crate::components::soldier::Faction;
+use std::collections::HashMap;
+
+/// One side's orders
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SideOrders {
+    pub roe: RulesOfEngagement,
+    pub retreat: RetreatOrder,
+}
+
+/// Resource: standing orders per side
+#[derive(Debug, Clone, Default)]
+pub struct StandingOrders {
+    sides: HashMap<Faction, SideOrders>,
+}
+
+impl StandingOrders {
+    pub fn get(&self, faction: Faction) -> SideOrders {
+        self.sides.get(&faction).copied().unwrap_or_default()
+    }
+
+    pub fn get_mut(&mut self, faction: Faction) -> &mut SideOrders {
+        self.sides.entry(faction).or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orders_bind_only_their_own_side() {
+        let mut orders = StandingOrders::default();
+        orders.get_mut(Faction::CentralPowers).roe = RulesOfEngagement::HoldFire;
+        orders.get_mut(Faction::CentralPowers).retreat.issue(2);
+
+        assert_eq!(orders.get(Faction::Allies), SideOrders::default());
+        let central = orders.get(Faction::CentralPowers);
+        assert_eq!(central.roe, RulesOfEngagement::HoldFire);
+        assert!(central.retreat.is_active(2));
+    }
+}
@@ -1,20 +1,47 @@
 // Action component for turn-based action system
 
+use crate::components::health::Health;
+use crate::config::game_config::WoundedConfig;
 use specs::{Component, Entity, VecStorage};
 
+/// How many times longer a crawl takes than walking the same tile
+pub const CRAWL_TIME_MULTIPLIER: f32 = 2.5;
+
+/// Seconds it takes to string a coil of barbed wire: most of a turn's work
+pub const LAY_WIRE_TIME: f32 = 10.0;
+
 /// Types of actions entities can perform
 #[derive(Debug, Clone)]
 pub enum ActionType {
     /// Move in a direction with terrain cost multiplier
     Move { dx: i32, dy: i32, terrain_cost: f32 },
+    /// Crawl one tile: slower than a move, but leaves the soldier prone
+    Crawl { dx: i32, dy: i32, terrain_cost: f32 },
     /// Rotate facing direction (true = clockwise, false = counter-clockwise)
     Rotate { clockwise: bool },
     /// Shoot at a target entity
     Shoot { target: Entity },
+    /// Step one tile, then fire at a target without stopping to aim
+    MoveAndFire {
+        dx: i32,
+        dy: i32,
+        terrain_cost: f32,
+        target: Entity,
+    },
+    /// Fire wildly at a target, seen or not: a poor shot that wastes rounds
+    PanicFire { target: Entity },
+    /// Steady the aim: the next shot gets the weapon's aim bonus
+    Aim,
     /// Reload weapon
     Reload,
+    /// Switch between primary and secondary weapon
+    SwapWeapon,
     /// Throw grenade at position
     ThrowGrenade { target_x: i32, target_y: i32 },
+    /// String a coil of barbed wire across the adjacent tile in a direction
+    LayWire { dx: i32, dy: i32 },
+    /// Take ammo (or the weapon itself) from a corpse on your tile
+    Loot,
     /// Wait/do nothing
     Wait,
 }
@@ -25,13 +52,37 @@ impl ActionType {
     pub fn base_time_cost(&self) -> f32 {
         match self {
             ActionType::Move { terrain_cost, .. } => 1.5 * terrain_cost, // Updated: 2.0 -> 1.5
+            ActionType::Crawl { terrain_cost, .. } => 1.5 * terrain_cost * CRAWL_TIME_MULTIPLIER,
             ActionType::Rotate { .. } => 0.3, // New: Rotation cost
             ActionType::Shoot { .. } => 3.0,
+            // A step and a shot back to back, so dearer than either alone
+            ActionType::MoveAndFire { terrain_cost, .. } => 1.5 * terrain_cost + 3.0,
+            ActionType::PanicFire { .. } => 3.0,
+            ActionType::Aim => 3.0,
             ActionType::Reload => 5.0,
+            ActionType::SwapWeapon => 1.5,
             ActionType::ThrowGrenade { .. } => 4.0,
+            ActionType::LayWire { .. } => LAY_WIRE_TIME,
+            ActionType::Loot => 4.0,
             ActionType::Wait => 1.0,
         }
     }
+
+    /// Time cost for an actor in the given health carrying the given load
+    ///
+    /// Badly wounded soldiers move slower, and so do overloaded ones; `load`
+    /// is the multiplier from soldier_stats::load_factor (1.0 = no effect).
+    pub fn time_cost_for(&self, health: Option<&Health>, load: f32, wounded: &WoundedConfig) -> f32 {
+        let cost = self.base_time_cost();
+        if !matches!(
+            self,
+            ActionType::Move { .. } | ActionType::Crawl { .. } | ActionType::MoveAndFire { .. }
+        ) {
+            return cost;
+        }
+        let wound_factor = health.map_or(1.0, |health| wounded.movement_factor(health.percentage()));
+        cost * wound_factor * load
+    }
 }
 
 /// Component: A queued action on an entity
@@ -88,4 +139,68 @@ impl OngoingAction {
     pub fn progress_time(&mut self, delta: f32) {
         self.time_completed += delta;
     }
+
+    /// Throw away the work done so far
+    pub fn reset_progress(&mut self) {
+        self.time_completed = 0.0;
+    }
+
+    pub fn is_reload(&self) -> bool {
+        matches!(self.action_type, ActionType::Reload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_badly_wounded_soldier_moves_slower() {
+        let wounded = WoundedConfig::default();
+        let step = ActionType::Move {
+            dx: 1,
+            dy: 0,
+            terrain_cost: 1.0,
+        };
+
+        let healthy = Health::soldier();
+        let mut hurt = Health::soldier();
+        hurt.take_damage(80);
+
+        assert_eq!(step.time_cost_for(Some(&healthy), 1.0, &wounded), step.base_time_cost());
+        assert!(step.time_cost_for(Some(&hurt), 1.0, &wounded) > step.base_time_cost());
+
+        // Only movement is slowed
+        assert_eq!(ActionType::Reload.time_cost_for(Some(&hurt), 1.0, &wounded), ActionType::Reload.base_time_cost());
+    }
+
+    #[test]
+    fn test_overloaded_soldier_moves_slower() {
+        use crate::components::{
+            inventory::Inventory,
+            soldier::Rank,
+            soldier_stats::{load_factor, SoldierStats},
+            weapon::Weapon,
+        };
+
+        let wounded = WoundedConfig::default();
+        let step = ActionType::Move {
+            dx: 1,
+            dy: 0,
+            terrain_cost: 1.0,
+        };
+        let stats = SoldierStats::default_for_rank(&Rank::Private);
+        let rifle = Weapon::rifle();
+
+        let standard = load_factor(Some(&stats), Some(&Inventory::for_rank(Rank::Private)), Some(&rifle));
+        let overloaded = load_factor(Some(&stats), Some(&Inventory::new(8, 2, 2)), Some(&rifle));
+        assert_eq!(standard, 1.0);
+        assert!(overloaded > 1.0);
+
+        let healthy = Health::soldier();
+        let within = step.time_cost_for(Some(&healthy), standard, &wounded);
+        let over = step.time_cost_for(Some(&healthy), overloaded, &wounded);
+        assert!(over > within, "overloaded step took {} vs {}", over, within);
+        assert_eq!(ActionType::Reload.time_cost_for(Some(&healthy), overloaded, &wounded), 5.0);
+    }
 }
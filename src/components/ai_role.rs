@@ -0,0 +1,72 @@
+// AI role component
+// A soldier's standing job in the squad, settled once when the battle starts
+//
+// Most privates are riflemen; a few are picked out as scouts or rearguards.
+// The role decides which AI personality plans for them, so it holds from
+// one turn to the next instead of being rolled afresh each planning pass.
+
+use crate::components::soldier::Rank;
+use rand::Rng;
+use specs::{Component, VecStorage};
+
+/// Extra tiles a scout sees, and so adds to the side's shared vision
+pub const SCOUT_VISION_BONUS: i32 = 4;
+
+/// Component: a soldier's AI role (soldiers without one plan as riflemen)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AiRole {
+    #[default]
+    Rifleman,
+    /// Sees further, reports what it sees to the side for longer, and is the
+    /// side's sniper: the one who stops to aim a long shot
+    Scout,
+    Rearguard,
+}
+
+impl Component for AiRole {
+    type Storage = VecStorage<Self>;
+}
+
+impl AiRole {
+    /// Roll a role for a new soldier: 1 private in 40 scouts, 1 in 40 holds the rear
+    ///
+    /// NCOs and officers lead rather than specialise, so they are always riflemen.
+    pub fn roll(rank: Rank, rng: &mut impl Rng) -> Self {
+        if rank != Rank::Private {
+            return AiRole::Rifleman;
+        }
+
+        let roll = rng.random_range(0.0..1.0);
+        if roll < 0.025 {
+            AiRole::Scout
+        } else if roll < 0.05 {
+            AiRole::Rearguard
+        } else {
+            AiRole::Rifleman
+        }
+    }
+
+    pub fn is_scout(&self) -> bool {
+        matches!(self, AiRole::Scout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::game_rng::AiRng;
+
+    #[test]
+    fn test_only_privates_are_picked_out() {
+        let mut rng = AiRng::new(7);
+        for rank in [Rank::Corporal, Rank::Sergeant, Rank::Lieutenant, Rank::Captain] {
+            assert!((0..200).all(|_| AiRole::roll(rank, &mut rng) == AiRole::Rifleman));
+        }
+
+        let roles: Vec<AiRole> = (0..1000).map(|_| AiRole::roll(Rank::Private, &mut rng)).collect();
+        let count = |role| roles.iter().filter(|r| **r == role).count();
+        assert!(count(AiRole::Scout) > 10 && count(AiRole::Scout) < 50);
+        assert!(count(AiRole::Rearguard) > 10 && count(AiRole::Rearguard) < 50);
+        assert!(count(AiRole::Rifleman) > 900);
+    }
+}
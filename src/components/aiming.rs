@@ -0,0 +1,14 @@
+// Aiming marker component
+
+use specs::{Component, NullStorage};
+
+/// Marker component: Soldier has taken time to steady their aim
+///
+/// The next shot gets the weapon's aim bonus. Moving or coming under fire
+/// throws the aim off.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Aiming;
+
+impl Component for Aiming {
+    type Storage = NullStorage<Self>;
+}
@@ -0,0 +1,43 @@
+// Blood trail component
+// Tiles a badly wounded soldier has bled on, left for pursuers to follow
+
+use crate::game_logic::battlefield::Position;
+use specs::{Component, VecStorage};
+
+/// One spot of blood and the turn it was left
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BloodDrop {
+    pub position: Position,
+    pub turn: u32,
+}
+
+/// Component: recent blood drops along a wounded soldier's path, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct BloodTrail {
+    pub drops: Vec<BloodDrop>,
+}
+
+impl Component for BloodTrail {
+    type Storage = VecStorage<Self>;
+}
+
+impl BloodTrail {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Leave a drop on `position` during `turn`
+    pub fn record(&mut self, position: Position, turn: u32) {
+        self.drops.push(BloodDrop { position, turn });
+    }
+
+    /// Drop anything older than `duration_turns` turns
+    pub fn prune(&mut self, current_turn: u32, duration_turns: u32) {
+        self.drops
+            .retain(|drop| current_turn.saturating_sub(drop.turn) < duration_turns);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.drops.is_empty()
+    }
+}
@@ -0,0 +1,31 @@
+// Detonations
+// Where grenades and mines went off since the game loop last looked
+
+use crate::game_logic::battlefield::Position;
+
+/// Resource: blast points since the last dispatch was checked for impact feedback
+///
+/// Filled by ActionExecutionSystem (grenades) and MineDetonationSystem; the
+/// game loop hands them to ImpactFlash and clears them.
+#[derive(Debug, Clone, Default)]
+pub struct Detonations {
+    points: Vec<Position>,
+}
+
+impl Detonations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, pos: Position) {
+        self.points.push(pos);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Position> {
+        self.points.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+}
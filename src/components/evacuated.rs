@@ -0,0 +1,14 @@
+// Evacuated marker component
+
+use specs::{Component, NullStorage};
+
+/// Marker component: Wounded soldier was carried off at their side's aid post
+///
+/// Evacuated soldiers leave the map (no Position or TimeBudget) but are not
+/// casualties; each one counts as a save for their side.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Evacuated;
+
+impl Component for Evacuated {
+    type Storage = NullStorage<Self>;
+}
@@ -0,0 +1,81 @@
+// Heard shots
+// Where unseen gunfire came from, as the player hears it
+//
+// When a soldier the player cannot see fires within earshot, the shot's
+// origin is noted for a few turns. Unlike a last-seen marker, which stays
+// until the enemy turns up again, it fades almost at once and is only ever
+// a guess: enough to say where the shooting is, not to track the shooter.
+
+use crate::game_logic::battlefield::Position;
+use rand::Rng;
+
+/// Tiles within which the player hears a shot fired
+pub const HEARING_RANGE: f32 = 40.0;
+
+/// How far off a heard shot may be placed, in tiles per tile of distance
+pub const HEARING_BLUR: f32 = 0.1;
+
+/// Where a listener at `ear` makes out a shot fired from `origin` to be
+///
+/// Off by up to a tile in each direction for every ten tiles away, so
+/// distant fire gives only the rough direction.
+pub fn misjudge_origin(origin: Position, ear: Position, rng: &mut impl Rng) -> Position {
+    let spread = (ear.distance_to(&origin) * HEARING_BLUR).round() as i32;
+    Position::new(
+        origin.x + rng.random_range(-spread..=spread),
+        origin.y + rng.random_range(-spread..=spread),
+    )
+}
+
+/// One unseen shot's origin and when it was heard
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeardShot {
+    pub origin: Position,
+    pub heard_turn: u32,
+}
+
+/// Resource: recently heard shots, oldest first
+///
+/// Filled by ActionExecutionSystem; each marker lasts `fade_turns` turns
+/// (0 turns them off).
+#[derive(Debug, Clone, Default)]
+pub struct HeardShots {
+    pub fade_turns: u32,
+    shots: Vec<HeardShot>,
+}
+
+impl HeardShots {
+    pub fn new(fade_turns: u32) -> Self {
+        Self {
+            fade_turns,
+            shots: Vec::new(),
+        }
+    }
+
+    /// Note a shot heard from `origin` (ignored while markers are off)
+    pub fn record(&mut self, origin: Position, turn: u32) {
+        if self.fade_turns == 0 {
+            return;
+        }
+        self.shots.retain(|shot| shot.origin != origin);
+        self.shots.push(HeardShot {
+            origin,
+            heard_turn: turn,
+        });
+    }
+
+    /// Shots still showing on `turn`, with how many turns old each is
+    pub fn active(&self, turn: u32) -> impl Iterator<Item = (&HeardShot, u32)> {
+        self.shots
+            .iter()
+            .map(move |shot| (shot, turn.saturating_sub(shot.heard_turn)))
+            .filter(|(_, age)| *age < self.fade_turns)
+    }
+
+    /// Drop markers that have faded by `turn`
+    pub fn prune(&mut self, turn: u32) {
+        let fade_turns = self.fade_turns;
+        self.shots
+            .retain(|shot| turn.saturating_sub(shot.heard_turn) < fade_turns);
+    }
+}
@@ -0,0 +1,112 @@
+// Inventory component for consumable equipment
+
+use crate::components::{action::ActionType, soldier::Rank, weapon::Weapon};
+use specs::{Component, VecStorage};
+
+/// Load units a grenade weighs, against a soldier's carrying capacity
+pub const GRENADE_LOAD: i32 = 3;
+/// Load units a smoke grenade weighs
+pub const SMOKE_LOAD: i32 = 2;
+/// Load units a medkit weighs
+pub const MEDKIT_LOAD: i32 = 2;
+/// Load units a coil of barbed wire weighs
+pub const WIRE_LOAD: i32 = 4;
+/// Rounds of ammunition that make up one load unit
+pub const ROUNDS_PER_LOAD: i32 = 5;
+
+/// Component: Consumables carried by a soldier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    pub grenades: u32,
+    pub smoke_grenades: u32,
+    pub medkits: u32,
+    /// Coils of barbed wire, each enough to wire one tile
+    pub wire_coils: u32,
+}
+
+impl Component for Inventory {
+    type Storage = VecStorage<Self>;
+}
+
+impl Inventory {
+    pub fn new(grenades: u32, smoke_grenades: u32, medkits: u32) -> Self {
+        Self {
+            grenades,
+            smoke_grenades,
+            medkits,
+            wire_coils: 0,
+        }
+    }
+
+    /// Also carry `coils` of barbed wire
+    pub fn with_wire(mut self, coils: u32) -> Self {
+        self.wire_coils = coils;
+        self
+    }
+
+    pub fn empty() -> Self {
+        Self::new(0, 0, 0)
+    }
+
+    /// Standard loadout for a rank
+    ///
+    /// NCOs carry smoke to cover their section; officers carry the medkits.
+    pub fn for_rank(rank: Rank) -> Self {
+        match rank {
+            Rank::Private => Self::new(2, 0, 0),
+            Rank::Corporal => Self::new(2, 1, 0),
+            Rank::Sergeant => Self::new(3, 2, 1),
+            Rank::Lieutenant => Self::new(1, 1, 1),
+            Rank::Captain => Self::new(1, 0, 2),
+        }
+    }
+
+    /// Take a grenade, returns false if none are left
+    pub fn use_grenade(&mut self) -> bool {
+        Self::take(&mut self.grenades)
+    }
+
+    /// Take a coil of wire, returns false if none are left
+    pub fn use_wire(&mut self) -> bool {
+        Self::take(&mut self.wire_coils)
+    }
+
+    /// The consumable `action_type` needs but none is left of, if any
+    pub fn shortfall(&self, action_type: &ActionType) -> Option<&'static str> {
+        let (count, item) = match action_type {
+            ActionType::ThrowGrenade { .. } => (self.grenades, "grenades"),
+            ActionType::LayWire { .. } => (self.wire_coils, "wire"),
+            _ => return None,
+        };
+        (count == 0).then_some(item)
+    }
+
+    /// Load units the consumables weigh
+    pub fn load(&self) -> i32 {
+        self.grenades as i32 * GRENADE_LOAD
+            + self.smoke_grenades as i32 * SMOKE_LOAD
+            + self.medkits as i32 * MEDKIT_LOAD
+            + self.wire_coils as i32 * WIRE_LOAD
+    }
+
+    fn take(count: &mut u32) -> bool {
+        if *count == 0 {
+            return false;
+        }
+        *count -= 1;
+        true
+    }
+}
+
+impl Default for Inventory {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// Load units a soldier carries: consumables plus the rounds in hand
+pub fn carried_load(inventory: Option<&Inventory>, weapon: Option<&Weapon>) -> i32 {
+    let kit = inventory.map_or(0, Inventory::load);
+    let rounds = weapon.map_or(0, |weapon| weapon.ammo.current.max(0));
+    kit + rounds / ROUNDS_PER_LOAD
+}
@@ -4,6 +4,7 @@
 use crate::components::position::Position;
 use crate::components::soldier::{Faction, Rank};
 use specs::{Component, VecStorage, Entity};
+use std::collections::HashMap;
 
 /// Marks the last known position of an entity that has left vision
 /// These are "ghost" markers showing where enemies were last spotted
@@ -55,3 +56,36 @@ impl LastSeenMarker {
         self.turns_ago >= max_turns
     }
 }
+
+/// Resource: shooters given away by their muzzle flash at night
+///
+/// Filled by ActionExecutionSystem, keyed by the faction that saw the flash.
+/// Each side's view takes its sightings and turns them into markers.
+#[derive(Debug, Clone, Default)]
+pub struct FlashSightings {
+    sightings: HashMap<(Faction, Entity), LastSeenMarker>,
+}
+
+impl FlashSightings {
+    /// Record that `observers` saw a flash; a newer flash replaces an older one
+    pub fn record(&mut self, observers: Faction, marker: LastSeenMarker) {
+        self.sightings.insert((observers, marker.tracked_entity), marker);
+    }
+
+    pub fn get(&self, observers: Faction, shooter: Entity) -> Option<&LastSeenMarker> {
+        self.sightings.get(&(observers, shooter))
+    }
+
+    /// Remove and return everything `observers` have seen
+    pub fn take(&mut self, observers: Faction) -> Vec<LastSeenMarker> {
+        let keys: Vec<_> = self
+            .sightings
+            .keys()
+            .filter(|(faction, _)| *faction == observers)
+            .copied()
+            .collect();
+        keys.into_iter()
+            .filter_map(|key| self.sightings.remove(&key))
+            .collect()
+    }
+}
@@ -0,0 +1,17 @@
+// Last stand marker component
+
+use specs::{Component, NullStorage};
+
+/// Steadiness multiplier for a soldier making a last stand
+pub const LAST_STAND_ACCURACY: f32 = 1.1;
+
+/// Marker component: a cornered NCO or officer fighting to the last
+///
+/// Set and cleared each turn by MoraleSystem. A soldier making a last stand
+/// never surrenders, however shaken, and shoots a little steadier.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LastStand;
+
+impl Component for LastStand {
+    type Storage = NullStorage<Self>;
+}
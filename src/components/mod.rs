@@ -2,17 +2,32 @@
 // Contains all Specs components for game entities
 
 pub mod action;
+pub mod ai_role;
+pub mod aiming;
+pub mod blood_trail;
 pub mod dead;
+pub mod detonation;
+pub mod evacuated;
 pub mod facing;
 pub mod health;
+pub mod heard_shot;
+pub mod inventory;
 pub mod last_seen;
+pub mod last_stand;
+pub mod morale;
 pub mod muzzle_flash;
 pub mod pathfinding;
 pub mod player;
+pub mod prone;
+pub mod rested;
 pub mod position;
 pub mod soldier;
 pub mod soldier_stats;
+pub mod squad;
+pub mod surrendered;
 pub mod time_budget;
+pub mod tracer;
+pub mod under_fire;
 pub mod vision;
 pub mod weapon;
 
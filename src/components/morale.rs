@@ -0,0 +1,42 @@
+// Morale component
+// Will to keep fighting; worn down under fire, recovered in quiet turns
+
+use specs::{Component, VecStorage};
+
+pub const MAX_MORALE: f32 = 100.0;
+
+/// Component: Soldier's morale, from 0.0 (broken) to MAX_MORALE
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Morale {
+    pub current: f32,
+}
+
+impl Component for Morale {
+    type Storage = VecStorage<Self>;
+}
+
+impl Morale {
+    pub fn new(current: f32) -> Self {
+        Self {
+            current: current.clamp(0.0, MAX_MORALE),
+        }
+    }
+
+    pub fn shake(&mut self, amount: f32) {
+        self.current = (self.current - amount).max(0.0);
+    }
+
+    pub fn recover(&mut self, amount: f32) {
+        self.current = (self.current + amount).min(MAX_MORALE);
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.current <= 0.0
+    }
+}
+
+impl Default for Morale {
+    fn default() -> Self {
+        Self::new(MAX_MORALE)
+    }
+}
@@ -12,6 +12,8 @@ pub struct PlannedPath {
     pub total_cost: f32,
     /// Whether this path should be rendered (for visual preview)
     pub show_preview: bool,
+    /// Turns spent in a row waiting for the next tile to clear
+    pub blocked_turns: u32,
 }
 
 impl Component for PlannedPath {
@@ -25,6 +27,7 @@ impl PlannedPath {
             steps,
             total_cost,
             show_preview,
+            blocked_turns: 0,
         }
     }
 
@@ -0,0 +1,14 @@
+// Prone marker component
+
+use specs::{Component, NullStorage};
+
+/// Marker component: Soldier is flat on the ground after crawling
+///
+/// Prone soldiers present a small target. They stay down until their next
+/// normal move.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Prone;
+
+impl Component for Prone {
+    type Storage = NullStorage<Self>;
+}
@@ -0,0 +1,16 @@
+// Rest counter component
+
+use specs::{Component, VecStorage};
+
+/// Component: how often a soldier has stopped to rest this battle
+///
+/// Each Wait a soldier takes counts as a rest, winning back part of the
+/// battle fatigue FatigueConfig piles on as the turns go by.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rested {
+    pub rests: u32,
+}
+
+impl Component for Rested {
+    type Storage = VecStorage<Self>;
+}
@@ -1,5 +1,6 @@
 // Soldier component for individual units
 
+use serde::Deserialize;
 use specs::{Component, VecStorage};
 
 /// Represents a soldier unit on the battlefield
@@ -15,7 +16,8 @@ impl Component for Soldier {
 }
 
 /// Faction/side the soldier belongs to
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Faction {
     Allies,
     CentralPowers,
@@ -29,10 +31,26 @@ impl Faction {
             Faction::CentralPowers => 'Ӝ',
         }
     }
+    /// The side's name, as the event log and panels give it
+    pub fn name(&self) -> &'static str {
+        match self {
+            Faction::Allies => "Allies",
+            Faction::CentralPowers => "Central Powers",
+        }
+    }
+
+    /// The side this faction fights against
+    pub fn opposing(&self) -> Faction {
+        match self {
+            Faction::Allies => Faction::CentralPowers,
+            Faction::CentralPowers => Faction::Allies,
+        }
+    }
 }
 
 /// Military rank of the soldier
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Rank {
     Private,
     Corporal,
@@ -41,6 +59,7 @@ pub enum Rank {
     Captain,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RankBaseStats {
     pub base_hp: i32,
     pub vision_range: i32,
@@ -60,6 +79,12 @@ impl Rank {
         }
     }
 
+    /// Whether a soldier of this rank fights to the last when cornered
+    pub fn stands_when_cornered(&self) -> bool {
+        *self >= Rank::Sergeant
+    }
+
+    /// Compiled default stats; rebalanced values are loaded via RankStatsTable
     pub fn base_stats(&self) -> RankBaseStats {
         match self {
             Rank::Private => RankBaseStats {
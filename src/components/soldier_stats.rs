@@ -1,5 +1,21 @@
+use crate::components::{
+    inventory::{carried_load, Inventory},
+    weapon::Weapon,
+};
 use specs::{Component, VecStorage};
 
+/// Load, as a share of carrying capacity, at or below which a soldier travels light
+pub const LIGHT_LOAD_RATIO: f32 = 0.25;
+/// Movement time multiplier for a soldier travelling light
+pub const LIGHT_LOAD_FACTOR: f32 = 0.9;
+/// Ceiling on the movement time multiplier for an overloaded soldier
+pub const MAX_OVERLOAD_FACTOR: f32 = 2.0;
+/// Share of the movement speed modifier's deviation carried into the turn budget
+pub const BUDGET_SPEED_WEIGHT: f32 = 0.5;
+/// Bounds on the turn budget multiplier under rank-scaled budgets
+pub const MIN_BUDGET_FACTOR: f32 = 0.85;
+pub const MAX_BUDGET_FACTOR: f32 = 1.15;
+
 #[derive(Debug, Clone)]
 pub struct SoldierStats {
     pub accuracy_modifier: f32,
@@ -36,4 +52,42 @@ impl SoldierStats {
             carrying_capacity: base.carrying_capacity_base,
         }
     }
+
+    /// Turn budget multiplier under rank-scaled budgets
+    ///
+    /// Half of the movement speed modifier's deviation from 1.0 carries over,
+    /// so a fit private at 1.1 gets 5% more time and a captain at 1.2 gets
+    /// 10%, within MIN_BUDGET_FACTOR..=MAX_BUDGET_FACTOR.
+    pub fn budget_factor(&self) -> f32 {
+        (1.0 + (self.movement_speed_modifier - 1.0) * BUDGET_SPEED_WEIGHT)
+            .clamp(MIN_BUDGET_FACTOR, MAX_BUDGET_FACTOR)
+    }
+
+    /// Movement time multiplier for carrying `load` units
+    ///
+    /// A load past capacity slows the soldier in proportion (150% of
+    /// capacity takes 1.5x as long, up to MAX_OVERLOAD_FACTOR); a light
+    /// load speeds them up a little.
+    pub fn load_factor(&self, load: i32) -> f32 {
+        if self.carrying_capacity <= 0 {
+            return 1.0;
+        }
+        let ratio = load as f32 / self.carrying_capacity as f32;
+        if ratio > 1.0 {
+            ratio.min(MAX_OVERLOAD_FACTOR)
+        } else if ratio <= LIGHT_LOAD_RATIO {
+            LIGHT_LOAD_FACTOR
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Movement time multiplier for what a soldier carries (1.0 without stats)
+pub fn load_factor(
+    stats: Option<&SoldierStats>,
+    inventory: Option<&Inventory>,
+    weapon: Option<&Weapon>,
+) -> f32 {
+    stats.map_or(1.0, |stats| stats.load_factor(carried_load(inventory, weapon)))
 }
@@ -0,0 +1,64 @@
+// Squad membership components
+// Groups soldiers into squads for the bound-and-cover movement doctrine
+
+use specs::{Component, VecStorage};
+
+/// Turns each half of a squad holds one role before the halves swap
+pub const BOUND_TURNS: u32 = 2;
+
+/// Component: Soldier belongs to a squad, at a fixed place in its order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SquadMember {
+    pub squad: u32,
+    pub index: usize,
+}
+
+impl Component for SquadMember {
+    type Storage = VecStorage<Self>;
+}
+
+/// Component: Role assigned by the planner while the squad is in contact
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundingRole {
+    /// Holds position and covers the bound with fire
+    Overwatch,
+    /// Moves up while the other half covers
+    Advance,
+}
+
+impl Component for BoundingRole {
+    type Storage = VecStorage<Self>;
+}
+
+impl SquadMember {
+    pub fn new(squad: u32, index: usize) -> Self {
+        Self { squad, index }
+    }
+
+    /// Even and odd members form the two halves, trading roles every BOUND_TURNS
+    pub fn bounding_role(&self, current_turn: u32) -> BoundingRole {
+        let advancing_half = (current_turn / BOUND_TURNS) % 2;
+        if self.index as u32 % 2 == advancing_half {
+            BoundingRole::Advance
+        } else {
+            BoundingRole::Overwatch
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_halves_alternate_roles() {
+        let even = SquadMember::new(1, 0);
+        let odd = SquadMember::new(1, 1);
+
+        for turn in 0..8 {
+            assert_ne!(even.bounding_role(turn), odd.bounding_role(turn));
+        }
+        assert_eq!(even.bounding_role(0), BoundingRole::Advance);
+        assert_eq!(even.bounding_role(BOUND_TURNS), BoundingRole::Overwatch);
+    }
+}
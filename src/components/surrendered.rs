@@ -0,0 +1,13 @@
+// Surrendered marker component
+
+use specs::{Component, NullStorage};
+
+/// Marker component: Soldier has surrendered and is out of the fight
+///
+/// Surrendered soldiers no longer act and are not valid targets.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Surrendered;
+
+impl Component for Surrendered {
+    type Storage = NullStorage<Self>;
+}
@@ -11,6 +11,8 @@ pub struct TimeBudget {
     pub time_debt: f32,
     /// Time spent this turn
     pub time_spent_this_turn: f32,
+    /// Debt run up by actions charged this turn (carried debt not included)
+    pub debt_this_turn: f32,
 }
 
 impl Component for TimeBudget {
@@ -23,6 +25,7 @@ impl TimeBudget {
             base_duration,
             time_debt: 0.0,
             time_spent_this_turn: 0.0,
+            debt_this_turn: 0.0,
         }
     }
 
@@ -36,14 +39,26 @@ impl TimeBudget {
         self.time_spent_this_turn += cost;
 
         // Update debt if we've gone over budget
+        let debt_before = self.time_debt;
         let total_spent = self.time_spent_this_turn + self.time_debt;
         if total_spent > self.base_duration {
             self.time_debt = total_spent - self.base_duration;
         }
+        self.debt_this_turn += (self.time_debt - debt_before).max(0.0);
 
         true // Always succeeds, may create debt
     }
 
+    /// Give back time for an action that never happened
+    pub fn refund_time(&mut self, cost: f32) {
+        let refund = cost.min(self.time_spent_this_turn);
+        self.time_spent_this_turn -= refund;
+        // Only debt run up this turn is forgiven; carried debt stays owed
+        let forgiven = refund.min(self.debt_this_turn);
+        self.debt_this_turn -= forgiven;
+        self.time_debt = (self.time_debt - forgiven).max(0.0);
+    }
+
     /// Check if we can afford an action (for UI display)
     pub fn can_afford(&self, cost: f32) -> bool {
         self.available_time() >= cost
@@ -52,6 +67,7 @@ impl TimeBudget {
     /// Reset for new turn (keeps debt)
     pub fn reset_for_new_turn(&mut self) {
         self.time_spent_this_turn = 0.0;
+        self.debt_this_turn = 0.0;
         // Keep time_debt to carry forward
     }
 }
@@ -91,4 +107,15 @@ mod tests {
         assert_eq!(budget.time_debt, 2.0);
         assert_eq!(budget.available_time(), 8.0);
     }
+
+    #[test]
+    fn test_refund_keeps_carried_debt() {
+        let mut budget = TimeBudget::new(10.0);
+        budget.consume_time(12.0);
+        budget.reset_for_new_turn();
+        budget.consume_time(3.0);
+        budget.refund_time(3.0);
+        assert_eq!(budget.time_spent_this_turn, 0.0);
+        assert_eq!(budget.time_debt, 2.0);
+    }
 }
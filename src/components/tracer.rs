@@ -0,0 +1,53 @@
+// Shot tracers
+// Cosmetic lines showing where each shot flew, drawn for a single frame
+
+use crate::components::soldier::Faction;
+use crate::game_logic::battlefield::Position;
+use crate::game_logic::line_of_sight::sight_line;
+use specs::Entity;
+
+/// One shot's line of fire, shooter's tile first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tracer {
+    pub shooter: Entity,
+    pub faction: Faction,
+    pub path: Vec<Position>,
+}
+
+/// Resource: tracers for the shots fired since the last frame was drawn
+///
+/// Filled by ActionExecutionSystem while `enabled`; the renderer draws
+/// them once and clears them.
+#[derive(Debug, Clone, Default)]
+pub struct ShotTracers {
+    pub enabled: bool,
+    tracers: Vec<Tracer>,
+}
+
+impl ShotTracers {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            tracers: Vec::new(),
+        }
+    }
+
+    /// Record a shot from `from` at `to` (ignored while tracers are off)
+    pub fn record(&mut self, shooter: Entity, faction: Faction, from: &Position, to: &Position) {
+        if self.enabled {
+            self.tracers.push(Tracer {
+                shooter,
+                faction,
+                path: sight_line(from, to),
+            });
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Tracer> {
+        self.tracers.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.tracers.clear();
+    }
+}
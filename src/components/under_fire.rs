@@ -0,0 +1,25 @@
+// Under-fire marker component
+// Records the last turn a soldier was shot at, hit or miss
+
+use specs::{Component, VecStorage};
+
+/// Component: Soldier has recently been targeted by enemy fire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnderFire {
+    pub turn: u32,
+}
+
+impl Component for UnderFire {
+    type Storage = VecStorage<Self>;
+}
+
+impl UnderFire {
+    pub fn new(turn: u32) -> Self {
+        Self { turn }
+    }
+
+    /// Shot at this turn or the one before
+    pub fn is_recent(&self, current_turn: u32) -> bool {
+        current_turn.saturating_sub(self.turn) <= 1
+    }
+}
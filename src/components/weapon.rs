@@ -17,6 +17,11 @@ pub enum WeaponType {
 }
 
 impl WeaponType {
+    /// Loaded a charger at a time, so a full reload takes more than one turn
+    pub fn is_bolt_action(&self) -> bool {
+        matches!(self, WeaponType::Rifle)
+    }
+
     /// Get default stats for this weapon type
     /// This is the factory pattern - adding new weapons is just data
     pub fn default_stats(&self) -> WeaponStats {
@@ -28,7 +33,10 @@ impl WeaponType {
                 base_accuracy: 0.7,   // 70% hit chance at effective range
                 damage: 25,           // enough to kill in 3-4 hits
                 fire_time: 3.0,       // seconds per shot
-                reload_time: 5.0,     // seconds to reload
+                reload_time: 10.0,    // two chargers, one reload action each
+                heat_per_shot: 0.0,   // bolt action never overheats
+                flash_range: 40,   // a rifle flash carries across no-man's land
+                aim_bonus: 0.5,       // a steadied rifle is the precision weapon
             },
             WeaponType::SubmachineGun => WeaponStats {
                 name: "SMG".to_string(),
@@ -38,6 +46,9 @@ impl WeaponType {
                 damage: 18,
                 fire_time: 2.0,       // faster fire rate
                 reload_time: 4.0,
+                heat_per_shot: 0.0,
+                flash_range: 25,
+                aim_bonus: 0.1,
             },
             WeaponType::MachineGun => WeaponStats {
                 name: "Machine Gun".to_string(),
@@ -47,6 +58,9 @@ impl WeaponType {
                 damage: 30,
                 fire_time: 2.5,
                 reload_time: 8.0,     // long reload
+                heat_per_shot: 35.0,  // three bursts in a row overheat it
+                flash_range: 50,   // sustained bursts light up the line
+                aim_bonus: 0.2,
             },
             WeaponType::Pistol => WeaponStats {
                 name: "Pistol".to_string(),
@@ -56,6 +70,9 @@ impl WeaponType {
                 damage: 15,
                 fire_time: 2.0,
                 reload_time: 3.0,
+                heat_per_shot: 0.0,
+                flash_range: 15,
+                aim_bonus: 0.0,
             },
         }
     }
@@ -71,6 +88,9 @@ pub struct WeaponStats {
     pub damage: i32,          // Base damage per hit
     pub fire_time: f32,       // Time cost to fire (seconds)
     pub reload_time: f32,     // Time cost to reload (seconds)
+    pub heat_per_shot: f32,   // Barrel heat added per burst (0 = never overheats)
+    pub flash_range: i32,     // Tiles at which the muzzle flash gives the shooter away at night
+    pub aim_bonus: f32,       // Hit chance multiplier gained by aiming first (0.5 = +50%)
 }
 
 /// Ammunition state for a weapon
@@ -78,6 +98,8 @@ pub struct WeaponStats {
 pub struct AmmoState {
     pub current: i32,
     pub max_capacity: i32,
+    /// Most rounds a reload can fill the magazine to; max_capacity while supplied
+    pub reserve: i32,
 }
 
 impl AmmoState {
@@ -85,6 +107,7 @@ impl AmmoState {
         Self {
             current: capacity,
             max_capacity: capacity,
+            reserve: capacity,
         }
     }
 
@@ -96,6 +119,11 @@ impl AmmoState {
         self.current >= self.max_capacity
     }
 
+    /// A quarter of the magazine or less left
+    pub fn is_low(&self) -> bool {
+        self.current * 4 <= self.max_capacity
+    }
+
     pub fn consume(&mut self, amount: i32) -> bool {
         if self.current >= amount {
             self.current -= amount;
@@ -106,7 +134,37 @@ impl AmmoState {
     }
 
     pub fn reload(&mut self) {
-        self.current = self.max_capacity;
+        self.current = self.current.max(self.reload_ceiling());
+    }
+
+    /// Push in up to `rounds` more, never past what a full reload would leave
+    pub fn load(&mut self, rounds: i32) {
+        self.current = self.current.max((self.current + rounds).min(self.reload_ceiling()));
+    }
+
+    /// Rounds a reload would leave in the magazine
+    pub fn reload_ceiling(&self) -> i32 {
+        self.reserve.clamp(0, self.max_capacity)
+    }
+
+    /// Whether reloading would add any rounds
+    pub fn can_reload(&self) -> bool {
+        self.current < self.reload_ceiling()
+    }
+
+    /// Whether the reserve is below a full magazine
+    pub fn is_short_of_supply(&self) -> bool {
+        self.reserve < self.max_capacity
+    }
+
+    /// Lose `rounds` of reserve, as a soldier cut off from supply does each turn
+    pub fn cut_reserve(&mut self, rounds: i32) {
+        self.reserve = (self.reserve - rounds).max(0);
+    }
+
+    /// Restore the reserve to a full magazine
+    pub fn resupply(&mut self) {
+        self.reserve = self.max_capacity;
     }
 
     pub fn percentage(&self) -> f32 {
@@ -118,12 +176,23 @@ impl AmmoState {
     }
 }
 
+/// Barrel heat at which a weapon locks up until it cools
+pub const OVERHEAT_THRESHOLD: f32 = 100.0;
+/// Heat shed by a turn in which the weapon isn't fired
+pub const IDLE_COOLING: f32 = 40.0;
+/// Cooling multiplier when standing in mud or water
+pub const WET_COOLING_MULTIPLIER: f32 = 2.0;
+
 /// Component: Weapon equipped by an entity
 #[derive(Debug, Clone)]
 pub struct Weapon {
     pub weapon_type: WeaponType,
     pub stats: WeaponStats,
     pub ammo: AmmoState,
+    /// Barrel heat from sustained fire; at OVERHEAT_THRESHOLD the weapon can't fire
+    pub heat: f32,
+    /// Turn the weapon was last fired; the barrel only cools in turns it rests
+    pub fired_turn: Option<u32>,
 }
 
 impl Component for Weapon {
@@ -139,6 +208,8 @@ impl Weapon {
             weapon_type,
             stats,
             ammo: AmmoState::new(ammo_capacity),
+            heat: 0.0,
+            fired_turn: None,
         }
     }
 
@@ -164,16 +235,159 @@ impl Weapon {
 
     /// Check if weapon can fire
     pub fn can_fire(&self) -> bool {
-        !self.ammo.is_empty()
+        !self.ammo.is_empty() && !self.is_overheated()
+    }
+
+    /// Too hot to fire until it cools below the threshold
+    pub fn is_overheated(&self) -> bool {
+        self.heat >= OVERHEAT_THRESHOLD
     }
 
-    /// Consume ammo for firing
+    /// Consume ammo for firing, heating the barrel
     pub fn fire(&mut self) -> bool {
-        self.ammo.consume(1)
+        let fired = self.ammo.consume(1);
+        if fired {
+            self.heat += self.stats.heat_per_shot;
+        }
+        fired
+    }
+
+    /// Shed barrel heat
+    pub fn cool(&mut self, amount: f32) {
+        self.heat = (self.heat - amount).max(0.0);
     }
 
     /// Reload weapon
     pub fn reload(&mut self) {
         self.ammo.reload();
     }
+
+    /// Load `share` of a full magazine, as one charger of a bolt-action reload does
+    pub fn load_share(&mut self, share: f32) {
+        self.ammo.load((self.ammo.max_capacity as f32 * share).ceil() as i32);
+    }
+
+    /// Take what's usable from a fallen soldier's weapon
+    ///
+    /// A weapon of the same type gives up rounds, up to the looter's
+    /// capacity. A different one is only worth taking when the looter's own
+    /// is empty, in which case the two are exchanged.
+    pub fn loot_from(&mut self, corpse: &mut Weapon) -> Looted {
+        if corpse.ammo.is_empty() {
+            return Looted::Nothing;
+        }
+
+        if corpse.weapon_type == self.weapon_type {
+            let rounds = corpse
+                .ammo
+                .current
+                .min(self.ammo.max_capacity - self.ammo.current);
+            if rounds <= 0 {
+                return Looted::Nothing;
+            }
+            corpse.ammo.current -= rounds;
+            self.ammo.current += rounds;
+            Looted::Rounds(rounds)
+        } else if self.ammo.is_empty() {
+            std::mem::swap(self, corpse);
+            Looted::Weapon
+        } else {
+            Looted::Nothing
+        }
+    }
+}
+
+/// What a soldier took when looting a fallen soldier's weapon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Looted {
+    /// Rounds moved into the looter's own magazine
+    Rounds(i32),
+    /// The looter traded their empty weapon for the fallen one
+    Weapon,
+    Nothing,
+}
+
+/// Component: Holstered secondary weapon (e.g. a pistol sidearm)
+///
+/// SwapWeapon exchanges it with the `Weapon` component, so anything reading
+/// `Weapon` (can_fire, calculate_shot) always sees the weapon in hand.
+#[derive(Debug, Clone)]
+pub struct SecondaryWeapon {
+    pub weapon: Weapon,
+}
+
+impl Component for SecondaryWeapon {
+    type Storage = VecStorage<Self>;
+}
+
+impl SecondaryWeapon {
+    pub fn new(weapon: Weapon) -> Self {
+        Self { weapon }
+    }
+
+    /// Standard sidearm: pistol
+    pub fn sidearm() -> Self {
+        Self::new(Weapon::pistol())
+    }
+
+    /// Put the active weapon away and draw this one
+    pub fn swap_with(&mut self, active: &mut Weapon) {
+        std::mem::swap(&mut self.weapon, active);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sustained_bursts_overheat_machine_gun() {
+        let mut mg = Weapon::machine_gun();
+        let mut bursts = 0;
+        while mg.can_fire() {
+            assert!(mg.fire());
+            bursts += 1;
+            assert!(bursts < 10, "machine gun never overheated");
+        }
+
+        assert!(mg.is_overheated());
+        assert!(!mg.ammo.is_empty());
+        assert_eq!(bursts, 3);
+
+        // Rifles never heat up
+        let mut rifle = Weapon::rifle();
+        rifle.fire();
+        assert_eq!(rifle.heat, 0.0);
+    }
+
+    #[test]
+    fn test_loot_takes_rounds_or_swaps_empty_weapon() {
+        let mut looter = Weapon::rifle();
+        looter.ammo.current = 0;
+        let mut corpse = Weapon::rifle();
+        corpse.ammo.current = 6;
+
+        assert_eq!(looter.loot_from(&mut corpse), Looted::Rounds(6));
+        assert_eq!(looter.ammo.current, 6);
+        assert!(corpse.ammo.is_empty());
+
+        let mut lmg = Weapon::machine_gun();
+        assert_eq!(looter.loot_from(&mut lmg), Looted::Nothing);
+        looter.ammo.current = 0;
+        assert_eq!(looter.loot_from(&mut lmg), Looted::Weapon);
+        assert_eq!(looter.weapon_type, WeaponType::MachineGun);
+        assert_eq!(lmg.weapon_type, WeaponType::Rifle);
+    }
+
+    #[test]
+    fn test_waiting_cools_below_threshold() {
+        let mut mg = Weapon::machine_gun();
+        while mg.can_fire() {
+            mg.fire();
+        }
+
+        mg.cool(IDLE_COOLING);
+        assert!(mg.heat < OVERHEAT_THRESHOLD);
+        assert!(mg.can_fire());
+    }
 }
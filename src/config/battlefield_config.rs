@@ -1,6 +1,8 @@
 // Battlefield Generation Configuration
 // Defines parameters for procedural battlefield generation
 
+use serde::{Deserialize, Serialize};
+
 /// Type of battlefield to generate
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BattlefieldType {
@@ -17,7 +19,7 @@ pub enum BattlefieldType {
 }
 
 /// Density of trench networks
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TrenchDensity {
     None,
     Sparse,      // 10-20% coverage
@@ -27,7 +29,7 @@ pub enum TrenchDensity {
 }
 
 /// Level of fortification
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FortificationLevel {
     None,
     Light,       // Basic sandbags
@@ -36,6 +38,14 @@ pub enum FortificationLevel {
     Fortress,    // Maximum fortifications
 }
 
+/// Exact per-side fortification quantities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FortificationCounts {
+    pub sandbags: usize,
+    pub bunkers: usize,
+    pub mg_nests: usize,
+}
+
 /// Comprehensive battlefield generation configuration
 #[derive(Debug, Clone)]
 pub struct BattlefieldGenerationConfig {
@@ -46,7 +56,9 @@ pub struct BattlefieldGenerationConfig {
     // Map type and style
     pub battlefield_type: BattlefieldType,
     pub trench_density: TrenchDensity,
+    pub support_trench_lines: usize, // Parallel lines behind each front trench (0 = front only)
     pub fortification_level: FortificationLevel,
+    pub fortification_counts: Option<FortificationCounts>, // Overrides the level's counts
 
     // Terrain features
     pub mud_coverage: f32,          // 0.0 to 1.0
@@ -59,10 +71,15 @@ pub struct BattlefieldGenerationConfig {
     pub barbed_wire_coverage: f32,  // 0.0 to 1.0 (in no-man's land)
     pub mg_nest_count: usize,       // Number of MG nests per side
     pub bunker_count: usize,        // Number of bunkers per side
+    pub mine_density: f32,          // Hidden mines per 100 no-man's-land tiles
 
     // Generation parameters
     pub seed: u64,                  // For reproducible generation
     pub no_mans_land_width: usize,  // Width of area between trench lines
+    pub noise_octaves: u32,         // Perlin layers summed for the base terrain
+    pub noise_persistence: f32,     // Amplitude kept per octave (0.0 to 1.0)
+    pub noise_scale: f32,           // Feature frequency; lower = larger blobs
+    pub min_spawn_separation: usize, // Minimum tiles between spawn zone centers
 
     // Faction positions
     pub allies_side: Side,          // Which side allies spawn (North/South/East/West)
@@ -84,7 +101,9 @@ impl Default for BattlefieldGenerationConfig {
             height: 100,
             battlefield_type: BattlefieldType::WesternFront,
             trench_density: TrenchDensity::Moderate,
+            support_trench_lines: 0,
             fortification_level: FortificationLevel::Moderate,
+            fortification_counts: None,
             mud_coverage: 0.3,
             crater_density: 2.0,
             water_features: true,
@@ -93,8 +112,13 @@ impl Default for BattlefieldGenerationConfig {
             barbed_wire_coverage: 0.4,
             mg_nest_count: 3,
             bunker_count: 2,
+            mine_density: 0.0,
             seed: 12345,
             no_mans_land_width: 20,
+            noise_octaves: 1,
+            noise_persistence: 0.5,
+            noise_scale: 0.05,
+            min_spawn_separation: 0,
             allies_side: Side::South,
         }
     }
@@ -125,18 +149,56 @@ impl BattlefieldGenerationConfig {
         self
     }
 
+    /// Builder: Set how many support/reserve trench lines run behind each front line
+    pub fn with_support_trench_lines(mut self, lines: usize) -> Self {
+        self.support_trench_lines = lines;
+        self
+    }
+
     /// Builder: Set fortification level
     pub fn with_fortifications(mut self, level: FortificationLevel) -> Self {
         self.fortification_level = level;
         self
     }
 
+    /// Builder: Set exact per-side fortification counts, overriding the level
+    pub fn with_fortification_counts(mut self, counts: FortificationCounts) -> Self {
+        self.fortification_counts = Some(counts);
+        self
+    }
+
+    /// Per-side fortification counts: the explicit override, else the level's
+    pub fn effective_fortification_counts(&self) -> FortificationCounts {
+        self.fortification_counts
+            .unwrap_or_else(|| self.fortification_level.counts())
+    }
+
     /// Builder: Set random seed
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.seed = seed;
         self
     }
 
+    /// Builder: Set hidden mine density (mines per 100 no-man's-land tiles)
+    pub fn with_mine_density(mut self, density: f32) -> Self {
+        self.mine_density = density.max(0.0);
+        self
+    }
+
+    /// Builder: Set base terrain noise (octaves, persistence, feature scale)
+    pub fn with_noise(mut self, octaves: u32, persistence: f32, scale: f32) -> Self {
+        self.noise_octaves = octaves.max(1);
+        self.noise_persistence = persistence.clamp(0.0, 1.0);
+        self.noise_scale = scale.max(0.001);
+        self
+    }
+
+    /// Builder: Set minimum distance between the two spawn zone centers
+    pub fn with_min_spawn_separation(mut self, tiles: usize) -> Self {
+        self.min_spawn_separation = tiles;
+        self
+    }
+
     /// Builder: Set allies spawn side
     pub fn with_allies_side(mut self, side: Side) -> Self {
         self.allies_side = side;
@@ -301,4 +363,14 @@ impl FortificationLevel {
             FortificationLevel::Fortress => (30, 6, 7),
         }
     }
+
+    /// Counts for this fortification level, by name
+    pub fn counts(&self) -> FortificationCounts {
+        let (sandbags, bunkers, mg_nests) = self.get_counts();
+        FortificationCounts {
+            sandbags,
+            bunkers,
+            mg_nests,
+        }
+    }
 }
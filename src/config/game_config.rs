@@ -1,6 +1,23 @@
 // Game configuration settings
 
+use crate::ai::personality_data::PersonalityLibrary;
+use crate::config::glyph_theme::GlyphTheme;
+use crate::config::hud_thresholds::HudThresholds;
+use crate::components::{rested::Rested, soldier_stats::SoldierStats};
+use crate::config::rank_stats::RankStatsTable;
+use crate::game_logic::fog_of_war::EnemyInfoLevel;
+use crate::game_logic::line_of_sight::DiagonalLos;
+use crate::game_logic::pathfinding::PathHeuristic;
+use crate::game_logic::targeting::GrenadeThrow;
+use crate::game_logic::player_actions::{PlayerAction, PlayerActions};
+use crate::game_logic::soldier_spawning::RankDistribution;
+use crate::game_logic::triggers::Trigger;
 use crate::game_logic::turn_state::TurnOrderMode;
+use crate::simulation::weather::TimeOfDay;
+use serde::{Deserialize, Serialize};
+
+/// Camera deadzone, as a fraction of the viewport, unless a setting overrides it
+pub const DEFAULT_CAMERA_DEADZONE: f32 = 0.33;
 
 /// Global game configuration
 #[derive(Debug, Clone)]
@@ -9,12 +26,636 @@ pub struct GameConfig {
     pub time_budget_seconds: f32,
     /// Turn order mode
     pub turn_order_mode: TurnOrderMode,
+    /// Play as a commander ordering the allied squad, with no soldier of your own
+    pub commander_mode: bool,
+    /// Start the enemy in its trenches, bunkers and MG nests instead of its spawn zone
+    pub dug_in_defenders: bool,
+    /// Coils of barbed wire each soldier starts with, to string in front of their position
+    pub wire_coils: u32,
     /// Movement time cost per tile (seconds)
     pub movement_time_cost: f32,
     /// Rotation time cost (seconds)
     pub rotation_time_cost: f32,
     /// Tile scale in meters (for UI display)
     pub tile_scale_meters: f32,
+    /// When the HUD reports HP and time budget as low or critical
+    pub hud_thresholds: HudThresholds,
+    /// Camera deadzone as a fraction of the viewport (0.1-0.9); larger follows more loosely
+    pub camera_deadzone: f32,
+    /// Flash a tracer line along each shot's line of fire
+    pub shot_tracers: bool,
+    /// Flash the screen border when a grenade or mine goes off in view
+    pub impact_flash: bool,
+    /// Turns a marker lingers where an unseen enemy's shot was heard (0 = off)
+    pub heard_shot_turns: u32,
+    /// Keep the camera on a squad the player orders to move until it arrives
+    pub watch_orders: bool,
+    /// Show each side's men, ammo, flags and morale on the commander's panel
+    pub commander_stats: bool,
+    /// Debug overlay of the scores behind the AI soldier under the cursor
+    pub show_ai_scores: bool,
+    /// Stop auto-advance when a friendly dies, a flag changes hands or a new enemy appears
+    pub auto_pause: bool,
+    /// How much routine detail (reloads, low ammo) the event log reports
+    pub log_verbosity: LogVerbosity,
+    /// How much the context panel reveals about enemy soldiers
+    pub enemy_info: EnemyInfoLevel,
+    /// Optional random events layer (mines, duds, misfires)
+    pub random_events: RandomEventsConfig,
+    /// Turns an AI soldier must keep an enemy in sight before firing (0 = off)
+    pub spot_then_shoot_turns: u32,
+    /// Optional harassing-fire attrition in no-man's land
+    pub attrition: AttritionConfig,
+    /// Optional supply-line attrition for soldiers cut off from their spawn zone
+    pub supply: SupplyConfig,
+    /// Optional wild, wasteful fire from suppressed AI soldiers
+    pub panic_fire: PanicFireConfig,
+    /// Accuracy and movement penalties as a long battle wears on
+    pub fatigue: FatigueConfig,
+    /// Movement and accuracy penalties for badly wounded soldiers
+    pub wounded: WoundedConfig,
+    /// Blood left behind by badly wounded soldiers on the move
+    pub blood_trails: BloodTrailConfig,
+    /// Who gets the tile when two soldiers move into it in the same pass
+    pub move_conflict_rule: MoveConflictRule,
+    /// Whether corpses block their tile
+    pub corpse_rule: CorpseRule,
+    /// Whether soldiers on a path step round friends in their way
+    pub blocked_step_rule: BlockedStepRule,
+    /// Whether cover makes a target harder to hit or softens the hits it takes
+    pub cover_model: CoverModel,
+    /// Whether a hit spoils a reload under way
+    pub reload_interrupt: ReloadInterrupt,
+    /// Whether sight slips diagonally between two walls meeting at a corner
+    pub diagonal_los: DiagonalLos,
+    /// Whether grenades can be lobbed over cover or need a line of sight
+    pub grenade_throw: GrenadeThrow,
+    /// Distance estimate path searches steer by
+    pub path_heuristic: PathHeuristic,
+    /// Objective flag capture rules
+    pub objectives: ObjectivesConfig,
+    /// Turns before the match is decided on points (None = no limit)
+    pub turn_limit: Option<u32>,
+    /// Day or night; at night muzzle flashes give shooters away
+    pub time_of_day: TimeOfDay,
+    /// How cleverly the enemy plays
+    pub difficulty: Difficulty,
+    /// Share of each rank among enemy soldiers, reinforcements included
+    pub enemy_ranks: RankDistribution,
+    /// Seed for AI decisions such as personality rolls (None = the map seed)
+    pub ai_seed: Option<u64>,
+    /// Optional waves of fresh enemy soldiers
+    pub reinforcements: ReinforcementsConfig,
+    /// Place the friendly squad inside its spawn zone before the first turn
+    pub deployment: bool,
+    /// Scale each soldier's time budget by their movement speed modifier
+    pub rank_time_budgets: bool,
+    /// Per-rank base stats (compiled defaults unless loaded from a data file)
+    pub rank_stats: RankStatsTable,
+    /// AI personalities overriding the compiled ones (empty unless loaded from a data file)
+    pub personalities: PersonalityLibrary,
+    /// Characters used to draw the battlefield (Unicode unless loaded from a data file)
+    pub glyphs: GlyphTheme,
+    /// Scenario triggers for a custom mission (none unless loaded from a scenario file)
+    pub triggers: Vec<Trigger>,
+    /// Actions the player may take (all, unless a scenario rules some out)
+    pub player_actions: PlayerActions,
+}
+
+/// Settings for the random events layer
+///
+/// Systems check `enabled` before rolling anything against the GameRng.
+#[derive(Debug, Clone)]
+pub struct RandomEventsConfig {
+    /// Master toggle for all random events
+    pub enabled: bool,
+    /// Hidden mines per 100 no-man's-land tiles
+    pub mine_density: f32,
+    /// Damage dealt at the mine's own tile (falls off with distance)
+    pub mine_damage: i32,
+    /// Blast radius in tiles (Chebyshev distance)
+    pub mine_radius: i32,
+    /// Chance (0.0-1.0) that a thrown grenade fails to explode
+    pub grenade_dud_chance: f32,
+    /// Chance (0.0-1.0) that a weapon misfires instead of shooting
+    pub misfire_chance: f32,
+}
+
+/// Settings for no-man's-land attrition
+///
+/// When enabled, soldiers standing on open NoMansLand tiles in the central band
+/// lose `damage_per_turn` HP each turn, representing constant harassing fire.
+#[derive(Debug, Clone)]
+pub struct AttritionConfig {
+    pub enabled: bool,
+    pub damage_per_turn: i32,
+}
+
+/// Settings for supply-line attrition
+///
+/// When enabled, a soldier with no passable path back to their side's spawn
+/// zone loses `rounds_lost_per_turn` from the reserve their reloads draw on
+/// each turn, until the line reopens and they are resupplied.
+#[derive(Debug, Clone)]
+pub struct SupplyConfig {
+    pub enabled: bool,
+    pub rounds_lost_per_turn: i32,
+}
+
+impl Default for SupplyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rounds_lost_per_turn: 1,
+        }
+    }
+}
+
+/// Settings for AI panic fire
+///
+/// When enabled, an AI soldier shot at recently whose morale is below
+/// `morale_threshold` is suppressed, and each planning pass has `chance` of
+/// firing wildly toward the nearest enemy it knows of instead of acting on
+/// its plan.
+#[derive(Debug, Clone)]
+pub struct PanicFireConfig {
+    pub enabled: bool,
+    pub morale_threshold: f32,
+    pub chance: f32,
+}
+
+impl Default for PanicFireConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            morale_threshold: 30.0,
+            chance: 0.35,
+        }
+    }
+}
+
+/// Battle fatigue for a grinding match
+///
+/// From `onset_turn` on, each turn adds `per_turn` to every soldier's fatigue
+/// penalty, up to `max_penalty`; the penalty takes that fraction off hit chance
+/// and adds it to move times. Each rest a soldier takes wins back
+/// `rest_relief`, but never more than half of the fatigue built up.
+#[derive(Debug, Clone)]
+pub struct FatigueConfig {
+    pub enabled: bool,
+    pub onset_turn: u32,
+    pub per_turn: f32,
+    pub max_penalty: f32,
+    pub rest_relief: f32,
+}
+
+impl FatigueConfig {
+    /// Fatigue penalty (0.0 = fresh) on `turn` for a soldier with the given rests
+    pub fn penalty(&self, turn: u32, rested: Option<&Rested>) -> f32 {
+        if !self.enabled {
+            return 0.0;
+        }
+        let worn = (turn.saturating_sub(self.onset_turn) as f32 * self.per_turn).min(self.max_penalty);
+        let rests = rested.map_or(0, |rested| rested.rests);
+        let relief = (rests as f32 * self.rest_relief).min(worn * 0.5);
+        worn - relief
+    }
+
+    /// Hit chance multiplier (1.0 = no penalty)
+    pub fn accuracy_factor(&self, turn: u32, rested: Option<&Rested>) -> f32 {
+        1.0 - self.penalty(turn, rested)
+    }
+
+    /// Movement time multiplier (1.0 = no penalty)
+    pub fn movement_factor(&self, turn: u32, rested: Option<&Rested>) -> f32 {
+        1.0 + self.penalty(turn, rested)
+    }
+}
+
+impl Default for FatigueConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            onset_turn: 20,
+            per_turn: 0.01,
+            max_penalty: 0.3,
+            rest_relief: 0.01,
+        }
+    }
+}
+
+/// Blood trails left by badly wounded soldiers
+///
+/// Below `hp_threshold` of their max HP a soldier bleeds on each tile they
+/// leave; drops fade after `duration_turns` turns.
+#[derive(Debug, Clone)]
+pub struct BloodTrailConfig {
+    pub enabled: bool,
+    /// HP fraction (0.0-1.0) below which a soldier bleeds
+    pub hp_threshold: f32,
+    pub duration_turns: u32,
+}
+
+impl BloodTrailConfig {
+    pub fn is_bleeding(&self, hp_fraction: f32) -> bool {
+        self.enabled && hp_fraction < self.hp_threshold
+    }
+}
+
+impl Default for BloodTrailConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            hp_threshold: 0.5,
+            duration_turns: 3,
+        }
+    }
+}
+
+/// Whether the dead keep the living out of their tile
+///
+/// Movement, path following and path planning all consult it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CorpseRule {
+    /// The living step over the dead
+    #[default]
+    Passable,
+    /// A corpse holds its tile like a standing soldier
+    Blocking,
+}
+
+impl CorpseRule {
+    /// Whether an occupant of a tile keeps others from entering it
+    pub fn blocks(self, occupant_dead: bool) -> bool {
+        !occupant_dead || self == CorpseRule::Blocking
+    }
+}
+
+/// What a soldier following a path does when a friend stands in the next tile
+///
+/// A soldier blocked by an enemy, or whose destination is taken, stops
+/// either way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlockedStepRule {
+    /// Stop unless the friend is on the move themselves
+    #[default]
+    Halt,
+    /// Step round the friend onto a free tile that rejoins the path, else
+    /// wait a turn or two for them to move
+    Reroute,
+}
+
+impl BlockedStepRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BlockedStepRule::Halt => "Halt",
+            BlockedStepRule::Reroute => "Step Round",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            BlockedStepRule::Halt => BlockedStepRule::Reroute,
+            BlockedStepRule::Reroute => BlockedStepRule::Halt,
+        }
+    }
+}
+
+/// How much routine detail the event log reports
+///
+/// Detailed adds the ammo bookkeeping: reloads by the player and friendly
+/// soldiers, and a warning each time the player's magazine runs low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogVerbosity {
+    /// Combat and objectives only
+    #[default]
+    Normal,
+    /// Also reloads and low-ammo warnings
+    Detailed,
+}
+
+impl LogVerbosity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogVerbosity::Normal => "Normal",
+            LogVerbosity::Detailed => "Detailed",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            LogVerbosity::Normal => LogVerbosity::Detailed,
+            LogVerbosity::Detailed => LogVerbosity::Normal,
+        }
+    }
+
+    pub fn is_detailed(self) -> bool {
+        self == LogVerbosity::Detailed
+    }
+}
+
+/// Which stage of a shot a target's cover works on
+///
+/// Either way the protection is the tile's cover_bonus: under Damage every shot
+/// keeps its open-ground chance to land but a hit loses that fraction of its
+/// damage; under ToHit the hit chance loses that fraction instead and a hit
+/// lands in full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoverModel {
+    /// Cover soaks up part of every hit
+    #[default]
+    Damage,
+    /// Cover makes the target harder to hit; a hit does full damage
+    ToHit,
+}
+
+impl CoverModel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CoverModel::Damage => "Reduces Damage",
+            CoverModel::ToHit => "Reduces Hit Chance",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            CoverModel::Damage => CoverModel::ToHit,
+            CoverModel::ToHit => CoverModel::Damage,
+        }
+    }
+}
+
+/// What a hit does to a bolt-action reload under way
+///
+/// A rifle is loaded a charger at a time over more than one turn; under Fumble
+/// a soldier hit in the middle of it drops the charger and has to start again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReloadInterrupt {
+    /// Being hit throws the reload back to the start
+    #[default]
+    Fumble,
+    /// The reload carries on where it left off
+    Steady,
+}
+
+impl ReloadInterrupt {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReloadInterrupt::Fumble => "Fumbled When Hit",
+            ReloadInterrupt::Steady => "Carries On",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            ReloadInterrupt::Fumble => ReloadInterrupt::Steady,
+            ReloadInterrupt::Steady => ReloadInterrupt::Fumble,
+        }
+    }
+}
+
+/// How two soldiers moving into the same empty tile are settled
+///
+/// The loser stays put and gets the move's time back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MoveConflictRule {
+    /// Higher rank goes first; equal ranks are settled by the game RNG
+    #[default]
+    Rank,
+    /// The game RNG picks the winner
+    Random,
+}
+
+/// Enemy difficulty level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    #[default]
+    Normal,
+    /// Reinforcements enter near the fighting instead of at their spawn zone
+    Hard,
+}
+
+impl Difficulty {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Normal,
+        }
+    }
+}
+
+/// Rank make-up of the enemy force
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EnemyForce {
+    #[default]
+    Standard,
+    /// Green conscripts: privates only, under their squad sergeant
+    Conscripts,
+    /// Seasoned troops, heavy with NCOs and officers
+    Veterans,
+}
+
+impl EnemyForce {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnemyForce::Standard => "Standard",
+            EnemyForce::Conscripts => "Conscripts",
+            EnemyForce::Veterans => "Veterans",
+        }
+    }
+
+    /// Share of each rank (privates/corporals/sergeants/officers) in the force
+    pub fn ranks(&self) -> RankDistribution {
+        match self {
+            EnemyForce::Standard => RankDistribution::default(),
+            EnemyForce::Conscripts => RankDistribution::new(100, 0, 0, 0),
+            EnemyForce::Veterans => RankDistribution::new(20, 35, 35, 10),
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            EnemyForce::Standard => EnemyForce::Conscripts,
+            EnemyForce::Conscripts => EnemyForce::Veterans,
+            EnemyForce::Veterans => EnemyForce::Standard,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            EnemyForce::Standard => EnemyForce::Veterans,
+            EnemyForce::Conscripts => EnemyForce::Standard,
+            EnemyForce::Veterans => EnemyForce::Conscripts,
+        }
+    }
+}
+
+/// Settings for enemy reinforcement waves
+///
+/// Every `interval_turns` turns a wave of `wave_size` fresh soldiers joins
+/// the enemy side, up to `max_waves` waves per match.
+#[derive(Debug, Clone)]
+pub struct ReinforcementsConfig {
+    pub enabled: bool,
+    pub interval_turns: u32,
+    pub wave_size: usize,
+    pub max_waves: u32,
+}
+
+/// Penalties for soldiers fighting on while badly wounded
+///
+/// Below `hp_threshold` of their max HP a soldier's moves cost more time and
+/// their shots are less likely to hit.
+#[derive(Debug, Clone)]
+pub struct WoundedConfig {
+    pub enabled: bool,
+    /// HP fraction (0.0-1.0) below which the penalties apply
+    pub hp_threshold: f32,
+    /// Multiplier on the time cost of each move
+    pub movement_cost_multiplier: f32,
+    /// Multiplier on hit chance
+    pub accuracy_multiplier: f32,
+}
+
+impl WoundedConfig {
+    pub fn is_wounded(&self, hp_fraction: f32) -> bool {
+        self.enabled && hp_fraction < self.hp_threshold
+    }
+
+    /// Movement time multiplier at the given HP fraction (1.0 = no penalty)
+    pub fn movement_factor(&self, hp_fraction: f32) -> f32 {
+        if self.is_wounded(hp_fraction) {
+            self.movement_cost_multiplier
+        } else {
+            1.0
+        }
+    }
+
+    /// Hit chance multiplier at the given HP fraction (1.0 = no penalty)
+    pub fn accuracy_factor(&self, hp_fraction: f32) -> f32 {
+        if self.is_wounded(hp_fraction) {
+            self.accuracy_multiplier
+        } else {
+            1.0
+        }
+    }
+}
+
+impl Default for WoundedConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            hp_threshold: 0.3,
+            movement_cost_multiplier: 1.5,
+            accuracy_multiplier: 0.7,
+        }
+    }
+}
+
+/// Settings for objective flags
+#[derive(Debug, Clone)]
+pub struct ObjectivesConfig {
+    /// Manhattan distance from a flag that counts toward capturing or
+    /// contesting it (0 = the flag's own tile only)
+    pub capture_radius: i32,
+    /// Which flags exist at the start and who holds them
+    pub layout: ObjectiveLayout,
+    /// Scoring weight of each side's home flag
+    pub home_flag_weight: u32,
+    /// Scoring weight of the neutral flag in the middle
+    pub neutral_flag_weight: u32,
+    /// Give each side an aid post at its spawn where wounded can be evacuated
+    pub casualty_evac: bool,
+    /// Turn by which each home flag must fall, or its defenders keep it
+    /// (None = no timer)
+    pub objective_timer: Option<u32>,
+}
+
+impl Default for ObjectivesConfig {
+    fn default() -> Self {
+        Self {
+            capture_radius: 2,
+            layout: ObjectiveLayout::default(),
+            home_flag_weight: 1,
+            neutral_flag_weight: 1,
+            casualty_evac: false,
+            objective_timer: None,
+        }
+    }
+}
+
+/// Starting flag setup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ObjectiveLayout {
+    /// Each side holds a flag near its own spawn
+    #[default]
+    Standard,
+    /// Both home flags plus a neutral flag in the middle
+    NeutralCenter,
+    /// A single neutral flag in the middle that both sides fight for
+    Contested,
+}
+
+impl ObjectiveLayout {
+    pub const ALL: &'static [Self] = &[Self::Standard, Self::NeutralCenter, Self::Contested];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Standard => "Home Flags",
+            Self::NeutralCenter => "Home + Neutral",
+            Self::Contested => "Contested Center",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|&l| l == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(self) -> Self {
+        let idx = Self::ALL.iter().position(|&l| l == self).unwrap_or(0);
+        Self::ALL[(idx + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+impl Default for ReinforcementsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_turns: 10,
+            wave_size: 2,
+            max_waves: 3,
+        }
+    }
+}
+
+impl Default for AttritionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            damage_per_turn: 3,
+        }
+    }
+}
+
+impl Default for RandomEventsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mine_density: 0.5,
+            mine_damage: 80,
+            mine_radius: 1,
+            grenade_dud_chance: 0.1,
+            misfire_chance: 0.02,
+        }
+    }
 }
 
 impl Default for GameConfig {
@@ -22,9 +663,53 @@ impl Default for GameConfig {
         Self {
             time_budget_seconds: 12.0,  // Updated: 10.0 -> 12.0
             turn_order_mode: TurnOrderMode::PlayerFirst,
+            commander_mode: false,
+            dug_in_defenders: false,
+            wire_coils: 0,
             movement_time_cost: 1.5,     // New: 1.5s per tile
             rotation_time_cost: 0.3,     // New: 0.3s per rotation
             tile_scale_meters: 2.0,      // New: ~2 meters per tile
+            hud_thresholds: HudThresholds::default(),
+            camera_deadzone: DEFAULT_CAMERA_DEADZONE,
+            shot_tracers: true,
+            impact_flash: true,
+            heard_shot_turns: 2,
+            watch_orders: false,
+            commander_stats: true,
+            show_ai_scores: false,
+            auto_pause: false,
+            log_verbosity: LogVerbosity::default(),
+            enemy_info: EnemyInfoLevel::default(),
+            random_events: RandomEventsConfig::default(),
+            spot_then_shoot_turns: 0,
+            attrition: AttritionConfig::default(),
+            supply: SupplyConfig::default(),
+            panic_fire: PanicFireConfig::default(),
+            fatigue: FatigueConfig::default(),
+            wounded: WoundedConfig::default(),
+            blood_trails: BloodTrailConfig::default(),
+            move_conflict_rule: MoveConflictRule::default(),
+            corpse_rule: CorpseRule::default(),
+            blocked_step_rule: BlockedStepRule::default(),
+            cover_model: CoverModel::default(),
+            reload_interrupt: ReloadInterrupt::default(),
+            diagonal_los: DiagonalLos::default(),
+            grenade_throw: GrenadeThrow::default(),
+            path_heuristic: PathHeuristic::default(),
+            objectives: ObjectivesConfig::default(),
+            turn_limit: None,
+            time_of_day: TimeOfDay::Day,
+            difficulty: Difficulty::Normal,
+            enemy_ranks: RankDistribution::default(),
+            ai_seed: None,
+            reinforcements: ReinforcementsConfig::default(),
+            deployment: false,
+            rank_time_budgets: false,
+            rank_stats: RankStatsTable::default(),
+            personalities: PersonalityLibrary::default(),
+            glyphs: GlyphTheme::default(),
+            triggers: Vec::new(),
+            player_actions: PlayerActions::default(),
         }
     }
 }
@@ -45,4 +730,302 @@ impl GameConfig {
         self.turn_order_mode = mode;
         self
     }
+
+    /// Play as a disembodied commander instead of a soldier
+    pub fn with_commander_mode(mut self, enabled: bool) -> Self {
+        self.commander_mode = enabled;
+        self
+    }
+
+    /// Place the enemy in its fortifications for an assault on prepared positions
+    pub fn with_dug_in_defenders(mut self, enabled: bool) -> Self {
+        self.dug_in_defenders = enabled;
+        self
+    }
+
+    /// Hand every soldier coils of barbed wire to string in front of their position
+    pub fn with_wire_coils(mut self, coils: u32) -> Self {
+        self.wire_coils = coils;
+        self
+    }
+
+    /// Set the HUD status thresholds
+    pub fn with_hud_thresholds(mut self, thresholds: HudThresholds) -> Self {
+        self.hud_thresholds = thresholds;
+        self
+    }
+
+    /// Set how far the player can move from center before the camera follows (0.1-0.9)
+    pub fn with_camera_deadzone(mut self, ratio: f32) -> Self {
+        self.camera_deadzone = ratio.clamp(0.1, 0.9);
+        self
+    }
+
+    /// Show or hide the tracer line drawn along each shot
+    pub fn with_shot_tracers(mut self, enabled: bool) -> Self {
+        self.shot_tracers = enabled;
+        self
+    }
+
+    /// Turn the border flash for detonations in view on or off
+    pub fn with_impact_flash(mut self, enabled: bool) -> Self {
+        self.impact_flash = enabled;
+        self
+    }
+
+    /// Set how many turns a heard shot stays marked (0 turns the markers off)
+    pub fn with_heard_shot_turns(mut self, turns: u32) -> Self {
+        self.heard_shot_turns = turns;
+        self
+    }
+
+    /// Show or hide the force stats on the commander's panel
+    pub fn with_commander_stats(mut self, enabled: bool) -> Self {
+        self.commander_stats = enabled;
+        self
+    }
+
+    /// Start with the AI scoring overlay shown (and recording) or hidden
+    pub fn with_ai_scores(mut self, enabled: bool) -> Self {
+        self.show_ai_scores = enabled;
+        self
+    }
+
+    /// Follow ordered squads with the camera, or leave the camera alone
+    pub fn with_watch_orders(mut self, enabled: bool) -> Self {
+        self.watch_orders = enabled;
+        self
+    }
+
+    /// Enable or disable pausing on significant events
+    pub fn with_auto_pause(mut self, enabled: bool) -> Self {
+        self.auto_pause = enabled;
+        self
+    }
+
+    /// Set how much routine detail the event log reports
+    pub fn with_log_verbosity(mut self, verbosity: LogVerbosity) -> Self {
+        self.log_verbosity = verbosity;
+        self
+    }
+
+    /// Set how much is revealed about enemy soldiers
+    pub fn with_enemy_info(mut self, level: EnemyInfoLevel) -> Self {
+        self.enemy_info = level;
+        self
+    }
+
+    /// Set whether cover works against the hit roll or the damage
+    pub fn with_cover_model(mut self, model: CoverModel) -> Self {
+        self.cover_model = model;
+        self
+    }
+
+    /// Set whether a hit spoils a reload under way
+    pub fn with_reload_interrupt(mut self, rule: ReloadInterrupt) -> Self {
+        self.reload_interrupt = rule;
+        self
+    }
+
+    /// Set whether sight can pass diagonally between two corner walls
+    pub fn with_diagonal_los(mut self, rule: DiagonalLos) -> Self {
+        self.diagonal_los = rule;
+        self
+    }
+
+    /// Set the distance estimate path searches steer by
+    pub fn with_path_heuristic(mut self, heuristic: PathHeuristic) -> Self {
+        self.path_heuristic = heuristic;
+        self
+    }
+
+    /// Set whether a grenade's landing tile must be in the thrower's sight
+    pub fn with_grenade_throw(mut self, rule: GrenadeThrow) -> Self {
+        self.grenade_throw = rule;
+        self
+    }
+
+    /// Enable or disable the random events layer
+    pub fn with_random_events(mut self, enabled: bool) -> Self {
+        self.random_events.enabled = enabled;
+        self
+    }
+
+    /// Enable or disable supply-line attrition for cut-off soldiers
+    pub fn with_supply_lines(mut self, enabled: bool) -> Self {
+        self.supply.enabled = enabled;
+        self
+    }
+
+    /// Enable or disable panic fire from suppressed AI soldiers
+    pub fn with_panic_fire(mut self, enabled: bool) -> Self {
+        self.panic_fire.enabled = enabled;
+        self
+    }
+
+    /// Enable or disable battle fatigue
+    pub fn with_fatigue(mut self, enabled: bool) -> Self {
+        self.fatigue.enabled = enabled;
+        self
+    }
+
+    /// Enable or disable no-man's-land attrition
+    pub fn with_nml_attrition(mut self, enabled: bool) -> Self {
+        self.attrition.enabled = enabled;
+        self
+    }
+
+    /// Enable or disable the wounded movement and accuracy penalties
+    pub fn with_wounded_penalties(mut self, enabled: bool) -> Self {
+        self.wounded.enabled = enabled;
+        self
+    }
+
+    /// Enable or disable blood trails behind badly wounded soldiers
+    pub fn with_blood_trails(mut self, enabled: bool) -> Self {
+        self.blood_trails.enabled = enabled;
+        self
+    }
+
+    /// Set how simultaneous moves into the same tile are settled
+    pub fn with_move_conflict_rule(mut self, rule: MoveConflictRule) -> Self {
+        self.move_conflict_rule = rule;
+        self
+    }
+
+    /// Set whether corpses block their tile
+    pub fn with_corpse_rule(mut self, rule: CorpseRule) -> Self {
+        self.corpse_rule = rule;
+        self
+    }
+
+    /// Set what path followers do when a friend blocks their next step
+    pub fn with_blocked_step_rule(mut self, rule: BlockedStepRule) -> Self {
+        self.blocked_step_rule = rule;
+        self
+    }
+
+    /// Set how far from a flag soldiers count toward capturing it
+    pub fn with_capture_radius(mut self, radius: i32) -> Self {
+        self.objectives.capture_radius = radius.max(0);
+        self
+    }
+
+    /// Choose which flags exist at the start and who holds them
+    pub fn with_objective_layout(mut self, layout: ObjectiveLayout) -> Self {
+        self.objectives.layout = layout;
+        self
+    }
+
+    /// Set how much the home flags and the neutral flag each count in scoring
+    pub fn with_objective_weights(mut self, home: u32, neutral: u32) -> Self {
+        self.objectives.home_flag_weight = home;
+        self.objectives.neutral_flag_weight = neutral;
+        self
+    }
+
+    /// Decide the match on points after this many turns (None = no limit)
+    pub fn with_turn_limit(mut self, turn_limit: Option<u32>) -> Self {
+        self.turn_limit = turn_limit;
+        self
+    }
+
+    /// Fight by day or by night
+    pub fn with_time_of_day(mut self, time_of_day: TimeOfDay) -> Self {
+        self.time_of_day = time_of_day;
+        self
+    }
+
+    /// Set the rank make-up of the enemy force
+    pub fn with_enemy_ranks(mut self, ranks: RankDistribution) -> Self {
+        self.enemy_ranks = ranks;
+        self
+    }
+
+    /// Set the enemy difficulty
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Seed AI decisions separately from the map
+    pub fn with_ai_seed(mut self, seed: u64) -> Self {
+        self.ai_seed = Some(seed);
+        self
+    }
+
+    /// Enable or disable enemy reinforcement waves
+    pub fn with_reinforcements(mut self, enabled: bool) -> Self {
+        self.reinforcements.enabled = enabled;
+        self
+    }
+
+    /// Enable or disable aid posts for evacuating the wounded
+    pub fn with_casualty_evac(mut self, enabled: bool) -> Self {
+        self.objectives.casualty_evac = enabled;
+        self
+    }
+
+    /// Set the turn by which each home flag must fall (None = no timer)
+    pub fn with_objective_timer(mut self, turn: Option<u32>) -> Self {
+        self.objectives.objective_timer = turn;
+        self
+    }
+
+    /// Enable or disable the deployment phase before the first turn
+    pub fn with_deployment(mut self, enabled: bool) -> Self {
+        self.deployment = enabled;
+        self
+    }
+
+    /// Give quicker (usually higher-ranked) soldiers a longer turn budget
+    pub fn with_rank_time_budgets(mut self, enabled: bool) -> Self {
+        self.rank_time_budgets = enabled;
+        self
+    }
+
+    /// Turn budget for a soldier with `stats` under the current rules
+    pub fn time_budget_for(&self, stats: &SoldierStats) -> f32 {
+        if self.rank_time_budgets {
+            self.time_budget_seconds * stats.budget_factor()
+        } else {
+            self.time_budget_seconds
+        }
+    }
+
+    /// Use a custom per-rank stats table
+    pub fn with_rank_stats(mut self, rank_stats: RankStatsTable) -> Self {
+        self.rank_stats = rank_stats;
+        self
+    }
+
+    /// Use AI personalities loaded from a data file
+    pub fn with_personalities(mut self, personalities: PersonalityLibrary) -> Self {
+        self.personalities = personalities;
+        self
+    }
+
+    /// Draw the battlefield with a custom glyph theme
+    pub fn with_glyphs(mut self, glyphs: GlyphTheme) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    /// Run a custom mission's triggers
+    pub fn with_triggers(mut self, triggers: Vec<Trigger>) -> Self {
+        self.triggers = triggers;
+        self
+    }
+
+    /// Bar the player from the given actions, for tutorial or challenge battles
+    pub fn with_disabled_actions(mut self, actions: impl IntoIterator<Item = PlayerAction>) -> Self {
+        self.player_actions = self.player_actions.with_disabled(actions);
+        self
+    }
+
+    /// Require AI soldiers to track an enemy for `turns` turns before firing
+    pub fn with_spot_then_shoot(mut self, turns: u32) -> Self {
+        self.spot_then_shoot_turns = turns;
+        self
+    }
 }
@@ -0,0 +1,265 @@
+// Glyph theme data file
+// Characters used to draw terrain, soldiers and flags, loaded from TOML
+//
+// The Unicode set is the game's native look; the ASCII set swaps every
+// box-drawing and symbol glyph for a plain character so the map stays
+// readable on terminals and fonts without them. A data file picks a base
+// set and may override individual glyphs on top of it.
+
+use crate::components::soldier::Rank;
+use crate::game_logic::battlefield::TerrainType;
+use crate::game_logic::objectives::FlagStatus;
+use serde::de::value::StrDeserializer;
+use serde::de::{DeserializeOwned, IntoDeserializer};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+/// Default location of the glyph theme file, relative to the working directory
+pub const GLYPH_THEME_PATH: &str = "data/glyphs.toml";
+
+/// Built-in glyph set a theme starts from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GlyphSet {
+    #[default]
+    Unicode,
+    Ascii,
+}
+
+/// Characters for everything drawn on the battlefield
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GlyphTheme {
+    pub base: GlyphSet,
+    terrain: HashMap<TerrainType, char>,
+    ranks: HashMap<Rank, char>,
+    flags: HashMap<FlagStatus, char>,
+}
+
+/// Error loading or parsing a glyph theme file
+#[derive(Debug)]
+pub enum GlyphThemeError {
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+    UnknownTerrain(String),
+    UnknownRank(String),
+    UnknownFlag(String),
+}
+
+impl fmt::Display for GlyphThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GlyphThemeError::Io(e) => write!(f, "could not read glyph theme: {}", e),
+            GlyphThemeError::Parse(e) => write!(f, "invalid glyph theme file: {}", e),
+            GlyphThemeError::UnknownTerrain(name) => {
+                write!(f, "invalid glyph theme file: unknown terrain '{}'", name)
+            }
+            GlyphThemeError::UnknownRank(name) => {
+                write!(f, "invalid glyph theme file: unknown rank '{}'", name)
+            }
+            GlyphThemeError::UnknownFlag(name) => {
+                write!(f, "invalid glyph theme file: unknown flag status '{}'", name)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GlyphThemeFile {
+    base: Option<GlyphSet>,
+    #[serde(default)]
+    terrain: HashMap<String, char>,
+    #[serde(default)]
+    ranks: HashMap<String, char>,
+    #[serde(default)]
+    flags: HashMap<String, char>,
+}
+
+impl GlyphTheme {
+    /// The game's native Unicode glyphs
+    pub fn unicode() -> Self {
+        Self::default()
+    }
+
+    /// Plain ASCII glyphs for limited terminals
+    pub fn ascii() -> Self {
+        Self {
+            base: GlyphSet::Ascii,
+            ..Self::default()
+        }
+    }
+
+    /// Character for a terrain type
+    pub fn terrain(&self, terrain: TerrainType) -> char {
+        if let Some(ch) = self.terrain.get(&terrain) {
+            return *ch;
+        }
+        match self.base {
+            GlyphSet::Unicode => terrain.properties().character,
+            GlyphSet::Ascii => ascii_terrain(terrain),
+        }
+    }
+
+    /// Character for a soldier of the given rank
+    pub fn rank(&self, rank: Rank) -> char {
+        if let Some(ch) = self.ranks.get(&rank) {
+            return *ch;
+        }
+        match self.base {
+            GlyphSet::Unicode => rank.to_icon(),
+            GlyphSet::Ascii => match rank {
+                Rank::Captain => 'C',
+                Rank::Lieutenant => 'L',
+                Rank::Sergeant => 's',
+                Rank::Corporal => 'c',
+                Rank::Private => 'p',
+            },
+        }
+    }
+
+    /// Character for an objective flag in the given status
+    pub fn flag(&self, status: FlagStatus) -> char {
+        if let Some(ch) = self.flags.get(&status) {
+            return *ch;
+        }
+        match (self.base, status) {
+            (GlyphSet::Unicode, FlagStatus::Neutral) => '⚐',
+            (GlyphSet::Unicode, _) => '⚑',
+            (GlyphSet::Ascii, FlagStatus::Neutral) => '?',
+            (GlyphSet::Ascii, _) => '!',
+        }
+    }
+
+    /// Parse a TOML document into a theme
+    pub fn from_toml_str(contents: &str) -> Result<Self, GlyphThemeError> {
+        let file: GlyphThemeFile = toml::from_str(contents).map_err(GlyphThemeError::Parse)?;
+
+        let mut terrain = HashMap::new();
+        for (name, ch) in file.terrain {
+            let terrain_type =
+                parse_key(&name).ok_or(GlyphThemeError::UnknownTerrain(name))?;
+            terrain.insert(terrain_type, ch);
+        }
+
+        let mut ranks = HashMap::new();
+        for (name, ch) in file.ranks {
+            let rank = parse_key(&name).ok_or(GlyphThemeError::UnknownRank(name))?;
+            ranks.insert(rank, ch);
+        }
+
+        let mut flags = HashMap::new();
+        for (name, ch) in file.flags {
+            let status = parse_key(&name).ok_or(GlyphThemeError::UnknownFlag(name))?;
+            flags.insert(status, ch);
+        }
+
+        Ok(Self {
+            base: file.base.unwrap_or_default(),
+            terrain,
+            ranks,
+            flags,
+        })
+    }
+
+    /// Load a glyph theme file; a missing file yields the Unicode theme
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, GlyphThemeError> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::from_toml_str(&contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(GlyphThemeError::Io(e)),
+        }
+    }
+}
+
+/// Parse a snake_case table key into the enum variant it names
+fn parse_key<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let deserializer: StrDeserializer<'_, serde::de::value::Error> = key.into_deserializer();
+    T::deserialize(deserializer).ok()
+}
+
+/// ASCII stand-in for each terrain's Unicode glyph
+fn ascii_terrain(terrain: TerrainType) -> char {
+    match terrain {
+        TerrainType::NoMansLand => '.',
+        TerrainType::Grass => ',',
+        TerrainType::Mud => ':',
+        TerrainType::Water => '~',
+        TerrainType::DeepWater => 'W',
+        TerrainType::TrenchFloor => '_',
+        TerrainType::TrenchParapet => '"',
+        TerrainType::TrenchRamp => '/',
+        TerrainType::Trench => 't',
+        TerrainType::Sandbags => '[',
+        TerrainType::Bunker => 'B',
+        TerrainType::MgNest => 'M',
+        TerrainType::BarbedWire => 'x',
+        TerrainType::Tree => 'T',
+        TerrainType::Forest => '&',
+        TerrainType::Hedge => 'h',
+        TerrainType::Rubble => '%',
+        TerrainType::ShellCrater => 'o',
+        TerrainType::CraterWater => 'O',
+        TerrainType::BuildingWall => '#',
+        TerrainType::BuildingFloor => '.',
+        TerrainType::BuildingDoor => '+',
+        TerrainType::BuildingWindow => '|',
+        TerrainType::Ruins => 'R',
+        TerrainType::Road => '=',
+        TerrainType::Path => '-',
+        TerrainType::CommTrench => 'H',
+        TerrainType::Fortification => 'F',
+        TerrainType::CivilianBuilding => 'b',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_theme_replaces_water_and_tree() {
+        let unicode = GlyphTheme::unicode();
+        let ascii = GlyphTheme::ascii();
+
+        assert_eq!(unicode.terrain(TerrainType::Water), '≈');
+        assert_eq!(unicode.terrain(TerrainType::Tree), '♣');
+        assert_eq!(ascii.terrain(TerrainType::Water), '~');
+        assert_eq!(ascii.terrain(TerrainType::Tree), 'T');
+        assert!(ascii.rank(Rank::Captain).is_ascii());
+    }
+
+    #[test]
+    fn test_file_overrides_single_glyphs() {
+        let theme = GlyphTheme::from_toml_str(
+            r#"
+            base = "ascii"
+
+            [terrain]
+            deep_water = "~"
+
+            [ranks]
+            captain = "K"
+
+            [flags]
+            contested = "*"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(theme.terrain(TerrainType::DeepWater), '~');
+        assert_eq!(theme.terrain(TerrainType::Tree), 'T');
+        assert_eq!(theme.rank(Rank::Captain), 'K');
+        assert_eq!(theme.flag(FlagStatus::Contested), '*');
+        assert_eq!(theme.flag(FlagStatus::Held), '!');
+        assert!(GlyphTheme::from_toml_str("[flags]\nburning = \"^\"\n").is_err());
+        assert!(GlyphTheme::from_toml_str("[terrain]\nlava = \"^\"\n").is_err());
+    }
+
+    #[test]
+    fn test_shipped_data_file_loads() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(GLYPH_THEME_PATH);
+        assert_eq!(GlyphTheme::load(path).unwrap(), GlyphTheme::unicode());
+    }
+}
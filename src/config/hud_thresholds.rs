@@ -0,0 +1,145 @@
+// HUD status thresholds
+// When the info and context panels report a soldier as wounded or short on time
+
+/// Cut-off points for HUD status labels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HudThresholds {
+    /// HP fraction (0.0-1.0) at or below which a soldier is Wounded
+    pub hp_wounded: f32,
+    /// HP fraction at or below which a soldier is Critical
+    pub hp_critical: f32,
+    /// Seconds left at or below which the time budget is Low
+    pub time_low: f32,
+    /// Seconds left at or below which the time budget is Critical
+    pub time_critical: f32,
+}
+
+/// Smallest gap kept between a "worse" and a "better" threshold
+const HP_STEP: f32 = 0.05;
+const TIME_STEP: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HpStatus {
+    Healthy,
+    Wounded,
+    Critical,
+}
+
+impl HpStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HpStatus::Healthy => "Healthy",
+            HpStatus::Wounded => "Wounded",
+            HpStatus::Critical => "Critical",
+        }
+    }
+
+    pub fn color_name(&self) -> &'static str {
+        match self {
+            HpStatus::Healthy => "GREEN",
+            HpStatus::Wounded => "YELLOW",
+            HpStatus::Critical => "RED",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeStatus {
+    Debt,
+    Good,
+    Low,
+    Critical,
+}
+
+impl TimeStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeStatus::Debt => "DEBT",
+            TimeStatus::Good => "Good",
+            TimeStatus::Low => "Low",
+            TimeStatus::Critical => "Critical",
+        }
+    }
+}
+
+impl HudThresholds {
+    pub fn hp_status(&self, hp_fraction: f32) -> HpStatus {
+        if hp_fraction > self.hp_wounded {
+            HpStatus::Healthy
+        } else if hp_fraction > self.hp_critical {
+            HpStatus::Wounded
+        } else {
+            HpStatus::Critical
+        }
+    }
+
+    pub fn time_status(&self, available: f32, time_debt: f32) -> TimeStatus {
+        if time_debt < 0.0 {
+            TimeStatus::Debt
+        } else if available > self.time_low {
+            TimeStatus::Good
+        } else if available > self.time_critical {
+            TimeStatus::Low
+        } else {
+            TimeStatus::Critical
+        }
+    }
+
+    /// Nudge the Wounded threshold, staying above Critical
+    pub fn adjust_hp_wounded(&mut self, delta: f32) {
+        self.hp_wounded = (self.hp_wounded + delta).clamp(self.hp_critical + HP_STEP, 1.0 - HP_STEP);
+    }
+
+    /// Nudge the Critical threshold, staying below Wounded
+    pub fn adjust_hp_critical(&mut self, delta: f32) {
+        self.hp_critical = (self.hp_critical + delta).clamp(HP_STEP, self.hp_wounded - HP_STEP);
+    }
+
+    /// Nudge the Low time threshold, staying above Critical
+    pub fn adjust_time_low(&mut self, delta: f32) {
+        self.time_low = (self.time_low + delta).clamp(self.time_critical + TIME_STEP, 10.0);
+    }
+
+    /// Nudge the Critical time threshold, staying below Low
+    pub fn adjust_time_critical(&mut self, delta: f32) {
+        self.time_critical = (self.time_critical + delta).clamp(0.0, self.time_low - TIME_STEP);
+    }
+}
+
+impl Default for HudThresholds {
+    fn default() -> Self {
+        Self {
+            hp_wounded: 0.7,
+            hp_critical: 0.3,
+            time_low: 3.0,
+            time_critical: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_threshold_changes_hp_label() {
+        let defaults = HudThresholds::default();
+        assert_eq!(defaults.hp_status(0.6).label(), "Wounded");
+
+        let mut custom = defaults;
+        custom.adjust_hp_wounded(-0.2);
+        assert_eq!(custom.hp_status(0.6).label(), "Healthy");
+        assert_eq!(custom.hp_status(0.2).label(), "Critical");
+    }
+
+    #[test]
+    fn test_thresholds_stay_ordered() {
+        let mut thresholds = HudThresholds::default();
+        thresholds.adjust_hp_critical(1.0);
+        assert!(thresholds.hp_critical < thresholds.hp_wounded);
+
+        thresholds.adjust_time_low(-10.0);
+        assert!(thresholds.time_critical < thresholds.time_low);
+        assert_eq!(thresholds.time_status(5.0, -1.0), TimeStatus::Debt);
+    }
+}
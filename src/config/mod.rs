@@ -3,3 +3,8 @@
 
 pub mod battlefield_config;
 pub mod game_config;
+pub mod glyph_theme;
+pub mod hud_thresholds;
+pub mod new_game_defaults;
+pub mod rank_stats;
+pub mod scenario;
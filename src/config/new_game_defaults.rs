@@ -0,0 +1,119 @@
+// New game defaults file
+// The last new-game setup the player started, saved so the menu reopens on it
+
+use crate::config::battlefield_config::{FortificationCounts, FortificationLevel, TrenchDensity};
+use crate::config::game_config::{Difficulty, EnemyForce, ObjectiveLayout, ObjectivesConfig};
+use crate::game_logic::soldier_spawning::RankDistribution;
+use crate::simulation::weather::TimeOfDay;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+/// Default location of the saved setup, relative to the working directory
+pub const NEW_GAME_DEFAULTS_PATH: &str = "new_game.toml";
+
+/// Every value the new game screen lets the player set
+///
+/// Sizes and counts are stored as values rather than menu positions, so a
+/// file stays valid if the menu's option lists change; a value no longer on
+/// offer falls back to the menu's default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewGameDefaults {
+    pub preset: String,
+    pub map_width: usize,
+    pub map_height: usize,
+    pub trench_density: TrenchDensity,
+    #[serde(default)]
+    pub support_trench_lines: usize,
+    pub fortification_level: FortificationLevel,
+    pub fortification_counts: Option<FortificationCounts>,
+    pub mud_coverage: f32,
+    pub crater_density: f32,
+    pub forest_coverage: f32,
+    pub building_density: f32,
+    pub barbed_wire_coverage: f32,
+    pub noise_scale: f32,
+    pub noise_octaves: u32,
+    pub noise_persistence: f32,
+    pub seed: u64,
+    /// Seed for AI decisions; None reuses the map seed
+    #[serde(default)]
+    pub ai_seed: Option<u64>,
+    pub ally_count: usize,
+    pub enemy_count: usize,
+    #[serde(default)]
+    pub dug_in_defenders: bool,
+    #[serde(default)]
+    pub wire_coils: u32,
+    pub commander_mode: bool,
+    pub objective_layout: ObjectiveLayout,
+    #[serde(default = "default_capture_radius")]
+    pub capture_radius: i32,
+    #[serde(default)]
+    pub casualty_evac: bool,
+    #[serde(default)]
+    pub objective_timer: Option<u32>,
+    pub turn_limit: Option<u32>,
+    pub time_of_day: TimeOfDay,
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub enemy_force: EnemyForce,
+    /// Enemy rank shares; None takes the force preset's
+    #[serde(default)]
+    pub enemy_ranks: Option<RankDistribution>,
+    pub reinforcements: bool,
+    #[serde(default)]
+    pub supply_lines: bool,
+    #[serde(default)]
+    pub panic_fire: bool,
+    #[serde(default)]
+    pub fatigue: bool,
+    #[serde(default)]
+    pub deployment: bool,
+    #[serde(default)]
+    pub rank_time_budgets: bool,
+    pub time_budget: f32,
+}
+
+fn default_capture_radius() -> i32 {
+    ObjectivesConfig::default().capture_radius
+}
+
+/// Error loading or saving the new game defaults file
+#[derive(Debug)]
+pub enum NewGameDefaultsError {
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+    Serialize(toml::ser::Error),
+}
+
+impl fmt::Display for NewGameDefaultsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NewGameDefaultsError::Io(e) => write!(f, "could not access new game defaults: {}", e),
+            NewGameDefaultsError::Parse(e) => write!(f, "invalid new game defaults file: {}", e),
+            NewGameDefaultsError::Serialize(e) => {
+                write!(f, "could not write new game defaults: {}", e)
+            }
+        }
+    }
+}
+
+impl NewGameDefaults {
+    /// Load the saved setup; a missing file yields None
+    pub fn load(path: impl AsRef<Path>) -> Result<Option<Self>, NewGameDefaultsError> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map(Some)
+                .map_err(NewGameDefaultsError::Parse),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(NewGameDefaultsError::Io(e)),
+        }
+    }
+
+    /// Write the setup, replacing any earlier one
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), NewGameDefaultsError> {
+        let contents = toml::to_string(self).map_err(NewGameDefaultsError::Serialize)?;
+        std::fs::write(path, contents).map_err(NewGameDefaultsError::Io)
+    }
+}
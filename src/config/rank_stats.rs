@@ -0,0 +1,170 @@
+// Rank stats data file
+// Per-rank base stats loaded from TOML, with Rank::base_stats() as fallback
+
+use crate::components::soldier::{Rank, RankBaseStats};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+/// Default location of the rank stats file, relative to the working directory
+pub const RANK_STATS_PATH: &str = "data/rank_stats.toml";
+
+/// Base stats for every rank
+///
+/// Starts from the compiled defaults; a data file only needs to list the
+/// ranks and fields it changes.
+#[derive(Debug, Clone)]
+pub struct RankStatsTable {
+    stats: HashMap<Rank, RankBaseStats>,
+}
+
+/// Error loading or parsing a rank stats file
+#[derive(Debug)]
+pub enum RankStatsError {
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+}
+
+impl fmt::Display for RankStatsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RankStatsError::Io(e) => write!(f, "could not read rank stats: {}", e),
+            RankStatsError::Parse(e) => write!(f, "invalid rank stats file: {}", e),
+        }
+    }
+}
+
+/// One rank's section in the data file; omitted fields keep their default
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RankStatsOverride {
+    base_hp: Option<i32>,
+    vision_range: Option<i32>,
+    accuracy_base: Option<f32>,
+    movement_speed_base: Option<f32>,
+    carrying_capacity_base: Option<i32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RankStatsFile {
+    private: Option<RankStatsOverride>,
+    corporal: Option<RankStatsOverride>,
+    sergeant: Option<RankStatsOverride>,
+    lieutenant: Option<RankStatsOverride>,
+    captain: Option<RankStatsOverride>,
+}
+
+impl RankStatsOverride {
+    fn apply(&self, stats: &mut RankBaseStats) {
+        if let Some(v) = self.base_hp {
+            stats.base_hp = v;
+        }
+        if let Some(v) = self.vision_range {
+            stats.vision_range = v;
+        }
+        if let Some(v) = self.accuracy_base {
+            stats.accuracy_base = v;
+        }
+        if let Some(v) = self.movement_speed_base {
+            stats.movement_speed_base = v;
+        }
+        if let Some(v) = self.carrying_capacity_base {
+            stats.carrying_capacity_base = v;
+        }
+    }
+}
+
+impl RankStatsTable {
+    /// Base stats for a rank
+    pub fn get(&self, rank: Rank) -> RankBaseStats {
+        self.stats.get(&rank).copied().unwrap_or_else(|| rank.base_stats())
+    }
+
+    /// Parse a TOML document, applying it on top of the compiled defaults
+    pub fn from_toml_str(contents: &str) -> Result<Self, RankStatsError> {
+        let file: RankStatsFile = toml::from_str(contents).map_err(RankStatsError::Parse)?;
+        let mut table = Self::default();
+
+        let sections = [
+            (Rank::Private, &file.private),
+            (Rank::Corporal, &file.corporal),
+            (Rank::Sergeant, &file.sergeant),
+            (Rank::Lieutenant, &file.lieutenant),
+            (Rank::Captain, &file.captain),
+        ];
+
+        for (rank, section) in sections {
+            if let (Some(section), Some(stats)) = (section, table.stats.get_mut(&rank)) {
+                section.apply(stats);
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Load a rank stats file; a missing file yields the compiled defaults
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RankStatsError> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::from_toml_str(&contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(RankStatsError::Io(e)),
+        }
+    }
+}
+
+impl Default for RankStatsTable {
+    fn default() -> Self {
+        Self {
+            stats: Rank::all()
+                .into_iter()
+                .map(|rank| (rank, rank.base_stats()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_file_overrides_base_hp() {
+        let table = RankStatsTable::from_toml_str(
+            r#"
+            [sergeant]
+            base_hp = 175
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(table.get(Rank::Sergeant).base_hp, 175);
+        // Untouched fields and ranks keep the compiled defaults
+        assert_eq!(
+            table.get(Rank::Sergeant).vision_range,
+            Rank::Sergeant.base_stats().vision_range
+        );
+        assert_eq!(table.get(Rank::Private), Rank::Private.base_stats());
+    }
+
+    #[test]
+    fn test_missing_file_falls_back_to_defaults() {
+        let table = RankStatsTable::load("does/not/exist.toml").unwrap();
+        assert_eq!(table.get(Rank::Captain), Rank::Captain.base_stats());
+    }
+
+    #[test]
+    fn test_unknown_field_is_rejected() {
+        assert!(RankStatsTable::from_toml_str("[private]\nbase_mp = 3\n").is_err());
+    }
+
+    #[test]
+    fn test_shipped_data_file_matches_defaults() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(RANK_STATS_PATH);
+        let table = RankStatsTable::load(path).unwrap();
+        for rank in Rank::all() {
+            assert_eq!(table.get(rank), rank.base_stats());
+        }
+    }
+}
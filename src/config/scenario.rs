@@ -0,0 +1,167 @@
+// Scenario file
+// Triggers for a custom mission, loaded from TOML
+//
+// Each [[trigger]] pairs a condition with an effect:
+//
+//   [[trigger]]
+//   condition = { turn_at_least = 20 }
+//   effect = { end_game = { winner = "central_powers" } }
+//
+//   [[trigger]]
+//   condition = { flag_held = { faction = "allies", flag = "neutral" } }
+//   effect = { spawn_reinforcements = { faction = "central_powers", count = 4 } }
+//
+// A tutorial or challenge mission can also bar the player from some actions:
+//
+//   disabled_actions = ["grenade", "crawl"]
+//
+// and say how much each flag counts toward the score:
+//
+//   flag_weights = { home = 1, neutral = 3 }
+
+use crate::config::game_config::GameConfig;
+use crate::game_logic::player_actions::PlayerAction;
+use crate::game_logic::triggers::Trigger;
+use serde::Deserialize;
+use std::fmt;
+use std::path::Path;
+
+/// Default location of the scenario file, relative to the working directory
+pub const SCENARIO_PATH: &str = "data/scenario.toml";
+
+/// Error loading or parsing a scenario file
+#[derive(Debug)]
+pub enum ScenarioError {
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioError::Io(e) => write!(f, "could not read scenario: {}", e),
+            ScenarioError::Parse(e) => write!(f, "invalid scenario file: {}", e),
+        }
+    }
+}
+
+/// What each home flag and the neutral flag count toward the score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FlagWeights {
+    #[serde(default = "default_flag_weight")]
+    pub home: u32,
+    #[serde(default = "default_flag_weight")]
+    pub neutral: u32,
+}
+
+fn default_flag_weight() -> u32 {
+    1
+}
+
+/// A custom mission: its triggers, the player actions it rules out and how its flags score
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    #[serde(default, rename = "trigger")]
+    pub triggers: Vec<Trigger>,
+    #[serde(default)]
+    pub disabled_actions: Vec<PlayerAction>,
+    /// None keeps the game's own weights
+    #[serde(default)]
+    pub flag_weights: Option<FlagWeights>,
+}
+
+impl Scenario {
+    /// Lay this mission's rules over `config`
+    pub fn apply_to(self, config: GameConfig) -> GameConfig {
+        let config = config
+            .with_triggers(self.triggers)
+            .with_disabled_actions(self.disabled_actions);
+        match self.flag_weights {
+            Some(weights) => config.with_objective_weights(weights.home, weights.neutral),
+            None => config,
+        }
+    }
+}
+
+/// Parse a scenario from a TOML document
+pub fn scenario_from_toml_str(contents: &str) -> Result<Scenario, ScenarioError> {
+    toml::from_str(contents).map_err(ScenarioError::Parse)
+}
+
+/// Parse a scenario's triggers from a TOML document
+pub fn triggers_from_toml_str(contents: &str) -> Result<Vec<Trigger>, ScenarioError> {
+    Ok(scenario_from_toml_str(contents)?.triggers)
+}
+
+/// Load a scenario; a missing file yields an empty one
+pub fn load_scenario(path: impl AsRef<Path>) -> Result<Scenario, ScenarioError> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => scenario_from_toml_str(&contents),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Scenario::default()),
+        Err(e) => Err(ScenarioError::Io(e)),
+    }
+}
+
+/// Load a scenario's triggers; a missing file yields none
+pub fn load_triggers(path: impl AsRef<Path>) -> Result<Vec<Trigger>, ScenarioError> {
+    Ok(load_scenario(path)?.triggers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::Faction;
+    use crate::game_logic::triggers::{TriggerCondition, TriggerEffect};
+
+    #[test]
+    fn test_parse_scenario_triggers() {
+        let triggers = triggers_from_toml_str(
+            r#"
+            [[trigger]]
+            condition = { turn_at_least = 5 }
+            effect = { end_game = {} }
+
+            [[trigger]]
+            condition = { casualties_exceed = { faction = "allies", count = 3 } }
+            effect = { log_message = "The line is wavering" }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(triggers.len(), 2);
+        assert_eq!(triggers[0].condition, TriggerCondition::TurnAtLeast(5));
+        assert_eq!(triggers[0].effect, TriggerEffect::EndGame { winner: None });
+        assert_eq!(
+            triggers[1].condition,
+            TriggerCondition::CasualtiesExceed {
+                faction: Faction::Allies,
+                count: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_disabled_actions() {
+        let scenario = scenario_from_toml_str(r#"disabled_actions = ["grenade", "swap_weapon"]"#).unwrap();
+        assert!(scenario.triggers.is_empty());
+        assert_eq!(
+            scenario.disabled_actions,
+            vec![PlayerAction::Grenade, PlayerAction::SwapWeapon]
+        );
+    }
+
+    #[test]
+    fn test_flag_weights_reach_the_game() {
+        let scenario = scenario_from_toml_str("flag_weights = { neutral = 3 }").unwrap();
+        assert_eq!(scenario.flag_weights, Some(FlagWeights { home: 1, neutral: 3 }));
+
+        let config = scenario.apply_to(GameConfig::default());
+        assert_eq!(config.objectives.home_flag_weight, 1);
+        assert_eq!(config.objectives.neutral_flag_weight, 3);
+
+        // No weights in the file: the game's own stand
+        let config = scenario_from_toml_str("").unwrap().apply_to(GameConfig::default());
+        assert_eq!(config.objectives.neutral_flag_weight, 1);
+    }
+}
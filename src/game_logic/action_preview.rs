@@ -0,0 +1,180 @@
+// Action cost preview
+// Time an action will take, worked out before the player commits to it
+//
+// The player's step and the preview of the eight tiles around them are both
+// priced by step_action and effective_load, so the number shown on a tile is
+// the number the time budget loses when the player steps onto it. Paths and
+// the AI price their steps with the same load through fatigued_load.
+
+use crate::components::{
+    action::ActionType,
+    health::Health,
+    inventory::Inventory,
+    rested::Rested,
+    soldier_stats::{load_factor, SoldierStats},
+    weapon::Weapon,
+};
+use crate::config::game_config::{FatigueConfig, WoundedConfig};
+use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos};
+use crate::game_logic::turn_state::TurnState;
+use specs::{Entity, World, WorldExt};
+
+/// The eight steps around a soldier, clockwise from north
+pub const STEP_DIRECTIONS: [(i32, i32); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// The walk or crawl onto the tile `(dx, dy)` from `from`, priced by its terrain
+///
+/// None when the tile is off the map.
+pub fn step_action(
+    battlefield: &Battlefield,
+    from: BattlefieldPos,
+    dx: i32,
+    dy: i32,
+    crawling: bool,
+) -> Option<ActionType> {
+    let to = BattlefieldPos::new(from.x + dx, from.y + dy);
+    if !battlefield.in_bounds(&to) {
+        return None;
+    }
+    let terrain_cost = battlefield
+        .get_tile(&to)
+        .map(|tile| tile.terrain.movement_cost())
+        .unwrap_or(1.0);
+
+    Some(if crawling {
+        ActionType::Crawl {
+            dx,
+            dy,
+            terrain_cost,
+        }
+    } else {
+        ActionType::Move {
+            dx,
+            dy,
+            terrain_cost,
+        }
+    })
+}
+
+/// How much kit weight and battle fatigue slow `entity`'s moves (1.0 = fresh and unladen)
+pub fn effective_load(world: &World, entity: Entity, fatigue: &FatigueConfig) -> f32 {
+    fatigued_load(
+        world.read_storage::<SoldierStats>().get(entity),
+        world.read_storage::<Inventory>().get(entity),
+        world.read_storage::<Weapon>().get(entity),
+        fatigue,
+        world.read_resource::<TurnState>().current_turn,
+        world.read_storage::<Rested>().get(entity),
+    )
+}
+
+/// effective_load from a system's storages rather than the world
+pub fn fatigued_load(
+    stats: Option<&SoldierStats>,
+    inventory: Option<&Inventory>,
+    weapon: Option<&Weapon>,
+    fatigue: &FatigueConfig,
+    turn: u32,
+    rested: Option<&Rested>,
+) -> f32 {
+    load_factor(stats, inventory, weapon) * fatigue.movement_factor(turn, rested)
+}
+
+/// Seconds each passable neighbouring tile would cost to step onto
+pub fn step_costs(
+    battlefield: &Battlefield,
+    from: BattlefieldPos,
+    crawling: bool,
+    health: Option<&Health>,
+    load: f32,
+    wounded: &WoundedConfig,
+) -> Vec<(BattlefieldPos, f32)> {
+    STEP_DIRECTIONS
+        .iter()
+        .filter_map(|&(dx, dy)| {
+            let to = BattlefieldPos::new(from.x + dx, from.y + dy);
+            let passable = battlefield
+                .get_tile(&to)
+                .is_some_and(|tile| tile.terrain.is_passable());
+            if !passable {
+                return None;
+            }
+            let action = step_action(battlefield, from, dx, dy, crawling)?;
+            Some((to, action.time_cost_for(health, load, wounded)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::time_budget::TimeBudget;
+    use crate::game_logic::battlefield::TerrainType;
+    use crate::game_logic::world_setup::register_components;
+    use specs::Builder;
+
+    #[test]
+    fn test_previewed_step_cost_matches_time_spent() {
+        let mut battlefield = Battlefield::new(10, 10);
+        let from = BattlefieldPos::new(5, 5);
+        battlefield.set_terrain(BattlefieldPos::new(6, 5), TerrainType::Mud);
+        let wounded = WoundedConfig::default();
+        let mut hurt = Health::soldier();
+        hurt.take_damage(80);
+
+        let preview = step_costs(&battlefield, from, false, Some(&hurt), 1.0, &wounded);
+        assert_eq!(preview.len(), 8);
+        let (_, previewed) = preview
+            .iter()
+            .find(|(pos, _)| *pos == BattlefieldPos::new(6, 5))
+            .copied()
+            .unwrap();
+
+        // Queue the step the way the player's move does
+        let action = step_action(&battlefield, from, 1, 0, false).unwrap();
+        let mut budget = TimeBudget::new(20.0);
+        budget.consume_time(action.time_cost_for(Some(&hurt), 1.0, &wounded));
+
+        let spent = 20.0 - budget.available_time();
+        assert!((previewed - spent).abs() < 1e-5, "previewed {} but spent {}", previewed, spent);
+        let mud_cost = 1.5 * TerrainType::Mud.movement_cost();
+        assert!(previewed > mud_cost, "a wounded soldier should pay more than {}", mud_cost);
+    }
+
+    #[test]
+    fn test_preview_charges_battle_fatigue_like_the_step() {
+        let mut world = World::new();
+        register_components(&mut world);
+        let mut turn_state = TurnState::new();
+        turn_state.current_turn = 40;
+        world.insert(turn_state);
+        let soldier = world.create_entity().with(Weapon::rifle()).build();
+        let fatigue = FatigueConfig {
+            enabled: true,
+            ..FatigueConfig::default()
+        };
+        let battlefield = Battlefield::new(10, 10);
+        let from = BattlefieldPos::new(5, 5);
+        let wounded = WoundedConfig::default();
+
+        let load = effective_load(&world, soldier, &fatigue);
+        assert!(load > effective_load(&world, soldier, &FatigueConfig::default()));
+
+        let preview = step_costs(&battlefield, from, false, None, load, &wounded);
+        let (_, previewed) = preview[0];
+        // Priced the way the player's committed step is
+        let committed = step_action(&battlefield, from, 0, -1, false)
+            .unwrap()
+            .time_cost_for(None, effective_load(&world, soldier, &fatigue), &wounded);
+        assert_eq!(previewed, committed);
+    }
+}
@@ -0,0 +1,201 @@
+// Approach lane analysis
+// Cover and exposure along straight lanes between the spawns, for judging map fairness
+//
+// A lane runs from a point in one side's spawn zone to the center of the
+// enemy's. Lane starts are fanned out across the spawn zone perpendicular to
+// the line between the two centers, so they sample the whole frontage rather
+// than just the middle of it.
+
+use crate::game_logic::battlefield::{Battlefield, Position, SpawnZone};
+
+/// Cover and exposure along one approach lane
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaneReport {
+    pub start: Position,
+    pub end: Position,
+    /// Tiles walked, including both ends
+    pub length: usize,
+    /// Fraction (0.0-1.0) of the lane's tiles that give any cover
+    pub cover_coverage: f32,
+    /// Average cover bonus over the lane's tiles
+    pub mean_cover: f32,
+    /// Longest run of consecutive lane tiles with nothing that blocks or
+    /// partially blocks sight, i.e. the longest open stretch to cross
+    pub sightline: usize,
+}
+
+/// Lanes sampled from each side's spawn toward the other
+#[derive(Debug, Clone, Default)]
+pub struct ApproachAnalysis {
+    pub allies: Vec<LaneReport>,
+    pub central_powers: Vec<LaneReport>,
+}
+
+impl ApproachAnalysis {
+    /// Mean cover coverage over a side's lanes (0.0 if it has none)
+    pub fn average_coverage(lanes: &[LaneReport]) -> f32 {
+        if lanes.is_empty() {
+            return 0.0;
+        }
+        lanes.iter().map(|l| l.cover_coverage).sum::<f32>() / lanes.len() as f32
+    }
+
+    /// Mean sightline over a side's lanes (0.0 if it has none)
+    pub fn average_sightline(lanes: &[LaneReport]) -> f32 {
+        if lanes.is_empty() {
+            return 0.0;
+        }
+        lanes.iter().map(|l| l.sightline as f32).sum::<f32>() / lanes.len() as f32
+    }
+}
+
+/// Analyze `lane_count` approach lanes per side between the spawn zones
+///
+/// Returns an empty analysis if the battlefield has no spawn zones.
+pub fn analyze_approach_lanes(battlefield: &Battlefield, lane_count: usize) -> ApproachAnalysis {
+    let (Some(ally_spawn), Some(enemy_spawn)) = (&battlefield.ally_spawn, &battlefield.enemy_spawn)
+    else {
+        return ApproachAnalysis::default();
+    };
+
+    ApproachAnalysis {
+        allies: sample_lanes(ally_spawn, enemy_spawn.center, lane_count, battlefield)
+            .into_iter()
+            .map(|(from, to)| analyze_lane(battlefield, from, to))
+            .collect(),
+        central_powers: sample_lanes(enemy_spawn, ally_spawn.center, lane_count, battlefield)
+            .into_iter()
+            .map(|(from, to)| analyze_lane(battlefield, from, to))
+            .collect(),
+    }
+}
+
+/// Fan `count` lanes from across a spawn zone's frontage to a target
+fn sample_lanes(
+    spawn: &SpawnZone,
+    target: Position,
+    count: usize,
+    battlefield: &Battlefield,
+) -> Vec<(Position, Position)> {
+    let from = spawn.center;
+    let dx = (target.x - from.x) as f32;
+    let dy = (target.y - from.y) as f32;
+    let length = (dx * dx + dy * dy).sqrt();
+    if length == 0.0 || count == 0 {
+        return Vec::new();
+    }
+    let (perp_x, perp_y) = (-dy / length, dx / length);
+    let spread = spawn.radius as f32;
+
+    (0..count)
+        .map(|i| {
+            let offset = if count == 1 {
+                0.0
+            } else {
+                -spread + 2.0 * spread * i as f32 / (count - 1) as f32
+            };
+            let start = Position::new(
+                (from.x + (perp_x * offset).round() as i32).clamp(0, battlefield.width() as i32 - 1),
+                (from.y + (perp_y * offset).round() as i32).clamp(0, battlefield.height() as i32 - 1),
+            );
+            (start, target)
+        })
+        .collect()
+}
+
+/// Walk a straight lane and measure its cover and exposure
+pub fn analyze_lane(battlefield: &Battlefield, start: Position, end: Position) -> LaneReport {
+    let tiles = lane_tiles(start, end);
+    let covers: Vec<f32> = tiles
+        .iter()
+        .map(|pos| {
+            battlefield
+                .get_tile(pos)
+                .map(|tile| tile.terrain.cover_bonus())
+                .unwrap_or(0.0)
+        })
+        .collect();
+
+    let covered = covers.iter().filter(|&&cover| cover > 0.0).count();
+
+    let mut sightline = 0;
+    let mut open_run = 0;
+    for pos in &tiles {
+        let open = battlefield.get_tile(pos).is_none_or(|tile| {
+            !tile.terrain.blocks_los() && !tile.terrain.partially_blocks_los()
+        });
+        open_run = if open { open_run + 1 } else { 0 };
+        sightline = sightline.max(open_run);
+    }
+
+    LaneReport {
+        start,
+        end,
+        length: tiles.len(),
+        cover_coverage: covered as f32 / tiles.len() as f32,
+        mean_cover: covers.iter().sum::<f32>() / tiles.len() as f32,
+        sightline,
+    }
+}
+
+/// Tiles on the straight line between two points, both ends included
+fn lane_tiles(start: Position, end: Position) -> Vec<Position> {
+    let dx = end.x - start.x;
+    let dy = end.y - start.y;
+    let steps = dx.abs().max(dy.abs());
+    if steps == 0 {
+        return vec![start];
+    }
+
+    (0..=steps)
+        .map(|i| {
+            let t = i as f32 / steps as f32;
+            Position::new(
+                start.x + (dx as f32 * t).round() as i32,
+                start.y + (dy as f32 * t).round() as i32,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::battlefield::TerrainType;
+
+    fn open_field() -> Battlefield {
+        let mut battlefield = Battlefield::new(40, 40);
+        battlefield.set_spawn_zones(
+            SpawnZone::new(Position::new(20, 35), 4),
+            SpawnZone::new(Position::new(20, 4), 4),
+        );
+        battlefield
+    }
+
+    #[test]
+    fn test_open_map_has_no_cover_on_any_lane() {
+        let analysis = analyze_approach_lanes(&open_field(), 5);
+
+        assert_eq!(analysis.allies.len(), 5);
+        assert_eq!(analysis.central_powers.len(), 5);
+        for lane in analysis.allies.iter().chain(&analysis.central_powers) {
+            assert!(lane.cover_coverage < 0.01, "lane {:?} reports cover", lane);
+            // Nothing blocks sight, so the whole lane is one open stretch
+            assert_eq!(lane.sightline, lane.length);
+        }
+    }
+
+    #[test]
+    fn test_sandbag_line_adds_cover_and_cuts_sightline() {
+        let mut battlefield = open_field();
+        for x in 0..40 {
+            battlefield.set_terrain(Position::new(x, 20), TerrainType::Sandbags);
+        }
+
+        let lane = analyze_lane(&battlefield, Position::new(20, 35), Position::new(20, 4));
+
+        assert!(lane.cover_coverage > 0.0);
+        // The sandbags at y=20 split the lane into 15 and 16 open tiles
+        assert_eq!(lane.sightline, 16);
+    }
+}
@@ -0,0 +1,103 @@
+// Advance to Contact
+// Player auto-march: ends turns along the planned path until something happens
+
+/// Why an advance to contact stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HaltReason {
+    /// An enemy became visible that was not visible last turn
+    EnemySpotted,
+    /// The player was shot at since the advance began
+    UnderFire,
+    /// The planned path ran out (or was cancelled)
+    PathComplete,
+}
+
+impl HaltReason {
+    pub fn message(&self) -> &'static str {
+        match self {
+            HaltReason::EnemySpotted => "Contact! Enemy spotted - halting advance",
+            HaltReason::UnderFire => "Under fire! Halting advance",
+            HaltReason::PathComplete => "Advance complete",
+        }
+    }
+}
+
+/// Auto-advance state for the player
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AdvanceToContact {
+    started_turn: Option<u32>,
+}
+
+impl AdvanceToContact {
+    pub fn start(&mut self, current_turn: u32) {
+        self.started_turn = Some(current_turn);
+    }
+
+    pub fn stop(&mut self) {
+        self.started_turn = None;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.started_turn.is_some()
+    }
+
+    /// Decide whether to end another turn, checked each time the player is
+    /// back in planning. Returns the reason (and deactivates) when halting.
+    ///
+    /// `newly_spotted` is whether update_visibility turned up an enemy that was
+    /// not visible before; `last_shot_at` is the player's UnderFire turn.
+    pub fn check(
+        &mut self,
+        newly_spotted: bool,
+        last_shot_at: Option<u32>,
+        has_path: bool,
+    ) -> Option<HaltReason> {
+        let started_turn = self.started_turn?;
+
+        let reason = if newly_spotted {
+            Some(HaltReason::EnemySpotted)
+        } else if last_shot_at.is_some_and(|turn| turn >= started_turn) {
+            Some(HaltReason::UnderFire)
+        } else if !has_path {
+            Some(HaltReason::PathComplete)
+        } else {
+            None
+        };
+
+        if reason.is_some() {
+            self.stop();
+        }
+        reason
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_halts_on_turn_enemy_first_visible() {
+        let mut advance = AdvanceToContact::default();
+        advance.start(1);
+
+        // Marching along with nothing in sight
+        assert_eq!(advance.check(false, None, true), None);
+        assert_eq!(advance.check(false, None, true), None);
+        assert!(advance.is_active());
+
+        // An enemy appears in the newly-visible set
+        assert_eq!(advance.check(true, None, true), Some(HaltReason::EnemySpotted));
+        assert!(!advance.is_active());
+        assert_eq!(advance.check(false, None, true), None);
+    }
+
+    #[test]
+    fn test_advance_halts_when_fired_upon() {
+        let mut advance = AdvanceToContact::default();
+        advance.start(5);
+
+        // Shot at before the advance began does not count
+        assert_eq!(advance.check(false, Some(4), true), None);
+        assert_eq!(advance.check(false, Some(6), true), Some(HaltReason::UnderFire));
+    }
+}
@@ -0,0 +1,170 @@
+// Auto-Pause
+// Stops auto-advance when something happens the player shouldn't miss
+//
+// The game snapshots what the player's side has at stake before the systems
+// run and compares it after: a newly fallen friendly or a flag that changed
+// owner sets the pause, as does update_visibility turning up a new enemy.
+
+use crate::components::{
+    dead::Dead,
+    soldier::{Faction, Soldier},
+};
+use crate::game_logic::objectives::Objectives;
+use specs::{Join, World, WorldExt};
+
+/// Why the game paused itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseReason {
+    /// A soldier on the player's side was killed
+    FriendlyKilled,
+    /// An objective flag changed hands
+    ObjectiveChanged,
+    /// An enemy came into view that wasn't visible before
+    EnemySpotted,
+}
+
+impl PauseReason {
+    pub fn message(&self) -> &'static str {
+        match self {
+            PauseReason::FriendlyKilled => "Man down! Auto-paused",
+            PauseReason::ObjectiveChanged => "Objective changed hands! Auto-paused",
+            PauseReason::EnemySpotted => "New enemy spotted! Auto-paused",
+        }
+    }
+}
+
+/// What one side has at stake, taken before and after the systems run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BattleSnapshot {
+    fallen: usize,
+    flag_owners: Vec<Option<Faction>>,
+}
+
+impl BattleSnapshot {
+    /// Count `faction`'s dead and note who holds each flag
+    pub fn capture(world: &World, faction: Faction) -> Self {
+        let soldiers = world.read_storage::<Soldier>();
+        let dead_markers = world.read_storage::<Dead>();
+        let fallen = (&soldiers, &dead_markers)
+            .join()
+            .filter(|(soldier, _)| soldier.faction == faction)
+            .count();
+        let flag_owners = world
+            .read_resource::<Objectives>()
+            .flags
+            .values()
+            .map(|flag| flag.owning_faction)
+            .collect();
+
+        Self { fallen, flag_owners }
+    }
+}
+
+/// Pending auto-pause, set by significant events while enabled
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AutoPause {
+    pub enabled: bool,
+    triggered: Option<PauseReason>,
+}
+
+impl AutoPause {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            triggered: None,
+        }
+    }
+
+    /// Ask for a pause; ignored when disabled, and the first reason wins
+    pub fn trigger(&mut self, reason: PauseReason) {
+        if self.enabled && self.triggered.is_none() {
+            self.triggered = Some(reason);
+        }
+    }
+
+    /// Trigger on anything that changed between two snapshots of the same side
+    pub fn compare(&mut self, before: &BattleSnapshot, after: &BattleSnapshot) {
+        if after.fallen > before.fallen {
+            self.trigger(PauseReason::FriendlyKilled);
+        }
+        if after.flag_owners != before.flag_owners {
+            self.trigger(PauseReason::ObjectiveChanged);
+        }
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        self.triggered.is_some()
+    }
+
+    /// Hand over the pending pause, clearing it
+    pub fn take(&mut self) -> Option<PauseReason> {
+        self.triggered.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::Rank;
+    use crate::game_logic::battlefield::Position;
+    use crate::game_logic::objectives::ObjectiveFlag;
+    use specs::Builder;
+
+    fn world_with_soldier(faction: Faction) -> (World, specs::Entity) {
+        let mut world = World::new();
+        world.register::<Soldier>();
+        world.register::<Dead>();
+        world.insert(Objectives::new());
+        let soldier = world
+            .create_entity()
+            .with(Soldier {
+                name: "Pvt. Hale".to_string(),
+                faction,
+                rank: Rank::Private,
+            })
+            .build();
+        (world, soldier)
+    }
+
+    #[test]
+    fn test_friendly_kill_sets_auto_pause_when_enabled() {
+        let (world, soldier) = world_with_soldier(Faction::Allies);
+        let before = BattleSnapshot::capture(&world, Faction::Allies);
+        world.write_storage::<Dead>().insert(soldier, Dead).unwrap();
+        let after = BattleSnapshot::capture(&world, Faction::Allies);
+
+        let mut disabled = AutoPause::new(false);
+        disabled.compare(&before, &after);
+        assert!(!disabled.is_triggered());
+
+        let mut auto_pause = AutoPause::new(true);
+        auto_pause.compare(&before, &after);
+        assert!(auto_pause.is_triggered());
+        assert_eq!(auto_pause.take(), Some(PauseReason::FriendlyKilled));
+        assert!(!auto_pause.is_triggered());
+    }
+
+    #[test]
+    fn test_enemy_losses_and_flag_captures() {
+        let (mut world, soldier) = world_with_soldier(Faction::CentralPowers);
+        world.write_resource::<Objectives>().add_flag(
+            "hill".to_string(),
+            ObjectiveFlag::new(Position::new(5, 5), Faction::CentralPowers),
+        );
+        let before = BattleSnapshot::capture(&world, Faction::Allies);
+        world.write_storage::<Dead>().insert(soldier, Dead).unwrap();
+        let mut auto_pause = AutoPause::new(true);
+        auto_pause.compare(&before, &BattleSnapshot::capture(&world, Faction::Allies));
+        assert!(!auto_pause.is_triggered(), "an enemy falling is no reason to stop");
+
+        world
+            .get_mut::<Objectives>()
+            .unwrap()
+            .flags
+            .get_mut("hill")
+            .unwrap()
+            .capture(Faction::Allies);
+        auto_pause.compare(&before, &BattleSnapshot::capture(&world, Faction::Allies));
+        assert_eq!(auto_pause.take(), Some(PauseReason::ObjectiveChanged));
+    }
+}
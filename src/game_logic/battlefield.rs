@@ -1,6 +1,10 @@
 // Battlefield grid structure and management
 
-use std::collections::HashMap;
+use rand::seq::SliceRandom;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use super::line_of_sight::DiagonalLos;
+use super::pathfinding::PathHeuristic;
 use super::terrain_properties::TerrainProperties;
 
 /// Represents a coordinate on the battlefield
@@ -28,7 +32,8 @@ impl Position {
 }
 
 /// Types of terrain on the battlefield
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TerrainType {
     // Basic terrain
     NoMansLand,
@@ -145,6 +150,11 @@ impl TerrainType {
         self.properties().blocks_los()
     }
 
+    /// Returns whether this terrain partially blocks line of sight
+    pub fn partially_blocks_los(&self) -> bool {
+        self.properties().partially_blocks_los()
+    }
+
     /// Returns the ASCII character representation (backward compatible)
     pub fn to_char(&self) -> char {
         self.properties().character
@@ -155,6 +165,19 @@ impl TerrainType {
         self.properties().is_passable
     }
 
+    /// Whether a soldier standing here is dug in (trench, bunker, MG nest)
+    pub fn is_dug_in(&self) -> bool {
+        matches!(
+            self,
+            TerrainType::TrenchFloor
+                | TerrainType::Trench
+                | TerrainType::CommTrench
+                | TerrainType::Bunker
+                | TerrainType::MgNest
+                | TerrainType::Fortification
+        ) && self.is_passable()
+    }
+
     /// Returns the cover bonus for combat
     pub fn cover_bonus(&self) -> f32 {
         self.properties().cover_bonus
@@ -204,6 +227,16 @@ pub struct Battlefield {
     tiles: HashMap<Position, Tile>,
     pub ally_spawn: Option<SpawnZone>,
     pub enemy_spawn: Option<SpawnZone>,
+    /// Hidden mines (not rendered, triggered by stepping on the tile)
+    mines: HashSet<Position>,
+    /// Rows [start, end) of the central no-man's-land band, if generated
+    no_mans_land_band: Option<(i32, i32)>,
+    /// Bumped whenever a tile's terrain changes, so cached sight lines know to refresh
+    terrain_revision: u64,
+    /// Whether sight slips diagonally between two walls meeting at a corner
+    diagonal_los: DiagonalLos,
+    /// Distance estimate A* steers by on this map
+    path_heuristic: PathHeuristic,
 }
 
 impl Default for Battlefield {
@@ -214,6 +247,11 @@ impl Default for Battlefield {
             tiles: HashMap::new(),
             ally_spawn: None,
             enemy_spawn: None,
+            mines: HashSet::new(),
+            no_mans_land_band: None,
+            terrain_revision: 0,
+            diagonal_los: DiagonalLos::default(),
+            path_heuristic: PathHeuristic::default(),
         }
     }
 }
@@ -236,6 +274,11 @@ impl Battlefield {
             tiles,
             ally_spawn: None,
             enemy_spawn: None,
+            mines: HashSet::new(),
+            no_mans_land_band: None,
+            terrain_revision: 0,
+            diagonal_los: DiagonalLos::default(),
+            path_heuristic: PathHeuristic::default(),
         }
     }
 
@@ -259,11 +302,57 @@ impl Battlefield {
 
     /// Sets terrain type at a position
     pub fn set_terrain(&mut self, pos: Position, terrain: TerrainType) {
-        if let Some(tile) = self.tiles.get_mut(&pos) {
+        if let Some(tile) = self.tiles.get_mut(&pos)
+            && tile.terrain != terrain
+        {
             tile.terrain = terrain;
+            self.terrain_revision += 1;
+        }
+    }
+
+    /// Counter that changes whenever any tile's terrain does
+    pub fn terrain_revision(&self) -> u64 {
+        self.terrain_revision
+    }
+
+    /// Take on `source`'s terrain while keeping this map's fog of war
+    ///
+    /// The drawn map tracks what the player has seen; copying the simulation's
+    /// map wholesale would throw that memory away.
+    pub fn sync_terrain_from(&mut self, source: &Battlefield) {
+        if self.terrain_revision == source.terrain_revision {
+            return;
+        }
+        for (pos, tile) in self.tiles.iter_mut() {
+            if let Some(source_tile) = source.tiles.get(pos) {
+                tile.terrain = source_tile.terrain;
+            }
+        }
+        self.terrain_revision = source.terrain_revision;
+    }
+
+    pub fn diagonal_los(&self) -> DiagonalLos {
+        self.diagonal_los
+    }
+
+    /// Set how sight treats diagonal corners for every FOV query on this map
+    pub fn set_diagonal_los(&mut self, rule: DiagonalLos) {
+        if self.diagonal_los != rule {
+            self.diagonal_los = rule;
+            // Cached sight lines were worked out under the old rule
+            self.terrain_revision += 1;
         }
     }
 
+    pub fn path_heuristic(&self) -> PathHeuristic {
+        self.path_heuristic
+    }
+
+    /// Set the distance estimate every path search on this map steers by
+    pub fn set_path_heuristic(&mut self, heuristic: PathHeuristic) {
+        self.path_heuristic = heuristic;
+    }
+
     /// Checks if a position is within battlefield bounds
     pub fn in_bounds(&self, pos: &Position) -> bool {
         pos.x >= 0 && pos.x < self.width as i32 && pos.y >= 0 && pos.y < self.height as i32
@@ -286,16 +375,59 @@ impl Battlefield {
         }
     }
 
+    /// Marks every tile visible and explored (debug map reveal)
+    pub fn reveal_all(&mut self) {
+        for tile in self.tiles.values_mut() {
+            tile.visible = true;
+            tile.explored = true;
+        }
+    }
+
     /// Set the spawn zones for both factions
     pub fn set_spawn_zones(&mut self, ally_spawn: SpawnZone, enemy_spawn: SpawnZone) {
         self.ally_spawn = Some(ally_spawn);
         self.enemy_spawn = Some(enemy_spawn);
     }
 
-    /// Get spawn positions for a faction
-    pub fn get_spawn_positions(&self, is_allies: bool, count: usize) -> Vec<Position> {
-        use rand::Rng;
+    /// Record the rows [start, end) of the central no-man's-land band
+    pub fn set_no_mans_land_band(&mut self, start: i32, end: i32) {
+        self.no_mans_land_band = Some((start, end));
+    }
+
+    /// Whether a position lies inside the central no-man's-land band
+    pub fn in_no_mans_land_band(&self, pos: &Position) -> bool {
+        self.no_mans_land_band
+            .is_some_and(|(start, end)| pos.y >= start && pos.y < end)
+    }
+
+    /// Places a hidden mine at a position
+    pub fn place_mine(&mut self, pos: Position) {
+        if self.in_bounds(&pos) {
+            self.mines.insert(pos);
+        }
+    }
 
+    /// Returns whether a hidden mine is buried at a position
+    pub fn has_mine(&self, pos: &Position) -> bool {
+        self.mines.contains(pos)
+    }
+
+    /// Removes a mine, returning whether one was present
+    pub fn remove_mine(&mut self, pos: &Position) -> bool {
+        self.mines.remove(pos)
+    }
+
+    pub fn mine_count(&self) -> usize {
+        self.mines.len()
+    }
+
+    /// Get spawn positions for a faction
+    pub fn get_spawn_positions(
+        &self,
+        is_allies: bool,
+        count: usize,
+        rng: &mut impl rand::Rng,
+    ) -> Vec<Position> {
         let spawn_zone = if is_allies {
             &self.ally_spawn
         } else {
@@ -307,7 +439,6 @@ impl Battlefield {
             None => return vec![],
         };
 
-        let mut rng = rand::thread_rng();
         let mut positions = Vec::new();
         let mut attempts = 0;
         let max_attempts = count * 50;
@@ -344,4 +475,122 @@ impl Battlefield {
 
         positions
     }
+
+    /// Spawn positions for defenders manning the dug-in tiles on their half of the map
+    ///
+    /// Bunkers and MG nests are filled first, then trenches, keeping soldiers
+    /// 2 tiles apart as in get_spawn_positions. Any shortfall is made up from
+    /// the side's spawn zone.
+    pub fn get_dug_in_positions(
+        &self,
+        is_allies: bool,
+        count: usize,
+        rng: &mut impl rand::Rng,
+    ) -> Vec<Position> {
+        let (Some(home), Some(enemy)) = (&self.ally_spawn, &self.enemy_spawn) else {
+            return self.get_spawn_positions(is_allies, count, rng);
+        };
+        let (home, enemy) = if is_allies { (home, enemy) } else { (enemy, home) };
+
+        let mut posts: Vec<Position> = self
+            .tiles
+            .iter()
+            .filter(|(pos, tile)| {
+                tile.terrain.is_dug_in()
+                    && !self.has_mine(pos)
+                    && home.center.distance_to(pos) < enemy.center.distance_to(pos)
+            })
+            .map(|(pos, _)| *pos)
+            .collect();
+        // Tile order is arbitrary; sort first so the shuffle is reproducible
+        posts.sort_by_key(|pos| (pos.y, pos.x));
+        posts.shuffle(rng);
+        posts.sort_by_key(|pos| match self.get_tile(pos).map(|tile| tile.terrain) {
+            Some(TerrainType::Bunker | TerrainType::MgNest) => 0,
+            Some(TerrainType::Fortification) => 1,
+            _ => 2,
+        });
+
+        let mut positions: Vec<Position> = Vec::new();
+        for pos in posts {
+            if positions.len() >= count {
+                break;
+            }
+            if positions.iter().all(|p| p.distance_to(&pos) >= 2.0) {
+                positions.push(pos);
+            }
+        }
+
+        if positions.len() < count {
+            let fallback = self.get_spawn_positions(is_allies, count, rng);
+            for pos in fallback {
+                if positions.len() >= count {
+                    break;
+                }
+                if positions.iter().all(|p| p.distance_to(&pos) >= 2.0) {
+                    positions.push(pos);
+                }
+            }
+        }
+
+        positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{position::Position as PositionComponent, vision::Vision};
+    use crate::config::{battlefield_config::BattlefieldGenerationConfig, game_config::GameConfig};
+    use crate::game_logic::world_setup::build_skirmish_world;
+    use specs::{Join, WorldExt};
+
+    #[test]
+    fn test_terrain_sync_keeps_explored_tiles() {
+        let mut simulated = Battlefield::new(8, 8);
+        let mut drawn = simulated.clone();
+        drawn.set_visible(Position::new(2, 2), true);
+        drawn.set_visible(Position::new(5, 5), true);
+        drawn.reset_visibility();
+        drawn.set_visible(Position::new(5, 5), true);
+
+        simulated.set_terrain(Position::new(2, 2), TerrainType::ShellCrater);
+        drawn.sync_terrain_from(&simulated);
+
+        let crater = drawn.get_tile(&Position::new(2, 2)).unwrap();
+        assert_eq!(crater.terrain, TerrainType::ShellCrater);
+        assert!(crater.explored && !crater.visible);
+        let lookout = drawn.get_tile(&Position::new(5, 5)).unwrap();
+        assert!(lookout.explored && lookout.visible);
+        assert_eq!(drawn.terrain_revision(), simulated.terrain_revision());
+    }
+
+    #[test]
+    fn test_reveal_all_marks_every_tile_visible() {
+        let config = GameConfig::default();
+        let world = build_skirmish_world(BattlefieldGenerationConfig::default(), &config, 3);
+
+        let snapshot = |world: &specs::World| -> Vec<(i32, i32, i32)> {
+            let positions = world.read_storage::<PositionComponent>();
+            let visions = world.read_storage::<Vision>();
+            (&positions, &visions)
+                .join()
+                .map(|(p, v)| (p.x(), p.y(), v.range))
+                .collect()
+        };
+        let before = snapshot(&world);
+
+        world.write_resource::<Battlefield>().reveal_all();
+
+        let battlefield = world.read_resource::<Battlefield>();
+        for y in 0..battlefield.height() as i32 {
+            for x in 0..battlefield.width() as i32 {
+                let tile = battlefield.get_tile(&Position::new(x, y)).unwrap();
+                assert!(tile.visible && tile.explored);
+            }
+        }
+        drop(battlefield);
+
+        assert_eq!(snapshot(&world), before);
+    }
 }
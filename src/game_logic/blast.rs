@@ -0,0 +1,131 @@
+// Blast areas
+// Who an explosion at a tile catches, how hard it hits, and what it does to the ground
+//
+// Blast radius is measured in Chebyshev distance, the same square area a
+// mine blast covers, so a thrower can read it straight off the grid. An
+// explosion on open ground leaves a shell crater, so a bombarded sector
+// slowly turns into a moonscape of craters to shelter in.
+
+use crate::components::{dead::Dead, position::Position, soldier::{Faction, Soldier}};
+use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos, TerrainType};
+use specs::{Entity, Join, World, WorldExt};
+
+/// Tiles from the impact point a hand grenade's blast reaches
+pub const GRENADE_BLAST_RADIUS: i32 = 2;
+
+/// Damage a hand grenade deals on the tile it lands on
+pub const GRENADE_DAMAGE: i32 = 60;
+
+/// Blow a crater into open ground at `center`
+///
+/// Only Grass and No Man's Land give way; trenches, buildings and other
+/// worked terrain keep their shape. Returns whether a crater was made.
+pub fn crater_ground(battlefield: &mut Battlefield, center: BattlefieldPos) -> bool {
+    let open_ground = battlefield
+        .get_tile(&center)
+        .is_some_and(|tile| matches!(tile.terrain, TerrainType::Grass | TerrainType::NoMansLand));
+    if open_ground {
+        battlefield.set_terrain(center, TerrainType::ShellCrater);
+    }
+    open_ground
+}
+
+/// Whether `pos` lies within `radius` tiles of `center`
+pub fn in_blast(center: BattlefieldPos, radius: i32, pos: BattlefieldPos) -> bool {
+    (pos.x - center.x).abs().max((pos.y - center.y).abs()) <= radius
+}
+
+/// Damage a blast of `max_damage` at `center` deals to a soldier at `pos`
+///
+/// Full damage on the impact tile, falling off linearly to the edge of the
+/// blast radius. Returns None outside the radius.
+pub fn blast_damage(center: BattlefieldPos, radius: i32, max_damage: i32, pos: BattlefieldPos) -> Option<i32> {
+    if !in_blast(center, radius, pos) {
+        return None;
+    }
+    let distance = (pos.x - center.x).abs().max((pos.y - center.y).abs());
+    let falloff = 1.0 - distance as f32 / (radius + 1) as f32;
+    Some((max_damage as f32 * falloff).round() as i32)
+}
+
+/// Living soldiers of `faction` a blast at `center` would catch
+pub fn friendlies_in_blast(
+    world: &World,
+    faction: Faction,
+    center: BattlefieldPos,
+    radius: i32,
+) -> Vec<Entity> {
+    let entities = world.entities();
+    let positions = world.read_storage::<Position>();
+    let soldiers = world.read_storage::<Soldier>();
+    let dead = world.read_storage::<Dead>();
+
+    (&entities, &positions, &soldiers, !&dead)
+        .join()
+        .filter(|(_, pos, soldier, _)| {
+            soldier.faction == faction && in_blast(center, radius, *pos.as_battlefield_pos())
+        })
+        .map(|(entity, _, _, _)| entity)
+        .collect()
+}
+
+/// Whether a blast at `center` would catch any living soldier of `faction`
+pub fn danger_close(world: &World, faction: Faction, center: BattlefieldPos, radius: i32) -> bool {
+    !friendlies_in_blast(world, faction, center, radius).is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::Rank;
+    use crate::game_logic::world_setup::register_components;
+    use specs::Builder;
+
+    #[test]
+    fn test_danger_close_when_friendly_in_blast() {
+        let mut world = World::new();
+        register_components(&mut world);
+        let soldier = |name: &str, faction| Soldier {
+            name: name.to_string(),
+            faction,
+            rank: Rank::Private,
+        };
+        world
+            .create_entity()
+            .with(Position::new(10, 10))
+            .with(soldier("Thrower", Faction::Allies))
+            .build();
+        world
+            .create_entity()
+            .with(Position::new(20, 10))
+            .with(soldier("Enemy", Faction::CentralPowers))
+            .build();
+
+        // Right on top of the enemy, well clear of the thrower
+        let far = BattlefieldPos::new(20, 10);
+        assert!(!danger_close(&world, Faction::Allies, far, GRENADE_BLAST_RADIUS));
+
+        // Thrower is on the edge of the blast
+        let close = BattlefieldPos::new(10 + GRENADE_BLAST_RADIUS, 10 + GRENADE_BLAST_RADIUS);
+        assert!(danger_close(&world, Faction::Allies, close, GRENADE_BLAST_RADIUS));
+        let clear = BattlefieldPos::new(11 + GRENADE_BLAST_RADIUS, 10);
+        assert!(!danger_close(&world, Faction::Allies, clear, GRENADE_BLAST_RADIUS));
+    }
+
+    #[test]
+    fn test_explosion_on_grass_leaves_crater_cover() {
+        let mut battlefield = Battlefield::new(10, 10);
+        let grass = BattlefieldPos::new(4, 4);
+        let trench = BattlefieldPos::new(6, 4);
+        battlefield.set_terrain(grass, TerrainType::Grass);
+        battlefield.set_terrain(trench, TerrainType::TrenchFloor);
+
+        assert!(crater_ground(&mut battlefield, grass));
+        let crater = battlefield.get_tile(&grass).unwrap().terrain;
+        assert_eq!(crater, TerrainType::ShellCrater);
+        assert!(crater.cover_bonus() > 0.0);
+
+        assert!(!crater_ground(&mut battlefield, trench));
+        assert_eq!(battlefield.get_tile(&trench).unwrap().terrain, TerrainType::TrenchFloor);
+    }
+}
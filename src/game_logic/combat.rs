@@ -1,11 +1,35 @@
 // Combat calculation logic
 // Hitscan combat system with range-based accuracy
+//
+// A target's cover works on one stage of the shot, set by CoverModel: either
+// it cuts the hit chance and a hit lands in full, or the hit chance ignores
+// it and each hit loses the cover's share of its damage.
 
-use crate::components::{health::Health, position::Position, weapon::Weapon};
+use crate::components::{
+    health::Health,
+    last_stand::LAST_STAND_ACCURACY,
+    position::Position,
+    rested::Rested,
+    weapon::Weapon,
+};
+use crate::config::game_config::{CoverModel, FatigueConfig, WoundedConfig};
 use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos};
 use crate::game_logic::line_of_sight::calculate_fov;
+use crate::simulation::weather::Wind;
 use rand::Rng;
 
+/// Hit chance multiplier against a prone (crawling) target
+pub const PRONE_TARGET_PROFILE: f32 = 0.4;
+
+/// Hit chance multiplier for a shot fired while moving
+pub const MOVING_FIRE_ACCURACY: f32 = 0.6;
+
+/// Hit chance multiplier for a panicked soldier firing wildly
+pub const PANIC_FIRE_ACCURACY: f32 = 0.25;
+
+/// Rounds a burst of panic fire goes through
+pub const PANIC_FIRE_ROUNDS: i32 = 3;
+
 /// Result of a combat calculation
 #[derive(Debug, Clone)]
 pub struct CombatResult {
@@ -15,53 +39,237 @@ pub struct CombatResult {
     pub distance: i32,
     pub blocked_by_los: bool,
     pub cover_bonus: f32,
+    /// Tiles the effective aim point was pushed downwind
+    pub wind_drift: f32,
 }
 
-/// Calculate if a shot hits and how much damage it deals
+/// What a shot would do, worked out before the hit roll
 ///
-/// # Parameters
-/// - `weapon`: The weapon being used
-/// - `shooter_pos`: Position of the shooter
-/// - `target_pos`: Position of the target
-/// - `battlefield`: The battlefield (for LOS checks)
-/// - `shooter_vision`: Vision range of shooter (for LOS calculation)
-/// - `shooter_accuracy`: Optional accuracy modifier from soldier stats
+/// Prediction has no side effects, so the targeting panel can show it for
+/// any target; calculate_shot resolves a real shot from the same numbers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShotPrediction {
+    pub hit_chance: f32,
+    /// Damage dealt if the shot hits, after the target's cover
+    pub damage_on_hit: i32,
+    pub distance: i32,
+    pub out_of_range: bool,
+    pub blocked_by_los: bool,
+    pub cover_bonus: f32,
+    /// Tiles the effective aim point was pushed downwind
+    pub wind_drift: f32,
+}
+
+impl ShotPrediction {
+    /// Average damage over many identical shots
+    pub fn expected_damage(&self) -> f32 {
+        self.hit_chance * self.damage_on_hit as f32
+    }
+
+    /// Whether a hit would finish a target with `target_hp` left
+    pub fn would_kill(&self, target_hp: i32) -> bool {
+        self.hit_chance > 0.0 && self.damage_on_hit >= target_hp
+    }
+}
+
+/// The shooter's and the target's condition, as far as it bends a shot
 ///
-/// # Returns
-/// CombatResult with hit/miss, damage, and other details
-pub fn calculate_shot(
-    weapon: &Weapon,
-    shooter_pos: &Position,
-    target_pos: &Position,
-    battlefield: &Battlefield,
-    shooter_vision: i32,
-    shooter_accuracy: Option<f32>,
-) -> CombatResult {
+/// The real shot and the targeting preview both build these the same way,
+/// so the hit chance the player is shown is the one that gets rolled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShotModifiers {
+    /// Hit chance multiplier from the shooter's wounds, stance and handling
+    pub steadiness: f32,
+    /// Hit chance multiplier from the target's stance (1.0 = standing)
+    pub target_profile: f32,
+    pub aimed: bool,
+}
+
+impl Default for ShotModifiers {
+    fn default() -> Self {
+        Self {
+            steadiness: 1.0,
+            target_profile: 1.0,
+            aimed: false,
+        }
+    }
+}
+
+impl ShotModifiers {
+    /// Badly wounded shooters are less steady
+    pub fn with_wounds(mut self, wounded: &WoundedConfig, health: Option<&Health>) -> Self {
+        if let Some(health) = health {
+            self.steadiness *= wounded.accuracy_factor(health.percentage());
+        }
+        self
+    }
+
+    /// A cornered NCO making a last stand is steadier
+    pub fn with_last_stand(mut self, last_stand: bool) -> Self {
+        if last_stand {
+            self.steadiness *= LAST_STAND_ACCURACY;
+        }
+        self
+    }
+
+    /// Tired hands shake late in a long match
+    pub fn with_fatigue(mut self, fatigue: &FatigueConfig, turn: u32, rested: Option<&Rested>) -> Self {
+        self.steadiness *= fatigue.accuracy_factor(turn, rested);
+        self
+    }
+
+    /// How the shot is fired: on the move, in a panic, or steadily (1.0)
+    pub fn with_handling(mut self, handling: f32) -> Self {
+        self.steadiness *= handling;
+        self
+    }
+
+    /// Soldiers flat on the ground are a small target
+    pub fn with_prone_target(mut self, prone: bool) -> Self {
+        if prone {
+            self.target_profile = PRONE_TARGET_PROFILE;
+        }
+        self
+    }
+
+    pub fn with_aim(mut self, aimed: bool) -> Self {
+        self.aimed = aimed;
+        self
+    }
+}
+
+/// Everything a shot depends on apart from the hit roll
+///
+/// `new` gives a plain shot: a steady shooter, a standing target, no wind,
+/// no aim and the default cover model. The `with_*` methods add the rest.
+#[derive(Clone, Copy)]
+pub struct ShotParams<'a> {
+    pub weapon: &'a Weapon,
+    pub shooter_pos: &'a Position,
+    pub target_pos: &'a Position,
+    /// The battlefield, for the LOS check and the target's cover
+    pub battlefield: &'a Battlefield,
+    /// Vision range of the shooter (for the LOS check)
+    pub shooter_vision: i32,
+    /// Accuracy modifier from soldier stats
+    pub shooter_accuracy: Option<f32>,
+    /// Deflects the aim point on very long shots
+    pub wind: Option<&'a Wind>,
+    /// Hit chance multiplier from the shooter's wounds and handling (1.0 = steady)
+    pub steadiness: f32,
+    /// Hit chance multiplier from the target's stance (1.0 = standing)
+    pub target_profile: f32,
+    /// Whether the shooter spent time aiming (applies the weapon's aim bonus)
+    pub aimed: bool,
+    /// Whether the target's cover cuts the hit chance or the damage
+    pub cover_model: CoverModel,
+}
+
+impl<'a> ShotParams<'a> {
+    pub fn new(
+        weapon: &'a Weapon,
+        shooter_pos: &'a Position,
+        target_pos: &'a Position,
+        battlefield: &'a Battlefield,
+        shooter_vision: i32,
+    ) -> Self {
+        Self {
+            weapon,
+            shooter_pos,
+            target_pos,
+            battlefield,
+            shooter_vision,
+            shooter_accuracy: None,
+            wind: None,
+            steadiness: 1.0,
+            target_profile: 1.0,
+            aimed: false,
+            cover_model: CoverModel::default(),
+        }
+    }
+
+    pub fn with_accuracy(mut self, shooter_accuracy: Option<f32>) -> Self {
+        self.shooter_accuracy = shooter_accuracy;
+        self
+    }
+
+    pub fn with_wind(mut self, wind: &'a Wind) -> Self {
+        self.wind = Some(wind);
+        self
+    }
+
+    pub fn with_steadiness(mut self, steadiness: f32) -> Self {
+        self.steadiness = steadiness;
+        self
+    }
+
+    pub fn with_target_profile(mut self, target_profile: f32) -> Self {
+        self.target_profile = target_profile;
+        self
+    }
+
+    pub fn with_aim(mut self, aimed: bool) -> Self {
+        self.aimed = aimed;
+        self
+    }
+
+    pub fn with_cover_model(mut self, cover_model: CoverModel) -> Self {
+        self.cover_model = cover_model;
+        self
+    }
+
+    /// Apply the shooter's and target's condition in one go
+    pub fn with_modifiers(self, modifiers: ShotModifiers) -> Self {
+        self.with_steadiness(modifiers.steadiness)
+            .with_target_profile(modifiers.target_profile)
+            .with_aim(modifiers.aimed)
+    }
+}
+
+/// Work out a shot's hit chance and damage without firing it
+///
+/// Takes the same inputs as calculate_shot, less the RNG.
+pub fn predict_shot(params: &ShotParams) -> ShotPrediction {
+    let ShotParams {
+        weapon,
+        shooter_pos,
+        target_pos,
+        battlefield,
+        shooter_vision,
+        shooter_accuracy,
+        wind,
+        steadiness,
+        target_profile,
+        aimed,
+        cover_model,
+    } = *params;
+
     // Calculate distance to target
-    let distance = calculate_distance(shooter_pos, target_pos);
+    let distance = range_in_tiles(shooter_pos.as_battlefield_pos(), target_pos.as_battlefield_pos());
+    let no_shot = ShotPrediction {
+        hit_chance: 0.0,
+        damage_on_hit: 0,
+        distance,
+        out_of_range: false,
+        blocked_by_los: false,
+        cover_bonus: 0.0,
+        wind_drift: 0.0,
+    };
 
     // Check if target is in range
-    if distance > weapon.stats.max_range {
-        return CombatResult {
-            hit: false,
-            damage: 0,
-            hit_chance: 0.0,
-            distance,
-            blocked_by_los: false,
-            cover_bonus: 0.0,
+    if !in_weapon_range(weapon, shooter_pos.as_battlefield_pos(), target_pos.as_battlefield_pos()) {
+        return ShotPrediction {
+            out_of_range: true,
+            ..no_shot
         };
     }
 
     // Check line of sight
     let has_los = check_line_of_sight(shooter_pos, target_pos, battlefield, shooter_vision);
     if !has_los {
-        return CombatResult {
-            hit: false,
-            damage: 0,
-            hit_chance: 0.0,
-            distance,
+        return ShotPrediction {
             blocked_by_los: true,
-            cover_bonus: 0.0,
+            ..no_shot
         };
     }
 
@@ -73,32 +281,85 @@ pub fn calculate_shot(
         .unwrap_or(0.0);
 
     // Calculate hit chance based on range and shooter accuracy
-    let hit_chance = calculate_hit_chance(weapon, distance, shooter_accuracy);
+    let base_hit_chance = calculate_hit_chance(weapon, distance, shooter_accuracy);
+
+    // Wind pushes the effective aim point off the target at long range
+    let wind_drift = wind
+        .map(|w| {
+            let (aim_x, aim_y) = w.effective_aim_point(
+                shooter_pos.as_battlefield_pos(),
+                target_pos.as_battlefield_pos(),
+            );
+            let dx = aim_x - target_pos.x() as f32;
+            let dy = aim_y - target_pos.y() as f32;
+            (dx * dx + dy * dy).sqrt()
+        })
+        .unwrap_or(0.0);
+    // A steadied aim makes up much of what range and wind take away
+    let aim_factor = if aimed { 1.0 + weapon.stats.aim_bonus } else { 1.0 };
+    // Cover works on exactly one stage: the hit roll or the damage
+    let (cover_hit_factor, cover_damage_factor) = match cover_model {
+        CoverModel::ToHit => (1.0 - cover_bonus, 1.0),
+        CoverModel::Damage => (1.0, 1.0 - cover_bonus),
+    };
+    let hit_chance = (base_hit_chance
+        * wind_accuracy_factor(wind_drift)
+        * steadiness
+        * target_profile
+        * aim_factor)
+        .min(1.0)
+        * cover_hit_factor;
+
+    let base_damage = weapon.stats.damage as f32;
+    let damage_on_hit = (base_damage * cover_damage_factor).round() as i32;
+
+    ShotPrediction {
+        hit_chance,
+        damage_on_hit,
+        cover_bonus,
+        wind_drift,
+        ..no_shot
+    }
+}
+
+/// Calculate if a shot hits and how much damage it deals
+///
+/// The odds come from predict_shot; this only adds the hit roll, which is
+/// skipped for a target out of range or out of sight.
+///
+/// # Parameters
+/// - `params`: The shot's weapon, positions and modifiers
+/// - `rng`: Source for the hit roll (the world's GameRng during play)
+///
+/// # Returns
+/// CombatResult with hit/miss, damage, and other details
+pub fn calculate_shot(params: &ShotParams, rng: &mut impl Rng) -> CombatResult {
+    let prediction = predict_shot(params);
 
     // Roll to hit
-    let mut rng = rand::rng();
-    let roll: f32 = rng.random();
-    let hit = roll < hit_chance;
-
-    let damage = if hit {
-        // Apply cover damage reduction
-        let base_damage = weapon.stats.damage as f32;
-        let reduced_damage = base_damage * (1.0 - cover_bonus);
-        reduced_damage.round() as i32
+    let hit = if prediction.out_of_range || prediction.blocked_by_los {
+        false
     } else {
-        0
+        let roll: f32 = rng.random();
+        roll < prediction.hit_chance
     };
 
     CombatResult {
         hit,
-        damage,
-        hit_chance,
-        distance,
-        blocked_by_los: false,
-        cover_bonus,
+        damage: if hit { prediction.damage_on_hit } else { 0 },
+        hit_chance: prediction.hit_chance,
+        distance: prediction.distance,
+        blocked_by_los: prediction.blocked_by_los,
+        cover_bonus: prediction.cover_bonus,
+        wind_drift: prediction.wind_drift,
     }
 }
 
+/// Accuracy multiplier for a given wind drift in tiles (floored at 0.3)
+fn wind_accuracy_factor(drift: f32) -> f32 {
+    (1.0 - drift * 0.25).max(0.3)
+}
+
 /// Calculate hit chance based on weapon and distance
 ///
 /// Accuracy degrades linearly from effective_range to max_range:
@@ -138,13 +399,26 @@ fn calculate_hit_chance(weapon: &Weapon, distance: i32, soldier_accuracy: Option
     modified_chance.clamp(0.0, 1.0)
 }
 
-/// Calculate distance between two positions (Euclidean distance, rounded up)
-fn calculate_distance(pos1: &Position, pos2: &Position) -> i32 {
-    let dx = (pos1.x() - pos2.x()) as f32;
-    let dy = (pos1.y() - pos2.y()) as f32;
+/// Range in whole tiles between two positions (Euclidean distance, rounded up)
+///
+/// The one metric for weapon range: the targeting preview, AI shot
+/// generation and shot resolution all measure with it.
+pub fn range_in_tiles(from: &BattlefieldPos, to: &BattlefieldPos) -> i32 {
+    let dx = (from.x - to.x) as f32;
+    let dy = (from.y - to.y) as f32;
     (dx * dx + dy * dy).sqrt().ceil() as i32
 }
 
+/// Whether `to` is within the weapon's max range of `from`
+pub fn in_weapon_range(weapon: &Weapon, from: &BattlefieldPos, to: &BattlefieldPos) -> bool {
+    range_in_tiles(from, to) <= weapon.stats.max_range
+}
+
+/// Whether `to` is within the weapon's effective range of `from`, where it shoots true
+pub fn in_effective_range(weapon: &Weapon, from: &BattlefieldPos, to: &BattlefieldPos) -> bool {
+    range_in_tiles(from, to) <= weapon.stats.effective_range
+}
+
 /// Check if shooter has line of sight to target
 /// Uses the FOV system to determine visibility
 fn check_line_of_sight(
@@ -174,9 +448,9 @@ mod tests {
 
     #[test]
     fn test_distance_calculation() {
-        let pos1 = Position::new(0, 0);
-        let pos2 = Position::new(3, 4);
-        assert_eq!(calculate_distance(&pos1, &pos2), 5); // 3-4-5 triangle
+        let pos1 = BattlefieldPos::new(0, 0);
+        let pos2 = BattlefieldPos::new(3, 4);
+        assert_eq!(range_in_tiles(&pos1, &pos2), 5); // 3-4-5 triangle
     }
 
     #[test]
@@ -225,7 +499,7 @@ mod tests {
 
         let mut hits = 0;
         for _ in 0..100 {
-            let result = calculate_shot(&weapon, &shooter_pos, &target_pos, &battlefield, shooter_vision, None);
+            let result = calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, shooter_vision), &mut rand::rng());
             if result.hit {
                 hits += 1;
             }
@@ -237,4 +511,179 @@ mod tests {
         println!("Hits: {}/100 ({}%)", hits, hits);
         assert!(hits >= 50 && hits <= 90, "Hit rate seems wrong: {}/100. Expected around 70/100", hits);
     }
+
+    #[test]
+    fn test_wind_reduces_long_range_hit_chance() {
+        let weapon = Weapon::rifle();
+        let shooter_pos = Position::new(10, 50);
+        let target_pos = Position::new(38, 50); // 28 tiles, past the wind threshold
+        let battlefield = Battlefield::new(100, 100);
+        let gale = Wind::new(crate::components::facing::Direction8::S, 1.0);
+
+        let calm = calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 40), &mut rand::rng());
+        let windy = calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 40).with_wind(&gale), &mut rand::rng());
+
+        assert_eq!(calm.wind_drift, 0.0);
+        assert!(windy.wind_drift > 0.0);
+        assert!(windy.hit_chance < calm.hit_chance);
+    }
+
+    #[test]
+    fn test_badly_wounded_shooter_has_lower_hit_chance() {
+        let weapon = Weapon::rifle();
+        let shooter_pos = Position::new(50, 50);
+        let target_pos = Position::new(55, 52);
+        let battlefield = Battlefield::new(100, 100);
+        let wounded = crate::config::game_config::WoundedConfig::default();
+
+        let mut hurt = Health::soldier();
+        hurt.take_damage(80);
+
+        let healthy = calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 10).with_steadiness(wounded.accuracy_factor(1.0)), &mut rand::rng());
+        let bleeding = calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 10).with_steadiness(wounded.accuracy_factor(hurt.percentage())), &mut rand::rng());
+
+        assert!(bleeding.hit_chance < healthy.hit_chance);
+    }
+
+    #[test]
+    fn test_late_battle_fatigue_lowers_hit_chance() {
+        use crate::components::rested::Rested;
+
+        let weapon = Weapon::rifle();
+        let shooter_pos = Position::new(50, 50);
+        let target_pos = Position::new(55, 52);
+        let battlefield = Battlefield::new(100, 100);
+        let fatigue = crate::config::game_config::FatigueConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let shot_on = |turn, rested: Option<&Rested>| {
+            calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 10).with_steadiness(fatigue.accuracy_factor(turn, rested)), &mut rand::rng())
+        };
+
+        let early = shot_on(3, None);
+        let late = shot_on(60, None);
+        assert!(late.hit_chance < early.hit_chance);
+
+        let rested = shot_on(60, Some(&Rested { rests: 100 }));
+        assert!(rested.hit_chance > late.hit_chance, "resting wins some accuracy back");
+        assert!(rested.hit_chance < early.hit_chance, "but not all of it");
+    }
+
+    #[test]
+    fn test_crawling_target_is_harder_to_hit() {
+        let weapon = Weapon::rifle();
+        let shooter_pos = Position::new(50, 50);
+        let target_pos = Position::new(55, 52);
+        let battlefield = Battlefield::new(100, 100);
+
+        let standing = calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 10), &mut rand::rng());
+        let crawling = calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 10).with_target_profile(PRONE_TARGET_PROFILE), &mut rand::rng());
+
+        assert!(crawling.hit_chance < standing.hit_chance);
+    }
+
+    #[test]
+    fn test_prediction_matches_the_shot_it_predicts() {
+        use crate::utils::game_rng::GameRng;
+
+        let weapon = Weapon::rifle();
+        let shooter_pos = Position::new(10, 50);
+        let target_pos = Position::new(30, 53);
+        let mut battlefield = Battlefield::new(100, 100);
+        battlefield.set_terrain(
+            BattlefieldPos::new(30, 53),
+            crate::game_logic::battlefield::TerrainType::ShellCrater,
+        );
+        let wind = Wind::new(crate::components::facing::Direction8::E, 0.6);
+
+        for seed in 0..50 {
+            let prediction = predict_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 30).with_accuracy(Some(0.05)).with_wind(&wind).with_steadiness(0.8));
+            let shot = calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 30).with_accuracy(Some(0.05)).with_wind(&wind).with_steadiness(0.8), &mut GameRng::new(seed));
+
+            assert_eq!(shot.hit_chance, prediction.hit_chance);
+            assert_eq!(shot.cover_bonus, prediction.cover_bonus);
+            if shot.hit {
+                assert_eq!(shot.damage, prediction.damage_on_hit);
+            }
+        }
+        let prediction = predict_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 30));
+        assert!(prediction.would_kill(prediction.damage_on_hit));
+        assert!(!prediction.would_kill(prediction.damage_on_hit + 1));
+    }
+
+    #[test]
+    fn test_aimed_shot_beats_unaimed_shot() {
+        let weapon = Weapon::rifle();
+        let shooter_pos = Position::new(10, 50);
+        let target_pos = Position::new(35, 50); // past effective range
+        let battlefield = Battlefield::new(100, 100);
+
+        let snap = calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 40), &mut rand::rng());
+        let aimed = calculate_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 40).with_aim(true), &mut rand::rng());
+
+        assert!(aimed.hit_chance > snap.hit_chance);
+        assert!((aimed.hit_chance - snap.hit_chance * (1.0 + weapon.stats.aim_bonus)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cover_works_on_the_configured_stage() {
+        use crate::game_logic::battlefield::TerrainType;
+
+        let weapon = Weapon::rifle();
+        let shooter_pos = Position::new(10, 50);
+        let target_pos = Position::new(16, 50);
+        let open = Battlefield::new(100, 100);
+        let mut trench = Battlefield::new(100, 100);
+        trench.set_terrain(BattlefieldPos::new(16, 50), TerrainType::ShellCrater);
+        let cover = TerrainType::ShellCrater.cover_bonus();
+        assert!(cover > 0.0);
+
+        let shot = |battlefield: &Battlefield, model| {
+            predict_shot(&ShotParams::new(&weapon, &shooter_pos, &target_pos, battlefield, 20).with_cover_model(model))
+        };
+        let in_the_open = shot(&open, CoverModel::Damage);
+
+        // Damage: as easy to hit as in the open, but each hit is softened
+        let soaked = shot(&trench, CoverModel::Damage);
+        assert_eq!(soaked.hit_chance, in_the_open.hit_chance);
+        assert!(soaked.damage_on_hit < in_the_open.damage_on_hit);
+
+        // ToHit: harder to hit, but a hit lands in full
+        let hidden = shot(&trench, CoverModel::ToHit);
+        assert!((hidden.hit_chance - in_the_open.hit_chance * (1.0 - cover)).abs() < 1e-6);
+        assert_eq!(hidden.damage_on_hit, in_the_open.damage_on_hit);
+        assert_eq!(shot(&open, CoverModel::ToHit), in_the_open);
+    }
+
+    #[test]
+    fn test_shot_modifiers_stack_every_condition() {
+        let wounded = WoundedConfig::default();
+        let mut health = Health::new(100);
+        health.current = 10;
+
+        let modifiers = ShotModifiers::default()
+            .with_wounds(&wounded, Some(&health))
+            .with_last_stand(true)
+            .with_handling(MOVING_FIRE_ACCURACY)
+            .with_prone_target(true)
+            .with_aim(true);
+        let expected = wounded.accuracy_factor(0.1) * LAST_STAND_ACCURACY * MOVING_FIRE_ACCURACY;
+        assert!((modifiers.steadiness - expected).abs() < 1e-6);
+        assert_eq!(modifiers.target_profile, PRONE_TARGET_PROFILE);
+
+        let weapon = Weapon::rifle();
+        let shooter_pos = Position::new(10, 50);
+        let target_pos = Position::new(16, 50);
+        let battlefield = Battlefield::new(100, 100);
+        let plain = ShotParams::new(&weapon, &shooter_pos, &target_pos, &battlefield, 20);
+        let modified = predict_shot(&plain.with_modifiers(modifiers));
+        let by_hand = predict_shot(
+            &plain
+                .with_steadiness(expected)
+                .with_target_profile(PRONE_TARGET_PROFILE)
+                .with_aim(true),
+        );
+        assert_eq!(modified, by_hand);
+    }
 }
@@ -0,0 +1,94 @@
+// Danger map
+// How exposed each tile is to known enemy rifles, for picking a way out
+//
+// Every known enemy adds danger to the tiles it can see within its weapon's
+// max range, more the closer the tile is to it. Pathfinding can weigh a
+// tile's danger on top of its terrain cost, so a soldier falling back slips
+// away through ground the enemy can't cover instead of across its sights.
+
+use crate::game_logic::battlefield::{Battlefield, Position};
+use crate::game_logic::line_of_sight::calculate_fov;
+
+/// Extra step cost, in movement-cost units, for each point of danger on a tile
+pub const DANGER_STEP_COST: f32 = 4.0;
+
+/// Danger per tile, from the enemies a side knows about
+#[derive(Debug, Clone, PartialEq)]
+pub struct DangerMap {
+    width: i32,
+    height: i32,
+    danger: Vec<f32>,
+}
+
+impl DangerMap {
+    /// A map the size of `battlefield` with no danger anywhere
+    pub fn new(battlefield: &Battlefield) -> Self {
+        let width = battlefield.width() as i32;
+        let height = battlefield.height() as i32;
+        Self {
+            width,
+            height,
+            danger: vec![0.0; (width * height) as usize],
+        }
+    }
+
+    /// Build a map from `(enemy position, weapon max range)` pairs
+    pub fn from_threats(
+        battlefield: &Battlefield,
+        threats: impl IntoIterator<Item = (Position, i32)>,
+    ) -> Self {
+        let mut map = Self::new(battlefield);
+        for (origin, range) in threats {
+            map.add_threat(&origin, range, battlefield);
+        }
+        map
+    }
+
+    /// Add an enemy at `origin` covering what it can see out to `range` tiles
+    ///
+    /// A tile at the enemy's feet gets a full point of danger, falling off
+    /// linearly to nearly nothing at the edge of its range.
+    pub fn add_threat(&mut self, origin: &Position, range: i32, battlefield: &Battlefield) {
+        if range <= 0 {
+            return;
+        }
+        let reach = range as f32 + 1.0;
+        for tile in calculate_fov(origin, range, battlefield) {
+            let distance = origin.distance_to(&tile);
+            if distance > range as f32 {
+                continue;
+            }
+            if let Some(idx) = self.index(&tile) {
+                self.danger[idx] += 1.0 - distance / reach;
+            }
+        }
+    }
+
+    /// Danger on `pos` (0.0 off the map or out of every enemy's sight)
+    pub fn danger_at(&self, pos: &Position) -> f32 {
+        self.index(pos).map_or(0.0, |idx| self.danger[idx])
+    }
+
+    fn index(&self, pos: &Position) -> Option<usize> {
+        (pos.x >= 0 && pos.y >= 0 && pos.x < self.width && pos.y < self.height)
+            .then(|| (pos.y * self.width + pos.x) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::battlefield::TerrainType;
+
+    #[test]
+    fn test_danger_falls_off_with_range_and_stops_at_walls() {
+        let mut battlefield = Battlefield::new(20, 20);
+        battlefield.set_terrain(Position::new(10, 12), TerrainType::BuildingWall);
+        let enemy = Position::new(10, 10);
+        let map = DangerMap::from_threats(&battlefield, [(enemy, 8)]);
+
+        assert!(map.danger_at(&Position::new(10, 11)) > map.danger_at(&Position::new(10, 6)));
+        assert_eq!(map.danger_at(&Position::new(10, 14)), 0.0, "wall should shelter the tile");
+        assert_eq!(map.danger_at(&Position::new(10, 19)), 0.0, "beyond range");
+    }
+}
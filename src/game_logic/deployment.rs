@@ -0,0 +1,180 @@
+// Deployment phase
+// Placing the friendly squad inside its spawn zone before the first turn
+//
+// The player walks through their side's soldiers one at a time and drops
+// each on a chosen tile. Only free, passable tiles inside the friendly
+// spawn zone are accepted, so deployment can tighten a line of departure
+// but never sneak anyone forward.
+
+use crate::components::{
+    dead::Dead,
+    player::Player,
+    position::Position,
+    soldier::{Faction, Soldier},
+};
+use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos, SpawnZone};
+use crate::game_logic::spatial_index::PositionIndex;
+use specs::{Entity, Join, World, WorldExt};
+use std::fmt;
+
+/// Why a soldier can't be placed on a tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementError {
+    OutsideZone,
+    Impassable,
+    Occupied,
+}
+
+impl fmt::Display for PlacementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlacementError::OutsideZone => write!(f, "outside the deployment zone"),
+            PlacementError::Impassable => write!(f, "nobody can stand there"),
+            PlacementError::Occupied => write!(f, "already taken"),
+        }
+    }
+}
+
+/// A squad being placed before the battle starts
+#[derive(Debug, Clone)]
+pub struct Deployment {
+    pub zone: SpawnZone,
+    /// Soldiers to place, the player's own soldier first
+    squad: Vec<Entity>,
+    selected: usize,
+}
+
+impl Deployment {
+    /// Start deploying `faction`'s living soldiers, if the map gives it a spawn zone
+    pub fn new(world: &World, battlefield: &Battlefield, faction: Faction) -> Option<Self> {
+        let zone = match faction {
+            Faction::Allies => battlefield.ally_spawn.clone(),
+            Faction::CentralPowers => battlefield.enemy_spawn.clone(),
+        }?;
+
+        let entities = world.entities();
+        let soldiers = world.read_storage::<Soldier>();
+        let players = world.read_storage::<Player>();
+        let dead = world.read_storage::<Dead>();
+        let mut squad: Vec<Entity> = (&entities, &soldiers, !&dead)
+            .join()
+            .filter(|(_, soldier, _)| soldier.faction == faction)
+            .map(|(entity, _, _)| entity)
+            .collect();
+        squad.sort_by_key(|entity| (!players.contains(*entity), entity.id()));
+
+        Some(Self {
+            zone,
+            squad,
+            selected: 0,
+        })
+    }
+
+    /// The soldier the next placement moves
+    pub fn selected(&self) -> Option<Entity> {
+        self.squad.get(self.selected).copied()
+    }
+
+    /// Move the selection on to the next soldier, wrapping around
+    pub fn select_next(&mut self) {
+        if !self.squad.is_empty() {
+            self.selected = (self.selected + 1) % self.squad.len();
+        }
+    }
+
+    /// Whether the selected soldier could be placed on `pos`
+    pub fn check_placement(
+        &self,
+        world: &World,
+        battlefield: &Battlefield,
+        pos: BattlefieldPos,
+    ) -> Result<(), PlacementError> {
+        if !self.zone.contains(&pos) {
+            return Err(PlacementError::OutsideZone);
+        }
+        if !battlefield
+            .get_tile(&pos)
+            .is_some_and(|tile| tile.terrain.is_passable())
+        {
+            return Err(PlacementError::Impassable);
+        }
+        let index = world.read_resource::<PositionIndex>();
+        if index
+            .occupants(pos)
+            .iter()
+            .any(|other| Some(*other) != self.selected())
+        {
+            return Err(PlacementError::Occupied);
+        }
+        Ok(())
+    }
+
+    /// Put the selected soldier on `pos` and select the next one
+    pub fn place(
+        &mut self,
+        world: &World,
+        battlefield: &Battlefield,
+        pos: BattlefieldPos,
+    ) -> Result<(), PlacementError> {
+        self.check_placement(world, battlefield, pos)?;
+        let Some(entity) = self.selected() else {
+            return Ok(());
+        };
+
+        let mut positions = world.write_storage::<Position>();
+        if let Some(current) = positions.get_mut(entity) {
+            world
+                .write_resource::<PositionIndex>()
+                .move_entity(entity, *current.as_battlefield_pos(), pos);
+            *current = Position::new(pos.x, pos.y);
+        }
+        self.select_next();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::Rank;
+    use crate::game_logic::world_setup::register_components;
+    use specs::Builder;
+
+    #[test]
+    fn test_placement_stays_inside_spawn_zone() {
+        let mut world = World::new();
+        register_components(&mut world);
+        let mut battlefield = Battlefield::new(40, 40);
+        battlefield.set_spawn_zones(
+            SpawnZone::new(BattlefieldPos::new(20, 34), 3),
+            SpawnZone::new(BattlefieldPos::new(20, 5), 3),
+        );
+        let soldier = world
+            .create_entity()
+            .with(Position::new(20, 34))
+            .with(Soldier {
+                name: "Private".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Private,
+            })
+            .build();
+        world.insert(PositionIndex::default());
+        PositionIndex::refresh(&world);
+
+        let mut deployment = Deployment::new(&world, &battlefield, Faction::Allies).unwrap();
+        assert_eq!(deployment.selected(), Some(soldier));
+
+        // Forward of the zone is refused and nobody moves
+        let forward = BattlefieldPos::new(20, 28);
+        assert_eq!(
+            deployment.place(&world, &battlefield, forward),
+            Err(PlacementError::OutsideZone)
+        );
+        assert_eq!(*world.read_storage::<Position>().get(soldier).unwrap(), Position::new(20, 34));
+
+        let inside = BattlefieldPos::new(22, 33);
+        assert_eq!(deployment.place(&world, &battlefield, inside), Ok(()));
+        assert_eq!(*world.read_storage::<Position>().get(soldier).unwrap(), Position::new(22, 33));
+        assert!(world.read_resource::<PositionIndex>().contains_entity_at(inside));
+    }
+}
@@ -0,0 +1,212 @@
+// Fog of war
+// How much the player is told about an enemy soldier under the cursor
+//
+// Full tells everything, always. Realistic gives exact figures only for an
+// enemy in sight and within CLOSE_RANGE of a friendly soldier; one in sight
+// but further off shows a rough HP status, and one out of sight only its
+// faction. Minimal never tells more than where the enemy is and whose side
+// it is on.
+
+use crate::components::{health::Health, soldier::Soldier, weapon::Weapon};
+use crate::config::hud_thresholds::HudThresholds;
+
+/// Tiles within which a visible enemy can be sized up exactly under Realistic
+pub const CLOSE_RANGE: f32 = 8.0;
+
+/// How much is revealed about enemy soldiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnemyInfoLevel {
+    /// Exact HP, name, rank and weapon, seen or not
+    Full,
+    /// Exact only when the enemy is visible and close
+    #[default]
+    Realistic,
+    /// Position and faction only, never HP
+    Minimal,
+}
+
+impl EnemyInfoLevel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnemyInfoLevel::Full => "Full",
+            EnemyInfoLevel::Realistic => "Realistic",
+            EnemyInfoLevel::Minimal => "Minimal",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            EnemyInfoLevel::Full => EnemyInfoLevel::Realistic,
+            EnemyInfoLevel::Realistic => EnemyInfoLevel::Minimal,
+            EnemyInfoLevel::Minimal => EnemyInfoLevel::Full,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            EnemyInfoLevel::Full => EnemyInfoLevel::Minimal,
+            EnemyInfoLevel::Realistic => EnemyInfoLevel::Full,
+            EnemyInfoLevel::Minimal => EnemyInfoLevel::Realistic,
+        }
+    }
+
+    /// What may be shown about an enemy `distance` tiles from the nearest friendly
+    pub fn readout(self, visible: bool, distance: f32) -> EnemyReadout {
+        match self {
+            EnemyInfoLevel::Full => EnemyReadout {
+                identity: true,
+                hp: HpReadout::Exact,
+                weapon: true,
+            },
+            EnemyInfoLevel::Realistic if visible => EnemyReadout {
+                identity: true,
+                hp: if distance <= CLOSE_RANGE {
+                    HpReadout::Exact
+                } else {
+                    HpReadout::Status
+                },
+                weapon: distance <= CLOSE_RANGE,
+            },
+            EnemyInfoLevel::Realistic | EnemyInfoLevel::Minimal => EnemyReadout {
+                identity: false,
+                hp: HpReadout::Hidden,
+                weapon: false,
+            },
+        }
+    }
+}
+
+/// How an enemy's HP is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HpReadout {
+    /// Current and maximum HP
+    Exact,
+    /// Only a Healthy / Wounded / Critical label
+    Status,
+    /// Nothing at all
+    Hidden,
+}
+
+/// The parts of an enemy's details the player gets to see
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnemyReadout {
+    /// Name and rank
+    pub identity: bool,
+    pub hp: HpReadout,
+    pub weapon: bool,
+}
+
+impl EnemyReadout {
+    /// Whether the targeting panel may show hit chance and damage: only
+    /// against an enemy the player can size up at all
+    pub fn shows_shot_odds(&self) -> bool {
+        self.hp != HpReadout::Hidden
+    }
+
+    /// Whether it may say a hit would kill, which gives away the exact HP
+    pub fn shows_kill(&self) -> bool {
+        self.hp == HpReadout::Exact
+    }
+}
+
+/// Context panel lines describing an enemy soldier
+pub fn enemy_info_lines(
+    readout: EnemyReadout,
+    soldier: &Soldier,
+    health: Option<&Health>,
+    weapon: Option<&Weapon>,
+    thresholds: &HudThresholds,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    if readout.identity {
+        lines.push(format!("Unit: {}", soldier.name));
+    } else {
+        lines.push("Unit: Unknown".to_string());
+    }
+    lines.push(format!("Faction: {}", soldier.faction.name()));
+    if readout.identity {
+        lines.push(format!("Rank: {}", soldier.rank.as_str()));
+    }
+
+    match (readout.hp, health) {
+        (HpReadout::Exact, Some(health)) => lines.push(format!(
+            "HP: {}/{} ({}%)",
+            health.current,
+            health.maximum,
+            health.percentage_display()
+        )),
+        (HpReadout::Status, Some(health)) => lines.push(format!(
+            "HP: {}",
+            thresholds.hp_status(health.percentage()).label()
+        )),
+        _ => lines.push("HP: ???".to_string()),
+    }
+
+    if readout.weapon
+        && let Some(weapon) = weapon
+    {
+        lines.push(format!("Weapon: {}", weapon.stats.name));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::{Faction, Rank};
+
+    #[test]
+    fn test_minimal_never_reveals_hp_while_full_always_does() {
+        let soldier = Soldier {
+            name: "Gefr. Brandt".to_string(),
+            faction: Faction::CentralPowers,
+            rank: Rank::Corporal,
+        };
+        let mut health = Health::new(100);
+        health.take_damage(35);
+        let weapon = Weapon::rifle();
+        let thresholds = HudThresholds::default();
+
+        for (visible, distance) in [(true, 1.0), (true, 20.0), (false, 1.0)] {
+            let minimal = enemy_info_lines(
+                EnemyInfoLevel::Minimal.readout(visible, distance),
+                &soldier,
+                Some(&health),
+                Some(&weapon),
+                &thresholds,
+            );
+            assert!(minimal.contains(&"HP: ???".to_string()), "{:?}", minimal);
+            assert!(minimal.contains(&"Faction: Central Powers".to_string()), "{:?}", minimal);
+            assert!(!minimal.iter().any(|line| line.contains("Brandt") || line.contains("Weapon")));
+
+            let full = enemy_info_lines(
+                EnemyInfoLevel::Full.readout(visible, distance),
+                &soldier,
+                Some(&health),
+                Some(&weapon),
+                &thresholds,
+            );
+            assert!(full.contains(&"HP: 65/100 (65%)".to_string()), "{:?}", full);
+        }
+    }
+
+    #[test]
+    fn test_realistic_needs_sight_and_closeness_for_exact_hp() {
+        let realistic = EnemyInfoLevel::Realistic;
+        assert_eq!(realistic.readout(true, CLOSE_RANGE).hp, HpReadout::Exact);
+        assert_eq!(realistic.readout(true, CLOSE_RANGE + 1.0).hp, HpReadout::Status);
+        assert_eq!(realistic.readout(false, 1.0).hp, HpReadout::Hidden);
+    }
+
+    #[test]
+    fn test_shot_odds_give_away_no_more_than_the_hp_readout() {
+        let close = EnemyInfoLevel::Realistic.readout(true, 1.0);
+        assert!(close.shows_shot_odds() && close.shows_kill());
+
+        let far = EnemyInfoLevel::Realistic.readout(true, CLOSE_RANGE + 1.0);
+        assert!(far.shows_shot_odds() && !far.shows_kill());
+
+        let minimal = EnemyInfoLevel::Minimal.readout(true, 1.0);
+        assert!(!minimal.shows_shot_odds() && !minimal.shows_kill());
+    }
+}
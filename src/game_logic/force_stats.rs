@@ -0,0 +1,141 @@
+// Force stats
+// Live strength of each side, for the commander's status panel
+//
+// Counted fresh from the storages whenever the panel is drawn, so the figures
+// follow the battle turn by turn. Only soldiers still in the fight count:
+// the dead and the surrendered add nothing to a side's men, ammo or morale.
+// The enemy's ammo and morale are only shown when enemy info is Full.
+
+use crate::components::{
+    dead::Dead,
+    morale::{Morale, MAX_MORALE},
+    soldier::{Faction, Soldier},
+    surrendered::Surrendered,
+    weapon::Weapon,
+};
+use crate::game_logic::objectives::Objectives;
+use specs::{Join, World, WorldExt};
+
+/// One side's standing strength
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ForceStats {
+    /// Soldiers neither dead nor surrendered
+    pub alive: usize,
+    /// Rounds loaded in their weapons
+    pub ammo: i32,
+    pub objectives_held: usize,
+    /// Mean morale as a share of the maximum (None with nobody left to ask)
+    pub average_morale: Option<f32>,
+}
+
+impl ForceStats {
+    /// One status-line summary, e.g. "Allies: 12 men, 340 rds, 1 flag(s), morale 74%"
+    ///
+    /// Without `sized_up` the line stops at men and flags, as for an enemy
+    /// the commander can't inspect.
+    pub fn display(&self, faction: Faction, sized_up: bool) -> String {
+        if !sized_up {
+            return format!("{}: {} men, {} flag(s)", faction.name(), self.alive, self.objectives_held);
+        }
+        let morale = self
+            .average_morale
+            .map(|morale| format!("{:.0}%", morale * 100.0))
+            .unwrap_or_else(|| "-".to_string());
+        format!(
+            "{}: {} men, {} rds, {} flag(s), morale {}",
+            faction.name(),
+            self.alive,
+            self.ammo,
+            self.objectives_held,
+            morale
+        )
+    }
+}
+
+/// Tally `faction`'s soldiers still in the fight, their ammo, flags and morale
+pub fn force_stats(world: &World, faction: Faction) -> ForceStats {
+    let entities = world.entities();
+    let soldiers = world.read_storage::<Soldier>();
+    let dead = world.read_storage::<Dead>();
+    let surrendered = world.read_storage::<Surrendered>();
+    let weapons = world.read_storage::<Weapon>();
+    let morales = world.read_storage::<Morale>();
+
+    let mut stats = ForceStats::default();
+    let mut morale_total = 0.0;
+    let mut morale_count = 0;
+    for (entity, soldier, _, _) in (&entities, &soldiers, !&dead, !&surrendered).join() {
+        if soldier.faction != faction {
+            continue;
+        }
+        stats.alive += 1;
+        stats.ammo += weapons.get(entity).map_or(0, |weapon| weapon.ammo.current);
+        if let Some(morale) = morales.get(entity) {
+            morale_total += morale.current / MAX_MORALE;
+            morale_count += 1;
+        }
+    }
+
+    stats.average_morale = (morale_count > 0).then(|| morale_total / morale_count as f32);
+    stats.objectives_held = world
+        .read_resource::<Objectives>()
+        .flags
+        .values()
+        .filter(|flag| flag.is_held_by(faction))
+        .count();
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::Rank;
+    use crate::game_logic::world_setup::register_components;
+    use specs::Builder;
+
+    #[test]
+    fn test_alive_counts_leave_out_the_fallen() {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(Objectives::new());
+
+        let mut spawn = |faction: Faction| {
+            world
+                .create_entity()
+                .with(Soldier {
+                    name: "Pvt. Reed".to_string(),
+                    faction,
+                    rank: Rank::Private,
+                })
+                .with(Morale::new(50.0))
+                .build()
+        };
+        let allies: Vec<_> = (0..4).map(|_| spawn(Faction::Allies)).collect();
+        let central: Vec<_> = (0..3).map(|_| spawn(Faction::CentralPowers)).collect();
+
+        world.write_storage::<Dead>().insert(allies[0], Dead).unwrap();
+        world.write_storage::<Dead>().insert(allies[1], Dead).unwrap();
+        world.write_storage::<Dead>().insert(central[2], Dead).unwrap();
+
+        let allied = force_stats(&world, Faction::Allies);
+        let enemy = force_stats(&world, Faction::CentralPowers);
+        assert_eq!(allied.alive, 2);
+        assert_eq!(enemy.alive, 2);
+        assert_eq!(allied.average_morale, Some(0.5));
+    }
+
+    #[test]
+    fn test_enemy_line_hides_ammo_and_morale_unless_sized_up() {
+        let stats = ForceStats {
+            alive: 12,
+            ammo: 340,
+            objectives_held: 1,
+            average_morale: Some(0.74),
+        };
+        assert_eq!(
+            stats.display(Faction::Allies, true),
+            "Allies: 12 men, 340 rds, 1 flag(s), morale 74%"
+        );
+        assert_eq!(stats.display(Faction::CentralPowers, false), "Central Powers: 12 men, 1 flag(s)");
+    }
+}
@@ -0,0 +1,153 @@
+// Squad formation moves
+// Spreads a squad's destinations around an ordered point so members don't clump
+//
+// The squad leader (index 0) takes the ordered tile itself; the others fill
+// the nearest free slots on a grid spaced FORMATION_SPACING tiles apart,
+// skipping anything off the map or impassable. Each member then paths to
+// its own slot.
+
+use crate::components::{
+    dead::Dead,
+    pathfinding::PlannedPath,
+    position::Position,
+    squad::SquadMember,
+    surrendered::Surrendered,
+};
+use crate::game_logic::{
+    battlefield::{Battlefield, Position as BattlefieldPos},
+    pathfinding::{calculate_path_avoiding, corpse_obstacles, path_cost},
+};
+use specs::{Join, World, WorldExt};
+
+/// Tiles between neighbouring slots in a formation
+pub const FORMATION_SPACING: i32 = 2;
+
+/// Distinct destination tiles for `count` soldiers moving to `target`
+///
+/// Slots are handed out nearest-first, so the first slot is always the
+/// target itself when it can be stood on. May return fewer than `count`
+/// slots on a cramped map.
+pub fn formation_destinations(
+    target: BattlefieldPos,
+    count: usize,
+    battlefield: &Battlefield,
+) -> Vec<BattlefieldPos> {
+    // Enough rings of slots for the whole squad, with room to skip blocked tiles
+    let rings = (count as f32).sqrt().ceil() as i32 + 1;
+
+    let mut slots: Vec<BattlefieldPos> = (-rings..=rings)
+        .flat_map(|gy| (-rings..=rings).map(move |gx| (gx, gy)))
+        .map(|(gx, gy)| {
+            BattlefieldPos::new(
+                target.x + gx * FORMATION_SPACING,
+                target.y + gy * FORMATION_SPACING,
+            )
+        })
+        .filter(|slot| {
+            battlefield
+                .get_tile(slot)
+                .is_some_and(|tile| tile.terrain.is_passable())
+        })
+        .collect();
+
+    // Nearest first; ties broken by row then column so orders are repeatable
+    slots.sort_by_key(|slot| {
+        let dx = slot.x - target.x;
+        let dy = slot.y - target.y;
+        (dx * dx + dy * dy, slot.y, slot.x)
+    });
+    slots.truncate(count);
+    slots
+}
+
+/// Order a squad's standing members to move to `target` in formation
+///
+/// Each member gets a PlannedPath to its own slot, assigned in squad order.
+/// Returns how many members were given a path.
+pub fn order_formation_move(world: &World, squad: u32, target: BattlefieldPos) -> usize {
+    let battlefield = world.read_resource::<Battlefield>();
+    let entities = world.entities();
+    let members = world.read_storage::<SquadMember>();
+    let positions = world.read_storage::<Position>();
+    let dead = world.read_storage::<Dead>();
+    let surrendered = world.read_storage::<Surrendered>();
+    let mut paths = world.write_storage::<PlannedPath>();
+    let corpse_tiles = corpse_obstacles(world);
+
+    let mut squad_members: Vec<_> = (&entities, &members, &positions)
+        .join()
+        .filter(|(entity, member, _)| {
+            member.squad == squad && dead.get(*entity).is_none() && surrendered.get(*entity).is_none()
+        })
+        .map(|(entity, member, pos)| (member.index, entity, *pos.as_battlefield_pos()))
+        .collect();
+    squad_members.sort_by_key(|(index, _, _)| *index);
+
+    let destinations = formation_destinations(target, squad_members.len(), &battlefield);
+
+    let mut ordered = 0;
+    for ((_, entity, start), destination) in squad_members.into_iter().zip(destinations) {
+        if let Some(steps) = calculate_path_avoiding(&start, &destination, &battlefield, &corpse_tiles) {
+            let cost = path_cost(&start, &steps, &battlefield);
+            paths.insert(entity, PlannedPath::new(steps, cost, true)).ok();
+            ordered += 1;
+        }
+    }
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::battlefield::TerrainType;
+    use crate::game_logic::world_setup::register_components;
+    use specs::Builder;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_squad_move_spreads_destinations() {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(Battlefield::new(40, 40));
+
+        let squad: Vec<_> = (0..5)
+            .map(|index| {
+                world
+                    .create_entity()
+                    .with(Position::new(5 + index as i32, 5))
+                    .with(SquadMember::new(7, index))
+                    .build()
+            })
+            .collect();
+
+        let target = BattlefieldPos::new(20, 20);
+        assert_eq!(order_formation_move(&world, 7, target), 5);
+
+        let paths = world.read_storage::<PlannedPath>();
+        let destinations: Vec<BattlefieldPos> = squad
+            .iter()
+            .map(|member| *paths.get(*member).unwrap().steps.last().unwrap())
+            .collect();
+
+        // The leader takes the ordered tile; nobody shares a destination
+        assert_eq!(destinations[0], target);
+        let distinct: HashSet<_> = destinations.iter().collect();
+        assert_eq!(distinct.len(), destinations.len());
+        for (i, a) in destinations.iter().enumerate() {
+            for b in &destinations[i + 1..] {
+                assert!(a.distance_to(b) >= FORMATION_SPACING as f32);
+            }
+        }
+    }
+
+    #[test]
+    fn test_formation_skips_impassable_slots() {
+        let mut battlefield = Battlefield::new(20, 20);
+        battlefield.set_terrain(BattlefieldPos::new(12, 10), TerrainType::BuildingWall);
+
+        let slots = formation_destinations(BattlefieldPos::new(10, 10), 9, &battlefield);
+
+        assert_eq!(slots.len(), 9);
+        assert!(!slots.contains(&BattlefieldPos::new(12, 10)));
+    }
+}
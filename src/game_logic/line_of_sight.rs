@@ -1,9 +1,50 @@
 // Line-of-sight and field-of-view calculations
+//
+// Blocking rules, applied uniformly to every FOV query:
+// - Full blockers (walls, buildings) are visible but nothing beyond them is.
+// - Partial blockers (parapets, sandbags, wire, hedges) are visible, and each
+//   one crossed on the way to a tile costs PARTIAL_RANGE_COST tiles of sight.
+//   A viewer can see into and a little past a partial blocker, but a thick
+//   enough band of them cuts sight off entirely.
+// - The viewer's own tile and the target tile never count as crossed.
+// - Under DiagonalLos::Strict a sight line may not slip diagonally between
+//   two full blockers that meet at a corner.
 
 use crate::game_logic::battlefield::{Battlefield, Position};
 use bracket_lib::prelude::*;
 use std::collections::HashSet;
 
+/// Sight range consumed by each Partial blocker between viewer and target
+pub const PARTIAL_RANGE_COST: f32 = 4.0;
+
+/// Whether sight passes diagonally between two walls meeting at a corner
+///
+/// Set on the Battlefield, so every FOV query on the map follows it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiagonalLos {
+    /// Shadowcasting alone decides; sight can squeeze through such corners
+    #[default]
+    Loose,
+    /// A diagonal step is blocked when both tiles beside it block sight
+    Strict,
+}
+
+impl DiagonalLos {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DiagonalLos::Loose => "Loose",
+            DiagonalLos::Strict => "Strict",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            DiagonalLos::Loose => DiagonalLos::Strict,
+            DiagonalLos::Strict => DiagonalLos::Loose,
+        }
+    }
+}
+
 /// Calculate field of view from a position with given range
 pub fn calculate_fov(
     origin: &Position,
@@ -19,15 +60,76 @@ pub fn calculate_fov(
     // Use bracket-lib's field_of_view_set
     // This uses symmetric shadowcasting algorithm
     let visible = field_of_view_set(Point::new(origin.x, origin.y), range, &map);
+    let strict = battlefield.diagonal_los() == DiagonalLos::Strict;
 
-    // Convert bracket-lib Points back to our Position type
+    // Convert bracket-lib Points back to our Position type, then apply the
+    // partial blocker range penalty and, if strict, the corner rule
     for point in visible {
-        visible_tiles.insert(Position::new(point.x, point.y));
+        let target = Position::new(point.x, point.y);
+        let line = sight_line(origin, &target);
+        if strict && cuts_blocked_corner(&line, battlefield) {
+            continue;
+        }
+        let partials = partial_blockers_on(&line, battlefield);
+        if partials == 0
+            || origin.distance_to(&target) + partials as f32 * PARTIAL_RANGE_COST <= range as f32
+        {
+            visible_tiles.insert(target);
+        }
     }
 
     visible_tiles
 }
 
+/// Tiles on the straight line from `from` to `to`, both ends included
+///
+/// Steps one tile along the longer axis at a time, rounding the other; the
+/// same line partial blockers are counted along.
+pub fn sight_line(from: &Position, to: &Position) -> Vec<Position> {
+    let dx = to.x - from.x;
+    let dy = to.y - from.y;
+    let steps = dx.abs().max(dy.abs());
+
+    (0..=steps)
+        .map(|i| {
+            if i == steps {
+                return *to;
+            }
+            let t = i as f32 / steps as f32;
+            Position::new(
+                from.x + (dx as f32 * t).round() as i32,
+                from.y + (dy as f32 * t).round() as i32,
+            )
+        })
+        .collect()
+}
+
+/// Count Partial LOS blockers on a sight line, leaving out both ends
+fn partial_blockers_on(line: &[Position], battlefield: &Battlefield) -> usize {
+    line.iter()
+        .skip(1)
+        .take(line.len().saturating_sub(2))
+        .filter(|pos| {
+            battlefield
+                .get_tile(pos)
+                .is_some_and(|tile| tile.terrain.partially_blocks_los())
+        })
+        .count()
+}
+
+/// Whether a sight line steps diagonally between two sight-blocking tiles
+fn cuts_blocked_corner(line: &[Position], battlefield: &Battlefield) -> bool {
+    let blocks = |x: i32, y: i32| {
+        battlefield
+            .get_tile(&Position::new(x, y))
+            .is_some_and(|tile| tile.terrain.blocks_los())
+    };
+    line.windows(2).any(|step| {
+        let (from, to) = (step[0], step[1]);
+        from.x != to.x && from.y != to.y && blocks(to.x, from.y) && blocks(from.x, to.y)
+    })
+}
+
 /// Wrapper to make Battlefield compatible with bracket-lib FOV
 struct BattlefieldFOVMap<'a> {
     battlefield: &'a Battlefield,
@@ -70,3 +172,60 @@ impl<'a> BaseMap for BattlefieldFOVMap<'a> {
         SmallVec::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::battlefield::TerrainType;
+
+    fn open_field() -> Battlefield {
+        let mut battlefield = Battlefield::new(30, 30);
+        for x in 0..30 {
+            for y in 0..30 {
+                battlefield.set_terrain(Position::new(x, y), TerrainType::NoMansLand);
+            }
+        }
+        battlefield
+    }
+
+    #[test]
+    fn test_partial_blocker_truncates_sight_beyond() {
+        let mut battlefield = open_field();
+        battlefield.set_terrain(Position::new(13, 10), TerrainType::Sandbags);
+
+        let visible = calculate_fov(&Position::new(10, 10), 10, &battlefield);
+
+        // The blocker itself and the tiles just past it are visible...
+        assert!(visible.contains(&Position::new(13, 10)));
+        assert!(visible.contains(&Position::new(16, 10)));
+        // ...but the far end of the range is not
+        assert!(!visible.contains(&Position::new(19, 10)));
+    }
+
+    #[test]
+    fn test_open_terrain_does_not_truncate_sight() {
+        let battlefield = open_field();
+
+        let visible = calculate_fov(&Position::new(10, 10), 10, &battlefield);
+
+        assert!(visible.contains(&Position::new(19, 10)));
+    }
+
+    #[test]
+    fn test_strict_diagonal_los_blocks_a_corner_of_two_walls() {
+        let mut battlefield = open_field();
+        battlefield.set_terrain(Position::new(11, 10), TerrainType::BuildingWall);
+        battlefield.set_terrain(Position::new(10, 11), TerrainType::BuildingWall);
+        let viewer = Position::new(10, 10);
+        let past_corner = Position::new(11, 11);
+
+        let loose = calculate_fov(&viewer, 10, &battlefield);
+        assert!(loose.contains(&past_corner));
+
+        battlefield.set_diagonal_los(DiagonalLos::Strict);
+        let strict = calculate_fov(&viewer, 10, &battlefield);
+        assert!(!strict.contains(&past_corner));
+        assert!(strict.contains(&Position::new(11, 10)), "the walls themselves stay visible");
+        assert!(strict.contains(&Position::new(10, 5)), "open lines are unaffected");
+    }
+}
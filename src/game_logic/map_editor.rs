@@ -0,0 +1,261 @@
+// Map Editor
+// Terrain brush for hand-painting scenario maps in the map_test tool
+//
+// The brush holds a cursor and a terrain picked from the palette; painting
+// stamps that terrain onto the tile under the cursor. A finished map is
+// written out one row per line, one character per terrain type, so it can be
+// loaded back exactly as it was painted.
+
+use crate::game_logic::battlefield::{Battlefield, Position, TerrainType};
+use std::fmt;
+
+/// Every terrain type, in palette (and legend) order
+pub const TERRAIN_PALETTE: [TerrainType; 29] = [
+    TerrainType::NoMansLand,
+    TerrainType::Grass,
+    TerrainType::Mud,
+    TerrainType::Water,
+    TerrainType::DeepWater,
+    TerrainType::TrenchFloor,
+    TerrainType::TrenchParapet,
+    TerrainType::TrenchRamp,
+    TerrainType::Trench,
+    TerrainType::Sandbags,
+    TerrainType::Bunker,
+    TerrainType::MgNest,
+    TerrainType::BarbedWire,
+    TerrainType::Tree,
+    TerrainType::Forest,
+    TerrainType::Hedge,
+    TerrainType::Rubble,
+    TerrainType::ShellCrater,
+    TerrainType::CraterWater,
+    TerrainType::BuildingWall,
+    TerrainType::BuildingFloor,
+    TerrainType::BuildingDoor,
+    TerrainType::BuildingWindow,
+    TerrainType::Ruins,
+    TerrainType::Road,
+    TerrainType::Path,
+    TerrainType::CommTrench,
+    TerrainType::Fortification,
+    TerrainType::CivilianBuilding,
+];
+
+/// Map file character for each palette entry, in the same order
+///
+/// Unlike the display glyph sets, no two terrains share a character.
+pub const MAP_FILE_GLYPHS: [char; 29] = [
+    '.', ',', ';', 'w', 'W', '_', '=', '/', 't', 's', 'B', 'M', 'x', 'T', 'F', 'h', ':', 'o', 'O', '#',
+    '-', '+', '|', '%', 'R', 'p', 'c', 'f', 'H',
+];
+
+/// Error reading a map file back in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapImportError {
+    Empty,
+    /// A row whose length differs from the first row's
+    RaggedRow { row: usize },
+    UnknownGlyph { glyph: char, row: usize, column: usize },
+}
+
+impl fmt::Display for MapImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapImportError::Empty => write!(f, "map file is empty"),
+            MapImportError::RaggedRow { row } => {
+                write!(f, "map row {} is not as wide as the first", row + 1)
+            }
+            MapImportError::UnknownGlyph { glyph, row, column } => write!(
+                f,
+                "unknown map character '{}' at row {}, column {}",
+                glyph,
+                row + 1,
+                column + 1
+            ),
+        }
+    }
+}
+
+/// Cursor and selected terrain for painting a map
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerrainBrush {
+    pub cursor: Position,
+    palette_index: usize,
+}
+
+impl TerrainBrush {
+    /// A brush at the top-left corner holding the first palette terrain
+    pub fn new() -> Self {
+        Self {
+            cursor: Position::new(0, 0),
+            palette_index: 0,
+        }
+    }
+
+    /// The terrain painting will lay down
+    pub fn terrain(&self) -> TerrainType {
+        TERRAIN_PALETTE[self.palette_index]
+    }
+
+    /// Step through the palette, wrapping at either end
+    pub fn cycle_terrain(&mut self, step: i32) {
+        let len = TERRAIN_PALETTE.len() as i32;
+        self.palette_index = (self.palette_index as i32 + step).rem_euclid(len) as usize;
+    }
+
+    /// Move the cursor, keeping it on the map
+    pub fn move_cursor(&mut self, dx: i32, dy: i32, battlefield: &Battlefield) {
+        self.cursor = Position::new(
+            (self.cursor.x + dx).clamp(0, battlefield.width() as i32 - 1),
+            (self.cursor.y + dy).clamp(0, battlefield.height() as i32 - 1),
+        );
+    }
+
+    /// Top-left map tile of a `view_width` x `view_height` window that keeps
+    /// the cursor in sight, for maps bigger than the screen
+    pub fn view_origin(&self, battlefield: &Battlefield, view_width: usize, view_height: usize) -> Position {
+        let scroll = |cursor: i32, view: usize, map: usize| {
+            let max = map.saturating_sub(view) as i32;
+            (cursor - view as i32 / 2).clamp(0, max)
+        };
+        Position::new(
+            scroll(self.cursor.x, view_width, battlefield.width()),
+            scroll(self.cursor.y, view_height, battlefield.height()),
+        )
+    }
+
+    /// Paint the tile under the cursor; returns whether its terrain changed
+    pub fn paint(&self, battlefield: &mut Battlefield) -> bool {
+        let before = battlefield.get_tile(&self.cursor).map(|tile| tile.terrain);
+        battlefield.set_terrain(self.cursor, self.terrain());
+        before.is_some_and(|terrain| terrain != self.terrain())
+    }
+}
+
+impl Default for TerrainBrush {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The map as a map file, one row per line
+pub fn export_map(battlefield: &Battlefield) -> String {
+    let mut out = String::with_capacity((battlefield.width() + 1) * battlefield.height());
+    for y in 0..battlefield.height() as i32 {
+        for x in 0..battlefield.width() as i32 {
+            let ch = battlefield
+                .get_tile(&Position::new(x, y))
+                .map_or(' ', |tile| map_file_glyph(tile.terrain));
+            out.push(ch);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Read a map written by `export_map`
+pub fn import_map(contents: &str) -> Result<Battlefield, MapImportError> {
+    let rows: Vec<&str> = contents.lines().filter(|line| !line.is_empty()).collect();
+    let width = rows.first().ok_or(MapImportError::Empty)?.chars().count();
+
+    let mut battlefield = Battlefield::new(width, rows.len());
+    for (row, line) in rows.iter().enumerate() {
+        if line.chars().count() != width {
+            return Err(MapImportError::RaggedRow { row });
+        }
+        for (column, glyph) in line.chars().enumerate() {
+            let terrain = terrain_for_glyph(glyph).ok_or(MapImportError::UnknownGlyph {
+                glyph,
+                row,
+                column,
+            })?;
+            battlefield.set_terrain(Position::new(column as i32, row as i32), terrain);
+        }
+    }
+    Ok(battlefield)
+}
+
+fn map_file_glyph(terrain: TerrainType) -> char {
+    TERRAIN_PALETTE
+        .iter()
+        .position(|&entry| entry == terrain)
+        .map_or('?', |index| MAP_FILE_GLYPHS[index])
+}
+
+fn terrain_for_glyph(glyph: char) -> Option<TerrainType> {
+    MAP_FILE_GLYPHS
+        .iter()
+        .position(|&entry| entry == glyph)
+        .map(|index| TERRAIN_PALETTE[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_painting_a_tile_shows_up_in_the_export() {
+        let mut battlefield = Battlefield::new(4, 3);
+        let mut brush = TerrainBrush::new();
+        while brush.terrain() != TerrainType::BarbedWire {
+            brush.cycle_terrain(1);
+        }
+        brush.move_cursor(2, 1, &battlefield);
+
+        assert!(brush.paint(&mut battlefield));
+        assert_eq!(
+            battlefield.get_tile(&Position::new(2, 1)).unwrap().terrain,
+            TerrainType::BarbedWire
+        );
+        assert!(!brush.paint(&mut battlefield), "repainting the same terrain changes nothing");
+        assert_eq!(export_map(&battlefield), "....\n..x.\n....\n");
+    }
+
+    #[test]
+    fn test_cursor_stays_on_the_map_and_palette_wraps() {
+        let battlefield = Battlefield::new(4, 3);
+        let mut brush = TerrainBrush::new();
+        brush.move_cursor(-5, 10, &battlefield);
+        assert_eq!(brush.cursor, Position::new(0, 2));
+
+        brush.cycle_terrain(-1);
+        assert_eq!(brush.terrain(), TerrainType::CivilianBuilding);
+    }
+
+    #[test]
+    fn test_every_terrain_survives_a_round_trip() {
+        let mut battlefield = Battlefield::new(TERRAIN_PALETTE.len(), 2);
+        for (x, terrain) in TERRAIN_PALETTE.iter().enumerate() {
+            battlefield.set_terrain(Position::new(x as i32, 1), *terrain);
+        }
+
+        let loaded = import_map(&export_map(&battlefield)).unwrap();
+        assert_eq!((loaded.width(), loaded.height()), (battlefield.width(), battlefield.height()));
+        for (x, terrain) in TERRAIN_PALETTE.iter().enumerate() {
+            assert_eq!(loaded.get_tile(&Position::new(x as i32, 1)).unwrap().terrain, *terrain);
+        }
+
+        assert_eq!(import_map("..\n.\n").err(), Some(MapImportError::RaggedRow { row: 1 }));
+        assert_eq!(
+            import_map(".?\n").err(),
+            Some(MapImportError::UnknownGlyph { glyph: '?', row: 0, column: 1 })
+        );
+    }
+
+    #[test]
+    fn test_view_follows_the_cursor_across_a_big_map() {
+        let battlefield = Battlefield::new(200, 100);
+        let mut brush = TerrainBrush::new();
+        assert_eq!(brush.view_origin(&battlefield, 80, 24), Position::new(0, 0));
+
+        brush.move_cursor(100, 50, &battlefield);
+        assert_eq!(brush.view_origin(&battlefield, 80, 24), Position::new(60, 38));
+
+        brush.move_cursor(200, 100, &battlefield);
+        assert_eq!(brush.view_origin(&battlefield, 80, 24), Position::new(120, 76));
+
+        // A map smaller than the window never scrolls
+        assert_eq!(brush.view_origin(&Battlefield::new(10, 10), 80, 24), Position::new(0, 0));
+    }
+}
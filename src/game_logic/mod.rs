@@ -1,17 +1,37 @@
 // Game Logic Module
 // Core game mechanics and rules
 
+pub mod action_preview;
+pub mod approach_lanes;
+pub mod auto_advance;
+pub mod auto_pause;
 pub mod battlefield;
+pub mod blast;
 pub mod combat;
+pub mod danger_map;
+pub mod deployment;
+pub mod fog_of_war;
+pub mod force_stats;
+pub mod formation;
 pub mod line_of_sight;
+pub mod map_editor;
 pub mod objectives;
+pub mod obstacles;
 pub mod pathfinding;
+pub mod player_actions;
+pub mod reinforcements;
+pub mod scoring;
 pub mod shared_vision;
 pub mod soldier_spawning;
+pub mod spatial_index;
+pub mod supply;
+pub mod surrender;
 pub mod terrain_generation;
 pub mod terrain_properties;
+pub mod targeting;
+pub mod threat_compass;
+pub mod timeline;
+pub mod triggers;
 pub mod turn_state;
 pub mod vision_cone;
-
-// Future game logic submodules:
-// pub mod fog_of_war;
+pub mod world_setup;
@@ -1,33 +1,132 @@
 use crate::components::soldier::Faction;
 use crate::game_logic::battlefield::{Battlefield, Position, TerrainType};
+use serde::Deserialize;
 use specs::Entity;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+/// Where a flag stands, for drawing it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlagStatus {
+    /// Owned by a side with nobody taking it from them
+    Held,
+    /// A capture is under way
+    Contested,
+    /// Nobody has taken it yet
+    Neutral,
+}
 
 #[derive(Debug, Clone)]
 pub struct ObjectiveFlag {
     pub position: Position,
-    pub owning_faction: Faction,
+    /// None while the flag is neutral and no side has taken it yet
+    pub owning_faction: Option<Faction>,
     pub capture_progress: i32,
+    /// The side the capture progress belongs to
+    pub capturing_faction: Option<Faction>,
     pub required_turns: i32,
     pub radius: i32,
+    /// How much holding the flag counts for in scoring
+    pub weight: u32,
+    /// Turn by which attackers must take the flag (None = no timer)
+    pub deadline: Option<u32>,
+    /// The defenders held out past the deadline; the flag can't change hands
+    pub secured: bool,
 }
 
 impl ObjectiveFlag {
     pub fn new(position: Position, owning_faction: Faction) -> Self {
         Self {
             position,
-            owning_faction,
+            owning_faction: Some(owning_faction),
             capture_progress: 0,
+            capturing_faction: None,
             required_turns: 5,
             radius: 2,
+            weight: 1,
+            deadline: None,
+            secured: false,
+        }
+    }
+
+    /// A flag neither side holds at the start
+    pub fn neutral(position: Position) -> Self {
+        Self {
+            owning_faction: None,
+            ..Self::new(position, Faction::Allies)
+        }
+    }
+
+    pub fn is_held_by(&self, faction: Faction) -> bool {
+        self.owning_faction == Some(faction)
+    }
+
+    /// Contested while capture progress is building, else held or neutral
+    pub fn status(&self) -> FlagStatus {
+        if self.capture_progress > 0 {
+            FlagStatus::Contested
+        } else if self.owning_faction.is_some() {
+            FlagStatus::Held
+        } else {
+            FlagStatus::Neutral
+        }
+    }
+
+    /// Set the capture radius (0 = the flag's own tile only)
+    pub fn with_radius(mut self, radius: i32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Set how much holding the flag counts for (a crossroads over a flank)
+    pub fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Give attackers until `turn` to take the flag
+    ///
+    /// A neutral flag has no defender to win it, so it gets no timer.
+    pub fn with_deadline(mut self, turn: u32) -> Self {
+        if self.owning_faction.is_some() {
+            self.deadline = Some(turn);
+        }
+        self
+    }
+
+    /// Turns the attackers have left on `turn`, while the timer is running
+    pub fn turns_left(&self, turn: u32) -> Option<u32> {
+        self.deadline.map(|deadline| deadline.saturating_sub(turn))
+    }
+
+    /// Hand the flag to its defenders for good once the deadline has passed
+    ///
+    /// Any capture under way is lost. Returns whether the timer ran out on
+    /// this call.
+    pub fn expire(&mut self, turn: u32) -> bool {
+        if self.turns_left(turn) != Some(0) {
+            return false;
         }
+        self.deadline = None;
+        self.secured = true;
+        self.reset_progress();
+        true
     }
 
     pub fn reset_progress(&mut self) {
         self.capture_progress = 0;
+        self.capturing_faction = None;
     }
 
-    pub fn increment_progress(&mut self) {
+    /// Count another turn of `attacker` working on the flag
+    ///
+    /// A side that takes over from another's unfinished capture starts from
+    /// nothing rather than inheriting its progress.
+    pub fn increment_progress(&mut self, attacker: Faction) {
+        if self.capturing_faction != Some(attacker) {
+            self.reset_progress();
+            self.capturing_faction = Some(attacker);
+        }
         self.capture_progress += 1;
     }
 
@@ -35,9 +134,11 @@ impl ObjectiveFlag {
         self.capture_progress >= self.required_turns
     }
 
+    /// Hand the flag to `new_faction`, which stops its timer: the attack succeeded
     pub fn capture(&mut self, new_faction: Faction) {
-        self.owning_faction = new_faction;
-        self.capture_progress = 0;
+        self.owning_faction = Some(new_faction);
+        self.reset_progress();
+        self.deadline = None;
     }
 
     pub fn is_position_in_radius(&self, pos: &Position) -> bool {
@@ -45,15 +146,38 @@ impl ObjectiveFlag {
     }
 }
 
+/// Aid post at a side's rear where its wounded are carried off the field
+#[derive(Debug, Clone)]
+pub struct EvacPoint {
+    pub position: Position,
+    pub faction: Faction,
+    /// Wounded soldiers evacuated through this post
+    pub saved: u32,
+}
+
+impl EvacPoint {
+    pub fn new(position: Position, faction: Faction) -> Self {
+        Self {
+            position,
+            faction,
+            saved: 0,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Objectives {
-    pub flags: HashMap<String, ObjectiveFlag>,
+    /// Ordered by id so flag iteration is the same on every run
+    pub flags: BTreeMap<String, ObjectiveFlag>,
+    /// Casualty-evacuation aid posts (empty unless the scenario has them)
+    pub evac_points: Vec<EvacPoint>,
 }
 
 impl Objectives {
     pub fn new() -> Self {
         Self {
-            flags: HashMap::new(),
+            flags: BTreeMap::new(),
+            evac_points: Vec::new(),
         }
     }
 
@@ -61,6 +185,24 @@ impl Objectives {
         self.flags.insert(id, flag);
     }
 
+    pub fn add_evac_point(&mut self, point: EvacPoint) {
+        self.evac_points.push(point);
+    }
+
+    /// The aid post `faction` evacuates its wounded through, if it has one
+    pub fn evac_point_for(&self, faction: Faction) -> Option<&EvacPoint> {
+        self.evac_points.iter().find(|point| point.faction == faction)
+    }
+
+    /// Wounded soldiers `faction` has evacuated so far
+    pub fn saved_by(&self, faction: Faction) -> u32 {
+        self.evac_points
+            .iter()
+            .filter(|point| point.faction == faction)
+            .map(|point| point.saved)
+            .sum()
+    }
+
     pub fn get_flag(&self, id: &str) -> Option<&ObjectiveFlag> {
         self.flags.get(id)
     }
@@ -69,22 +211,53 @@ impl Objectives {
         self.flags.get_mut(id)
     }
 
+    /// The weightiest flag `faction` doesn't hold and can still take (the first such, on a tie)
     pub fn get_enemy_flag_position(&self, faction: Faction) -> Option<Position> {
         self.flags
             .values()
-            .find(|flag| flag.owning_faction != faction)
+            .filter(|flag| !flag.is_held_by(faction) && !flag.secured)
+            .min_by_key(|flag| std::cmp::Reverse(flag.weight))
             .map(|flag| flag.position)
     }
 
+    /// Total weight of the flags `faction` holds
+    pub fn weight_held_by(&self, faction: Faction) -> u32 {
+        self.flags
+            .values()
+            .filter(|flag| flag.is_held_by(faction))
+            .map(|flag| flag.weight)
+            .sum()
+    }
+
+    /// Running objective timers for the status line, e.g. "central 3 turns"
+    pub fn timer_text(&self, turn: u32) -> Option<String> {
+        let timers: Vec<String> = self
+            .flags
+            .iter()
+            .filter_map(|(id, flag)| {
+                if flag.secured {
+                    Some(format!("{} held", id))
+                } else {
+                    flag.turns_left(turn).map(|left| format!("{} {} turns", id, left))
+                }
+            })
+            .collect();
+        (!timers.is_empty()).then(|| timers.join(", "))
+    }
+
+    /// Whether one side holds every flag
+    ///
+    /// A flag its defenders held past its deadline stays theirs, so the
+    /// attackers can no longer win this way.
     pub fn check_victory(&self) -> Option<Faction> {
         let allies_flags: Vec<_> = self.flags
             .values()
-            .filter(|flag| flag.owning_faction == Faction::Allies)
+            .filter(|flag| flag.is_held_by(Faction::Allies))
             .collect();
 
         let central_flags: Vec<_> = self.flags
             .values()
-            .filter(|flag| flag.owning_faction == Faction::CentralPowers)
+            .filter(|flag| flag.is_held_by(Faction::CentralPowers))
             .collect();
 
         if allies_flags.len() == self.flags.len() {
@@ -103,22 +276,36 @@ impl Default for Objectives {
     }
 }
 
+/// Whether the soldiers near a flag are all from one side other than its owner
+///
+/// Both sides standing on a neutral flag contest it, so neither gains ground.
+pub fn has_uncontested_attackers(flag: &ObjectiveFlag, entities_in_radius: &[(Entity, Faction)]) -> bool {
+    if flag.secured {
+        return false;
+    }
+    let mut factions = entities_in_radius.iter().map(|(_, faction)| *faction);
+    match factions.next() {
+        Some(first) => !flag.is_held_by(first) && factions.all(|faction| faction == first),
+        None => false,
+    }
+}
+
 pub fn check_flag_occupation(
     flag: &mut ObjectiveFlag,
     entities_in_radius: &[(Entity, Faction)],
 ) -> Option<Faction> {
     let defenders: Vec<_> = entities_in_radius
         .iter()
-        .filter(|(_, faction)| *faction == flag.owning_faction)
+        .filter(|(_, faction)| flag.is_held_by(*faction))
         .collect();
 
     let attackers: Vec<_> = entities_in_radius
         .iter()
-        .filter(|(_, faction)| *faction != flag.owning_faction)
+        .filter(|(_, faction)| !flag.is_held_by(*faction))
         .collect();
 
-    if !attackers.is_empty() && defenders.is_empty() {
-        flag.increment_progress();
+    if has_uncontested_attackers(flag, entities_in_radius) {
+        flag.increment_progress(attackers[0].1);
 
         if flag.is_captured() {
             let capturing_faction = attackers[0].1;
@@ -167,6 +354,19 @@ pub fn create_strategic_objectives(
     (ally_flag_pos, enemy_flag_pos)
 }
 
+/// Pick a defensible spot midway between the two spawn zones
+pub fn create_central_objective(battlefield: &Battlefield) -> Position {
+    let center = match (battlefield.ally_spawn.as_ref(), battlefield.enemy_spawn.as_ref()) {
+        (Some(ally), Some(enemy)) => Position::new(
+            (ally.center.x + enemy.center.x) / 2,
+            (ally.center.y + enemy.center.y) / 2,
+        ),
+        _ => Position::new(battlefield.width() as i32 / 2, battlefield.height() as i32 / 2),
+    };
+
+    find_strategic_position(battlefield, center, 10, true)
+}
+
 fn find_strategic_position(
     battlefield: &Battlefield,
     near: Position,
@@ -261,3 +461,24 @@ fn count_nearby_fortifications(battlefield: &Battlefield, pos: &Position, radius
 
     count
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attackers_give_up_on_a_secured_flag() {
+        let mut objectives = Objectives::new();
+        objectives.add_flag(
+            "home".to_string(),
+            ObjectiveFlag::new(Position::new(5, 5), Faction::CentralPowers)
+                .with_weight(3)
+                .with_deadline(10),
+        );
+        objectives.add_flag("neutral".to_string(), ObjectiveFlag::neutral(Position::new(9, 9)));
+        assert_eq!(objectives.get_enemy_flag_position(Faction::Allies), Some(Position::new(5, 5)));
+
+        assert!(objectives.get_flag_mut("home").unwrap().expire(10));
+        assert_eq!(objectives.get_enemy_flag_position(Faction::Allies), Some(Position::new(9, 9)));
+    }
+}
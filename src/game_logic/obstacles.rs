@@ -0,0 +1,91 @@
+// Obstacles
+// Barbed wire strung by soldiers in the field
+//
+// A soldier carrying coils of wire can spend most of a turn stringing one
+// across an adjacent tile of open ground. AI soldiers on their own half of
+// the field do so while no enemy is near, wiring the approach to their line.
+
+use crate::components::{facing::Direction8, soldier::Faction};
+use crate::game_logic::battlefield::{Battlefield, Position, TerrainType};
+
+/// Enemies within this many tiles keep an AI soldier from stopping to lay wire
+pub const WIRE_CLEAR_DISTANCE: f32 = 12.0;
+
+/// Whether wire can be strung across a tile of this terrain
+///
+/// Only open ground takes it; trenches, buildings and water do not.
+pub fn takes_wire(terrain: TerrainType) -> bool {
+    matches!(
+        terrain,
+        TerrainType::Grass
+            | TerrainType::NoMansLand
+            | TerrainType::Mud
+            | TerrainType::ShellCrater
+            | TerrainType::Road
+            | TerrainType::Path
+    )
+}
+
+/// String wire across `pos` unless it is occupied or not open ground
+///
+/// Returns whether the wire went up.
+pub fn lay_wire(battlefield: &mut Battlefield, pos: Position, occupied: bool) -> bool {
+    let open = battlefield
+        .get_tile(&pos)
+        .is_some_and(|tile| takes_wire(tile.terrain));
+    if !open || occupied {
+        return false;
+    }
+    battlefield.set_terrain(pos, TerrainType::BarbedWire);
+    true
+}
+
+/// Where a soldier of `faction` at `from` should wire toward, if defending
+///
+/// Only soldiers on their own half of the field, nearer their own spawn zone
+/// than the enemy's, are defending; they wire toward the enemy's.
+pub fn wiring_front(battlefield: &Battlefield, faction: Faction, from: &Position) -> Option<Position> {
+    let (own, enemy) = match faction {
+        Faction::Allies => (battlefield.ally_spawn.as_ref()?, battlefield.enemy_spawn.as_ref()?),
+        Faction::CentralPowers => (battlefield.enemy_spawn.as_ref()?, battlefield.ally_spawn.as_ref()?),
+    };
+    (from.distance_to(&own.center) < from.distance_to(&enemy.center)).then_some(enemy.center)
+}
+
+/// Step from `from` to the tile a defender should wire, facing `toward`
+///
+/// Tries straight at `toward`, then one point of the compass to either side.
+pub fn wire_step(
+    battlefield: &Battlefield,
+    from: &Position,
+    toward: &Position,
+    occupied: impl Fn(&Position) -> bool,
+) -> Option<(i32, i32)> {
+    let ahead = Direction8::from_movement(toward.x - from.x, toward.y - from.y)?;
+    [ahead, ahead.rotate_cw(), ahead.rotate_ccw()]
+        .into_iter()
+        .map(|direction| direction.to_vector())
+        .find(|&(dx, dy)| {
+            let pos = Position::new(from.x + dx, from.y + dy);
+            !occupied(&pos)
+                && battlefield
+                    .get_tile(&pos)
+                    .is_some_and(|tile| takes_wire(tile.terrain))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wire_step_skips_unwireable_ground() {
+        let mut battlefield = Battlefield::new(10, 10);
+        let from = Position::new(5, 5);
+        battlefield.set_terrain(Position::new(6, 5), TerrainType::TrenchFloor);
+
+        let step = wire_step(&battlefield, &from, &Position::new(9, 5), |_| false).unwrap();
+        assert_ne!(step, (1, 0), "trench floor takes no wire");
+        assert_eq!(step.0, 1, "still wires toward the enemy");
+    }
+}
@@ -1,8 +1,80 @@
-// Pathfinding logic using bracket-lib A* algorithm
-
-use crate::game_logic::battlefield::{Battlefield, Position};
+// Pathfinding logic: A* over the battlefield through bracket-lib's map traits
+//
+// The heuristic is set on the Battlefield, so every search on the map uses
+// the same one. Octile distance matches 8-directional steps exactly; Manhattan
+// overestimates diagonal travel and can lead A* onto longer routes.
+//
+// The search itself is a_star below rather than bracket-lib's a_star_search,
+// which ranks tiles by their last step's cost instead of the whole route's
+// and so neither finds the cheapest path nor weighs danger along the way.
+
+use crate::components::{dead::Dead, position::Position as EcsPosition};
+use crate::config::game_config::CorpseRule;
+use crate::game_logic::battlefield::{Battlefield, Position, TerrainType};
+use crate::game_logic::danger_map::{DangerMap, DANGER_STEP_COST};
+use crate::game_logic::terrain_properties::MIN_MOVEMENT_COST;
 use bracket_lib::prelude::*;
-use bracket_pathfinding::prelude::a_star_search;
+use specs::{Join, World, WorldExt};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Cost of a diagonal step relative to a cardinal one, as step_cost charges it
+const DIAGONAL_COST: f32 = 1.414;
+
+/// Weight on the heuristic that breaks ties between equally good tiles
+///
+/// Nudging the estimate up by a hair makes A* favour the tile nearer the goal
+/// when two would cost the same, so it settles on the same straight path each
+/// time instead of fanning out across equal-cost alternatives.
+const TIE_BREAK: f32 = 1.0 + 1.0 / 1000.0;
+
+/// Distance estimate A* uses to steer toward the goal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PathHeuristic {
+    /// Straight-line distance; never overestimates, but undersells diagonals
+    Euclidean,
+    /// Diagonal steps then straight ones; exact for 8-directional movement
+    #[default]
+    Octile,
+    /// Grid distance without diagonals; overestimates diagonal travel
+    Manhattan,
+}
+
+impl PathHeuristic {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PathHeuristic::Euclidean => "Euclidean",
+            PathHeuristic::Octile => "Octile",
+            PathHeuristic::Manhattan => "Manhattan",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            PathHeuristic::Euclidean => PathHeuristic::Octile,
+            PathHeuristic::Octile => PathHeuristic::Manhattan,
+            PathHeuristic::Manhattan => PathHeuristic::Euclidean,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            PathHeuristic::Euclidean => PathHeuristic::Manhattan,
+            PathHeuristic::Octile => PathHeuristic::Euclidean,
+            PathHeuristic::Manhattan => PathHeuristic::Octile,
+        }
+    }
+
+    /// Estimated cost of crossing `dx` by `dy` tiles of the cheapest terrain
+    pub fn estimate(&self, dx: i32, dy: i32) -> f32 {
+        let (dx, dy) = (dx.abs() as f32, dy.abs() as f32);
+        match self {
+            PathHeuristic::Euclidean => (dx * dx + dy * dy).sqrt(),
+            PathHeuristic::Octile => dx.max(dy) + (DIAGONAL_COST - 1.0) * dx.min(dy),
+            PathHeuristic::Manhattan => dx + dy,
+        }
+    }
+}
 
 /// Calculate A* path from start to end position
 /// Returns Some(Vec<Position>) if path found, None if no path exists
@@ -10,6 +82,30 @@ pub fn calculate_path(
     start: &Position,
     end: &Position,
     battlefield: &Battlefield,
+) -> Option<Vec<Position>> {
+    calculate_path_avoiding(start, end, battlefield, &HashSet::new())
+}
+
+/// Calculate A* path from start to end, never entering a tile in `avoid`
+pub fn calculate_path_avoiding(
+    start: &Position,
+    end: &Position,
+    battlefield: &Battlefield,
+    avoid: &HashSet<Position>,
+) -> Option<Vec<Position>> {
+    calculate_path_through_danger(start, end, battlefield, avoid, None)
+}
+
+/// Calculate A* path from start to end, steering clear of dangerous ground
+///
+/// Each step also costs DANGER_STEP_COST per point of danger on the tile
+/// entered, so the path trades extra distance for cover from enemy fire.
+pub fn calculate_path_through_danger(
+    start: &Position,
+    end: &Position,
+    battlefield: &Battlefield,
+    avoid: &HashSet<Position>,
+    danger: Option<&DangerMap>,
 ) -> Option<Vec<Position>> {
     // Don't pathfind if already at destination
     if start == end {
@@ -17,42 +113,172 @@ pub fn calculate_path(
     }
 
     // Create map wrapper for pathfinding
-    let map = BattlefieldPathMap::new(battlefield);
+    let map = BattlefieldPathMap::new(battlefield, avoid, danger);
 
     // Convert positions to indices
     let start_idx = map.point2d_to_index(Point::new(start.x, start.y));
     let end_idx = map.point2d_to_index(Point::new(end.x, end.y));
 
     // Run A* pathfinding
-    let path_result = a_star_search(start_idx, end_idx, &map);
+    let steps = a_star(&map, start_idx, end_idx)?;
 
-    if path_result.success {
-        // Convert indices back to Positions, excluding the start position
-        let positions: Vec<Position> = path_result
-            .steps
+    // Convert indices back to Positions (the start is left out: already there)
+    Some(
+        steps
             .into_iter()
-            .skip(1) // Skip start position (already there)
             .map(|idx| {
                 let pt = map.index_to_point2d(idx);
                 Position::new(pt.x, pt.y)
             })
-            .collect();
+            .collect(),
+    )
+}
 
-        Some(positions)
+/// A tile waiting in A*'s open list, cheapest estimated route first
+#[derive(Clone, Copy)]
+struct OpenTile {
+    idx: usize,
+    /// Cost of the route found to this tile so far
+    cost: f32,
+    /// `cost` plus the heuristic's estimate of the rest of the way
+    estimate: f32,
+}
+
+impl PartialEq for OpenTile {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OpenTile {}
+
+impl Ord for OpenTile {
+    // Reversed so BinaryHeap pops the lowest estimate; the tile index settles
+    // exact ties so the same search always expands tiles in the same order
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .total_cmp(&self.estimate)
+            .then_with(|| other.idx.cmp(&self.idx))
+    }
+}
+
+impl PartialOrd for OpenTile {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Cheapest route from `start` to `end` as map indices, excluding `start`
+fn a_star(map: &BattlefieldPathMap, start: usize, end: usize) -> Option<Vec<usize>> {
+    let mut open = BinaryHeap::new();
+    let mut best_cost = HashMap::from([(start, 0.0)]);
+    let mut came_from: HashMap<usize, usize> = HashMap::new();
+    open.push(OpenTile {
+        idx: start,
+        cost: 0.0,
+        estimate: map.get_pathing_distance(start, end),
+    });
+
+    while let Some(tile) = open.pop() {
+        if tile.idx == end {
+            let mut steps = vec![end];
+            let mut current = end;
+            while let Some(&previous) = came_from.get(&current) {
+                if previous == start {
+                    break;
+                }
+                steps.push(previous);
+                current = previous;
+            }
+            steps.reverse();
+            return Some(steps);
+        }
+
+        // A cheaper route to this tile was found after this entry was queued
+        if best_cost.get(&tile.idx).is_some_and(|&best| tile.cost > best) {
+            continue;
+        }
+
+        for (next, step) in map.get_available_exits(tile.idx) {
+            let cost = tile.cost + step;
+            if best_cost.get(&next).is_some_and(|&best| cost >= best) {
+                continue;
+            }
+            best_cost.insert(next, cost);
+            came_from.insert(next, tile.idx);
+            open.push(OpenTile {
+                idx: next,
+                cost,
+                estimate: cost + map.get_pathing_distance(next, end),
+            });
+        }
+    }
+
+    None
+}
+
+/// Tiles held by corpses, when the world's CorpseRule makes them block
+pub fn corpse_obstacles(world: &World) -> HashSet<Position> {
+    let blocking = world
+        .try_fetch::<CorpseRule>()
+        .is_some_and(|rule| rule.blocks(true));
+    if !blocking {
+        return HashSet::new();
+    }
+    let positions = world.read_storage::<EcsPosition>();
+    let dead = world.read_storage::<Dead>();
+    (&positions, &dead)
+        .join()
+        .map(|(pos, _)| *pos.as_battlefield_pos())
+        .collect()
+}
+
+/// Cost of one step between adjacent tiles: distance times the destination's terrain cost
+pub fn step_cost(from: &Position, to: &Position, battlefield: &Battlefield) -> f32 {
+    let terrain = battlefield
+        .get_tile(to)
+        .map(|t| t.terrain)
+        .unwrap_or(TerrainType::NoMansLand);
+
+    // 1.0 for cardinal, ~1.414 for diagonal
+    let distance_cost = if from.x != to.x && from.y != to.y {
+        DIAGONAL_COST
     } else {
-        None
+        1.0
+    };
+
+    distance_cost * terrain.movement_cost()
+}
+
+/// Total movement cost of following `steps` from `start`, as weighed by A*
+pub fn path_cost(start: &Position, steps: &[Position], battlefield: &Battlefield) -> f32 {
+    let mut from = *start;
+    let mut total = 0.0;
+    for step in steps {
+        total += step_cost(&from, step, battlefield);
+        from = *step;
     }
+    total
 }
 
 /// Wrapper to make Battlefield compatible with bracket-lib pathfinding
 /// Mirrors the pattern from BattlefieldFOVMap in line_of_sight.rs
 struct BattlefieldPathMap<'a> {
     battlefield: &'a Battlefield,
+    /// Passable tiles the path must still keep out of
+    avoid: &'a HashSet<Position>,
+    /// Enemy coverage to weigh on top of terrain, if any
+    danger: Option<&'a DangerMap>,
 }
 
 impl<'a> BattlefieldPathMap<'a> {
-    fn new(battlefield: &'a Battlefield) -> Self {
-        Self { battlefield }
+    fn new(
+        battlefield: &'a Battlefield,
+        avoid: &'a HashSet<Position>,
+        danger: Option<&'a DangerMap>,
+    ) -> Self {
+        Self { battlefield, avoid, danger }
     }
 }
 
@@ -86,11 +312,12 @@ impl<'a> BaseMap for BattlefieldPathMap<'a> {
     fn get_pathing_distance(&self, idx1: usize, idx2: usize) -> f32 {
         let p1 = self.index_to_point2d(idx1);
         let p2 = self.index_to_point2d(idx2);
-        let dx = (p1.x - p2.x) as f32;
-        let dy = (p1.y - p2.y) as f32;
 
-        // Euclidean distance for heuristic
-        (dx * dx + dy * dy).sqrt()
+        // Scaled by the cheapest terrain so fast tiles (roads) are never
+        // overestimated and A* still finds them
+        self.battlefield.path_heuristic().estimate(p1.x - p2.x, p1.y - p2.y)
+            * MIN_MOVEMENT_COST
+            * TIE_BREAK
     }
 
     fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
@@ -114,28 +341,19 @@ impl<'a> BaseMap for BattlefieldPathMap<'a> {
                 }
 
                 // Check if terrain is passable
-                let terrain = self
+                let passable = self
                     .battlefield
                     .get_tile(&new_pos)
-                    .map(|t| t.terrain)
-                    .unwrap_or(crate::game_logic::battlefield::TerrainType::NoMansLand);
+                    .map(|t| t.terrain.is_passable())
+                    .unwrap_or(true);
 
-                if !terrain.is_passable() {
+                if !passable || self.avoid.contains(&new_pos) {
                     continue; // Skip impassable terrain
                 }
 
-                // Get terrain cost multiplier
-                let terrain_cost = terrain.movement_cost();
-
-                // Calculate distance cost (1.0 for cardinal, ~1.414 for diagonal)
-                let distance_cost = if dx != 0 && dy != 0 {
-                    1.414 // sqrt(2) for diagonal movement
-                } else {
-                    1.0 // cardinal directions
-                };
-
-                // Total cost is distance * terrain multiplier
-                let total_cost = distance_cost * terrain_cost;
+                // Total cost is distance * terrain multiplier, plus any danger
+                let danger = self.danger.map_or(0.0, |map| map.danger_at(&new_pos));
+                let total_cost = step_cost(&pos, &new_pos, self.battlefield) + danger * DANGER_STEP_COST;
 
                 let new_idx = self.point2d_to_index(Point::new(new_pos.x, new_pos.y));
                 exits.push((new_idx, total_cost));
@@ -149,7 +367,50 @@ impl<'a> BaseMap for BattlefieldPathMap<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game_logic::battlefield::TerrainType;
+
+    /// How many times a path changes direction after leaving `start`
+    fn turns(start: &Position, path: &[Position]) -> usize {
+        let mut from = *start;
+        let steps: Vec<(i32, i32)> = path
+            .iter()
+            .map(|pos| {
+                let step = (pos.x - from.x, pos.y - from.y);
+                from = *pos;
+                step
+            })
+            .collect();
+        steps.windows(2).filter(|pair| pair[0] != pair[1]).count()
+    }
+
+    #[test]
+    fn test_octile_path_is_straighter_than_manhattan_on_open_ground() {
+        let mut battlefield = Battlefield::new(30, 30);
+        for x in 0..30 {
+            for y in 0..30 {
+                battlefield.set_terrain(Position::new(x, y), TerrainType::Grass);
+            }
+        }
+        let start = Position::new(2, 8);
+        let end = Position::new(24, 14);
+
+        battlefield.set_path_heuristic(PathHeuristic::Octile);
+        let octile = calculate_path(&start, &end, &battlefield).unwrap();
+        battlefield.set_path_heuristic(PathHeuristic::Manhattan);
+        let manhattan = calculate_path(&start, &end, &battlefield).unwrap();
+
+        assert_eq!(octile.last(), Some(&end));
+        assert_eq!(manhattan.last(), Some(&end));
+        let octile_cost = path_cost(&start, &octile, &battlefield);
+        assert!(octile_cost <= path_cost(&start, &manhattan, &battlefield));
+        assert!(
+            turns(&start, &octile) < turns(&start, &manhattan),
+            "octile should zig-zag less across open ground"
+        );
+
+        // Ties are broken the same way every time
+        battlefield.set_path_heuristic(PathHeuristic::Octile);
+        assert_eq!(calculate_path(&start, &end, &battlefield).unwrap(), octile);
+    }
 
     #[test]
     fn test_pathfinding_straight_line() {
@@ -203,6 +464,33 @@ mod tests {
         assert_eq!(path.last().unwrap(), &end);
     }
 
+    #[test]
+    fn test_pathfinding_uses_road_when_cheaper() {
+        let mut battlefield = Battlefield::new(12, 3);
+        for x in 0..12 {
+            battlefield.set_terrain(Position::new(x, 0), TerrainType::Road);
+            for y in 1..3 {
+                battlefield.set_terrain(Position::new(x, y), TerrainType::Grass);
+            }
+        }
+
+        let start = Position::new(0, 1);
+        let end = Position::new(11, 1);
+        let path = calculate_path(&start, &end, &battlefield).unwrap();
+
+        let grass_route: Vec<Position> = (1..12).map(|x| Position::new(x, 1)).collect();
+        let road_cost = path_cost(&start, &path, &battlefield);
+        let grass_cost = path_cost(&start, &grass_route, &battlefield);
+
+        assert!(path.iter().any(|p| p.y == 0), "path never used the road");
+        assert!(
+            road_cost < grass_cost,
+            "road route {} should beat grass route {}",
+            road_cost,
+            grass_cost
+        );
+    }
+
     #[test]
     fn test_pathfinding_same_position() {
         let battlefield = Battlefield::new(10, 10);
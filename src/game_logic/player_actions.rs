@@ -0,0 +1,242 @@
+// Player actions
+// Which actions the player may take, for tutorial and challenge battles
+//
+// A scenario file can rule actions out (`disabled_actions = ["grenade"]`).
+// The command-mode input handler looks each hotkey up here first, and a key
+// for a ruled-out action is ignored with a message in the event log.
+
+use crate::components::{
+    action::{ActionType, QueuedAction},
+    dead::Dead,
+    inventory::Inventory,
+    time_budget::TimeBudget,
+};
+use crate::game_logic::turn_state::TurnState;
+use crate::utils::event_log::EventLog;
+use serde::Deserialize;
+use specs::{Entity, World, WorldExt};
+use std::collections::HashSet;
+
+/// An action the player can be barred from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayerAction {
+    Move,
+    Wait,
+    Rotate,
+    Crawl,
+    Shoot,
+    Grenade,
+    Reload,
+    SwapWeapon,
+    Loot,
+    Aim,
+    LayWire,
+}
+
+impl PlayerAction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlayerAction::Move => "Moving",
+            PlayerAction::Wait => "Waiting",
+            PlayerAction::Rotate => "Turning",
+            PlayerAction::Crawl => "Crawling",
+            PlayerAction::Shoot => "Firing",
+            PlayerAction::Grenade => "Throwing grenades",
+            PlayerAction::Reload => "Reloading",
+            PlayerAction::SwapWeapon => "Swapping weapons",
+            PlayerAction::Loot => "Looting",
+            PlayerAction::Aim => "Aiming",
+            PlayerAction::LayWire => "Laying wire",
+        }
+    }
+
+    /// The action a command-mode hotkey starts, if it starts one
+    pub fn for_command_key(key: char) -> Option<Self> {
+        match key {
+            // 'A' advances to contact, a move like any other
+            'q' | 'w' | 'e' | 'a' | 'd' | 'z' | 'x' | 'c' | 'A' => Some(PlayerAction::Move),
+            's' => Some(PlayerAction::Wait),
+            ',' | '.' => Some(PlayerAction::Rotate),
+            'b' => Some(PlayerAction::Crawl),
+            'f' => Some(PlayerAction::Shoot),
+            'G' => Some(PlayerAction::Grenade),
+            'r' => Some(PlayerAction::Reload),
+            'p' => Some(PlayerAction::SwapWeapon),
+            'g' => Some(PlayerAction::Loot),
+            'i' => Some(PlayerAction::Aim),
+            'W' => Some(PlayerAction::LayWire),
+            _ => None,
+        }
+    }
+}
+
+/// The actions ruled out for the player; everything is allowed by default
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerActions {
+    disabled: HashSet<PlayerAction>,
+}
+
+impl PlayerActions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rule out every action in `actions`
+    pub fn with_disabled(mut self, actions: impl IntoIterator<Item = PlayerAction>) -> Self {
+        self.disabled.extend(actions);
+        self
+    }
+
+    pub fn allows(&self, action: PlayerAction) -> bool {
+        !self.disabled.contains(&action)
+    }
+
+    /// Whether `action` may go ahead; logs why when it may not
+    pub fn check(&self, action: PlayerAction, log: &mut EventLog) -> bool {
+        if self.allows(action) {
+            return true;
+        }
+        log.add(format!("{} is not allowed in this battle.", action.name()));
+        false
+    }
+
+    /// Whether a command-mode hotkey may go ahead; logs why when it may not
+    pub fn check_key(&self, key: char, log: &mut EventLog) -> bool {
+        PlayerAction::for_command_key(key).is_none_or(|action| self.check(action, log))
+    }
+}
+
+/// Queue `action_type` for the player, charging its time; returns whether it was queued
+pub fn queue_player_action(
+    world: &World,
+    player: Entity,
+    action_type: ActionType,
+    label: &str,
+) -> bool {
+    // Safety check: Don't allow dead player to act
+    if world.read_storage::<Dead>().contains(player) {
+        world
+            .write_resource::<EventLog>()
+            .add("You are dead!".to_string());
+        return false;
+    }
+
+    // Out of the consumable it needs: refuse before any time is spent
+    let shortfall = world
+        .read_storage::<Inventory>()
+        .get(player)
+        .and_then(|inventory| inventory.shortfall(&action_type));
+    if let Some(item) = shortfall {
+        world
+            .write_resource::<EventLog>()
+            .add(format!("You have no {} left.", item));
+        return false;
+    }
+
+    let time_cost = action_type.base_time_cost();
+
+    // Consume time budget and queue action
+    let mut time_budgets = world.write_storage::<TimeBudget>();
+    let mut queued_actions = world.write_storage::<QueuedAction>();
+
+    let Some(budget) = time_budgets.get_mut(player) else {
+        return false;
+    };
+    budget.consume_time(time_cost);
+    queued_actions
+        .insert(player, QueuedAction::new(action_type))
+        .ok();
+
+    let mut log = world.write_resource::<EventLog>();
+    log.add(format!("{} action queued ({:.1}s)", label, time_cost));
+
+    // Check if turn should end (budget exhausted or in debt)
+    if budget.available_time() <= 0.0 {
+        world
+            .write_resource::<TurnState>()
+            .mark_entity_ready(player);
+        log.add("Time budget exhausted. Waiting for others...".to_string());
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::world_setup::register_components;
+    use specs::Builder;
+
+    /// What command mode does with a key that queues `action_type` straight away
+    fn press(
+        world: &World,
+        actions: &PlayerActions,
+        player: Entity,
+        key: char,
+        action_type: ActionType,
+    ) {
+        let allowed = actions.check_key(key, &mut world.write_resource::<EventLog>());
+        if allowed {
+            queue_player_action(world, player, action_type, "Test");
+        }
+    }
+
+    #[test]
+    fn test_disabled_key_queues_nothing_while_enabled_one_does() {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(EventLog::new());
+        world.insert(TurnState::new());
+        let player = world
+            .create_entity()
+            .with(TimeBudget::new(6.0))
+            .build();
+        let actions = PlayerActions::new().with_disabled([PlayerAction::Reload]);
+
+        press(&world, &actions, player, 'r', ActionType::Reload);
+        assert!(world.read_storage::<QueuedAction>().get(player).is_none());
+        assert!(
+            world
+                .read_resource::<EventLog>()
+                .all()
+                .iter()
+                .any(|entry| entry.contains("Reloading is not allowed")),
+            "a refused key says why"
+        );
+
+        press(&world, &actions, player, 'i', ActionType::Aim);
+        assert!(world.read_storage::<QueuedAction>().get(player).is_some());
+    }
+
+    #[test]
+    fn test_advance_to_contact_counts_as_moving() {
+        assert_eq!(PlayerAction::for_command_key('A'), Some(PlayerAction::Move));
+
+        let mut log = EventLog::new();
+        let actions = PlayerActions::new().with_disabled([PlayerAction::Move]);
+        assert!(!actions.check_key('A', &mut log));
+        assert!(log.all().iter().any(|entry| entry.contains("Moving is not allowed")));
+    }
+
+    #[test]
+    fn test_throw_without_grenades_is_refused_free_of_charge() {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(EventLog::new());
+        world.insert(TurnState::new());
+        let player = world
+            .create_entity()
+            .with(TimeBudget::new(6.0))
+            .with(Inventory::empty())
+            .build();
+
+        let throw = ActionType::ThrowGrenade {
+            target_x: 3,
+            target_y: 3,
+        };
+        assert!(!queue_player_action(&world, player, throw, "Grenade"));
+        assert!(world.read_storage::<QueuedAction>().get(player).is_none());
+        let budgets = world.read_storage::<TimeBudget>();
+        assert_eq!(budgets.get(player).unwrap().available_time(), 6.0);
+    }
+}
@@ -0,0 +1,332 @@
+// Enemy reinforcements
+// Waves of fresh enemy soldiers, placed according to difficulty
+//
+// On Normal a wave walks in at the enemy spawn zone. On Hard it enters a few
+// tiles behind whichever enemy soldier is closest to the fighting, so fresh
+// troops arrive where they are needed rather than at the back of the map.
+// Scenario triggers can call in waves for either side on top of these.
+
+use crate::components::{
+    dead::Dead,
+    position::Position,
+    soldier::{Faction, Soldier},
+    surrendered::Surrendered,
+};
+use crate::config::game_config::{Difficulty, GameConfig};
+use crate::game_logic::{
+    battlefield::{Battlefield, Position as BattlefieldPos},
+    soldier_spawning::RankDistribution,
+    spatial_index::PositionIndex,
+    timeline::{Timeline, TimelineEventKind},
+    triggers::Triggers,
+    turn_state::{TurnState, COMMANDER_FACTION},
+    world_setup::{spawn_facing, spawn_soldier},
+};
+use crate::utils::{event_log::EventLog, game_rng::GameRng};
+use rand::Rng;
+use specs::{Join, World, WorldExt};
+use std::collections::HashSet;
+
+/// Tiles behind the front-most soldier where a Hard wave enters
+pub const HARD_ENTRY_DEPTH: i32 = 6;
+
+/// Furthest ring searched around an entry point for free tiles
+const ENTRY_SEARCH_RADIUS: i32 = 8;
+
+/// Resource: reinforcement waves sent so far this match
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReinforcementTracker {
+    pub waves_sent: u32,
+    pub last_wave_turn: u32,
+}
+
+/// Where a wave of `count` soldiers for `faction` enters the map
+///
+/// `front` is the faction's soldier nearest the enemy (see front_soldier);
+/// tiles in `occupied` are never picked.
+pub fn entry_points(
+    battlefield: &Battlefield,
+    faction: Faction,
+    difficulty: Difficulty,
+    front: Option<BattlefieldPos>,
+    occupied: &HashSet<BattlefieldPos>,
+    count: usize,
+    rng: &mut impl Rng,
+) -> Vec<BattlefieldPos> {
+    let home = match faction {
+        Faction::Allies => &battlefield.ally_spawn,
+        Faction::CentralPowers => &battlefield.enemy_spawn,
+    };
+
+    if difficulty == Difficulty::Hard
+        && let Some(home) = home
+        && let Some(front) = front
+    {
+        // Step back from the front toward home, but never past the spawn zone
+        let dx = (home.center.x - front.x) as f32;
+        let dy = (home.center.y - front.y) as f32;
+        let distance = (dx * dx + dy * dy).sqrt();
+        let depth = (HARD_ENTRY_DEPTH as f32).min(distance);
+        let anchor = if distance > 0.0 {
+            BattlefieldPos::new(
+                front.x + (dx / distance * depth).round() as i32,
+                front.y + (dy / distance * depth).round() as i32,
+            )
+        } else {
+            front
+        };
+        return free_tiles_near(anchor, count, battlefield, occupied);
+    }
+
+    battlefield
+        .get_spawn_positions(faction == Faction::Allies, count, rng)
+        .into_iter()
+        .filter(|pos| !occupied.contains(pos))
+        .collect()
+}
+
+/// The friendly soldier nearest any hostile one, if both sides have soldiers
+pub fn front_soldier(friendly: &[BattlefieldPos], hostile: &[BattlefieldPos]) -> Option<BattlefieldPos> {
+    if hostile.is_empty() {
+        return None;
+    }
+    let gap = |pos: &BattlefieldPos| {
+        hostile
+            .iter()
+            .map(|enemy| pos.distance_to(enemy))
+            .fold(f32::INFINITY, f32::min)
+    };
+    friendly
+        .iter()
+        .min_by(|a, b| gap(a).total_cmp(&gap(b)))
+        .copied()
+}
+
+/// Up to `count` free, passable tiles around `anchor`, at least 2 tiles apart
+fn free_tiles_near(
+    anchor: BattlefieldPos,
+    count: usize,
+    battlefield: &Battlefield,
+    occupied: &HashSet<BattlefieldPos>,
+) -> Vec<BattlefieldPos> {
+    let mut tiles: Vec<BattlefieldPos> = Vec::new();
+    for radius in 0..=ENTRY_SEARCH_RADIUS {
+        if tiles.len() >= count {
+            break;
+        }
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                // Only the ring at this radius; inner tiles were already tried
+                if dx.abs().max(dy.abs()) != radius || tiles.len() >= count {
+                    continue;
+                }
+                let pos = BattlefieldPos::new(anchor.x + dx, anchor.y + dy);
+                let free = battlefield
+                    .get_tile(&pos)
+                    .is_some_and(|tile| tile.terrain.is_passable())
+                    && !occupied.contains(&pos)
+                    && tiles.iter().all(|other| other.distance_to(&pos) >= 2.0);
+                if free {
+                    tiles.push(pos);
+                }
+            }
+        }
+    }
+    tiles
+}
+
+/// Bring in the next enemy wave if one is due this turn
+///
+/// Returns how many soldiers arrived.
+pub fn spawn_reinforcements(world: &mut World, config: &GameConfig) -> usize {
+    let settings = &config.reinforcements;
+    if !settings.enabled {
+        return 0;
+    }
+
+    let turn = world.read_resource::<TurnState>().current_turn;
+    let tracker = *world.read_resource::<ReinforcementTracker>();
+    if tracker.waves_sent >= settings.max_waves
+        || turn < tracker.last_wave_turn + settings.interval_turns
+    {
+        return 0;
+    }
+
+    let arrived = send_wave(world, config, COMMANDER_FACTION.opposing(), settings.wave_size);
+    *world.write_resource::<ReinforcementTracker>() = ReinforcementTracker {
+        waves_sent: tracker.waves_sent + 1,
+        last_wave_turn: turn,
+    };
+    if arrived > 0 {
+        world
+            .write_resource::<EventLog>()
+            .add(format!("Enemy reinforcements arrive ({} soldiers)", arrived));
+    }
+    arrived
+}
+
+/// Bring in the waves scenario triggers have called for
+///
+/// Returns how many soldiers arrived.
+pub fn spawn_triggered_reinforcements(world: &mut World, config: &GameConfig) -> usize {
+    let waves = match world.try_fetch_mut::<Triggers>() {
+        Some(mut triggers) => std::mem::take(&mut triggers.pending_waves),
+        None => return 0,
+    };
+
+    let mut total = 0;
+    for (faction, count) in waves {
+        let arrived = send_wave(world, config, faction, count);
+        if arrived > 0 {
+            let side = match faction {
+                Faction::Allies => "Allied",
+                Faction::CentralPowers => "Enemy",
+            };
+            world
+                .write_resource::<EventLog>()
+                .add(format!("{} reinforcements arrive ({} soldiers)", side, arrived));
+        }
+        total += arrived;
+    }
+    total
+}
+
+/// Spawn up to `count` fresh soldiers for `faction`, returning how many arrived
+fn send_wave(world: &mut World, config: &GameConfig, faction: Faction, count: usize) -> usize {
+    let (friendly, hostile, occupied) = {
+        let entities = world.entities();
+        let soldiers = world.read_storage::<Soldier>();
+        let positions = world.read_storage::<Position>();
+        let dead = world.read_storage::<Dead>();
+        let surrendered = world.read_storage::<Surrendered>();
+
+        let mut friendly = Vec::new();
+        let mut hostile = Vec::new();
+        let mut occupied = HashSet::new();
+        for (entity, soldier, pos) in (&entities, &soldiers, &positions).join() {
+            let pos = *pos.as_battlefield_pos();
+            occupied.insert(pos);
+            if dead.get(entity).is_some() || surrendered.get(entity).is_some() {
+                continue;
+            }
+            if soldier.faction == faction {
+                friendly.push(pos);
+            } else {
+                hostile.push(pos);
+            }
+        }
+        (friendly, hostile, occupied)
+    };
+
+    let Some(mut rng) = world.remove::<GameRng>() else {
+        return 0;
+    };
+    let (entries, facing) = {
+        let battlefield = world.read_resource::<Battlefield>();
+        let entries = entry_points(
+            &battlefield,
+            faction,
+            // Difficulty only sharpens the enemy; the player's side always walks in
+            if faction == COMMANDER_FACTION {
+                Difficulty::Normal
+            } else {
+                config.difficulty
+            },
+            front_soldier(&friendly, &hostile),
+            &occupied,
+            count,
+            &mut rng,
+        );
+        (entries, spawn_facing(&battlefield, faction))
+    };
+
+    let ranks = if faction == COMMANDER_FACTION {
+        RankDistribution::default()
+    } else {
+        config.enemy_ranks
+    };
+    for pos in &entries {
+        let rank = ranks.pick(&mut rng);
+        spawn_soldier(world, *pos, faction, rank, facing, config, &mut rng);
+    }
+    world.insert(rng);
+    PositionIndex::refresh(world);
+
+    if let Some(&first) = entries.first() {
+        let turn = world.read_resource::<TurnState>().current_turn;
+        if let Some(mut timeline) = world.try_fetch_mut::<Timeline>() {
+            timeline.record(
+                turn,
+                first,
+                TimelineEventKind::Reinforcements,
+                format!("{} reinforcements arrive ({} soldiers)", faction.name(), entries.len()),
+            );
+        }
+    }
+
+    entries.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::battlefield_config::BattlefieldGenerationConfig;
+    use crate::game_logic::{battlefield::SpawnZone, world_setup::build_skirmish_world};
+
+    #[test]
+    fn test_waves_arrive_on_schedule() {
+        let config = GameConfig::default().with_reinforcements(true);
+        let battlefield_config = BattlefieldGenerationConfig::default()
+            .with_dimensions(40, 40)
+            .with_seed(2);
+        let mut world = build_skirmish_world(battlefield_config, &config, 3);
+        let soldier_count = |world: &World| world.read_storage::<Soldier>().join().count();
+        let before = soldier_count(&world);
+
+        assert_eq!(spawn_reinforcements(&mut world, &config), 0);
+
+        world.write_resource::<TurnState>().current_turn = config.reinforcements.interval_turns;
+        let arrived = spawn_reinforcements(&mut world, &config);
+        assert_eq!(arrived, config.reinforcements.wave_size);
+        assert_eq!(soldier_count(&world), before + arrived);
+
+        // Only one wave per interval
+        assert_eq!(spawn_reinforcements(&mut world, &config), 0);
+    }
+
+    #[test]
+    fn test_hard_reinforcements_enter_nearer_the_contact_line() {
+        let mut battlefield = Battlefield::new(60, 60);
+        battlefield.set_spawn_zones(
+            SpawnZone::new(BattlefieldPos::new(30, 55), 4),
+            SpawnZone::new(BattlefieldPos::new(30, 4), 4),
+        );
+        // Enemy patrol has pushed to y=28, meeting allies at y=32
+        let friendly = vec![BattlefieldPos::new(30, 28), BattlefieldPos::new(26, 10)];
+        let hostile = vec![BattlefieldPos::new(30, 32)];
+        let occupied: HashSet<_> = friendly.iter().chain(&hostile).copied().collect();
+
+        let distance_to_contact = |difficulty| {
+            let mut rng = GameRng::new(5);
+            let entries = entry_points(
+                &battlefield,
+                Faction::CentralPowers,
+                difficulty,
+                front_soldier(&friendly, &hostile),
+                &occupied,
+                2,
+                &mut rng,
+            );
+            assert_eq!(entries.len(), 2);
+            entries
+                .iter()
+                .map(|pos| pos.distance_to(&hostile[0]))
+                .fold(f32::INFINITY, f32::min)
+        };
+
+        let normal = distance_to_contact(Difficulty::Normal);
+        let hard = distance_to_contact(Difficulty::Hard);
+        assert!(hard < normal, "hard {} should be nearer than normal {}", hard, normal);
+        assert!(hard >= HARD_ENTRY_DEPTH as f32, "hard wave entered on top of the fighting");
+    }
+}
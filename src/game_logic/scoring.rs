@@ -0,0 +1,319 @@
+// Match scoring and game-over detection
+// Decides the winner when a match ends on objectives, annihilation or the turn limit
+//
+// A turn-limit result goes to the side with more points: held objectives
+// count most, each by its flag's weight, then enemy casualties and wounded
+// carried back to an aid post, then the share of the map closer to that
+// side's standing soldiers than to the enemy's.
+
+use crate::components::{
+    dead::Dead,
+    position::Position,
+    soldier::{Faction, Soldier},
+    surrendered::Surrendered,
+};
+use crate::game_logic::{
+    battlefield::{Battlefield, Position as BattlefieldPos},
+    objectives::Objectives,
+    triggers::Triggers,
+    turn_state::TurnState,
+};
+use specs::{Join, World, WorldExt};
+
+/// Points per unit of objective weight held
+pub const OBJECTIVE_POINTS: i32 = 100;
+/// Points per enemy killed or surrendered
+pub const CASUALTY_POINTS: i32 = 10;
+/// Points per wounded soldier evacuated to the side's aid post
+pub const SAVE_POINTS: i32 = 15;
+/// Points for controlling the whole map (scaled by the share controlled)
+pub const TERRITORY_POINTS: i32 = 50;
+
+/// Why a match ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOverReason {
+    /// One side holds every objective flag
+    Objectives,
+    /// A side has nobody left standing
+    Annihilation,
+    /// The turn limit ran out and the winner was decided on points
+    TurnLimit,
+    /// A scenario trigger ended the match
+    Scripted,
+}
+
+/// One side's tally toward a points victory
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SideScore {
+    pub objectives_held: usize,
+    /// Summed weight of the flags held
+    pub objective_weight: u32,
+    /// Enemy soldiers killed or surrendered
+    pub casualties_inflicted: usize,
+    /// Own wounded evacuated to the aid post
+    pub casualties_saved: usize,
+    /// Fraction (0.0-1.0) of the map nearer this side's soldiers
+    pub territory: f32,
+}
+
+impl SideScore {
+    pub fn points(&self) -> i32 {
+        self.objective_weight as i32 * OBJECTIVE_POINTS
+            + self.casualties_inflicted as i32 * CASUALTY_POINTS
+            + self.casualties_saved as i32 * SAVE_POINTS
+            + (self.territory * TERRITORY_POINTS as f32).round() as i32
+    }
+}
+
+/// Final result of a match
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameOutcome {
+    /// None on a drawn points result
+    pub winner: Option<Faction>,
+    pub reason: GameOverReason,
+    pub turn: u32,
+    pub allies: SideScore,
+    pub central_powers: SideScore,
+}
+
+/// Check whether the match is over
+///
+/// A scenario trigger, capturing every objective or wiping out the other
+/// side ends it at once; otherwise it ends once the turn after `turn_limit`
+/// begins (None = no limit). Both sides wiped out at once is a draw.
+pub fn check_game_over(world: &World, turn_limit: Option<u32>) -> Option<GameOutcome> {
+    let turn = world.read_resource::<TurnState>().current_turn;
+
+    let scripted = world.try_fetch::<Triggers>().and_then(|triggers| triggers.ended);
+    if let Some(end) = scripted {
+        return Some(GameOutcome {
+            winner: end.winner,
+            reason: GameOverReason::Scripted,
+            turn: end.turn,
+            allies: score_side(world, Faction::Allies),
+            central_powers: score_side(world, Faction::CentralPowers),
+        });
+    }
+
+    if let Some(winner) = world.read_resource::<Objectives>().check_victory() {
+        return Some(GameOutcome {
+            winner: Some(winner),
+            reason: GameOverReason::Objectives,
+            turn,
+            allies: score_side(world, Faction::Allies),
+            central_powers: score_side(world, Faction::CentralPowers),
+        });
+    }
+
+    let standing = |faction| {
+        let soldiers = world.read_storage::<Soldier>();
+        let dead = world.read_storage::<Dead>();
+        let surrendered = world.read_storage::<Surrendered>();
+        (&world.entities(), &soldiers)
+            .join()
+            .filter(|(entity, soldier)| {
+                soldier.faction == faction && !dead.contains(*entity) && !surrendered.contains(*entity)
+            })
+            .count()
+    };
+    let survivor = match (standing(Faction::Allies), standing(Faction::CentralPowers)) {
+        (0, 0) => Some(None),
+        (0, _) => Some(Some(Faction::CentralPowers)),
+        (_, 0) => Some(Some(Faction::Allies)),
+        _ => None,
+    };
+    if let Some(winner) = survivor {
+        return Some(GameOutcome {
+            winner,
+            reason: GameOverReason::Annihilation,
+            turn,
+            allies: score_side(world, Faction::Allies),
+            central_powers: score_side(world, Faction::CentralPowers),
+        });
+    }
+
+    let limit = turn_limit?;
+    if turn <= limit {
+        return None;
+    }
+
+    let allies = score_side(world, Faction::Allies);
+    let central_powers = score_side(world, Faction::CentralPowers);
+    let winner = match allies.points().cmp(&central_powers.points()) {
+        std::cmp::Ordering::Greater => Some(Faction::Allies),
+        std::cmp::Ordering::Less => Some(Faction::CentralPowers),
+        std::cmp::Ordering::Equal => None,
+    };
+
+    Some(GameOutcome {
+        winner,
+        reason: GameOverReason::TurnLimit,
+        turn: limit,
+        allies,
+        central_powers,
+    })
+}
+
+/// Tally a side's objectives, casualties inflicted and saved, and territory
+pub fn score_side(world: &World, faction: Faction) -> SideScore {
+    let (objectives_held, objective_weight, casualties_saved) = {
+        let objectives = world.read_resource::<Objectives>();
+        let held = objectives.flags.values().filter(|flag| flag.is_held_by(faction)).count();
+        (
+            held,
+            objectives.weight_held_by(faction),
+            objectives.saved_by(faction) as usize,
+        )
+    };
+
+    let entities = world.entities();
+    let soldiers = world.read_storage::<Soldier>();
+    let dead = world.read_storage::<Dead>();
+    let surrendered = world.read_storage::<Surrendered>();
+    let positions = world.read_storage::<Position>();
+
+    let mut casualties_inflicted = 0;
+    let mut own_positions = Vec::new();
+    let mut enemy_positions = Vec::new();
+    for (entity, soldier, pos) in (&entities, &soldiers, &positions).join() {
+        let down = dead.get(entity).is_some() || surrendered.get(entity).is_some();
+        if soldier.faction == faction {
+            if !down {
+                own_positions.push(*pos.as_battlefield_pos());
+            }
+        } else if down {
+            casualties_inflicted += 1;
+        } else {
+            enemy_positions.push(*pos.as_battlefield_pos());
+        }
+    }
+
+    SideScore {
+        objectives_held,
+        objective_weight,
+        casualties_inflicted,
+        casualties_saved,
+        territory: territory_share(
+            &world.read_resource::<Battlefield>(),
+            &own_positions,
+            &enemy_positions,
+        ),
+    }
+}
+
+/// Fraction of tiles strictly nearer one of `own` than any of `enemy`
+fn territory_share(
+    battlefield: &Battlefield,
+    own: &[BattlefieldPos],
+    enemy: &[BattlefieldPos],
+) -> f32 {
+    let total = battlefield.width() * battlefield.height();
+    if total == 0 || own.is_empty() {
+        return 0.0;
+    }
+
+    let nearest = |tile: &BattlefieldPos, soldiers: &[BattlefieldPos]| {
+        soldiers
+            .iter()
+            .map(|pos| pos.manhattan_distance_to(tile))
+            .min()
+            .unwrap_or(i32::MAX)
+    };
+
+    let mut held = 0;
+    for y in 0..battlefield.height() as i32 {
+        for x in 0..battlefield.width() as i32 {
+            let tile = BattlefieldPos::new(x, y);
+            if nearest(&tile, own) < nearest(&tile, enemy) {
+                held += 1;
+            }
+        }
+    }
+    held as f32 / total as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        battlefield_config::BattlefieldGenerationConfig,
+        game_config::{GameConfig, ObjectiveLayout},
+    };
+    use crate::game_logic::world_setup::build_skirmish_world;
+
+    #[test]
+    fn test_turn_limit_goes_to_side_holding_more_objectives() {
+        let battlefield_config = BattlefieldGenerationConfig::default()
+            .with_dimensions(30, 30)
+            .with_seed(3);
+        let config = GameConfig::default().with_objective_layout(ObjectiveLayout::NeutralCenter);
+        let world = build_skirmish_world(battlefield_config, &config, 4);
+
+        // Allies take the neutral flag; each side still holds its own
+        world
+            .write_resource::<Objectives>()
+            .get_flag_mut("neutral")
+            .expect("neutral center layout has a neutral flag")
+            .capture(Faction::Allies);
+
+        assert_eq!(check_game_over(&world, Some(10)), None);
+
+        world.write_resource::<TurnState>().current_turn = 11;
+        let outcome = check_game_over(&world, Some(10)).expect("turn limit reached");
+
+        assert_eq!(outcome.reason, GameOverReason::TurnLimit);
+        assert_eq!(outcome.winner, Some(Faction::Allies));
+        assert_eq!(outcome.allies.objectives_held, 2);
+        assert_eq!(outcome.central_powers.objectives_held, 1);
+    }
+
+    #[test]
+    fn test_high_weight_flag_outscores_low_weight_flags() {
+        let battlefield_config = BattlefieldGenerationConfig::default()
+            .with_dimensions(30, 30)
+            .with_seed(3);
+        let config = GameConfig::default()
+            .with_objective_layout(ObjectiveLayout::NeutralCenter)
+            .with_objective_weights(1, 3);
+        let world = build_skirmish_world(battlefield_config, &config, 4);
+
+        // Allies hold only the crossroads; the Central Powers hold both flanks
+        {
+            let mut objectives = world.write_resource::<Objectives>();
+            objectives.get_flag_mut("neutral").unwrap().capture(Faction::Allies);
+            objectives.get_flag_mut("allies").unwrap().capture(Faction::CentralPowers);
+        }
+
+        world.write_resource::<TurnState>().current_turn = 11;
+        let outcome = check_game_over(&world, Some(10)).expect("turn limit reached");
+
+        assert_eq!(outcome.allies.objectives_held, 1);
+        assert_eq!(outcome.central_powers.objectives_held, 2);
+        assert_eq!(outcome.allies.objective_weight, 3);
+        assert_eq!(outcome.central_powers.objective_weight, 2);
+        assert_eq!(outcome.winner, Some(Faction::Allies));
+    }
+
+    #[test]
+    fn test_side_with_nobody_standing_loses() {
+        let battlefield_config = BattlefieldGenerationConfig::default()
+            .with_dimensions(30, 30)
+            .with_seed(3);
+        let world = build_skirmish_world(battlefield_config, &GameConfig::default(), 4);
+
+        let fallen: Vec<_> = (&world.entities(), &world.read_storage::<Soldier>())
+            .join()
+            .filter(|(_, soldier)| soldier.faction == Faction::CentralPowers)
+            .map(|(entity, _)| entity)
+            .collect();
+        let mut dead = world.write_storage::<Dead>();
+        for entity in fallen {
+            dead.insert(entity, Dead).unwrap();
+        }
+        drop(dead);
+
+        let outcome = check_game_over(&world, None).expect("a side was wiped out");
+        assert_eq!(outcome.reason, GameOverReason::Annihilation);
+        assert_eq!(outcome.winner, Some(Faction::Allies));
+    }
+}
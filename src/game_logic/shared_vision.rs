@@ -1,7 +1,12 @@
 // Shared Vision System
 // Calculates combined vision for all entities of a faction
+//
+// Each soldier's vision cone is cached between updates. A cone is only
+// recomputed when its soldier moved, turned or had its range change, or when
+// any terrain changed (the battlefield's terrain revision moved on); a
+// stationary soldier reuses last turn's tiles.
 
-use crate::components::facing::Facing;
+use crate::components::facing::{Direction8, Facing};
 use crate::components::position::Position;
 use crate::components::soldier::{Faction, Soldier};
 use crate::components::vision::Vision;
@@ -31,6 +36,65 @@ impl SharedVisionResult {
     }
 }
 
+/// One soldier's last vision cone and what it was computed from
+#[derive(Debug, Clone)]
+struct CachedCone {
+    origin: BattlefieldPosition,
+    facing: Direction8,
+    range: i32,
+    main: HashSet<BattlefieldPosition>,
+    peripheral: HashSet<BattlefieldPosition>,
+}
+
+/// Vision cones kept from one visibility update to the next
+#[derive(Debug, Clone, Default)]
+pub struct VisionCache {
+    terrain_revision: u64,
+    cones: HashMap<Entity, CachedCone>,
+    /// Cones computed afresh during the last update
+    recomputed: usize,
+}
+
+impl VisionCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How many cones the last update had to compute rather than reuse
+    pub fn recomputed(&self) -> usize {
+        self.recomputed
+    }
+
+    /// The cone for `entity`, recomputed only if something that shapes it changed
+    fn cone(
+        &mut self,
+        entity: Entity,
+        origin: &BattlefieldPosition,
+        facing: Direction8,
+        range: i32,
+        battlefield: &Battlefield,
+    ) -> &CachedCone {
+        let stale = self.cones.get(&entity).is_none_or(|cone| {
+            cone.origin != *origin || cone.facing != facing || cone.range != range
+        });
+        if stale {
+            let (main, peripheral) = calculate_vision_cone(origin, facing, range, battlefield);
+            self.recomputed += 1;
+            self.cones.insert(
+                entity,
+                CachedCone {
+                    origin: *origin,
+                    facing,
+                    range,
+                    main,
+                    peripheral,
+                },
+            );
+        }
+        &self.cones[&entity]
+    }
+}
+
 /// Calculate combined vision for all entities of a given faction
 /// This merges vision cones from all friendly units into one unified FOV
 pub fn calculate_faction_vision(
@@ -41,25 +105,55 @@ pub fn calculate_faction_vision(
     soldiers: &ReadStorage<Soldier>,
     faction: Faction,
     battlefield: &Battlefield,
+) -> SharedVisionResult {
+    update_faction_vision(
+        faction_lookouts(entities, positions, visions, facings, soldiers, faction),
+        battlefield,
+        &mut VisionCache::new(),
+    )
+}
+
+/// Every soldier of `faction` who can see, with where they stand and face
+pub fn faction_lookouts<'a>(
+    entities: &'a specs::world::EntitiesRes,
+    positions: &'a ReadStorage<Position>,
+    visions: &'a ReadStorage<Vision>,
+    facings: &'a ReadStorage<Facing>,
+    soldiers: &'a ReadStorage<Soldier>,
+    faction: Faction,
+) -> impl Iterator<Item = (Entity, &'a Position, &'a Vision, &'a Facing)> {
+    (entities, positions, visions, facings, soldiers)
+        .join()
+        .filter(move |(_, _, _, _, soldier)| soldier.faction == faction)
+        .map(|(entity, pos, vision, facing, _)| (entity, pos, vision, facing))
+}
+
+/// calculate_faction_vision, reusing the cones of soldiers that haven't moved
+///
+/// `lookouts` are the faction's soldiers (see faction_lookouts). Any terrain
+/// change throws the whole cache away, since a new crater or a fallen wall
+/// can open or close sight lines for anyone.
+pub fn update_faction_vision<'a>(
+    lookouts: impl Iterator<Item = (Entity, &'a Position, &'a Vision, &'a Facing)>,
+    battlefield: &Battlefield,
+    cache: &mut VisionCache,
 ) -> SharedVisionResult {
     let mut result = SharedVisionResult::new();
 
-    // Iterate through all entities with the matching faction
-    for (entity, pos, vision, facing, soldier) in
-        (entities, positions, visions, facings, soldiers).join()
-    {
-        // Skip entities not in our faction
-        if soldier.faction != faction {
-            continue;
-        }
+    if cache.terrain_revision != battlefield.terrain_revision() {
+        cache.cones.clear();
+        cache.terrain_revision = battlefield.terrain_revision();
+    }
+    cache.recomputed = 0;
+    let mut seen = HashSet::new();
 
-        // Calculate vision cone for this entity
-        // Convert component Position to BattlefieldPosition
-        let (main_vision, peripheral_vision) =
-            calculate_vision_cone(pos.as_battlefield_pos(), facing.direction, vision.range, battlefield);
+    for (entity, pos, vision, facing) in lookouts {
+        // Vision cone for this entity, from the cache if it hasn't moved
+        seen.insert(entity);
+        let cone = cache.cone(entity, pos.as_battlefield_pos(), facing.direction, vision.range, battlefield);
 
         // Merge main vision tiles
-        for tile in main_vision {
+        for tile in &cone.main {
             // Convert BattlefieldPosition back to component Position
             let comp_pos = Position::new(tile.x, tile.y);
             result.visible_tiles.insert(comp_pos);
@@ -68,7 +162,7 @@ pub fn calculate_faction_vision(
         }
 
         // Merge peripheral vision tiles
-        for tile in peripheral_vision {
+        for tile in &cone.peripheral {
             // Convert BattlefieldPosition back to component Position
             let comp_pos = Position::new(tile.x, tile.y);
             result.peripheral_tiles.insert(comp_pos);
@@ -77,6 +171,9 @@ pub fn calculate_faction_vision(
         }
     }
 
+    // Forget soldiers who are gone (or have changed sides)
+    cache.cones.retain(|entity, _| seen.contains(entity));
+
     result
 }
 
@@ -239,4 +336,59 @@ mod tests {
         // The enemy entity at (15,15) should not contribute to spotters
         assert!(!result.visible_tiles.is_empty());
     }
+
+    #[test]
+    fn test_stationary_soldier_reuses_cached_cone_until_it_moves() {
+        let mut world = World::new();
+        world.register::<Position>();
+        world.register::<Vision>();
+        world.register::<Facing>();
+        world.register::<Soldier>();
+
+        let mut bf = Battlefield::new(30, 30);
+        let sentry = world
+            .create_entity()
+            .with(Position::new(10, 10))
+            .with(Vision::new(6))
+            .with(Facing::new(Direction8::N))
+            .with(Soldier {
+                name: "Sentry".to_string(),
+                faction: Faction::Allies,
+                rank: crate::components::soldier::Rank::Private,
+            })
+            .build();
+
+        let mut cache = VisionCache::new();
+        let update = |world: &World, bf: &Battlefield, cache: &mut VisionCache| {
+            let entities = world.entities();
+            let positions = world.read_storage::<Position>();
+            let visions = world.read_storage::<Vision>();
+            let facings = world.read_storage::<Facing>();
+            let soldiers = world.read_storage::<Soldier>();
+            let lookouts =
+                faction_lookouts(&entities, &positions, &visions, &facings, &soldiers, Faction::Allies);
+            update_faction_vision(lookouts, bf, cache).visible_tiles
+        };
+
+        let first = update(&world, &bf, &mut cache);
+        assert_eq!(cache.recomputed(), 1);
+        let second = update(&world, &bf, &mut cache);
+        assert_eq!(cache.recomputed(), 0, "a soldier standing still keeps last turn's cone");
+        assert_eq!(first, second);
+
+        world
+            .write_storage::<Position>()
+            .insert(sentry, Position::new(11, 10))
+            .unwrap();
+        let moved = update(&world, &bf, &mut cache);
+        assert_eq!(cache.recomputed(), 1, "moving invalidates the cone");
+        assert!(moved.contains(&Position::new(11, 10)));
+
+        update(&world, &bf, &mut cache);
+        assert_eq!(cache.recomputed(), 0);
+        bf.set_terrain(BattlefieldPosition::new(11, 7), TerrainType::BuildingWall);
+        let walled = update(&world, &bf, &mut cache);
+        assert_eq!(cache.recomputed(), 1, "a terrain change refreshes every cone");
+        assert!(!walled.contains(&Position::new(11, 5)), "the new wall blocks the view north");
+    }
 }
@@ -1,10 +1,10 @@
 use rand::Rng;
 use rand::prelude::IndexedRandom;
-use crate::components::soldier::{Rank, Faction};
+use crate::components::soldier::{Rank, RankBaseStats, Faction};
 use crate::components::soldier_stats::SoldierStats;
+use serde::{Deserialize, Serialize};
 
-pub fn generate_soldier_stats(rank: Rank, rng: &mut impl Rng) -> SoldierStats {
-    let base = rank.base_stats();
+pub fn generate_soldier_stats(rank: Rank, base: &RankBaseStats, rng: &mut impl Rng) -> SoldierStats {
 
     let (acc_var, move_var, hp_var) = match rank {
         Rank::Private => (0.10, 0.15, 15),
@@ -22,26 +22,70 @@ pub fn generate_soldier_stats(rank: Rank, rng: &mut impl Rng) -> SoldierStats {
     }
 }
 
-pub fn select_random_rank(rng: &mut impl Rng) -> Rank {
-    let total_weight: u32 = Rank::all()
-        .iter()
-        .map(|r| r.distribution_weight())
-        .sum();
+/// Share of each rank in a force, in percent
+///
+/// Officers are split between lieutenants and captains in the standard 3:2
+/// ratio. The default is the standard mix from `Rank::distribution_weight`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RankDistribution {
+    pub privates: u32,
+    pub corporals: u32,
+    pub sergeants: u32,
+    pub officers: u32,
+}
 
-    let mut roll = rng.gen_range(0..total_weight);
+impl RankDistribution {
+    pub fn new(privates: u32, corporals: u32, sergeants: u32, officers: u32) -> Self {
+        Self {
+            privates,
+            corporals,
+            sergeants,
+            officers,
+        }
+    }
 
-    for rank in &Rank::all() {
-        let weight = rank.distribution_weight();
-        if roll < weight {
-            return *rank;
+    /// Roll a rank from this mix; an empty mix gives privates
+    pub fn pick(&self, rng: &mut impl Rng) -> Rank {
+        let lieutenant = Rank::Lieutenant.distribution_weight();
+        let captain = Rank::Captain.distribution_weight();
+        let officer_share = lieutenant + captain;
+        let weights = [
+            (Rank::Private, self.privates * officer_share),
+            (Rank::Corporal, self.corporals * officer_share),
+            (Rank::Sergeant, self.sergeants * officer_share),
+            (Rank::Lieutenant, self.officers * lieutenant),
+            (Rank::Captain, self.officers * captain),
+        ];
+
+        let total_weight: u32 = weights.iter().map(|(_, weight)| weight).sum();
+        if total_weight == 0 {
+            return Rank::Private;
         }
-        roll -= weight;
+
+        let mut roll = rng.random_range(0..total_weight);
+        for (rank, weight) in weights {
+            if roll < weight {
+                return rank;
+            }
+            roll -= weight;
+        }
+
+        Rank::Private
     }
+}
 
-    Rank::Private
+impl Default for RankDistribution {
+    fn default() -> Self {
+        Self::new(
+            Rank::Private.distribution_weight(),
+            Rank::Corporal.distribution_weight(),
+            Rank::Sergeant.distribution_weight(),
+            Rank::Lieutenant.distribution_weight() + Rank::Captain.distribution_weight(),
+        )
+    }
 }
 
-pub fn generate_name(faction: Faction, rank: Rank) -> String {
+pub fn generate_name(faction: Faction, rank: Rank, rng: &mut impl Rng) -> String {
     let first_names_allies = [
         "John", "William", "James", "George", "Thomas", "Robert", "Edward", "Arthur",
         "Charles", "Henry", "Albert", "Frederick", "Walter", "Harold", "Ernest", "Alfred"
@@ -60,16 +104,14 @@ pub fn generate_name(faction: Faction, rank: Rank) -> String {
         "Hoffmann", "Schulz", "Becker", "Koch", "Richter", "Wolf", "Schroeder", "Neumann"
     ];
 
-    let mut rng = rand::rng();
-
     let (first, last) = match faction {
         Faction::Allies => (
-            first_names_allies.as_slice().choose(&mut rng).unwrap(),
-            last_names_allies.as_slice().choose(&mut rng).unwrap(),
+            first_names_allies.as_slice().choose(rng).unwrap(),
+            last_names_allies.as_slice().choose(rng).unwrap(),
         ),
         Faction::CentralPowers => (
-            first_names_central.as_slice().choose(&mut rng).unwrap(),
-            last_names_central.as_slice().choose(&mut rng).unwrap(),
+            first_names_central.as_slice().choose(rng).unwrap(),
+            last_names_central.as_slice().choose(rng).unwrap(),
         ),
     };
 
@@ -86,7 +128,7 @@ mod tests {
         let mut counts = std::collections::HashMap::new();
 
         for _ in 0..1000 {
-            let rank = select_random_rank(&mut rng);
+            let rank = RankDistribution::default().pick(&mut rng);
             *counts.entry(rank).or_insert(0) += 1;
         }
 
@@ -97,13 +139,20 @@ mod tests {
         assert!(*captain_count < 50);
     }
 
+    #[test]
+    fn test_empty_rank_distribution_gives_privates() {
+        let mut rng = rand::rng();
+        let empty = RankDistribution::new(0, 0, 0, 0);
+        assert_eq!(empty.pick(&mut rng), Rank::Private);
+    }
+
     #[test]
     fn test_stat_generation() {
         let mut rng = rand::rng();
 
         for rank in &Rank::all() {
-            let stats = generate_soldier_stats(*rank, &mut rng);
             let base = rank.base_stats();
+            let stats = generate_soldier_stats(*rank, &base, &mut rng);
 
             assert!(stats.accuracy_modifier >= base.accuracy_base - 0.2);
             assert!(stats.accuracy_modifier <= base.accuracy_base + 0.2);
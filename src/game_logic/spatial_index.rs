@@ -0,0 +1,144 @@
+// Spatial index of entity positions
+// Tile -> occupants lookup, replacing O(n) joins when asking "who is here?"
+
+use crate::components::position::Position as PositionComponent;
+use crate::game_logic::battlefield::Position;
+use smallvec::SmallVec;
+use specs::{Entity, Join, World, WorldExt};
+use std::collections::HashMap;
+
+/// Resource: occupants of each tile, keyed by battlefield position
+///
+/// Covers every entity with a Position, dead ones included; callers filter
+/// by whatever markers they care about. ActionExecutionSystem rebuilds it at
+/// the start of each Execution phase and moves entities as their moves
+/// resolve, so between dispatches it matches the Position storage.
+#[derive(Debug, Clone, Default)]
+pub struct PositionIndex {
+    occupants: HashMap<Position, SmallVec<[Entity; 2]>>,
+}
+
+impl PositionIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an index from (entity, position) pairs
+    pub fn from_positions(positions: impl IntoIterator<Item = (Entity, Position)>) -> Self {
+        let mut index = Self::new();
+        index.rebuild(positions);
+        index
+    }
+
+    /// Replace the contents with fresh (entity, position) pairs
+    pub fn rebuild(&mut self, positions: impl IntoIterator<Item = (Entity, Position)>) {
+        self.occupants.clear();
+        for (entity, pos) in positions {
+            self.insert(entity, pos);
+        }
+    }
+
+    /// Rebuild the world's index from its Position storage
+    pub fn refresh(world: &World) {
+        let entities = world.entities();
+        let positions = world.read_storage::<PositionComponent>();
+        world.write_resource::<PositionIndex>().rebuild(
+            (&entities, &positions)
+                .join()
+                .map(|(entity, pos)| (entity, *pos.as_battlefield_pos())),
+        );
+    }
+
+    pub fn insert(&mut self, entity: Entity, pos: Position) {
+        let occupants = self.occupants.entry(pos).or_default();
+        if !occupants.contains(&entity) {
+            occupants.push(entity);
+        }
+    }
+
+    pub fn remove(&mut self, entity: Entity, pos: Position) {
+        if let Some(occupants) = self.occupants.get_mut(&pos) {
+            occupants.retain(|e| *e != entity);
+            if occupants.is_empty() {
+                self.occupants.remove(&pos);
+            }
+        }
+    }
+
+    pub fn move_entity(&mut self, entity: Entity, from: Position, to: Position) {
+        self.remove(entity, from);
+        self.insert(entity, to);
+    }
+
+    /// Entities standing on a tile
+    pub fn occupants(&self, pos: Position) -> &[Entity] {
+        self.occupants.get(&pos).map(|occupants| occupants.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn contains_entity_at(&self, pos: Position) -> bool {
+        !self.occupants(pos).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use specs::Builder;
+
+    #[test]
+    fn test_index_reports_occupant_after_move() {
+        let mut world = World::new();
+        let a = world.create_entity().build();
+        let b = world.create_entity().build();
+
+        let mut index =
+            PositionIndex::from_positions([(a, Position::new(1, 1)), (b, Position::new(2, 2))]);
+        assert_eq!(index.occupants(Position::new(1, 1)), &[a]);
+        assert_eq!(index.occupants(Position::new(2, 2)), &[b]);
+
+        index.move_entity(a, Position::new(1, 1), Position::new(3, 1));
+        assert!(!index.contains_entity_at(Position::new(1, 1)));
+        assert_eq!(index.occupants(Position::new(3, 1)), &[a]);
+    }
+
+    #[test]
+    fn test_refresh_matches_brute_force_join() {
+        let mut world = World::new();
+        world.register::<PositionComponent>();
+        world.insert(PositionIndex::new());
+        for i in 0..200 {
+            // Deliberate overlaps: 200 entities on 50 tiles
+            world
+                .create_entity()
+                .with(PositionComponent::new(i % 10, (i / 10) % 5))
+                .build();
+        }
+
+        PositionIndex::refresh(&world);
+
+        let index = world.read_resource::<PositionIndex>();
+        let entities = world.entities();
+        let positions = world.read_storage::<PositionComponent>();
+        for y in 0..6 {
+            for x in 0..11 {
+                let tile = Position::new(x, y);
+                let mut expected: Vec<Entity> = (&entities, &positions)
+                    .join()
+                    .filter(|(_, pos)| *pos.as_battlefield_pos() == tile)
+                    .map(|(e, _)| e)
+                    .collect();
+                let mut actual = index.occupants(tile).to_vec();
+                expected.sort();
+                actual.sort();
+                assert_eq!(actual, expected, "mismatch at {:?}", tile);
+            }
+        }
+    }
+
+    #[test]
+    fn test_position_equality_is_by_coordinates() {
+        assert_eq!(Position::new(4, 7), Position::new(4, 7));
+        assert_ne!(Position::new(4, 7), Position::new(7, 4));
+        assert_eq!(PositionComponent::new(4, 7), PositionComponent::new(4, 7));
+    }
+}
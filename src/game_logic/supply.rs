@@ -0,0 +1,72 @@
+// Supply lines
+// Which tiles a side can still reach its rear from
+//
+// Supply flows out of a side's spawn zone across passable ground, in the same
+// eight directions soldiers move; a tile held by a standing enemy soldier
+// stops it. A soldier on a tile the flood never reaches is cut off.
+
+use crate::game_logic::battlefield::{Battlefield, Position, SpawnZone};
+use std::collections::{HashSet, VecDeque};
+
+/// Tiles connected to `zone` by passable ground not in `blocked`
+pub fn supplied_tiles(
+    battlefield: &Battlefield,
+    zone: &SpawnZone,
+    blocked: &HashSet<Position>,
+) -> HashSet<Position> {
+    let open = |pos: &Position| {
+        !blocked.contains(pos)
+            && battlefield
+                .get_tile(pos)
+                .is_some_and(|tile| tile.terrain.is_passable())
+    };
+
+    let radius = zone.radius as i32;
+    let mut supplied = HashSet::new();
+    let mut frontier = VecDeque::new();
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            let pos = Position::new(zone.center.x + dx, zone.center.y + dy);
+            if zone.contains(&pos) && open(&pos) && supplied.insert(pos) {
+                frontier.push_back(pos);
+            }
+        }
+    }
+
+    while let Some(pos) = frontier.pop_front() {
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let next = Position::new(pos.x + dx, pos.y + dy);
+                if !supplied.contains(&next) && open(&next) {
+                    supplied.insert(next);
+                    frontier.push_back(next);
+                }
+            }
+        }
+    }
+    supplied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::battlefield::TerrainType;
+
+    #[test]
+    fn test_walls_and_enemies_cut_the_line() {
+        let mut battlefield = Battlefield::new(20, 10);
+        for y in 0..10 {
+            battlefield.set_terrain(Position::new(10, y), TerrainType::BuildingWall);
+        }
+        battlefield.set_terrain(Position::new(10, 5), TerrainType::BuildingDoor);
+        let zone = SpawnZone::new(Position::new(2, 5), 2);
+
+        let open = supplied_tiles(&battlefield, &zone, &HashSet::new());
+        assert!(open.contains(&Position::new(15, 5)), "supply flows through the door");
+
+        let held = HashSet::from([Position::new(10, 5)]);
+        let cut = supplied_tiles(&battlefield, &zone, &held);
+        assert!(cut.contains(&Position::new(9, 5)));
+        assert!(!cut.contains(&Position::new(15, 5)), "an enemy in the doorway cuts the line");
+    }
+}
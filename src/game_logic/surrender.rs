@@ -0,0 +1,109 @@
+// Surrender rules
+// Escape-route checks for broken soldiers and the running capture tally
+
+use crate::components::soldier::Faction;
+use crate::game_logic::battlefield::{Battlefield, Position};
+use std::collections::{HashSet, VecDeque};
+
+/// Resource: soldiers taken prisoner, counted by the faction they fought for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Captures {
+    pub allies: u32,
+    pub central_powers: u32,
+}
+
+impl Captures {
+    pub fn record(&mut self, faction: Faction) {
+        match faction {
+            Faction::Allies => self.allies += 1,
+            Faction::CentralPowers => self.central_powers += 1,
+        }
+    }
+
+    pub fn count(&self, faction: Faction) -> u32 {
+        match faction {
+            Faction::Allies => self.allies,
+            Faction::CentralPowers => self.central_powers,
+        }
+    }
+}
+
+/// Whether a soldier can still fall back to its own spawn zone
+///
+/// Tiles next to an enemy count as blocked, so a soldier hemmed in by
+/// enemies is cut off even when open ground lies beyond them. Without a
+/// spawn zone there is nothing to fall back to and no one is ever cut off.
+pub fn has_escape_route(
+    start: Position,
+    faction: Faction,
+    enemy_positions: &[Position],
+    battlefield: &Battlefield,
+) -> bool {
+    let zone = match faction {
+        Faction::Allies => battlefield.ally_spawn.as_ref(),
+        Faction::CentralPowers => battlefield.enemy_spawn.as_ref(),
+    };
+    let Some(zone) = zone else {
+        return true;
+    };
+
+    let blocked = |pos: &Position| {
+        enemy_positions
+            .iter()
+            .any(|enemy| (enemy.x - pos.x).abs() <= 1 && (enemy.y - pos.y).abs() <= 1)
+    };
+
+    let mut visited = HashSet::from([start]);
+    let mut frontier = VecDeque::from([start]);
+
+    while let Some(pos) = frontier.pop_front() {
+        if zone.contains(&pos) {
+            return true;
+        }
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let next = Position::new(pos.x + dx, pos.y + dy);
+                let passable = battlefield
+                    .get_tile(&next)
+                    .is_some_and(|tile| tile.terrain.is_passable());
+                if passable && !blocked(&next) && visited.insert(next) {
+                    frontier.push_back(next);
+                }
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::battlefield::SpawnZone;
+
+    fn battlefield() -> Battlefield {
+        let mut battlefield = Battlefield::new(20, 20);
+        battlefield.set_spawn_zones(
+            SpawnZone::new(Position::new(2, 2), 2),
+            SpawnZone::new(Position::new(17, 17), 2),
+        );
+        battlefield
+    }
+
+    #[test]
+    fn test_open_ground_leaves_escape_route() {
+        let battlefield = battlefield();
+        assert!(has_escape_route(Position::new(10, 10), Faction::Allies, &[], &battlefield));
+    }
+
+    #[test]
+    fn test_ring_of_enemies_cuts_off_escape() {
+        let battlefield = battlefield();
+        let ring: Vec<Position> = [(-2, -2), (0, -2), (2, -2), (-2, 0), (2, 0), (-2, 2), (0, 2), (2, 2)]
+            .iter()
+            .map(|(dx, dy)| Position::new(10 + dx, 10 + dy))
+            .collect();
+
+        assert!(!has_escape_route(Position::new(10, 10), Faction::Allies, &ring, &battlefield));
+    }
+}
@@ -0,0 +1,219 @@
+// Targeting
+// Whether the tile under the targeting cursor holds a soldier worth shooting at
+//
+// Checked each frame while targeting, to style the crosshair and fill in the
+// targeting panel. A target past the weapon's effective range but within its
+// max range can still be shot at, only less accurately.
+//
+// Grenades are aimed at ground rather than soldiers. Within throwing range
+// they may be lobbed over cover, unless the battle requires the thrower to
+// see where the grenade lands.
+
+use crate::components::{
+    position::Position, soldier::Soldier, surrendered::Surrendered, vision::Vision,
+    weapon::Weapon,
+};
+use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos};
+use crate::game_logic::combat::{in_effective_range, in_weapon_range};
+use crate::game_logic::line_of_sight::calculate_fov;
+use crate::game_logic::spatial_index::PositionIndex;
+use specs::{Entity, World, WorldExt};
+
+/// Tiles a hand grenade can be thrown
+pub const GRENADE_THROW_RANGE: i32 = 8;
+
+/// Whether a grenade may be thrown at a tile the thrower cannot see
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GrenadeThrow {
+    /// Grenades are lobbed over walls and cover to any tile in range
+    #[default]
+    Arced,
+    /// The landing tile must be in the thrower's line of sight
+    LineOfSight,
+}
+
+impl GrenadeThrow {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GrenadeThrow::Arced => "Arced",
+            GrenadeThrow::LineOfSight => "Line of Sight",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            GrenadeThrow::Arced => GrenadeThrow::LineOfSight,
+            GrenadeThrow::LineOfSight => GrenadeThrow::Arced,
+        }
+    }
+}
+
+/// Validation result for targeting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetValidation {
+    Valid,                // Enemy in effective range with LOS
+    OutOfEffectiveRange,  // Enemy in max range with LOS, but the shot will be inaccurate
+    NoTarget,             // No entity at cursor
+    Friendly,             // Friendly/self at cursor
+    OutOfRange,           // Target exists but out of weapon range
+    NoLineOfSight,        // Target exists but no LOS
+    Surrendered,          // Enemy has surrendered
+}
+
+impl TargetValidation {
+    /// Whether a shot can be taken at the target
+    pub fn can_fire(&self) -> bool {
+        matches!(self, TargetValidation::Valid | TargetValidation::OutOfEffectiveRange)
+    }
+}
+
+/// Check if `cursor` holds a valid target for `shooter` to fire at
+pub fn validate_target(
+    world: &World,
+    battlefield: &Battlefield,
+    shooter: Entity,
+    cursor: BattlefieldPos,
+) -> TargetValidation {
+    let positions = world.read_storage::<Position>();
+    let soldiers = world.read_storage::<Soldier>();
+    let weapons = world.read_storage::<Weapon>();
+    let visions = world.read_storage::<Vision>();
+
+    // Get shooter position, weapon, faction and vision
+    let (Some(shooter_pos), Some(weapon), Some(shooter_soldier)) = (
+        positions.get(shooter),
+        weapons.get(shooter),
+        soldiers.get(shooter),
+    ) else {
+        return TargetValidation::NoTarget;
+    };
+    let shooter_vision = visions.get(shooter).map(|v| v.range).unwrap_or(10);
+
+    // Check if there's a soldier at cursor position
+    let occupancy = world.read_resource::<PositionIndex>();
+    let Some((target, target_pos, target_soldier)) = occupancy
+        .occupants(cursor)
+        .iter()
+        .find_map(|&e| Some((e, positions.get(e)?, soldiers.get(e)?)))
+    else {
+        return TargetValidation::NoTarget;
+    };
+
+    // Don't allow shooting self or friendlies
+    if target == shooter || target_soldier.faction == shooter_soldier.faction {
+        return TargetValidation::Friendly;
+    }
+
+    if world.read_storage::<Surrendered>().contains(target) {
+        return TargetValidation::Surrendered;
+    }
+
+    // Check range (same metric the AI and shot resolution use)
+    let from = shooter_pos.as_battlefield_pos();
+    let to = target_pos.as_battlefield_pos();
+    if !in_weapon_range(weapon, from, to) {
+        return TargetValidation::OutOfRange;
+    }
+
+    // Check line of sight using FOV calculation
+    if !calculate_fov(from, shooter_vision, battlefield).contains(to) {
+        return TargetValidation::NoLineOfSight;
+    }
+
+    if !in_effective_range(weapon, from, to) {
+        return TargetValidation::OutOfEffectiveRange;
+    }
+    TargetValidation::Valid
+}
+
+/// Check if a grenade thrown from `from` may land on `target`
+///
+/// Only `Valid`, `OutOfRange` and `NoLineOfSight` come back.
+pub fn validate_grenade_target(
+    battlefield: &Battlefield,
+    from: &BattlefieldPos,
+    target: BattlefieldPos,
+    rule: GrenadeThrow,
+) -> TargetValidation {
+    if from.distance_to(&target) > GRENADE_THROW_RANGE as f32 {
+        return TargetValidation::OutOfRange;
+    }
+    if rule == GrenadeThrow::LineOfSight
+        && !calculate_fov(from, GRENADE_THROW_RANGE, battlefield).contains(&target)
+    {
+        return TargetValidation::NoLineOfSight;
+    }
+    TargetValidation::Valid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::{Faction, Rank};
+    use crate::game_logic::battlefield::TerrainType;
+    use crate::game_logic::world_setup::register_components;
+    use specs::Builder;
+
+    fn soldier(faction: Faction) -> Soldier {
+        Soldier {
+            name: "Test".to_string(),
+            faction,
+            rank: Rank::Private,
+        }
+    }
+
+    #[test]
+    fn test_target_past_effective_range_is_a_long_shot() {
+        let mut world = World::new();
+        register_components(&mut world);
+        let battlefield = Battlefield::new(60, 10);
+        let weapon = Weapon::rifle();
+        let (effective, max) = (weapon.stats.effective_range, weapon.stats.max_range);
+        let shooter = world
+            .create_entity()
+            .with(Position::new(0, 5))
+            .with(soldier(Faction::Allies))
+            .with(weapon)
+            .with(Vision::new(max + 10))
+            .build();
+        for x in [effective, effective + 1, max + 1] {
+            world
+                .create_entity()
+                .with(Position::new(x, 5))
+                .with(soldier(Faction::CentralPowers))
+                .build();
+        }
+        world.insert(PositionIndex::default());
+        PositionIndex::refresh(&world);
+
+        let check = |x| validate_target(&world, &battlefield, shooter, BattlefieldPos::new(x, 5));
+        assert_eq!(check(effective), TargetValidation::Valid);
+        assert_eq!(check(effective + 1), TargetValidation::OutOfEffectiveRange);
+        assert!(check(effective + 1).can_fire());
+        assert_eq!(check(max + 1), TargetValidation::OutOfRange);
+    }
+
+    #[test]
+    fn test_grenade_over_a_wall_needs_an_arced_throw() {
+        let mut battlefield = Battlefield::new(20, 20);
+        for y in 0..20 {
+            battlefield.set_terrain(BattlefieldPos::new(8, y), TerrainType::BuildingWall);
+        }
+        let thrower = BattlefieldPos::new(5, 10);
+        let behind_wall = BattlefieldPos::new(10, 10);
+
+        assert_eq!(
+            validate_grenade_target(&battlefield, &thrower, behind_wall, GrenadeThrow::LineOfSight),
+            TargetValidation::NoLineOfSight
+        );
+        assert_eq!(
+            validate_grenade_target(&battlefield, &thrower, behind_wall, GrenadeThrow::Arced),
+            TargetValidation::Valid
+        );
+        let too_far = BattlefieldPos::new(5, 10 + GRENADE_THROW_RANGE + 1);
+        assert_eq!(
+            validate_grenade_target(&battlefield, &thrower, too_far, GrenadeThrow::Arced),
+            TargetValidation::OutOfRange
+        );
+    }
+}
@@ -8,6 +8,10 @@ use crate::config::battlefield_config::{
 use noise::{NoiseFn, Perlin};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
+use std::collections::{HashSet, VecDeque};
+
+/// Tiles between one trench line and the next line behind it
+pub const SUPPORT_LINE_SPACING: usize = 8;
 
 /// Main battlefield generator
 pub struct BattlefieldGenerator {
@@ -56,9 +60,17 @@ impl BattlefieldGenerator {
         // Phase 6: Tactical balancing
         self.balance_tactical_features(&mut battlefield);
 
-        // Phase 7: Spawn zones
+        // Phase 7: Hidden mines (only when requested, so default maps are unchanged)
+        if self.config.mine_density > 0.0 {
+            self.place_mines(&mut battlefield);
+        }
+
+        // Phase 8: Spawn zones
         self.create_spawn_zones(&mut battlefield);
 
+        // Phase 9: Make sure the two sides can actually reach each other
+        self.ensure_spawn_connectivity(&mut battlefield);
+
         battlefield
     }
 
@@ -97,7 +109,7 @@ impl BattlefieldGenerator {
                 let pos = Position::new(x as i32, y as i32);
 
                 // Sample Perlin noise at this position
-                let noise_value = self.sample_perlin(x as f64, y as f64, 0.05);
+                let noise_value = self.sample_perlin(x as f64, y as f64);
 
                 // Determine base terrain based on noise and config
                 let terrain = self.determine_base_terrain(noise_value, &pos);
@@ -110,8 +122,23 @@ impl BattlefieldGenerator {
         self.mark_no_mans_land(battlefield);
     }
 
-    fn sample_perlin(&self, x: f64, y: f64, scale: f64) -> f64 {
-        self.perlin.get([x * scale, y * scale])
+    /// Fractal Perlin noise: each octave doubles frequency and scales amplitude
+    /// by the persistence, normalized back into [-1, 1]
+    fn sample_perlin(&self, x: f64, y: f64) -> f64 {
+        let persistence = self.config.noise_persistence as f64;
+        let mut frequency = self.config.noise_scale as f64;
+        let mut amplitude = 1.0;
+        let mut total = 0.0;
+        let mut max_amplitude = 0.0;
+
+        for _ in 0..self.config.noise_octaves.max(1) {
+            total += self.perlin.get([x * frequency, y * frequency]) * amplitude;
+            max_amplitude += amplitude;
+            frequency *= 2.0;
+            amplitude *= persistence;
+        }
+
+        total / max_amplitude
     }
 
     fn determine_base_terrain(&mut self, noise_value: f64, _pos: &Position) -> TerrainType {
@@ -146,6 +173,7 @@ impl BattlefieldGenerator {
 
     fn mark_no_mans_land(&mut self, battlefield: &mut Battlefield) {
         let (start, end) = self.get_no_mans_land_bounds();
+        battlefield.set_no_mans_land_band(start as i32, end as i32);
 
         for y in start..end {
             for x in 0..self.config.width {
@@ -196,7 +224,22 @@ impl BattlefieldGenerator {
     }
 
     fn generate_trench_line(&mut self, battlefield: &mut Battlefield, is_allies: bool) {
-        let y_position = self.get_trench_line_position(is_allies);
+        let front_y = self.get_trench_line_position(is_allies);
+
+        // Support and reserve lines run parallel to the front, further from no-man's land
+        let (nml_start, _) = self.get_no_mans_land_bounds();
+        let rearward: i64 = if front_y > nml_start { 1 } else { -1 };
+        for tier in 0..=self.config.support_trench_lines {
+            let y = front_y as i64 + rearward * (tier * SUPPORT_LINE_SPACING) as i64;
+            // Leave room for the parapets on either side of the floor
+            if tier > 0 && (y < 1 || y >= self.config.height as i64 - 1) {
+                break;
+            }
+            self.generate_trench_run(battlefield, y as usize, is_allies);
+        }
+    }
+
+    fn generate_trench_run(&mut self, battlefield: &mut Battlefield, y_position: usize, is_allies: bool) {
         let coverage = self.config.trench_density.coverage_percentage();
 
         // Generate main trench line with multi-tile structure
@@ -296,27 +339,28 @@ impl BattlefieldGenerator {
     // ========================================================================
 
     fn generate_fortifications(&mut self, battlefield: &mut Battlefield) {
-        if matches!(self.config.fortification_level, FortificationLevel::None) {
+        if matches!(self.config.fortification_level, FortificationLevel::None)
+            && self.config.fortification_counts.is_none()
+        {
             return;
         }
 
-        let (sandbag_count, bunker_count, mg_nest_count) =
-            self.config.fortification_level.get_counts();
+        let counts = self.config.effective_fortification_counts();
 
         // Place fortifications for both sides
         self.place_fortifications_for_side(
             battlefield,
             true,
-            sandbag_count,
-            bunker_count,
-            mg_nest_count,
+            counts.sandbags,
+            counts.bunkers,
+            counts.mg_nests,
         );
         self.place_fortifications_for_side(
             battlefield,
             false,
-            sandbag_count,
-            bunker_count,
-            mg_nest_count,
+            counts.sandbags,
+            counts.bunkers,
+            counts.mg_nests,
         );
 
         // Place barbed wire in no-man's land
@@ -441,6 +485,27 @@ impl BattlefieldGenerator {
         self.place_forests(battlefield);
     }
 
+    fn place_mines(&mut self, battlefield: &mut Battlefield) {
+        let (start, end) = self.get_no_mans_land_bounds();
+        let nml_tiles = ((end - start) * self.config.width) as f32;
+        let mine_count = (nml_tiles / 100.0 * self.config.mine_density) as usize;
+
+        for _ in 0..mine_count {
+            let x = self.rng.random_range(0..self.config.width) as i32;
+            let y = self.rng.random_range(start..end.max(start + 1)) as i32;
+            let pos = Position::new(x, y);
+
+            let placeable = battlefield.get_tile(&pos).is_some_and(|tile| {
+                tile.terrain.is_passable()
+                    && !matches!(tile.terrain, TerrainType::Water | TerrainType::DeepWater)
+            });
+
+            if placeable {
+                battlefield.place_mine(pos);
+            }
+        }
+    }
+
     fn place_shell_craters(&mut self, battlefield: &mut Battlefield) {
         let total_tiles = (self.config.width * self.config.height) as f32;
         let crater_count = (total_tiles / 100.0 * self.config.crater_density) as usize;
@@ -644,12 +709,41 @@ impl BattlefieldGenerator {
             ),
         };
 
+        let (ally_center, enemy_center) = self.separate_spawn_centers(ally_center, enemy_center);
+
         let ally_spawn = SpawnZone::new(ally_center, spawn_radius);
         let enemy_spawn = SpawnZone::new(enemy_center, spawn_radius);
 
         battlefield.set_spawn_zones(ally_spawn, enemy_spawn);
     }
 
+    /// Push spawn centers apart along the line between them until they are at
+    /// least `min_spawn_separation` tiles apart (or hit the map edge)
+    fn separate_spawn_centers(&self, ally: Position, enemy: Position) -> (Position, Position) {
+        let min_separation = self.config.min_spawn_separation as f32;
+        let distance = ally.distance_to(&enemy);
+        if distance >= min_separation || distance == 0.0 {
+            return (ally, enemy);
+        }
+
+        let push = (min_separation - distance) / 2.0;
+        let (ux, uy) = (
+            (ally.x - enemy.x) as f32 / distance,
+            (ally.y - enemy.y) as f32 / distance,
+        );
+        let clamp = |x: f32, y: f32| {
+            Position::new(
+                (x.round() as i32).clamp(0, self.config.width as i32 - 1),
+                (y.round() as i32).clamp(0, self.config.height as i32 - 1),
+            )
+        };
+
+        (
+            clamp(ally.x as f32 + ux * push, ally.y as f32 + uy * push),
+            clamp(enemy.x as f32 - ux * push, enemy.y as f32 - uy * push),
+        )
+    }
+
     fn calculate_spawn_radius(&self) -> usize {
         let map_size = self.config.width.min(self.config.height);
         (map_size / 8).max(10).min(20)
@@ -678,4 +772,180 @@ impl BattlefieldGenerator {
         let y = (self.config.height / 2) as i32;
         Position::new(x, y)
     }
+
+    // ========================================================================
+    // PHASE 9: Spawn Connectivity
+    // ========================================================================
+
+    /// Carve a corridor between spawn centers if water or buildings wall them off
+    fn ensure_spawn_connectivity(&mut self, battlefield: &mut Battlefield) {
+        let (ally, enemy) = match (&battlefield.ally_spawn, &battlefield.enemy_spawn) {
+            (Some(ally), Some(enemy)) => (ally.center, enemy.center),
+            _ => return,
+        };
+
+        if reachable_from(battlefield, ally).contains(&enemy) {
+            return;
+        }
+
+        // Walk the straight line between centers, opening anything impassable
+        let steps = (enemy.x - ally.x).abs().max((enemy.y - ally.y).abs());
+        for i in 0..=steps {
+            let t = if steps == 0 { 0.0 } else { i as f32 / steps as f32 };
+            let pos = Position::new(
+                ally.x + ((enemy.x - ally.x) as f32 * t).round() as i32,
+                ally.y + ((enemy.y - ally.y) as f32 * t).round() as i32,
+            );
+
+            let terrain = match battlefield.get_tile(&pos) {
+                Some(tile) => tile.terrain,
+                None => continue,
+            };
+            if !terrain.is_passable() {
+                // Deep water becomes a ford; walls and the like are knocked down
+                let opened = if terrain == TerrainType::DeepWater {
+                    TerrainType::Water
+                } else {
+                    TerrainType::Rubble
+                };
+                battlefield.set_terrain(pos, opened);
+            }
+        }
+    }
+}
+
+/// Every tile reachable from `start` by 8-way steps over passable terrain
+fn reachable_from(battlefield: &Battlefield, start: Position) -> HashSet<Position> {
+    let mut visited = HashSet::from([start]);
+    let mut frontier = VecDeque::from([start]);
+
+    while let Some(pos) = frontier.pop_front() {
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let next = Position::new(pos.x + dx, pos.y + dy);
+                let passable = battlefield
+                    .get_tile(&next)
+                    .is_some_and(|tile| tile.terrain.is_passable());
+                if passable && visited.insert(next) {
+                    frontier.push_back(next);
+                }
+            }
+        }
+    }
+
+    visited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Adjacent tile pairs with different base terrain; finer noise means more edges
+    fn base_terrain_edges(config: BattlefieldGenerationConfig) -> usize {
+        let mut generator = BattlefieldGenerator::new(config.with_type(BattlefieldType::WesternFront));
+        let mut battlefield = Battlefield::new(generator.config.width, generator.config.height);
+        generator.generate_base_layout(&mut battlefield);
+
+        let terrain = |x: i32, y: i32| battlefield.get_tile(&Position::new(x, y)).map(|t| t.terrain);
+        let (w, h) = (battlefield.width() as i32, battlefield.height() as i32);
+        let mut edges = 0;
+        for y in 0..h {
+            for x in 0..w {
+                if x + 1 < w && terrain(x, y) != terrain(x + 1, y) {
+                    edges += 1;
+                }
+                if y + 1 < h && terrain(x, y) != terrain(x, y + 1) {
+                    edges += 1;
+                }
+            }
+        }
+        edges
+    }
+
+    #[test]
+    fn test_noise_scale_changes_terrain_distribution() {
+        let base = BattlefieldGenerationConfig::default().with_seed(7);
+        let coarse = base_terrain_edges(base.clone().with_noise(1, 0.5, 0.02));
+        let fine = base_terrain_edges(base.with_noise(1, 0.5, 0.2));
+
+        assert!(
+            fine > coarse * 2,
+            "expected finer scale to fragment terrain (coarse {}, fine {})",
+            coarse,
+            fine
+        );
+    }
+
+    #[test]
+    fn test_spawn_centers_mutually_reachable() {
+        let mut generator = BattlefieldGenerator::new(BattlefieldGenerationConfig::verdun().with_seed(3));
+        let battlefield = generator.generate();
+        let ally = battlefield.ally_spawn.as_ref().unwrap().center;
+        let enemy = battlefield.enemy_spawn.as_ref().unwrap().center;
+
+        assert!(reachable_from(&battlefield, ally).contains(&enemy));
+        assert!(reachable_from(&battlefield, enemy).contains(&ally));
+    }
+
+    #[test]
+    fn test_walled_off_spawns_get_a_corridor() {
+        use super::super::battlefield::SpawnZone;
+
+        let mut generator = BattlefieldGenerator::new(BattlefieldGenerationConfig::default().with_dimensions(30, 30));
+        let mut battlefield = Battlefield::new(30, 30);
+        for x in 0..30 {
+            battlefield.set_terrain(Position::new(x, 15), TerrainType::DeepWater);
+        }
+        battlefield.set_spawn_zones(
+            SpawnZone::new(Position::new(15, 25), 3),
+            SpawnZone::new(Position::new(15, 5), 3),
+        );
+        assert!(!reachable_from(&battlefield, Position::new(15, 25)).contains(&Position::new(15, 5)));
+
+        generator.ensure_spawn_connectivity(&mut battlefield);
+
+        assert!(reachable_from(&battlefield, Position::new(15, 25)).contains(&Position::new(15, 5)));
+    }
+
+    #[test]
+    fn test_min_spawn_separation_pushes_centers_apart() {
+        let generator = BattlefieldGenerator::new(
+            BattlefieldGenerationConfig::default()
+                .with_dimensions(100, 100)
+                .with_min_spawn_separation(90),
+        );
+        let (ally, enemy) = generator.separate_spawn_centers(Position::new(50, 87), Position::new(50, 12));
+
+        assert!(ally.distance_to(&enemy) >= 89.0);
+        assert!(ally.y > 87 && enemy.y < 12);
+    }
+
+    #[test]
+    fn test_support_lines_add_parallel_trenches_behind_the_front() {
+        // Rows south of no-man's land (the allied side) holding a run of trench floor
+        let trench_rows = |support_lines| {
+            let config = BattlefieldGenerationConfig::default()
+                .with_seed(11)
+                .with_trench_density(TrenchDensity::VeryDense)
+                .with_support_trench_lines(support_lines);
+            let (_, nml_end) = BattlefieldGenerator::new(config.clone()).get_no_mans_land_bounds();
+            let battlefield = BattlefieldGenerator::new(config).generate();
+            (nml_end as i32..battlefield.height() as i32)
+                .filter(|&y| {
+                    (0..battlefield.width() as i32)
+                        .filter(|&x| {
+                            battlefield.get_tile(&Position::new(x, y)).map(|t| t.terrain)
+                                == Some(TerrainType::TrenchFloor)
+                        })
+                        .count()
+                        >= 10
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(trench_rows(0).len(), 1);
+        let layered = trench_rows(2);
+        assert_eq!(layered.len(), 3, "rows: {:?}", layered);
+        assert_eq!(layered[1] - layered[0], SUPPORT_LINE_SPACING as i32);
+    }
 }
@@ -8,7 +8,7 @@ use ratatui::style::Color;
 pub enum LOSBlocking {
     /// Does not block LOS at all
     None,
-    /// Partially blocks LOS (reduces visibility range or accuracy)
+    /// Visible, but each one crossed costs sight range (see line_of_sight.rs)
     Partial,
     /// Completely blocks LOS
     Full,
@@ -50,6 +50,12 @@ impl TerrainProperties {
     }
 }
 
+/// Cheapest movement cost of any terrain (Bunker)
+///
+/// Pathfinding scales its distance heuristic by this so it never overestimates;
+/// keep it in sync if a faster terrain (e.g. a better road) is added.
+pub const MIN_MOVEMENT_COST: f32 = 0.5;
+
 // Terrain property constants for easy reference
 impl TerrainProperties {
     // Basic terrain types
@@ -0,0 +1,104 @@
+// Threat Compass
+// Bearing from the player to the most pressing known enemy, for the HUD
+
+use crate::components::facing::Direction8;
+use crate::game_logic::battlefield::Position;
+
+/// Where the most pressing known enemy lies relative to the player
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThreatBearing {
+    pub direction: Direction8,
+    pub distance: f32,
+    /// Enemy is in sight right now (false = last-seen position)
+    pub visible: bool,
+}
+
+impl ThreatBearing {
+    pub fn display(&self) -> String {
+        let source = if self.visible { "" } else { " (last seen)" };
+        format!(
+            "{} {:?} {:.0} tiles{}",
+            self.direction.to_char(),
+            self.direction,
+            self.distance,
+            source
+        )
+    }
+}
+
+/// Compass direction from one tile to another, snapped to the nearest 45°
+pub fn bearing(from: &Position, to: &Position) -> Option<Direction8> {
+    let dx = (to.x - from.x) as f32;
+    let dy = (to.y - from.y) as f32;
+    if dx == 0.0 && dy == 0.0 {
+        return None;
+    }
+
+    // 0° = North, clockwise (screen y grows southward)
+    let degrees = dx.atan2(-dy).to_degrees().rem_euclid(360.0);
+    let sector = ((degrees / 45.0).round() as usize) % 8;
+    Some(
+        [
+            Direction8::N,
+            Direction8::NE,
+            Direction8::E,
+            Direction8::SE,
+            Direction8::S,
+            Direction8::SW,
+            Direction8::W,
+            Direction8::NW,
+        ][sector],
+    )
+}
+
+/// Pick the threat to point at: the nearest visible enemy, otherwise the
+/// nearest last-seen position
+pub fn nearest_threat(
+    player: &Position,
+    visible_enemies: &[Position],
+    last_seen: &[Position],
+) -> Option<ThreatBearing> {
+    let nearest = |positions: &[Position], visible: bool| {
+        positions
+            .iter()
+            .filter_map(|pos| {
+                bearing(player, pos).map(|direction| ThreatBearing {
+                    direction,
+                    distance: player.distance_to(pos),
+                    visible,
+                })
+            })
+            .min_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap_or(std::cmp::Ordering::Equal))
+    };
+
+    nearest(visible_enemies, true).or_else(|| nearest(last_seen, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enemy_due_east_bears_east() {
+        let player = Position::new(10, 10);
+        let threat = nearest_threat(&player, &[Position::new(17, 10)], &[]).unwrap();
+
+        assert_eq!(threat.direction, Direction8::E);
+        assert_eq!(threat.distance, 7.0);
+        assert!(threat.visible);
+    }
+
+    #[test]
+    fn test_visible_enemy_outranks_closer_last_seen() {
+        let player = Position::new(10, 10);
+        let threat = nearest_threat(
+            &player,
+            &[Position::new(10, 2)],
+            &[Position::new(12, 12)],
+        )
+        .unwrap();
+
+        assert_eq!(threat.direction, Direction8::N);
+        assert_eq!(bearing(&player, &Position::new(12, 12)), Some(Direction8::SE));
+    }
+}
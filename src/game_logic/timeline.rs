@@ -0,0 +1,121 @@
+// Battle timeline
+// The match's major moments, in the order they happened
+//
+// TimelineSystem notes each soldier as they fall and each flag as it changes
+// hands; reinforcement waves are noted where they are sent. The pause menu
+// lists the moments and can jump the camera to where each one happened.
+
+use crate::components::soldier::Faction;
+use crate::game_logic::battlefield::Position;
+use specs::Entity;
+use std::collections::{HashMap, HashSet};
+
+/// What kind of moment a timeline entry records
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineEventKind {
+    Kill,
+    Capture,
+    Reinforcements,
+}
+
+impl TimelineEventKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimelineEventKind::Kill => "KIA",
+            TimelineEventKind::Capture => "FLAG",
+            TimelineEventKind::Reinforcements => "RNF",
+        }
+    }
+}
+
+/// One major moment of the battle
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineEvent {
+    pub turn: u32,
+    pub position: Position,
+    pub kind: TimelineEventKind,
+    pub description: String,
+}
+
+/// Resource: the battle's major moments, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct Timeline {
+    events: Vec<TimelineEvent>,
+    /// Fallen soldiers already on the timeline
+    recorded_dead: HashSet<Entity>,
+    /// Last owner seen for each flag, to spot changes of hands
+    flag_owners: HashMap<String, Option<Faction>>,
+}
+
+impl Timeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(
+        &mut self,
+        turn: u32,
+        position: Position,
+        kind: TimelineEventKind,
+        description: String,
+    ) {
+        self.events.push(TimelineEvent {
+            turn,
+            position,
+            kind,
+            description,
+        });
+    }
+
+    /// Record `entity` falling, unless it already has; returns whether it was new
+    pub fn record_kill(
+        &mut self,
+        entity: Entity,
+        turn: u32,
+        position: Position,
+        description: String,
+    ) -> bool {
+        if !self.recorded_dead.insert(entity) {
+            return false;
+        }
+        self.record(turn, position, TimelineEventKind::Kill, description);
+        true
+    }
+
+    /// Note who holds flag `id`; returns whether it changed hands since last seen
+    ///
+    /// The first sighting of a flag only remembers its starting owner.
+    pub fn observe_flag(&mut self, id: &str, owner: Option<Faction>) -> bool {
+        match self.flag_owners.insert(id.to_string(), owner) {
+            Some(previous) => previous != owner,
+            None => false,
+        }
+    }
+
+    /// Every recorded moment, oldest first
+    pub fn events(&self) -> &[TimelineEvent] {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_change_is_noticed_only_after_first_sighting() {
+        let mut timeline = Timeline::new();
+        assert!(!timeline.observe_flag("hill", Some(Faction::CentralPowers)));
+        assert!(!timeline.observe_flag("hill", Some(Faction::CentralPowers)));
+        assert!(timeline.observe_flag("hill", Some(Faction::Allies)));
+        assert!(timeline.is_empty());
+    }
+}
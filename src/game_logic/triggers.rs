@@ -0,0 +1,157 @@
+// Scenario triggers
+// Conditions paired with effects, for custom missions without new code
+//
+// Each trigger fires at most once, the first time its condition holds.
+// Conditions look at the turn, flag ownership and casualties; effects end
+// the match, bring in reinforcements or post a message to the event log.
+// Ending the match and spawning soldiers need more than a system can do, so
+// those effects are recorded here and carried out by check_game_over and
+// spawn_triggered_reinforcements.
+
+use crate::components::soldier::Faction;
+use crate::game_logic::objectives::Objectives;
+use serde::Deserialize;
+
+/// When a trigger fires
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum TriggerCondition {
+    /// The turn counter has reached this turn
+    TurnAtLeast(u32),
+    /// `faction` holds the objective flag with id `flag`
+    FlagHeld { faction: Faction, flag: String },
+    /// More than `count` of `faction`'s soldiers are dead or surrendered
+    CasualtiesExceed { faction: Faction, count: usize },
+}
+
+impl TriggerCondition {
+    /// Whether the condition holds on `turn`
+    ///
+    /// `casualties` gives the number of a faction's soldiers who are down.
+    pub fn is_met(
+        &self,
+        turn: u32,
+        objectives: &Objectives,
+        casualties: impl Fn(Faction) -> usize,
+    ) -> bool {
+        match self {
+            TriggerCondition::TurnAtLeast(at) => turn >= *at,
+            TriggerCondition::FlagHeld { faction, flag } => objectives
+                .get_flag(flag)
+                .is_some_and(|flag| flag.is_held_by(*faction)),
+            TriggerCondition::CasualtiesExceed { faction, count } => casualties(*faction) > *count,
+        }
+    }
+}
+
+/// What a trigger does when it fires
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum TriggerEffect {
+    /// Bring `count` fresh soldiers in for `faction`
+    SpawnReinforcements { faction: Faction, count: usize },
+    /// End the match (no winner = a draw)
+    EndGame {
+        #[serde(default)]
+        winner: Option<Faction>,
+    },
+    /// Post a message to the event log
+    LogMessage(String),
+}
+
+/// A condition and the effect it sets off
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Trigger {
+    pub condition: TriggerCondition,
+    pub effect: TriggerEffect,
+}
+
+impl Trigger {
+    pub fn new(condition: TriggerCondition, effect: TriggerEffect) -> Self {
+        Self { condition, effect }
+    }
+}
+
+/// A match ended by a trigger
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptedEnd {
+    pub winner: Option<Faction>,
+    pub turn: u32,
+}
+
+/// Resource: the scenario's triggers and the effects still to carry out
+#[derive(Debug, Clone, Default)]
+pub struct Triggers {
+    triggers: Vec<Trigger>,
+    fired: Vec<bool>,
+    /// Set once an EndGame trigger fires
+    pub ended: Option<ScriptedEnd>,
+    /// Reinforcement waves fired but not yet spawned
+    pub pending_waves: Vec<(Faction, usize)>,
+}
+
+impl Triggers {
+    pub fn new(triggers: Vec<Trigger>) -> Self {
+        Self {
+            fired: vec![false; triggers.len()],
+            triggers,
+            ended: None,
+            pending_waves: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.triggers.is_empty()
+    }
+
+    /// Mark every trigger whose condition now holds as fired, returning their effects
+    pub fn fire(
+        &mut self,
+        turn: u32,
+        objectives: &Objectives,
+        casualties: impl Fn(Faction) -> usize,
+    ) -> Vec<TriggerEffect> {
+        let mut effects = Vec::new();
+        for (trigger, fired) in self.triggers.iter().zip(self.fired.iter_mut()) {
+            if !*fired && trigger.condition.is_met(turn, objectives, &casualties) {
+                *fired = true;
+                effects.push(trigger.effect.clone());
+            }
+        }
+        effects
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triggers_fire_once() {
+        let mut triggers = Triggers::new(vec![
+            Trigger::new(
+                TriggerCondition::TurnAtLeast(3),
+                TriggerEffect::LogMessage("Whistles blow".to_string()),
+            ),
+            Trigger::new(
+                TriggerCondition::CasualtiesExceed {
+                    faction: Faction::CentralPowers,
+                    count: 2,
+                },
+                TriggerEffect::EndGame {
+                    winner: Some(Faction::Allies),
+                },
+            ),
+        ]);
+        let objectives = Objectives::new();
+
+        assert!(triggers.fire(2, &objectives, |_| 0).is_empty());
+        assert_eq!(
+            triggers.fire(3, &objectives, |_| 2),
+            vec![TriggerEffect::LogMessage("Whistles blow".to_string())]
+        );
+        assert!(triggers.fire(4, &objectives, |_| 2).is_empty());
+        assert_eq!(triggers.fire(5, &objectives, |_| 3).len(), 1);
+    }
+}
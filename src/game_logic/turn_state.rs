@@ -1,8 +1,12 @@
 // Turn state management for turn-based gameplay
 
-use specs::Entity;
+use crate::components::{dead::Dead, soldier::{Faction, Soldier}};
+use specs::{Entity, Join, World, WorldExt};
 use std::collections::HashSet;
 
+/// Faction the player commands when they have no soldier of their own
+pub const COMMANDER_FACTION: Faction = Faction::Allies;
+
 /// Global turn state resource
 #[derive(Debug)]
 pub struct TurnState {
@@ -10,6 +14,7 @@ pub struct TurnState {
     pub phase: TurnPhase,
     pub turn_order_mode: TurnOrderMode,
     pub entities_ready: HashSet<Entity>,
+    pub factions_ready: HashSet<Faction>,
 }
 
 impl TurnState {
@@ -23,6 +28,7 @@ impl TurnState {
             phase: TurnPhase::Planning,
             turn_order_mode,
             entities_ready: HashSet::new(),
+            factions_ready: HashSet::new(),
         }
     }
 
@@ -34,9 +40,40 @@ impl TurnState {
         self.entities_ready.insert(entity);
     }
 
+    pub fn is_faction_ready(&self, faction: Faction) -> bool {
+        self.factions_ready.contains(&faction)
+    }
+
+    /// Mark a faction as done planning, along with each of its members
+    pub fn mark_faction_ready(&mut self, faction: Faction, members: impl IntoIterator<Item = Entity>) {
+        self.factions_ready.insert(faction);
+        self.entities_ready.extend(members);
+    }
+
+    /// Whether the player's side is done planning (PlayerFirst gating)
+    ///
+    /// With no Player entity the player is a commander without a soldier of
+    /// their own; they end the turn by marking the commander faction ready.
+    pub fn is_player_side_ready(&self, players: impl IntoIterator<Item = Entity>) -> bool {
+        let mut has_player = false;
+        for player in players {
+            if self.is_entity_ready(player) {
+                return true;
+            }
+            has_player = true;
+        }
+        !has_player && self.is_faction_ready(COMMANDER_FACTION)
+    }
+
+    /// End the planning phase for a commander without a Player entity
+    pub fn mark_commander_ready(&mut self) {
+        self.factions_ready.insert(COMMANDER_FACTION);
+    }
+
     pub fn reset_for_new_turn(&mut self) {
         self.current_turn += 1;
         self.entities_ready.clear();
+        self.factions_ready.clear();
         self.phase = TurnPhase::Planning;
     }
 }
@@ -68,3 +105,112 @@ pub enum TurnOrderMode {
     /// Speed stat determines action order (future)
     InitiativeBased,
 }
+
+/// Scope of an end-turn command issued by the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndTurnScope {
+    /// End only the acting soldier's own planning
+    SelfOnly,
+    /// Declare every living soldier of the actor's faction done
+    Faction,
+}
+
+/// Apply an end-turn command for `actor` to the TurnState resource
+pub fn end_turn(world: &World, actor: Entity, scope: EndTurnScope) {
+    let mut turn_state = world.write_resource::<TurnState>();
+
+    match scope {
+        EndTurnScope::SelfOnly => turn_state.mark_entity_ready(actor),
+        EndTurnScope::Faction => {
+            let soldiers = world.read_storage::<Soldier>();
+            let dead_markers = world.read_storage::<Dead>();
+
+            let faction = match soldiers.get(actor) {
+                Some(soldier) => soldier.faction,
+                None => {
+                    turn_state.mark_entity_ready(actor);
+                    return;
+                }
+            };
+
+            let members: Vec<Entity> = (&world.entities(), &soldiers, !&dead_markers)
+                .join()
+                .filter(|(_, soldier, _)| soldier.faction == faction)
+                .map(|(e, _, _)| e)
+                .collect();
+
+            turn_state.mark_faction_ready(faction, members);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::Rank;
+    use specs::Builder;
+
+    fn spawn(world: &mut World, faction: Faction) -> Entity {
+        world
+            .create_entity()
+            .with(Soldier {
+                name: "Test".to_string(),
+                faction,
+                rank: Rank::Private,
+            })
+            .build()
+    }
+
+    fn setup() -> (World, Entity, Entity, Entity, Entity) {
+        let mut world = World::new();
+        world.register::<Soldier>();
+        world.register::<Dead>();
+        world.insert(TurnState::new());
+
+        let player = spawn(&mut world, Faction::Allies);
+        let friendly = spawn(&mut world, Faction::Allies);
+        let fallen = spawn(&mut world, Faction::Allies);
+        let enemy = spawn(&mut world, Faction::CentralPowers);
+        world.write_storage::<Dead>().insert(fallen, Dead).unwrap();
+
+        (world, player, friendly, fallen, enemy)
+    }
+
+    #[test]
+    fn test_end_turn_self_marks_only_player() {
+        let (world, player, friendly, _, enemy) = setup();
+
+        end_turn(&world, player, EndTurnScope::SelfOnly);
+
+        let turn_state = world.read_resource::<TurnState>();
+        assert!(turn_state.is_entity_ready(player));
+        assert!(!turn_state.is_entity_ready(friendly));
+        assert!(!turn_state.is_entity_ready(enemy));
+        assert!(!turn_state.is_faction_ready(Faction::Allies));
+    }
+
+    #[test]
+    fn test_end_turn_faction_marks_living_friendlies() {
+        let (world, player, friendly, fallen, enemy) = setup();
+
+        end_turn(&world, player, EndTurnScope::Faction);
+
+        let turn_state = world.read_resource::<TurnState>();
+        assert!(turn_state.is_entity_ready(player));
+        assert!(turn_state.is_entity_ready(friendly));
+        assert!(!turn_state.is_entity_ready(fallen));
+        assert!(!turn_state.is_entity_ready(enemy));
+        assert!(turn_state.is_faction_ready(Faction::Allies));
+        assert!(!turn_state.is_faction_ready(Faction::CentralPowers));
+    }
+
+    #[test]
+    fn test_faction_readiness_clears_on_new_turn() {
+        let mut turn_state = TurnState::new();
+        turn_state.mark_faction_ready(Faction::Allies, Vec::new());
+
+        turn_state.reset_for_new_turn();
+
+        assert!(!turn_state.is_faction_ready(Faction::Allies));
+    }
+}
@@ -0,0 +1,788 @@
+// World setup helpers
+// Component registration, soldier spawning and headless skirmish worlds
+
+use crate::ai::{
+    contact::ContactTracker, intel::FactionIntel, scoring_snapshot::ScoringSnapshots,
+    standing_orders::StandingOrders,
+};
+use crate::components::{
+    action::{OngoingAction, QueuedAction},
+    ai_role::{AiRole, SCOUT_VISION_BONUS},
+    aiming::Aiming,
+    evacuated::Evacuated,
+    blood_trail::BloodTrail,
+    dead::Dead,
+    detonation::Detonations,
+    facing::{Direction8, Facing},
+    health::Health,
+    heard_shot::HeardShots,
+    inventory::Inventory,
+    last_seen::{FlashSightings, LastSeenMarker},
+    last_stand::LastStand,
+    morale::Morale,
+    muzzle_flash::MuzzleFlash,
+    pathfinding::PlannedPath,
+    player::Player,
+    position::Position,
+    prone::Prone,
+    rested::Rested,
+    soldier::{Faction, Rank, Soldier},
+    soldier_stats::SoldierStats,
+    squad::{BoundingRole, SquadMember},
+    surrendered::Surrendered,
+    time_budget::TimeBudget,
+    tracer::ShotTracers,
+    under_fire::UnderFire,
+    vision::Vision,
+    weapon::{SecondaryWeapon, Weapon},
+};
+use crate::config::{
+    battlefield_config::BattlefieldGenerationConfig,
+    game_config::{GameConfig, ObjectiveLayout, ObjectivesConfig},
+};
+use crate::game_logic::{
+    battlefield::{Battlefield, Position as BattlefieldPos},
+    objectives::{
+        create_central_objective, create_strategic_objectives, EvacPoint, ObjectiveFlag,
+        Objectives,
+    },
+    reinforcements::ReinforcementTracker,
+    soldier_spawning::{generate_name, generate_soldier_stats, RankDistribution},
+    spatial_index::PositionIndex,
+    surrender::Captures,
+    terrain_generation::BattlefieldGenerator,
+    timeline::Timeline,
+    triggers::Triggers,
+    turn_state::TurnState,
+};
+use crate::simulation::weather::Wind;
+use crate::utils::{event_log::EventLog, game_rng::{AiRng, GameRng}};
+use rand::Rng;
+use specs::{Builder, Entity, Join, World, WorldExt};
+
+/// Register every component the game systems read or write
+pub fn register_components(world: &mut World) {
+    world.register::<Position>();
+    world.register::<Soldier>();
+    world.register::<SoldierStats>();
+    world.register::<Player>();
+    world.register::<TimeBudget>();
+    world.register::<QueuedAction>();
+    world.register::<OngoingAction>();
+    world.register::<Vision>();
+    world.register::<PlannedPath>();
+    world.register::<Weapon>();
+    world.register::<SecondaryWeapon>();
+    world.register::<Health>();
+    world.register::<Inventory>();
+    world.register::<Dead>();
+    world.register::<Facing>();
+    world.register::<LastSeenMarker>();
+    world.register::<MuzzleFlash>();
+    world.register::<UnderFire>();
+    world.register::<SquadMember>();
+    world.register::<BoundingRole>();
+    world.register::<Morale>();
+    world.register::<Surrendered>();
+    world.register::<LastStand>();
+    world.register::<Rested>();
+    world.register::<Prone>();
+    world.register::<Aiming>();
+    world.register::<Evacuated>();
+    world.register::<BloodTrail>();
+    world.register::<AiRole>();
+}
+
+/// Spawn a single soldier with rank-based stats at the given position
+pub fn spawn_soldier(
+    world: &mut World,
+    pos: BattlefieldPos,
+    faction: Faction,
+    rank: Rank,
+    facing: Direction8,
+    config: &GameConfig,
+    rng: &mut impl Rng,
+) -> Entity {
+    let base_stats = config.rank_stats.get(rank);
+    let stats = generate_soldier_stats(rank, &base_stats, rng);
+    let name = generate_name(faction, rank, rng);
+    let max_hp = base_stats.base_hp + stats.max_hp_modifier;
+    let time_budget = config.time_budget_for(&stats);
+
+    let builder = world
+        .create_entity()
+        .with(Position::new(pos.x, pos.y))
+        .with(Soldier {
+            name,
+            faction,
+            rank,
+        })
+        .with(stats)
+        .with(TimeBudget::new(time_budget))
+        .with(Vision::new(base_stats.vision_range))
+        .with(Weapon::rifle())
+        .with(Health::new(max_hp))
+        .with(Inventory::for_rank(rank).with_wire(config.wire_coils))
+        .with(Morale::default())
+        .with(Facing::new(facing));
+
+    // NCOs and officers carry a pistol sidearm
+    if rank >= Rank::Sergeant {
+        builder.with(SecondaryWeapon::sidearm()).build()
+    } else {
+        builder.build()
+    }
+}
+
+/// Give every soldier still without one an AI role, rolled from the AI seed
+///
+/// Run once the forces are on the field, and again after reinforcements
+/// arrive. Scouts get their longer sight here, which carries into the
+/// side's shared vision.
+pub fn assign_ai_roles(world: &World) {
+    let entities = world.entities();
+    let soldiers = world.read_storage::<Soldier>();
+    let mut roles = world.write_storage::<AiRole>();
+    let mut visions = world.write_storage::<Vision>();
+    let mut rng = world.write_resource::<AiRng>();
+
+    let unassigned: Vec<(Entity, Rank)> = (&entities, &soldiers, !&roles)
+        .join()
+        .map(|(entity, soldier, _)| (entity, soldier.rank))
+        .collect();
+    for (entity, rank) in unassigned {
+        let role = AiRole::roll(rank, &mut *rng);
+        if role.is_scout()
+            && let Some(vision) = visions.get_mut(entity)
+        {
+            vision.range += SCOUT_VISION_BONUS;
+        }
+        roles.insert(entity, role).ok();
+    }
+}
+
+/// Spawn a squad at the given positions; the first soldier is always a Sergeant
+///
+/// The rest are ranked by rolls on `ranks`. The squad is identified by its
+/// sergeant's entity id.
+pub fn spawn_squad(
+    world: &mut World,
+    positions: &[BattlefieldPos],
+    faction: Faction,
+    facing: Direction8,
+    ranks: &RankDistribution,
+    config: &GameConfig,
+    rng: &mut impl Rng,
+) -> Vec<Entity> {
+    let members: Vec<Entity> = positions
+        .iter()
+        .enumerate()
+        .map(|(i, pos)| {
+            let rank = if i == 0 {
+                Rank::Sergeant
+            } else {
+                ranks.pick(rng)
+            };
+            spawn_soldier(world, *pos, faction, rank, facing, config, rng)
+        })
+        .collect();
+
+    if let Some(leader) = members.first() {
+        let squad = leader.id();
+        let mut storage = world.write_storage::<SquadMember>();
+        for (index, member) in members.iter().enumerate() {
+            storage.insert(*member, SquadMember::new(squad, index)).ok();
+        }
+    }
+
+    members
+}
+
+/// Facing from a faction's spawn zone across no-man's-land toward the enemy's
+///
+/// Spawn zones sit on opposite edges of the map (see `allies_side`), so the
+/// larger axis of the gap between them is the way to the enemy; the smaller
+/// one is only jitter from separating the zones.
+pub fn spawn_facing(battlefield: &Battlefield, faction: Faction) -> Direction8 {
+    let (home, enemy) = match faction {
+        Faction::Allies => (&battlefield.ally_spawn, &battlefield.enemy_spawn),
+        Faction::CentralPowers => (&battlefield.enemy_spawn, &battlefield.ally_spawn),
+    };
+    let (Some(home), Some(enemy)) = (home, enemy) else {
+        return Direction8::N;
+    };
+    let dx = enemy.center.x - home.center.x;
+    let dy = enemy.center.y - home.center.y;
+    let toward = if dx.abs() >= dy.abs() {
+        Direction8::from_movement(dx, 0)
+    } else {
+        Direction8::from_movement(0, dy)
+    };
+    toward.unwrap_or(Direction8::N)
+}
+
+/// Spawn both sides for a new game and return where the camera should start
+///
+/// Each side gets its own head count, so a large attacking force can be
+/// pitted against a handful of entrenched defenders; with dug_in_defenders the
+/// enemy starts in its own bunkers and trenches. Normally the first ally
+/// spawn holds the player's Sergeant and the allied squad fills the rest.
+/// In commander mode nobody carries the Player marker: the player orders
+/// the allied squad from above and the camera starts on it.
+pub fn spawn_forces(
+    world: &mut World,
+    battlefield: &Battlefield,
+    config: &GameConfig,
+    ally_count: usize,
+    enemy_count: usize,
+    rng: &mut impl Rng,
+) -> BattlefieldPos {
+    let player_slots = if config.commander_mode { 0 } else { 1 };
+    let ally_positions = battlefield.get_spawn_positions(true, ally_count + player_slots, rng);
+    let enemy_positions = if config.dug_in_defenders {
+        battlefield.get_dug_in_positions(false, enemy_count, rng)
+    } else {
+        battlefield.get_spawn_positions(false, enemy_count, rng)
+    };
+
+    if ally_positions.is_empty() {
+        panic!("Failed to generate ally spawn positions!");
+    }
+
+    let start_pos = ally_positions[0];
+    if !config.commander_mode {
+        let player_entity = spawn_soldier(
+            world,
+            start_pos,
+            Faction::Allies,
+            Rank::Sergeant,
+            spawn_facing(battlefield, Faction::Allies),
+            config,
+            rng,
+        );
+        world
+            .write_storage::<Player>()
+            .insert(player_entity, Player)
+            .expect("Failed to mark player entity");
+    }
+
+    let ally_count = ally_count.min(ally_positions.len() - player_slots);
+    spawn_squad(
+        world,
+        &ally_positions[player_slots..player_slots + ally_count],
+        Faction::Allies,
+        spawn_facing(battlefield, Faction::Allies),
+        &RankDistribution::default(),
+        config,
+        rng,
+    );
+
+    let enemy_count = enemy_count.min(enemy_positions.len());
+    spawn_squad(
+        world,
+        &enemy_positions[..enemy_count],
+        Faction::CentralPowers,
+        spawn_facing(battlefield, Faction::CentralPowers),
+        &config.enemy_ranks,
+        config,
+        rng,
+    );
+
+    start_pos
+}
+
+/// Insert the configured objective flags for a generated battlefield
+pub fn insert_objectives(world: &mut World, battlefield: &Battlefield, config: &ObjectivesConfig) {
+    world.insert(build_objectives(battlefield, config));
+}
+
+/// Lay out the starting flags for a battlefield
+pub fn build_objectives(battlefield: &Battlefield, config: &ObjectivesConfig) -> Objectives {
+    let mut objectives = Objectives::new();
+
+    if config.layout != ObjectiveLayout::Contested {
+        let (ally_flag_pos, enemy_flag_pos) = create_strategic_objectives(battlefield);
+        objectives.add_flag(
+            "allies".to_string(),
+            timed(
+                ObjectiveFlag::new(ally_flag_pos, Faction::Allies)
+                    .with_radius(config.capture_radius)
+                    .with_weight(config.home_flag_weight),
+                config.objective_timer,
+            ),
+        );
+        objectives.add_flag(
+            "central".to_string(),
+            timed(
+                ObjectiveFlag::new(enemy_flag_pos, Faction::CentralPowers)
+                    .with_radius(config.capture_radius)
+                    .with_weight(config.home_flag_weight),
+                config.objective_timer,
+            ),
+        );
+    }
+
+    if config.layout != ObjectiveLayout::Standard {
+        objectives.add_flag(
+            "neutral".to_string(),
+            ObjectiveFlag::neutral(create_central_objective(battlefield))
+                .with_radius(config.capture_radius)
+                .with_weight(config.neutral_flag_weight),
+        );
+    }
+
+    // Aid posts sit at the heart of each side's spawn, well behind the line
+    if config.casualty_evac {
+        for (zone, faction) in [
+            (&battlefield.ally_spawn, Faction::Allies),
+            (&battlefield.enemy_spawn, Faction::CentralPowers),
+        ] {
+            if let Some(zone) = zone {
+                objectives.add_evac_point(EvacPoint::new(zone.center, faction));
+            }
+        }
+    }
+
+    objectives
+}
+
+/// Put `flag` on the clock when the objective timer is on
+fn timed(flag: ObjectiveFlag, deadline: Option<u32>) -> ObjectiveFlag {
+    match deadline {
+        Some(turn) => flag.with_deadline(turn),
+        None => flag,
+    }
+}
+
+/// Generate the battlefield, burying mines only when random events are enabled
+pub fn generate_battlefield(
+    battlefield_config: BattlefieldGenerationConfig,
+    config: &GameConfig,
+) -> Battlefield {
+    let battlefield_config = if config.random_events.enabled {
+        battlefield_config.with_mine_density(config.random_events.mine_density)
+    } else {
+        battlefield_config
+    };
+
+    let mut battlefield = BattlefieldGenerator::new(battlefield_config).generate();
+    battlefield.set_diagonal_los(config.diagonal_los);
+    battlefield.set_path_heuristic(config.path_heuristic);
+    battlefield
+}
+
+/// Insert the turn state and every rule and tracker a match runs on
+///
+/// Shared by the interactive game and headless matches so both play by the
+/// same rules. Returns the match RNG, which the caller spawns forces with
+/// before inserting it. The event log is left to the caller.
+pub fn insert_match_resources(
+    world: &mut World,
+    config: &GameConfig,
+    battlefield_config: &BattlefieldGenerationConfig,
+) -> GameRng {
+    world.insert(TurnState::new_with_mode(config.turn_order_mode));
+    let mut game_rng = GameRng::new(battlefield_config.seed);
+    world.insert(AiRng::new(config.ai_seed.unwrap_or(battlefield_config.seed)));
+    world.insert(Wind::random(&mut game_rng));
+    world.insert(config.random_events.clone());
+    world.insert(config.attrition.clone());
+    world.insert(config.supply.clone());
+    world.insert(config.panic_fire.clone());
+    world.insert(config.fatigue.clone());
+    world.insert(config.wounded.clone());
+    world.insert(config.blood_trails.clone());
+    world.insert(config.move_conflict_rule);
+    world.insert(config.corpse_rule);
+    world.insert(config.blocked_step_rule);
+    world.insert(config.cover_model);
+    world.insert(config.reload_interrupt);
+    world.insert(config.log_verbosity);
+    world.insert(config.time_of_day);
+    world.insert(FlashSightings::default());
+    world.insert(HeardShots::new(config.heard_shot_turns));
+    world.insert(ShotTracers::new(config.shot_tracers));
+    world.insert(Detonations::new());
+    world.insert(config.personalities.clone());
+    world.insert(Captures::default());
+    world.insert(ContactTracker::new(config.spot_then_shoot_turns));
+    world.insert(FactionIntel::default());
+    let mut snapshots = ScoringSnapshots::default();
+    snapshots.recording = config.show_ai_scores;
+    world.insert(snapshots);
+    world.insert(StandingOrders::default());
+    world.insert(ReinforcementTracker::default());
+    world.insert(Timeline::new());
+    world.insert(Triggers::new(config.triggers.clone()));
+    game_rng
+}
+
+/// Build a headless world with a generated battlefield and AI-only squads
+///
+/// Forces are spawned as in commander mode, so no entity carries the Player
+/// marker. Suitable for benchmarks and AI-vs-AI runs; in PlayerFirst mode the
+/// planner waits for the allied side to be marked ready each turn.
+pub fn build_skirmish_world(
+    battlefield_config: BattlefieldGenerationConfig,
+    config: &GameConfig,
+    soldiers_per_side: usize,
+) -> World {
+    let mut world = World::new();
+    register_components(&mut world);
+
+    world.insert(EventLog::new());
+    let mut game_rng = insert_match_resources(&mut world, config, &battlefield_config);
+
+    let battlefield = generate_battlefield(battlefield_config, config);
+
+    let config = config.clone().with_commander_mode(true);
+    spawn_forces(
+        &mut world,
+        &battlefield,
+        &config,
+        soldiers_per_side,
+        soldiers_per_side,
+        &mut game_rng,
+    );
+    assign_ai_roles(&world);
+    world.insert(game_rng);
+    world.insert(PositionIndex::default());
+    PositionIndex::refresh(&world);
+
+    insert_objectives(&mut world, &battlefield, &config.objectives);
+    world.insert(battlefield);
+
+    world
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::battlefield_config::Side;
+    use crate::game_logic::turn_state::TurnOrderMode;
+    use specs::Join;
+
+    #[test]
+    fn test_spawn_soldier_has_rank_stats() {
+        let mut world = World::new();
+        register_components(&mut world);
+
+        let config = GameConfig::default();
+        let mut rng = rand::rng();
+        let entity = spawn_soldier(
+            &mut world,
+            BattlefieldPos::new(5, 5),
+            Faction::Allies,
+            Rank::Captain,
+            Direction8::N,
+            &config,
+            &mut rng,
+        );
+
+        let visions = world.read_storage::<Vision>();
+        assert_eq!(visions.get(entity).unwrap().range, Rank::Captain.base_stats().vision_range);
+
+        let positions = world.read_storage::<Position>();
+        assert_eq!(positions.get(entity).unwrap().x(), 5);
+    }
+
+    #[test]
+    fn test_all_private_force_spawns_only_privates_under_its_sergeant() {
+        let mut world = World::new();
+        register_components(&mut world);
+
+        let config = GameConfig::default().with_enemy_ranks(RankDistribution::new(100, 0, 0, 0));
+        let positions: Vec<_> = (0..12).map(|x| BattlefieldPos::new(x, 3)).collect();
+        let squad = spawn_squad(
+            &mut world,
+            &positions,
+            Faction::CentralPowers,
+            Direction8::S,
+            &config.enemy_ranks,
+            &config,
+            &mut rand::rng(),
+        );
+
+        let soldiers = world.read_storage::<Soldier>();
+        let ranks: Vec<_> = squad.iter().map(|e| soldiers.get(*e).unwrap().rank).collect();
+        assert_eq!(ranks[0], Rank::Sergeant);
+        assert!(ranks[1..].iter().all(|rank| *rank == Rank::Private), "{:?}", ranks);
+    }
+
+    #[test]
+    fn test_spawn_soldier_uses_configured_rank_stats() {
+        use crate::config::rank_stats::RankStatsTable;
+
+        let mut world = World::new();
+        register_components(&mut world);
+
+        let table = RankStatsTable::from_toml_str("[captain]\nbase_hp = 500\n").unwrap();
+        let config = GameConfig::default().with_rank_stats(table);
+        let mut rng = rand::rng();
+        let entity = spawn_soldier(
+            &mut world,
+            BattlefieldPos::new(5, 5),
+            Faction::Allies,
+            Rank::Captain,
+            Direction8::N,
+            &config,
+            &mut rng,
+        );
+
+        let max_hp = world.read_storage::<Health>().get(entity).unwrap().maximum;
+        assert!((495..=505).contains(&max_hp), "max hp {}", max_hp);
+    }
+
+    #[test]
+    fn test_faster_soldier_spawns_with_larger_budget_under_rank_budgets() {
+        use crate::config::rank_stats::RankStatsTable;
+
+        let mut world = World::new();
+        register_components(&mut world);
+
+        let table = RankStatsTable::from_toml_str(
+            "[private]\nmovement_speed_base = 0.9\n[captain]\nmovement_speed_base = 1.3\n",
+        )
+        .unwrap();
+        let mut rng = rand::rng();
+        let mut budget_of = |world: &mut World, rank: Rank, config: &GameConfig| {
+            let entity = spawn_soldier(
+                world,
+                BattlefieldPos::new(5, 5),
+                Faction::Allies,
+                rank,
+                Direction8::N,
+                config,
+                &mut rng,
+            );
+            world.read_storage::<TimeBudget>().get(entity).unwrap().base_duration
+        };
+
+        let flat = GameConfig::default().with_rank_stats(table.clone());
+        assert_eq!(budget_of(&mut world, Rank::Private, &flat), flat.time_budget_seconds);
+        assert_eq!(budget_of(&mut world, Rank::Captain, &flat), flat.time_budget_seconds);
+
+        let scaled = flat.with_rank_time_budgets(true);
+        let slow = budget_of(&mut world, Rank::Private, &scaled);
+        let fast = budget_of(&mut world, Rank::Captain, &scaled);
+        assert!(fast > scaled.time_budget_seconds, "fast budget {}", fast);
+        assert!(fast > slow, "fast {} vs slow {}", fast, slow);
+    }
+
+    #[test]
+    fn test_mines_only_generated_with_random_events() {
+        let battlefield_config = BattlefieldGenerationConfig::default();
+
+        let quiet = generate_battlefield(battlefield_config.clone(), &GameConfig::default());
+        assert_eq!(quiet.mine_count(), 0);
+
+        let eventful = generate_battlefield(
+            battlefield_config,
+            &GameConfig::default().with_random_events(true),
+        );
+        assert!(eventful.mine_count() > 0);
+    }
+
+    #[test]
+    fn test_skirmish_world_has_both_factions() {
+        let config = GameConfig::default().with_turn_order_mode(TurnOrderMode::Simultaneous);
+        let world = build_skirmish_world(BattlefieldGenerationConfig::default(), &config, 5);
+
+        let soldiers = world.read_storage::<Soldier>();
+        let players = world.read_storage::<Player>();
+
+        let allies = soldiers.join().filter(|s| s.faction == Faction::Allies).count();
+        let central = soldiers.join().filter(|s| s.faction == Faction::CentralPowers).count();
+
+        assert!(allies > 0);
+        assert!(central > 0);
+        assert_eq!(players.join().count(), 0);
+        assert_eq!(world.read_resource::<Objectives>().flags.len(), 2);
+    }
+
+    #[test]
+    fn test_contested_layout_starts_with_neutral_flag() {
+        let battlefield =
+            generate_battlefield(BattlefieldGenerationConfig::default(), &GameConfig::default());
+        let config = ObjectivesConfig {
+            layout: ObjectiveLayout::Contested,
+            ..ObjectivesConfig::default()
+        };
+
+        let objectives = build_objectives(&battlefield, &config);
+
+        assert_eq!(objectives.flags.len(), 1);
+        let flag = objectives.get_flag("neutral").unwrap();
+        assert_eq!(flag.owning_faction, None);
+        assert_eq!(objectives.check_victory(), None);
+
+        let with_home_flags = build_objectives(
+            &battlefield,
+            &ObjectivesConfig {
+                layout: ObjectiveLayout::NeutralCenter,
+                ..ObjectivesConfig::default()
+            },
+        );
+        assert_eq!(with_home_flags.flags.len(), 3);
+    }
+
+    #[test]
+    fn test_commander_mode_runs_turns_without_player() {
+        use crate::systems::{
+            action_execution::ActionExecutionSystem, ai_action_planner::AIActionPlannerSystem,
+            path_execution::PathExecutionSystem, turn_manager::TurnManagerSystem,
+        };
+        use specs::DispatcherBuilder;
+
+        let config = GameConfig::default().with_commander_mode(true);
+        let mut world = World::new();
+        register_components(&mut world);
+        let battlefield = generate_battlefield(BattlefieldGenerationConfig::default(), &config);
+        spawn_forces(&mut world, &battlefield, &config, 3, 3, &mut GameRng::new(1));
+        assert_eq!(world.read_storage::<Player>().join().count(), 0);
+        assert_eq!(world.read_storage::<Soldier>().join().count(), 6);
+
+        let mut world = build_skirmish_world(BattlefieldGenerationConfig::default(), &config, 3);
+        let mut dispatcher = DispatcherBuilder::new()
+            .with(PathExecutionSystem, "path_execution", &[])
+            .with(AIActionPlannerSystem, "ai_planner", &["path_execution"])
+            .with(TurnManagerSystem, "turn_manager", &["ai_planner"])
+            .with(ActionExecutionSystem, "action_execution", &["turn_manager"])
+            .build();
+
+        // Nothing moves until the commander ends planning
+        dispatcher.dispatch(&world);
+        world.maintain();
+        assert_eq!(world.read_resource::<TurnState>().current_turn, 1);
+
+        for _ in 0..3 {
+            let turn = world.read_resource::<TurnState>().current_turn;
+            world.write_resource::<TurnState>().mark_commander_ready();
+            for _ in 0..50 {
+                dispatcher.dispatch(&world);
+                world.maintain();
+                if world.read_resource::<TurnState>().current_turn > turn {
+                    break;
+                }
+            }
+            assert_eq!(world.read_resource::<TurnState>().current_turn, turn + 1);
+        }
+    }
+
+    #[test]
+    fn test_sides_spawn_their_own_counts() {
+        let config = GameConfig::default();
+        let mut world = World::new();
+        register_components(&mut world);
+        let battlefield = generate_battlefield(BattlefieldGenerationConfig::default(), &config);
+        spawn_forces(&mut world, &battlefield, &config, 30, 10, &mut GameRng::new(1));
+
+        let soldiers = world.read_storage::<Soldier>();
+        let players = world.read_storage::<Player>();
+        let count = |faction| {
+            (&world.entities(), &soldiers)
+                .join()
+                .filter(|(entity, soldier)| soldier.faction == faction && !players.contains(*entity))
+                .count()
+        };
+        assert_eq!(count(Faction::Allies), 30);
+        assert_eq!(count(Faction::CentralPowers), 10);
+    }
+
+    #[test]
+    fn test_dug_in_defenders_start_in_fortifications() {
+        use crate::game_logic::battlefield::{SpawnZone, TerrainType};
+
+        let mut battlefield = Battlefield::new(40, 40);
+        battlefield.ally_spawn = Some(SpawnZone::new(BattlefieldPos::new(20, 34), 4));
+        battlefield.enemy_spawn = Some(SpawnZone::new(BattlefieldPos::new(20, 5), 4));
+        battlefield.set_terrain(BattlefieldPos::new(8, 12), TerrainType::Bunker);
+        battlefield.set_terrain(BattlefieldPos::new(32, 12), TerrainType::MgNest);
+        for x in 10..30 {
+            battlefield.set_terrain(BattlefieldPos::new(x, 14), TerrainType::TrenchFloor);
+        }
+        // A trench on the attackers' half is not the defenders' to man
+        battlefield.set_terrain(BattlefieldPos::new(20, 28), TerrainType::TrenchFloor);
+
+        let config = GameConfig::default().with_dug_in_defenders(true);
+        let mut world = World::new();
+        register_components(&mut world);
+        spawn_forces(&mut world, &battlefield, &config, 3, 6, &mut GameRng::new(5));
+
+        let soldiers = world.read_storage::<Soldier>();
+        let positions = world.read_storage::<Position>();
+        let defenders: Vec<BattlefieldPos> = (&soldiers, &positions)
+            .join()
+            .filter(|(soldier, _)| soldier.faction == Faction::CentralPowers)
+            .map(|(_, pos)| *pos.as_battlefield_pos())
+            .collect();
+        assert_eq!(defenders.len(), 6);
+        for pos in &defenders {
+            let terrain = battlefield.get_tile(pos).unwrap().terrain;
+            assert!(terrain.is_dug_in(), "defender at {:?} on {:?}", pos, terrain);
+            assert!(pos.y < 20, "defender at {:?} left its own half", pos);
+        }
+        assert!(defenders.contains(&BattlefieldPos::new(8, 12)), "bunker manned first");
+        assert!(defenders.contains(&BattlefieldPos::new(32, 12)), "MG nest manned first");
+    }
+
+    #[test]
+    fn test_east_allies_spawn_facing_the_enemy() {
+        let config = GameConfig::default();
+        let battlefield_config = BattlefieldGenerationConfig::default()
+            .with_dimensions(60, 40)
+            .with_allies_side(Side::East);
+        let mut world = World::new();
+        register_components(&mut world);
+        let battlefield = generate_battlefield(battlefield_config, &config);
+        spawn_forces(&mut world, &battlefield, &config, 5, 5, &mut GameRng::new(3));
+
+        let soldiers = world.read_storage::<Soldier>();
+        let facings = world.read_storage::<Facing>();
+        let mut seen = 0;
+        for (soldier, facing) in (&soldiers, &facings).join() {
+            let expected = match soldier.faction {
+                Faction::Allies => Direction8::W,
+                Faction::CentralPowers => Direction8::E,
+            };
+            assert_eq!(facing.direction, expected, "{} faces the wrong way", soldier.name);
+            seen += 1;
+        }
+        assert_eq!(seen, 11);
+    }
+
+    #[test]
+    fn test_same_ai_seed_gives_the_same_roster_the_same_roles() {
+        let roles_for = |seed| {
+            let mut world = World::new();
+            register_components(&mut world);
+            world.insert(AiRng::new(seed));
+            for _ in 0..200 {
+                world
+                    .create_entity()
+                    .with(Soldier {
+                        name: "Pvt. Hale".to_string(),
+                        faction: Faction::Allies,
+                        rank: Rank::Private,
+                    })
+                    .with(Vision::new(10))
+                    .build();
+            }
+            assign_ai_roles(&world);
+
+            let roles = world.read_storage::<AiRole>();
+            let visions = world.read_storage::<Vision>();
+            (&roles, &visions)
+                .join()
+                .map(|(role, vision)| {
+                    let expected = if role.is_scout() { 10 + SCOUT_VISION_BONUS } else { 10 };
+                    assert_eq!(vision.range, expected);
+                    *role
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(roles_for(42), roles_for(42));
+        assert_ne!(roles_for(42), roles_for(43));
+    }
+}
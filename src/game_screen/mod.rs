@@ -0,0 +1,423 @@
+// Game screen
+// Draws the battle: battlefield panel, event log, context panel and info bar
+//
+// Everything here reads GameState and draws; input handling stays in main.
+// ScreenLayout is the one place the panels are laid out, so anything that
+// needs the battlefield's screen area takes it from there.
+
+mod overlays;
+mod panels;
+mod targeting;
+
+use overlays::{
+    render_blood_trails, render_cursor, render_heard_shots, render_last_seen_markers, render_muzzle_flashes,
+    render_paths, render_soldiers, render_step_costs, render_tracers,
+};
+use panels::{objective_timers_text, orders_text, render_ai_scores, render_context_info};
+use targeting::render_targeting_cursor;
+
+use argue_the_toss::{
+    components::{
+        dead::Dead,
+        facing::Facing,
+        health::Health,
+        inventory::Inventory,
+        position::Position,
+        soldier::{Faction, Soldier},
+        surrendered::Surrendered,
+        time_budget::TimeBudget,
+        vision::Vision,
+        weapon::Weapon,
+    },
+    game_logic::{
+        battlefield::Position as BattlefieldPos,
+        deployment::Deployment,
+        fog_of_war::EnemyInfoLevel,
+        force_stats::force_stats,
+        objectives::Objectives,
+        threat_compass::nearest_threat,
+        turn_state::{TurnState, COMMANDER_FACTION},
+    },
+    rendering::{
+        fire_cone::{FireConeOverlay, FireConeOverlayWidget},
+        sightlines::{SightlineOverlay, SightlineOverlayWidget},
+        widgets::BattlefieldWidget,
+    },
+    simulation::weather::Wind,
+    utils::{event_log::EventLog, input_mode::InputMode},
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Text},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+use specs::{Join, WorldExt};
+use std::time::Instant;
+
+use crate::GameState;
+
+/// Where each panel of the in-game screen sits
+struct ScreenLayout {
+    /// Battlefield panel, border included
+    battlefield: Rect,
+    event_log: Rect,
+    /// Cursor/target details, or the AI scoring overlay
+    context: Rect,
+    /// Player or commander info along the bottom
+    info: Rect,
+}
+
+impl ScreenLayout {
+    fn new(area: Rect) -> Self {
+        // Main layout: Top (battlefield + right pane) and Bottom (info panel)
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(10),      // Top: battlefield + right pane
+                Constraint::Length(7),    // Bottom: info panel
+            ])
+            .split(area);
+
+        // Top split: Battlefield (left), Event Log + Context Info (right)
+        let top_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(75),  // Battlefield
+                Constraint::Percentage(25),  // Right pane (event log + context)
+            ])
+            .split(main_chunks[0]);
+
+        // Split right pane vertically into Event Log (top) and Context Info (bottom)
+        let right_pane_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(60),  // Event Log
+                Constraint::Percentage(40),  // Context Info (cursor/target)
+            ])
+            .split(top_chunks[1]);
+
+        Self {
+            battlefield: top_chunks[0],
+            event_log: right_pane_chunks[0],
+            context: right_pane_chunks[1],
+            info: main_chunks[1],
+        }
+    }
+
+    /// Inside of the battlefield panel's border, where the map is drawn
+    fn battlefield_inner(&self) -> Rect {
+        Block::default().borders(Borders::ALL).inner(self.battlefield)
+    }
+}
+
+/// Draw the in-game screen: battlefield, event log, context panel and info bar
+pub fn ui(f: &mut Frame, state: &GameState) {
+    let layout = ScreenLayout::new(f.area());
+
+    // Render battlefield
+    let battlefield_block = Block::default()
+        .title("Battlefield")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White));
+
+    let inner_area = layout.battlefield_inner();
+    f.render_widget(battlefield_block, layout.battlefield);
+
+    let objectives = state.world.fetch::<Objectives>();
+    let battlefield_widget = BattlefieldWidget::new(&state.battlefield, &state.camera)
+        .with_peripheral_tiles(&state.peripheral_tiles)
+        .with_objectives(&objectives)
+        .with_glyphs(&state.config.glyphs);
+    f.render_widget(battlefield_widget, inner_area);
+    drop(objectives);
+
+    // Render planned paths (before soldiers so they appear underneath)
+    render_paths(f, inner_area, state);
+
+    // Render enemy blood trails (under markers and soldiers)
+    render_blood_trails(f, inner_area, state);
+
+    // Render last-seen markers (before soldiers, so they appear underneath)
+    render_last_seen_markers(f, inner_area, state);
+
+    // Shot tracers pass under the soldiers firing and being fired at
+    render_tracers(f, inner_area, state);
+
+    // Render soldiers on top
+    render_soldiers(f, inner_area, state);
+
+    // Render muzzle flashes (on top of soldiers)
+    render_muzzle_flashes(f, inner_area, state);
+
+    // Where unseen enemies were heard firing
+    render_heard_shots(f, inner_area, state);
+
+    // Step cost preview around the player
+    if state.input_mode == InputMode::Command && state.show_step_costs {
+        render_step_costs(f, inner_area, state);
+    }
+
+    // Fire cone: tint where the player can see and shoot without turning
+    if state.input_mode == InputMode::Command
+        && state.show_fire_cone
+        && let Some(player) = state.get_player_entity()
+    {
+        let positions = state.world.read_storage::<Position>();
+        let facings = state.world.read_storage::<Facing>();
+        let visions = state.world.read_storage::<Vision>();
+        if let (Some(pos), Some(facing), Some(vision)) =
+            (positions.get(player), facings.get(player), visions.get(player))
+        {
+            let overlay = FireConeOverlay::new(
+                *pos.as_battlefield_pos(),
+                facing.direction,
+                vision.range,
+                &state.battlefield,
+            );
+            f.render_widget(FireConeOverlayWidget::new(&overlay, &state.camera), inner_area);
+        }
+    }
+
+    // Sightline peek: tint what a soldier at the cursor could see
+    if state.input_mode == InputMode::Look && state.peek_sightlines {
+        let overlay = SightlineOverlay::with_default_range(state.cursor_pos, &state.battlefield);
+        f.render_widget(SightlineOverlayWidget::new(&overlay, &state.camera), inner_area);
+    }
+
+    // Render cursor in Look mode or Targeting mode
+    if state.input_mode == InputMode::Look {
+        render_cursor(f, inner_area, state);
+    } else if state.input_mode == InputMode::Targeting {
+        render_targeting_cursor(f, inner_area, state);
+    }
+
+    // Render event log (top of right pane)
+    let log_flashing = state
+        .log_flash_until
+        .is_some_and(|until| Instant::now() < until);
+    let event_log_block = Block::default()
+        .title("Event Log")
+        .borders(Borders::ALL)
+        .border_style(if log_flashing {
+            Style::default().fg(Color::Black).bg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::Cyan)
+        });
+
+    let event_lines: Vec<Line> = {
+        let event_log = state.world.fetch::<EventLog>();
+        event_log
+            .recent(15)
+            .iter()
+            .map(|e| Line::from(e.to_string()))
+            .collect()
+    };
+
+    let event_paragraph = Paragraph::new(Text::from(event_lines))
+        .block(event_log_block)
+        .wrap(Wrap { trim: true });
+    f.render_widget(event_paragraph, layout.event_log);
+
+    // Render context info (bottom of right pane), or the AI scoring overlay
+    if state.show_ai_scores {
+        render_ai_scores(f, layout.context, state);
+    } else {
+        render_context_info(f, layout.context, state);
+    }
+
+    // Render player info panel (bottom)
+    let mode_color = match state.input_mode {
+        InputMode::Command => Color::Green,
+        InputMode::Look => Color::Yellow,
+        InputMode::Targeting => Color::Red,
+    };
+
+    let info_block = Block::default()
+        .title(format!("Mode: {} | Player Info", state.input_mode.name()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(mode_color));
+
+    let commander = state.get_player_entity().is_none();
+    let help_text = if commander {
+        state.input_mode.commander_help_text()
+    } else {
+        state.input_mode.help_text()
+    };
+    let mut info_lines = vec![Line::from(help_text), Line::from("")];
+
+    // A commander has no personal stats; summarise the allied side instead
+    if commander {
+        let soldiers = state.world.read_storage::<Soldier>();
+        let dead_markers = state.world.read_storage::<Dead>();
+        let surrendered = state.world.read_storage::<Surrendered>();
+        let standing = (&soldiers, !&dead_markers, !&surrendered)
+            .join()
+            .filter(|(soldier, _, _)| soldier.faction == COMMANDER_FACTION)
+            .count();
+        let turn_state = state.world.fetch::<TurnState>();
+        let wind = state.world.fetch::<Wind>();
+        info_lines.push(Line::from(format!(
+            "Commander | Turn {} | Men standing: {} | Wind: {} | Orders: {}{}",
+            turn_state.current_turn,
+            standing,
+            wind.display(),
+            orders_text(&state.world, state.commanded_faction(), turn_state.current_turn),
+            objective_timers_text(&state.world, turn_state.current_turn)
+        )));
+
+        // Each side's strength, one line apiece
+        if state.config.commander_stats {
+            let enemy = state.commanded_faction().opposing();
+            for faction in [Faction::Allies, Faction::CentralPowers] {
+                let sized_up = faction != enemy || state.config.enemy_info == EnemyInfoLevel::Full;
+                info_lines.push(Line::from(force_stats(&state.world, faction).display(faction, sized_up)));
+            }
+        }
+    }
+
+    // Show player info
+    if let Some(player_entity) = state.get_player_entity() {
+        let positions = state.world.read_storage::<Position>();
+        let time_budgets = state.world.read_storage::<TimeBudget>();
+        let weapons = state.world.read_storage::<Weapon>();
+        let healths = state.world.read_storage::<Health>();
+        let inventories = state.world.read_storage::<Inventory>();
+        let turn_state = state.world.fetch::<TurnState>();
+
+        // Player position and wind
+        if let Some(pos) = positions.get(player_entity) {
+            let wind = state.world.fetch::<Wind>();
+            info_lines.push(Line::from(format!(
+                "Position: ({}, {}) | Wind: {} | Orders: {}{}",
+                pos.x(),
+                pos.y(),
+                wind.display(),
+                orders_text(&state.world, state.commanded_faction(), turn_state.current_turn),
+                objective_timers_text(&state.world, turn_state.current_turn)
+            )));
+        }
+
+        // Player HP with color coding
+        if let Some(health) = healths.get(player_entity) {
+            let hp_color_name = state
+                .config
+                .hud_thresholds
+                .hp_status(health.percentage())
+                .color_name();
+
+            // Threat compass: nearest visible enemy, else last known position
+            let threat = positions.get(player_entity).and_then(|pos| {
+                let visible: Vec<BattlefieldPos> = state
+                    .visible_entities
+                    .iter()
+                    .filter_map(|e| positions.get(*e).map(|p| *p.as_battlefield_pos()))
+                    .collect();
+                let last_seen: Vec<BattlefieldPos> = state
+                    .last_seen_markers
+                    .values()
+                    .map(|marker| *marker.position.as_battlefield_pos())
+                    .collect();
+                nearest_threat(pos.as_battlefield_pos(), &visible, &last_seen)
+            });
+            let threat_text = threat
+                .map(|t| t.display())
+                .unwrap_or_else(|| "none known".to_string());
+
+            info_lines.push(Line::from(format!(
+                "HP: {}/{} ({}%) [{}] | Threat: {}",
+                health.current,
+                health.maximum,
+                health.percentage_display(),
+                hp_color_name,
+                threat_text
+            )));
+        }
+
+        // Weapon info
+        if let Some(weapon) = weapons.get(player_entity) {
+            let heat = if weapon.is_overheated() {
+                " | OVERHEATED".to_string()
+            } else if weapon.heat > 0.0 {
+                format!(" | Heat: {:.0}", weapon.heat)
+            } else {
+                String::new()
+            };
+            // A bolt-action reload part way in
+            let reloading = state
+                .world
+                .read_storage::<argue_the_toss::components::action::OngoingAction>()
+                .get(player_entity)
+                .filter(|reload| reload.is_reload())
+                .map(|reload| {
+                    format!(" | Reloading... {:.0}/{:.0}s", reload.time_completed, reload.total_time)
+                })
+                .unwrap_or_default();
+            info_lines.push(Line::from(format!(
+                "Weapon: {} | Ammo: {}/{} ({:.0}%){}{}",
+                weapon.stats.name,
+                weapon.ammo.current,
+                weapon.ammo.max_capacity,
+                weapon.ammo.percentage(),
+                heat,
+                reloading
+            )));
+        }
+
+        // Consumables
+        if let Some(inventory) = inventories.get(player_entity) {
+            info_lines.push(Line::from(format!(
+                "Grenades: {} | Smoke: {} | Medkits: {}",
+                inventory.grenades, inventory.smoke_grenades, inventory.medkits
+            )));
+        }
+
+        // Time budget
+        if let Some(budget) = time_budgets.get(player_entity) {
+            let available = budget.available_time();
+            let budget_status = state
+                .config
+                .hud_thresholds
+                .time_status(available, budget.time_debt)
+                .label();
+
+            let time_info = if budget.time_debt < 0.0 {
+                format!(
+                    "Turn {} | Time: {:.1}s ({}) | Debt: {:.1}s",
+                    turn_state.current_turn, available, budget_status, -budget.time_debt
+                )
+            } else {
+                format!(
+                    "Turn {} | Time: {:.1}s ({})",
+                    turn_state.current_turn, available, budget_status
+                )
+            };
+
+            info_lines.push(Line::from(time_info));
+        }
+    }
+
+    let info_paragraph = Paragraph::new(Text::from(info_lines)).block(info_block);
+    f.render_widget(info_paragraph, layout.info);
+}
+
+/// Deployment cursor and instructions, drawn over the battlefield
+pub fn render_deployment(f: &mut Frame, state: &GameState, deployment: &Deployment) {
+    let area = ScreenLayout::new(f.area()).battlefield_inner();
+    render_cursor(f, area, state);
+
+    let placing = deployment
+        .selected()
+        .and_then(|entity| state.world.read_storage::<Soldier>().get(entity).map(|s| s.name.clone()))
+        .unwrap_or_default();
+    let banner = format!(
+        " DEPLOYMENT | Placing: {} | hjkl: cursor | Tab: next soldier | Enter: place | Space: begin battle ",
+        placing
+    );
+    let banner_area = Rect::new(area.x, area.y, area.width, 1.min(area.height));
+    f.render_widget(
+        Paragraph::new(banner).style(Style::default().fg(Color::Black).bg(Color::Yellow)),
+        banner_area,
+    );
+}
@@ -0,0 +1,425 @@
+// Battlefield overlays
+// Soldiers, paths, markers and cursors drawn over the terrain widget
+
+use argue_the_toss::{
+    components::{
+        blood_trail::BloodTrail,
+        dead::Dead,
+        health::Health,
+        heard_shot::HeardShots,
+        muzzle_flash::MuzzleFlash,
+        pathfinding::PlannedPath,
+        player::Player,
+        position::Position,
+        soldier::{Faction, Soldier},
+        time_budget::TimeBudget,
+        tracer::ShotTracers,
+    },
+    game_logic::{
+        action_preview::{effective_load, step_costs},
+        battlefield::Position as BattlefieldPos,
+        turn_state::{TurnState, COMMANDER_FACTION},
+    },
+};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    Frame,
+};
+use specs::{Join, WorldExt};
+
+use crate::GameState;
+
+pub fn render_paths(f: &mut Frame, area: Rect, state: &GameState) {
+    let entities = state.world.entities();
+    let paths = state.world.read_storage::<PlannedPath>();
+
+    let top_left = state.camera.top_left();
+
+    for (_entity, path) in (&entities, &paths).join() {
+        // Only render paths with preview enabled
+        if !path.show_preview {
+            continue;
+        }
+
+        for (i, pos) in path.steps.iter().enumerate() {
+            let screen_x = pos.x - top_left.x;
+            let screen_y = pos.y - top_left.y;
+
+            // Only render if within viewport
+            if screen_x >= 0
+                && screen_x < area.width as i32
+                && screen_y >= 0
+                && screen_y < area.height as i32
+            {
+                let buf_x = area.x + screen_x as u16;
+                let buf_y = area.y + screen_y as u16;
+
+                if buf_x < area.right() && buf_y < area.bottom() {
+                    // Show numbered path (1-9, then +)
+                    let ch = if i < 9 {
+                        char::from_digit((i + 1) as u32, 10).unwrap()
+                    } else {
+                        '+'
+                    };
+
+                    f.buffer_mut()[(buf_x, buf_y)]
+                        .set_char(ch)
+                        .set_style(Style::default().fg(Color::Cyan).bg(Color::DarkGray));
+                }
+            }
+        }
+    }
+}
+
+pub fn render_soldiers(f: &mut Frame, area: Rect, state: &GameState) {
+    let entities = state.world.entities();
+    let positions = state.world.read_storage::<Position>();
+    let soldiers = state.world.read_storage::<Soldier>();
+    let players = state.world.read_storage::<Player>();
+    let dead_markers = state.world.read_storage::<Dead>();
+    let muzzle_flashes = state.world.read_storage::<MuzzleFlash>();
+
+    let top_left = state.camera.top_left();
+
+    // First pass: render dead soldiers (corpses at bottom z-level)
+    for (entity, pos, soldier) in (&entities, &positions, &soldiers).join() {
+        if !dead_markers.contains(entity) {
+            continue; // Skip living soldiers in this pass
+        }
+
+        // Only render if visible to player (FOV check)
+        // Allied corpses always visible (you know where your fallen are)
+        let is_ally = soldier.faction == Faction::Allies;
+        if !is_ally && !state.reveal_map && !state.visible_entities.contains(&entity) {
+            continue;
+        }
+
+        let screen_x = pos.x() - top_left.x;
+        let screen_y = pos.y() - top_left.y;
+
+        // Only render if within viewport
+        if screen_x >= 0
+            && screen_x < area.width as i32
+            && screen_y >= 0
+            && screen_y < area.height as i32
+        {
+            let buf_x = area.x + screen_x as u16;
+            let buf_y = area.y + screen_y as u16;
+
+            if buf_x < area.right() && buf_y < area.bottom() {
+                f.buffer_mut()[(buf_x, buf_y)]
+                    .set_char('X') // Dead bodies shown as X
+                    .set_style(Style::default().fg(Color::DarkGray));
+            }
+        }
+    }
+
+    // Second pass: render living soldiers (on top of corpses)
+    for (entity, pos, soldier) in (&entities, &positions, &soldiers).join() {
+        if dead_markers.contains(entity) {
+            continue; // Skip dead soldiers in this pass
+        }
+
+        // Only render if:
+        // 1. Player (always visible)
+        // 2. Allied unit (always visible - you know where your allies are)
+        // 3. Enemy in visible_entities (FOV check)
+        // 4. Entity has muzzle flash (revealed by firing)
+        let is_ally = soldier.faction == Faction::Allies;
+        let recently_fired = muzzle_flashes.contains(entity);
+        if !players.contains(entity)
+            && !is_ally
+            && !recently_fired
+            && !state.reveal_map
+            && !state.visible_entities.contains(&entity)
+        {
+            continue;
+        }
+
+        let screen_x = pos.x() - top_left.x;
+        let screen_y = pos.y() - top_left.y;
+
+        // Only render if within viewport
+        if screen_x >= 0
+            && screen_x < area.width as i32
+            && screen_y >= 0
+            && screen_y < area.height as i32
+        {
+            let buf_x = area.x + screen_x as u16;
+            let buf_y = area.y + screen_y as u16;
+
+            if buf_x < area.right() && buf_y < area.bottom() {
+                let ch = if players.contains(entity) {
+                    '@' // Player character
+                } else {
+                    state.config.glyphs.rank(soldier.rank) // Rank icon
+                };
+
+                let color = if players.contains(entity) {
+                    Color::Rgb(0, 255, 255) // Player is bright cyan (unique color)
+                } else {
+                    match soldier.faction {
+                        Faction::Allies => Color::Blue,
+                        Faction::CentralPowers => Color::Red,
+                    }
+                };
+
+                f.buffer_mut()[(buf_x, buf_y)]
+                    .set_char(ch)
+                    .set_style(Style::default().fg(color));
+            }
+        }
+    }
+}
+
+pub fn render_tracers(f: &mut Frame, area: Rect, state: &GameState) {
+    let tracers = state.world.read_resource::<ShotTracers>();
+    let top_left = state.camera.top_left();
+
+    for tracer in tracers.iter() {
+        // Enemy fire only shows when the shooter is in sight
+        if tracer.faction != Faction::Allies
+            && !state.reveal_map
+            && !state.visible_entities.contains(&tracer.shooter)
+        {
+            continue;
+        }
+        let color = match tracer.faction {
+            Faction::Allies => Color::LightBlue,
+            Faction::CentralPowers => Color::LightRed,
+        };
+
+        for pair in tracer.path.windows(2) {
+            let (from, pos) = (pair[0], pair[1]);
+            let ch = match (pos.x - from.x, pos.y - from.y) {
+                (0, _) => '|',
+                (_, 0) => '-',
+                (dx, dy) if dx == dy => '\\',
+                _ => '/',
+            };
+
+            let screen_x = pos.x - top_left.x;
+            let screen_y = pos.y - top_left.y;
+            if screen_x >= 0
+                && screen_x < area.width as i32
+                && screen_y >= 0
+                && screen_y < area.height as i32
+            {
+                let buf_x = area.x + screen_x as u16;
+                let buf_y = area.y + screen_y as u16;
+                if buf_x < area.right() && buf_y < area.bottom() {
+                    f.buffer_mut()[(buf_x, buf_y)]
+                        .set_char(ch)
+                        .set_style(Style::default().fg(color));
+                }
+            }
+        }
+    }
+}
+
+pub fn render_heard_shots(f: &mut Frame, area: Rect, state: &GameState) {
+    let heard_shots = state.world.read_resource::<HeardShots>();
+    let turn = state.world.read_resource::<TurnState>().current_turn;
+    let top_left = state.camera.top_left();
+
+    for (shot, age) in heard_shots.active(turn) {
+        // Fresh gunfire stands out; it dims before it fades away
+        let style = if age == 0 {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        let screen_x = shot.origin.x - top_left.x;
+        let screen_y = shot.origin.y - top_left.y;
+        if screen_x >= 0
+            && screen_x < area.width as i32
+            && screen_y >= 0
+            && screen_y < area.height as i32
+        {
+            let buf_x = area.x + screen_x as u16;
+            let buf_y = area.y + screen_y as u16;
+            if buf_x < area.right() && buf_y < area.bottom() {
+                f.buffer_mut()[(buf_x, buf_y)].set_char('!').set_style(style);
+            }
+        }
+    }
+}
+
+pub fn render_muzzle_flashes(f: &mut Frame, area: Rect, state: &GameState) {
+    let entities = state.world.entities();
+    let muzzle_flashes = state.world.read_storage::<MuzzleFlash>();
+    let top_left = state.camera.top_left();
+
+    for (_entity, flash) in (&entities, &muzzle_flashes).join() {
+        let screen_x = flash.position.x() - top_left.x;
+        let screen_y = flash.position.y() - top_left.y;
+
+        // Only render if within viewport
+        if screen_x >= 0
+            && screen_x < area.width as i32
+            && screen_y >= 0
+            && screen_y < area.height as i32
+        {
+            let buf_x = area.x + screen_x as u16;
+            let buf_y = area.y + screen_y as u16;
+
+            if buf_x < area.right() && buf_y < area.bottom() {
+                // Render muzzle flash as bright yellow '*'
+                f.buffer_mut()[(buf_x, buf_y)]
+                    .set_char('*')
+                    .set_style(Style::default().fg(Color::Rgb(255, 255, 0)));
+            }
+        }
+    }
+}
+
+/// The player's time budget and what stepping onto each neighbouring tile would cost
+pub fn player_step_costs(state: &GameState) -> Option<(f32, Vec<(BattlefieldPos, f32)>)> {
+    let player_entity = state.get_player_entity()?;
+    let from = state.get_player_position()?;
+    let available = state
+        .world
+        .read_storage::<TimeBudget>()
+        .get(player_entity)?
+        .available_time();
+    let healths = state.world.read_storage::<Health>();
+    let load = effective_load(&state.world, player_entity, &state.config.fatigue);
+    let costs = step_costs(
+        &state.battlefield,
+        from,
+        state.crawling,
+        healths.get(player_entity),
+        load,
+        &state.config.wounded,
+    );
+    Some((available, costs))
+}
+
+pub fn render_step_costs(f: &mut Frame, area: Rect, state: &GameState) {
+    let Some((available, costs)) = player_step_costs(state) else {
+        return;
+    };
+    let top_left = state.camera.top_left();
+
+    for (pos, cost) in costs {
+        let screen_x = pos.x - top_left.x;
+        let screen_y = pos.y - top_left.y;
+
+        // Only render if within viewport
+        if screen_x >= 0
+            && screen_x < area.width as i32
+            && screen_y >= 0
+            && screen_y < area.height as i32
+        {
+            let buf_x = area.x + screen_x as u16;
+            let buf_y = area.y + screen_y as u16;
+
+            if buf_x < area.right() && buf_y < area.bottom() {
+                // Whole seconds, rounded up; red when the budget can't cover it
+                let seconds = cost.ceil() as u32;
+                let ch = char::from_digit(seconds, 10).unwrap_or('+');
+                let color = if cost <= available { Color::Green } else { Color::Red };
+                f.buffer_mut()[(buf_x, buf_y)]
+                    .set_char(ch)
+                    .set_style(Style::default().fg(color).bg(Color::DarkGray));
+            }
+        }
+    }
+}
+
+pub fn render_blood_trails(f: &mut Frame, area: Rect, state: &GameState) {
+    let soldiers = state.world.read_storage::<Soldier>();
+    let trails = state.world.read_storage::<BloodTrail>();
+    let top_left = state.camera.top_left();
+
+    for (soldier, trail) in (&soldiers, &trails).join() {
+        // Only the enemy's blood is worth tracking
+        if soldier.faction == COMMANDER_FACTION {
+            continue;
+        }
+
+        for drop in &trail.drops {
+            // Drops are only spotted on tiles someone can currently see
+            let seen = state
+                .battlefield
+                .get_tile(&drop.position)
+                .is_some_and(|tile| tile.visible);
+            if !seen {
+                continue;
+            }
+
+            let screen_x = drop.position.x - top_left.x;
+            let screen_y = drop.position.y - top_left.y;
+            if screen_x >= 0
+                && screen_x < area.width as i32
+                && screen_y >= 0
+                && screen_y < area.height as i32
+            {
+                let buf_x = area.x + screen_x as u16;
+                let buf_y = area.y + screen_y as u16;
+                f.buffer_mut()[(buf_x, buf_y)]
+                    .set_char(',')
+                    .set_style(Style::default().fg(Color::Red));
+            }
+        }
+    }
+}
+
+pub fn render_last_seen_markers(f: &mut Frame, area: Rect, state: &GameState) {
+    let top_left = state.camera.top_left();
+
+    // Render ghost markers for last-seen enemy positions
+    for marker in state.last_seen_markers.values() {
+        let screen_x = marker.position.x() - top_left.x;
+        let screen_y = marker.position.y() - top_left.y;
+
+        // Only render if within viewport
+        if screen_x >= 0
+            && screen_x < area.width as i32
+            && screen_y >= 0
+            && screen_y < area.height as i32
+        {
+            let buf_x = area.x + screen_x as u16;
+            let buf_y = area.y + screen_y as u16;
+
+            if buf_x < area.right() && buf_y < area.bottom() {
+                // Use rank icon but dimmed/ghostly
+                let ch = state.config.glyphs.rank(marker.rank);
+
+                // Dark gray color for ghost markers (old intel)
+                let color = Color::DarkGray;
+
+                f.buffer_mut()[(buf_x, buf_y)]
+                    .set_char(ch)
+                    .set_style(Style::default().fg(color));
+            }
+        }
+    }
+}
+
+pub fn render_cursor(f: &mut Frame, area: Rect, state: &GameState) {
+    let top_left = state.camera.top_left();
+    let screen_x = state.cursor_pos.x - top_left.x;
+    let screen_y = state.cursor_pos.y - top_left.y;
+
+    // Only render if within viewport
+    if screen_x >= 0
+        && screen_x < area.width as i32
+        && screen_y >= 0
+        && screen_y < area.height as i32
+    {
+        let buf_x = area.x + screen_x as u16;
+        let buf_y = area.y + screen_y as u16;
+
+        if buf_x < area.right() && buf_y < area.bottom() {
+            // Render cursor as a highlighted square with X character
+            // Set both char and background to ensure visibility
+            let current_char = f.buffer_mut()[(buf_x, buf_y)].symbol().chars().next().unwrap_or(' ');
+            f.buffer_mut()[(buf_x, buf_y)]
+                .set_char(if current_char == ' ' { 'X' } else { current_char })
+                .set_style(Style::default().fg(Color::Black).bg(Color::Yellow));
+        }
+    }
+}
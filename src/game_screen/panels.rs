@@ -0,0 +1,350 @@
+// Side and bottom panels
+// Context info under the cursor, the AI scoring overlay and status-line text
+
+use argue_the_toss::{
+    ai::{ScoringSnapshots, SideOrders, StandingOrders},
+    components::{
+        dead::Dead,
+        health::Health,
+        player::Player,
+        position::Position,
+        soldier::{Faction, Soldier},
+        weapon::Weapon,
+    },
+    game_logic::{
+        blast::GRENADE_BLAST_RADIUS,
+        fog_of_war::enemy_info_lines,
+        objectives::Objectives,
+        targeting::{TargetValidation, GRENADE_THROW_RANGE},
+        turn_state::COMMANDER_FACTION,
+    },
+    rendering::ai_debug::ScoringSnapshotWidget,
+    utils::input_mode::InputMode,
+};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Text},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use specs::{Join, World, WorldExt};
+
+use super::overlays::player_step_costs;
+use super::targeting::{enemy_readout, predict_player_shot, validate_cursor_target};
+use crate::GameState;
+
+/// Standing orders to the friendly AI, for the status line
+pub fn orders_text(world: &World, faction: Faction, current_turn: u32) -> String {
+    let SideOrders { roe, retreat } = world.fetch::<StandingOrders>().get(faction);
+    if retreat.is_active(current_turn) {
+        format!("{}, Retreat ({})", roe.name(), retreat.turns_left(current_turn))
+    } else {
+        roe.name().to_string()
+    }
+}
+
+/// Objective countdowns for the status line, or nothing while none are running
+pub fn objective_timers_text(world: &World, current_turn: u32) -> String {
+    world
+        .fetch::<Objectives>()
+        .timer_text(current_turn)
+        .map(|timers| format!(" | Flags: {}", timers))
+        .unwrap_or_default()
+}
+
+/// Debug overlay: scoring snapshot for the living AI soldier under the cursor
+pub fn render_ai_scores(f: &mut Frame, area: Rect, state: &GameState) {
+    let entities = state.world.entities();
+    let positions = state.world.read_storage::<Position>();
+    let soldiers = state.world.read_storage::<Soldier>();
+    let players = state.world.read_storage::<Player>();
+    let dead_markers = state.world.read_storage::<Dead>();
+    let snapshots = state.world.fetch::<ScoringSnapshots>();
+
+    let inspected = (&entities, &positions, &soldiers, !&players, !&dead_markers)
+        .join()
+        .find(|(_, pos, _, _, _)| *pos.as_battlefield_pos() == state.cursor_pos)
+        .map(|(entity, _, soldier, _, _)| (entity, soldier.name.as_str()));
+
+    let widget = ScoringSnapshotWidget::new(
+        inspected.map(|(_, name)| name),
+        inspected.and_then(|(entity, _)| snapshots.get(entity)),
+    );
+    f.render_widget(widget, area);
+}
+
+/// Render context-sensitive information (cursor/target details)
+pub fn render_context_info(f: &mut Frame, area: Rect, state: &GameState) {
+    use argue_the_toss::components::action::ActionType;
+    use specs::{Join, WorldExt};
+
+    let title = match state.input_mode {
+        InputMode::Look => "Cursor Info",
+        InputMode::Targeting => "Target Info",
+        InputMode::Command => "Context",
+    };
+
+    let context_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta));
+
+    let mut context_lines = vec![];
+
+    // Get the position to inspect
+    let inspect_pos = if state.input_mode == InputMode::Look || state.input_mode == InputMode::Targeting {
+        state.cursor_pos
+    } else {
+        state.get_player_position().unwrap_or(state.cursor_pos)
+    };
+
+    // Show position
+    context_lines.push(Line::from(format!(
+        "Position: ({}, {})",
+        inspect_pos.x, inspect_pos.y
+    )));
+
+    // Show terrain info
+    context_lines.push(Line::from(format!(
+        "Terrain: {}",
+        state.get_terrain_info(&inspect_pos)
+    )));
+
+    context_lines.push(Line::from(""));
+
+    // Show entity info at cursor/target position
+    let positions = state.world.read_storage::<Position>();
+    let soldiers = state.world.read_storage::<Soldier>();
+    let healths = state.world.read_storage::<Health>();
+    let weapons = state.world.read_storage::<Weapon>();
+    let players = state.world.read_storage::<Player>();
+    let entities = state.world.entities();
+
+    // Find entity at inspect position
+    let entity_at_pos = (&entities, &positions, &soldiers)
+        .join()
+        .find(|(_, pos, _)| pos.x() == inspect_pos.x && pos.y() == inspect_pos.y);
+
+    if let Some((entity, _, soldier)) = entity_at_pos
+        && soldier.faction != COMMANDER_FACTION
+    {
+        // Enemies: only as much as the fog-of-war setting allows
+        context_lines.extend(
+            enemy_info_lines(
+                enemy_readout(state, &inspect_pos),
+                soldier,
+                healths.get(entity),
+                weapons.get(entity),
+                &state.config.hud_thresholds,
+            )
+            .into_iter()
+            .map(Line::from),
+        );
+    } else if let Some((entity, _, soldier)) = entity_at_pos {
+        let is_player = players.contains(entity);
+        let player_marker = if is_player { " (YOU)" } else { "" };
+
+        context_lines.push(Line::from(format!(
+            "Unit: {}{}",
+            soldier.name, player_marker
+        )));
+        context_lines.push(Line::from(format!(
+            "Faction: {:?}",
+            soldier.faction
+        )));
+        context_lines.push(Line::from(format!(
+            "Rank: {}",
+            soldier.rank.as_str()
+        )));
+
+        // Show HP if entity is visible (or if it's the player)
+        if is_player {
+            if let Some(health) = healths.get(entity) {
+                let hp_status = state
+                    .config
+                    .hud_thresholds
+                    .hp_status(health.percentage())
+                    .label();
+
+                context_lines.push(Line::from(format!(
+                    "HP: {}/{} ({}%)",
+                    health.current,
+                    health.maximum,
+                    health.percentage_display()
+                )));
+                context_lines.push(Line::from(format!("Status: {}", hp_status)));
+            }
+        } else {
+            // For other entities, check if tile is visible
+            if let Some(tile) = state.battlefield.get_tile(&inspect_pos) {
+                if tile.visible {
+                    if let Some(health) = healths.get(entity) {
+                        context_lines.push(Line::from(format!(
+                            "HP: {}/{} ({}%)",
+                            health.current,
+                            health.maximum,
+                            health.percentage_display()
+                        )));
+                    }
+                } else {
+                    context_lines.push(Line::from("HP: ???"));
+                }
+            }
+        }
+
+        // Show weapon info if visible or player
+        if is_player {
+            if let Some(weapon) = weapons.get(entity) {
+                context_lines.push(Line::from(format!(
+                    "Weapon: {}",
+                    weapon.stats.name
+                )));
+            }
+        } else if let Some(tile) = state.battlefield.get_tile(&inspect_pos) {
+            if tile.visible {
+                if let Some(weapon) = weapons.get(entity) {
+                    context_lines.push(Line::from(format!(
+                        "Weapon: {}",
+                        weapon.stats.name
+                    )));
+                }
+            }
+        }
+    } else {
+        context_lines.push(Line::from("No entity here"));
+
+        // Check for last-seen marker at this position
+        let marker_at_pos = state.last_seen_markers.values().find(|marker| {
+            marker.position.x() == inspect_pos.x && marker.position.y() == inspect_pos.y
+        });
+
+        if let Some(marker) = marker_at_pos {
+            context_lines.push(Line::from(""));
+            context_lines.push(Line::from("--- Last Seen ---"));
+            context_lines.push(Line::from(format!(
+                "Enemy last seen: {} turns ago",
+                marker.turns_ago
+            )));
+            context_lines.push(Line::from(format!(
+                "Faction: {:?}",
+                marker.faction
+            )));
+            context_lines.push(Line::from(format!(
+                "Rank: {}",
+                marker.rank.as_str()
+            )));
+        }
+    }
+
+    // Show spotter information if tile is visible
+    if let Some(spotter_entity) = state.spotter_map.get(&inspect_pos) {
+        let soldiers = state.world.read_storage::<Soldier>();
+        let players = state.world.read_storage::<Player>();
+
+        if let Some(soldier) = soldiers.get(*spotter_entity) {
+            let spotter_name = if players.contains(*spotter_entity) {
+                "You"
+            } else {
+                &soldier.name
+            };
+
+            context_lines.push(Line::from(""));
+            context_lines.push(Line::from(format!("Spotted by: {}", spotter_name)));
+        }
+    }
+
+    // In Targeting mode, show additional targeting info
+    if state.input_mode == InputMode::Targeting && state.throwing_grenade {
+        context_lines.push(Line::from(""));
+        context_lines.push(Line::from("--- Grenade ---"));
+        context_lines.push(Line::from(format!("Blast radius: {} tiles", GRENADE_BLAST_RADIUS)));
+        match state.validate_grenade_cursor() {
+            TargetValidation::OutOfRange => context_lines.push(Line::styled(
+                format!("OUT OF RANGE (max {} tiles)", GRENADE_THROW_RANGE),
+                Style::default().fg(Color::Red),
+            )),
+            TargetValidation::NoLineOfSight => context_lines.push(Line::styled(
+                "NO LINE OF SIGHT",
+                Style::default().fg(Color::Red),
+            )),
+            _ => {}
+        }
+        if state.grenade_danger_close() {
+            context_lines.push(Line::styled("DANGER CLOSE", Style::default().fg(Color::Red)));
+            if state.danger_close_confirmed == Some(state.cursor_pos) {
+                context_lines.push(Line::from("Enter again to throw"));
+            }
+        }
+    } else if state.input_mode == InputMode::Targeting {
+        context_lines.push(Line::from(""));
+        context_lines.push(Line::from("--- Targeting ---"));
+
+        let validation = validate_cursor_target(state);
+        let status_msg = match validation {
+            TargetValidation::Valid => "VALID TARGET (X)",
+            TargetValidation::OutOfEffectiveRange => "LONG SHOT (x)",
+            TargetValidation::NoTarget => "No target (+)",
+            TargetValidation::Friendly => "FRIENDLY (!)",
+            TargetValidation::OutOfRange => "OUT OF RANGE (?)",
+            TargetValidation::NoLineOfSight => "NO LINE OF SIGHT (/)",
+            TargetValidation::Surrendered => "SURRENDERED (-)",
+        };
+        context_lines.push(Line::from(format!("Status: {}", status_msg)));
+
+        // Show weapon range
+        if let Some(player_entity) = state.get_player_entity() {
+            let weapons = state.world.read_storage::<Weapon>();
+            if let Some(weapon) = weapons.get(player_entity) {
+                context_lines.push(Line::from(format!(
+                    "Range: {}/{} tiles",
+                    weapon.stats.effective_range,
+                    weapon.stats.max_range
+                )));
+            }
+        }
+
+        // The odds are worked out from the target's real HP and cover, so
+        // they show no more than the fog-of-war setting would
+        let readout = enemy_readout(state, &state.cursor_pos);
+        if let Some((prediction, target_hp)) = predict_player_shot(state)
+            && readout.shows_shot_odds()
+        {
+            context_lines.push(Line::from(format!(
+                "Hit chance: {:.0}%",
+                prediction.hit_chance * 100.0
+            )));
+            context_lines.push(Line::from(format!(
+                "Damage: {} on hit ({:.1} expected)",
+                prediction.damage_on_hit,
+                prediction.expected_damage()
+            )));
+            if readout.shows_kill() && prediction.would_kill(target_hp) {
+                context_lines.push(Line::styled("A hit would kill", Style::default().fg(Color::Red)));
+            }
+        }
+    }
+
+    // Command mode: what the next action would take out of the time budget
+    if state.input_mode == InputMode::Command
+        && state.show_step_costs
+        && let Some(player_entity) = state.get_player_entity()
+        && let Some((available, costs)) = player_step_costs(state)
+    {
+        let cheapest = costs.iter().map(|(_, cost)| *cost).fold(f32::INFINITY, f32::min);
+        context_lines.push(Line::from(""));
+        context_lines.push(Line::from("--- Action Costs ---"));
+        context_lines.push(Line::from(format!("Time left: {:.1}s", available)));
+        if cheapest.is_finite() {
+            context_lines.push(Line::from(format!("Step: from {:.1}s", cheapest)));
+        }
+        context_lines.push(Line::from(format!(
+            "Fire: {:.1}s | Reload: {:.1}s",
+            ActionType::Shoot { target: player_entity }.base_time_cost(),
+            ActionType::Reload.base_time_cost()
+        )));
+    }
+
+    let context_paragraph = Paragraph::new(Text::from(context_lines)).block(context_block);
+    f.render_widget(context_paragraph, area);
+}
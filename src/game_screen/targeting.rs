@@ -0,0 +1,171 @@
+// Targeting readouts
+// What the player is told about a shot before taking it
+
+use argue_the_toss::{
+    components::{
+        aiming::Aiming,
+        dead::Dead,
+        health::Health,
+        last_stand::LastStand,
+        position::Position,
+        prone::Prone,
+        rested::Rested,
+        soldier::Soldier,
+        soldier_stats::SoldierStats,
+        vision::Vision,
+        weapon::Weapon,
+    },
+    config::game_config::WoundedConfig,
+    game_logic::{
+        battlefield::Position as BattlefieldPos,
+        combat::{predict_shot, ShotModifiers, ShotParams, ShotPrediction},
+        fog_of_war::EnemyReadout,
+        spatial_index::PositionIndex,
+        targeting::{validate_target, TargetValidation},
+        turn_state::{TurnState, COMMANDER_FACTION},
+    },
+    simulation::weather::Wind,
+};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    Frame,
+};
+use specs::{Join, WorldExt};
+
+use crate::GameState;
+
+/// Check if the cursor position is a valid target for the player to shoot
+pub fn validate_cursor_target(state: &GameState) -> TargetValidation {
+    match state.get_player_entity() {
+        Some(player) => validate_target(&state.world, &state.battlefield, player, state.cursor_pos),
+        None => TargetValidation::NoTarget,
+    }
+}
+
+/// How much the fog-of-war setting lets the player see of an enemy at `pos`
+pub fn enemy_readout(state: &GameState, pos: &BattlefieldPos) -> EnemyReadout {
+    let positions = state.world.read_storage::<Position>();
+    let soldiers = state.world.read_storage::<Soldier>();
+    let dead_markers = state.world.read_storage::<Dead>();
+    let nearest_friendly = (&positions, &soldiers, !&dead_markers)
+        .join()
+        .filter(|(_, friendly, _)| friendly.faction == COMMANDER_FACTION)
+        .map(|(friendly_pos, _, _)| friendly_pos.as_battlefield_pos().distance_to(pos))
+        .fold(f32::INFINITY, f32::min);
+    let visible = state.battlefield.get_tile(pos).is_some_and(|tile| tile.visible);
+    state.config.enemy_info.readout(visible, nearest_friendly)
+}
+
+/// Dry-run the player's shot at the soldier under the cursor
+///
+/// Returns the prediction and the target's remaining HP, or None unless
+/// the cursor is on a valid target.
+pub fn predict_player_shot(state: &GameState) -> Option<(ShotPrediction, i32)> {
+    if !validate_cursor_target(state).can_fire() {
+        return None;
+    }
+    let player_entity = state.get_player_entity()?;
+
+    let positions = state.world.read_storage::<Position>();
+    let healths = state.world.read_storage::<Health>();
+    let target = state
+        .world
+        .read_resource::<PositionIndex>()
+        .occupants(state.cursor_pos)
+        .iter()
+        .copied()
+        .find(|entity| healths.get(*entity).is_some() && *entity != player_entity)?;
+
+    // Built as execute_shoot builds it, for a shot fired standing still
+    let modifiers = ShotModifiers::default()
+        .with_wounds(&state.world.read_resource::<WoundedConfig>(), healths.get(player_entity))
+        .with_last_stand(state.world.read_storage::<LastStand>().contains(player_entity))
+        .with_fatigue(
+            &state.config.fatigue,
+            state.world.read_resource::<TurnState>().current_turn,
+            state.world.read_storage::<Rested>().get(player_entity),
+        )
+        .with_prone_target(state.world.read_storage::<Prone>().contains(target))
+        .with_aim(state.world.read_storage::<Aiming>().contains(player_entity));
+
+    let weapons = state.world.read_storage::<Weapon>();
+    let wind = state.world.read_resource::<Wind>();
+    let prediction = predict_shot(
+        &ShotParams::new(
+            weapons.get(player_entity)?,
+            positions.get(player_entity)?,
+            positions.get(target)?,
+            &state.battlefield,
+            state.world.read_storage::<Vision>().get(player_entity).map(|v| v.range).unwrap_or(10),
+        )
+        .with_accuracy(
+            state
+                .world
+                .read_storage::<SoldierStats>()
+                .get(player_entity)
+                .map(|stats| stats.accuracy_modifier),
+        )
+        .with_wind(&wind)
+        .with_modifiers(modifiers)
+        .with_cover_model(state.config.cover_model),
+    );
+    Some((prediction, healths.get(target)?.current))
+}
+
+pub fn render_targeting_cursor(f: &mut Frame, area: Rect, state: &GameState) {
+    let top_left = state.camera.top_left();
+    let screen_x = state.cursor_pos.x - top_left.x;
+    let screen_y = state.cursor_pos.y - top_left.y;
+
+    // Only render if within viewport
+    if screen_x >= 0
+        && screen_x < area.width as i32
+        && screen_y >= 0
+        && screen_y < area.height as i32
+    {
+        let buf_x = area.x + screen_x as u16;
+        let buf_y = area.y + screen_y as u16;
+
+        if buf_x < area.right() && buf_y < area.bottom() {
+            // Validate the target and choose style accordingly
+            let validation = validate_cursor_target(state);
+
+            let (cursor_char, cursor_style) = match validation {
+                TargetValidation::Valid => {
+                    // Valid target: bright red background with crosshair
+                    ('X', Style::default().fg(Color::White).bg(Color::Red))
+                }
+                TargetValidation::OutOfEffectiveRange => {
+                    // Long shot: amber crosshair, it will likely miss
+                    ('x', Style::default().fg(Color::Black).bg(Color::Rgb(255, 191, 0)))
+                }
+                TargetValidation::NoTarget => {
+                    // No target: dim red background
+                    ('+', Style::default().fg(Color::White).bg(Color::DarkGray))
+                }
+                TargetValidation::Friendly => {
+                    // Friendly: yellow/amber warning
+                    ('!', Style::default().fg(Color::Black).bg(Color::Yellow))
+                }
+                TargetValidation::OutOfRange => {
+                    // Out of range: orange/amber
+                    ('?', Style::default().fg(Color::White).bg(Color::Rgb(255, 140, 0)))
+                }
+                TargetValidation::NoLineOfSight => {
+                    // No LOS: magenta/purple
+                    ('/', Style::default().fg(Color::White).bg(Color::Magenta))
+                }
+                TargetValidation::Surrendered => {
+                    // Prisoner: blue, not to be fired on
+                    ('-', Style::default().fg(Color::White).bg(Color::Blue))
+                }
+            };
+
+            // Set both character and style for clear visual feedback
+            f.buffer_mut()[(buf_x, buf_y)]
+                .set_char(cursor_char)
+                .set_style(cursor_style);
+        }
+    }
+}
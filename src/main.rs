@@ -1,43 +1,74 @@
 // Argue the Toss - WWI Trench Warfare Roguelike
 // Main entry point
 
+mod game_screen;
+
 use argue_the_toss::{
+    ai::{
+        personality_data::PERSONALITIES_PATH, PersonalityLibrary, ScoringSnapshots,
+        StandingOrders,
+    },
     components::{
-        action::{OngoingAction, QueuedAction},
         dead::Dead,
-        facing::{Direction8, Facing},
+        detonation::Detonations,
+        facing::Facing,
         health::Health,
-        last_seen::LastSeenMarker,
-        muzzle_flash::MuzzleFlash,
+        heard_shot::HeardShots,
+        inventory::Inventory,
+        last_seen::{FlashSightings, LastSeenMarker},
         pathfinding::PlannedPath,
         player::Player,
         position::Position,
-        soldier::{Faction, Rank, Soldier},
-        soldier_stats::SoldierStats,
+        soldier::{Faction, Soldier},
+        squad::SquadMember,
         time_budget::TimeBudget,
+        tracer::ShotTracers,
+        under_fire::UnderFire,
         vision::Vision,
-        weapon::Weapon,
+        weapon::{SecondaryWeapon, Weapon},
+    },
+    config::{
+        game_config::GameConfig,
+        glyph_theme::{GlyphTheme, GLYPH_THEME_PATH},
+        new_game_defaults::NEW_GAME_DEFAULTS_PATH,
+        rank_stats::{RankStatsTable, RANK_STATS_PATH},
+        scenario::{load_scenario, SCENARIO_PATH},
     },
-    config::game_config::GameConfig,
     game_loop_guard::GameLoopGuard,
     game_logic::{
+        action_preview::{effective_load, step_action},
+        auto_advance::AdvanceToContact,
+        auto_pause::{AutoPause, BattleSnapshot, PauseReason},
         battlefield::{Battlefield, Position as BattlefieldPos},
-        objectives::{ObjectiveFlag, Objectives},
-        pathfinding::calculate_path,
-        shared_vision::calculate_faction_vision,
-        soldier_spawning::{generate_name, generate_soldier_stats, select_random_rank},
-        turn_state::TurnState,
+        blast::{danger_close, GRENADE_BLAST_RADIUS},
+        deployment::Deployment,
+        formation::order_formation_move,
+        pathfinding::{calculate_path_avoiding, corpse_obstacles},
+        player_actions::{queue_player_action, PlayerAction},
+        reinforcements::{spawn_reinforcements, spawn_triggered_reinforcements},
+        scoring::{check_game_over, GameOutcome},
+        shared_vision::{faction_lookouts, update_faction_vision, VisionCache},
+        spatial_index::PositionIndex,
+        targeting::{validate_grenade_target, TargetValidation},
+        timeline::Timeline,
+        turn_state::{end_turn, EndTurnScope, TurnPhase, TurnState, COMMANDER_FACTION},
+        world_setup::{
+            assign_ai_roles, generate_battlefield, insert_match_resources, insert_objectives,
+            register_components, spawn_forces,
+        },
     },
-    rendering::{viewport::Camera, widgets::BattlefieldWidget},
-    systems::{
-        action_execution::ActionExecutionSystem, ai_action_planner::AIActionPlannerSystem,
-        objective_capture::ObjectiveCaptureSystem, path_execution::PathExecutionSystem,
-        position_validation::PositionValidationSystem, turn_manager::TurnManagerSystem,
+    rendering::{
+        camera_follow::CameraFollow,
+        impact_flash::{ImpactFlash, ImpactFlashWidget},
+        viewport::{Camera, CameraBookmarks},
     },
+    systems::{match_systems, position_validation::PositionValidationSystem},
     ui::menu::{
+        game_over::GameOverWidget,
         main_menu::{MainMenuState, MainMenuWidget},
         new_game_config::{NewGameConfigState, NewGameConfigWidget},
-        settings_menu::{SettingsMenuState, SettingsMenuWidget},
+        settings_menu::{SettingsMenuState, SettingsMenuWidget, SettingsOption},
+        timeline_viewer::TimelineViewerWidget,
         widgets::MenuAction,
     },
     utils::{event_log::EventLog, input_mode::InputMode},
@@ -49,23 +80,27 @@ use crossterm::{
 };
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
-    text::{Line, Text},
-    widgets::{Block, Borders, Paragraph, Wrap},
-    Frame, Terminal,
+    layout::Rect,
+    Terminal,
 };
-use specs::{Builder, DispatcherBuilder, Join, World, WorldExt};
-use std::io;
+use specs::{Join, World, WorldExt};
+use std::io::{self, Write as _};
+use std::time::{Duration, Instant};
 
 use std::collections::{HashMap, HashSet};
 use specs::Entity;
 
+use game_screen::{render_deployment, ui};
+
 enum AppState {
     MainMenu,
     NewGameConfig,
+    /// Placing the friendly squad inside its spawn zone before the first turn
+    Deployment(GameState, Deployment),
     InGame(GameState),
     Paused(GameState),
+    /// Match decided; the final battlefield stays on screen under the result
+    GameOver(GameState, GameOutcome),
     Settings,
 }
 
@@ -73,130 +108,58 @@ struct GameState {
     world: World,
     battlefield: Battlefield,
     camera: Camera,
+    bookmarks: CameraBookmarks,
     running: bool,
     input_mode: InputMode,
     cursor_pos: BattlefieldPos,
     config: GameConfig,
     peripheral_tiles: HashMap<BattlefieldPos, bool>,
+    vision_cache: VisionCache,
     spotter_map: HashMap<BattlefieldPos, Entity>,
     last_seen_markers: HashMap<Entity, LastSeenMarker>,
     visible_entities: HashSet<Entity>,
+    /// An enemy came into view since the advance to contact last looked
+    enemy_spotted: bool,
+    /// Debug: render the whole map and every soldier regardless of FOV
+    reveal_map: bool,
+    /// Debug: show the inspected AI soldier's last scoring pass
+    show_ai_scores: bool,
+    /// Auto-advance along the planned path until contact
+    advance_to_contact: AdvanceToContact,
+    /// Commander mode: the turn being resolved after the commander ended planning
+    commander_turn: Option<u32>,
+    /// Halts auto-advance on a friendly death, a flag changing hands or new contact
+    auto_pause: AutoPause,
+    /// Lights the screen border for a frame when a blast lands in view
+    impact_flash: ImpactFlash,
+    camera_follow: CameraFollow,
+    /// The event log border flashes until this moment after an auto-pause
+    log_flash_until: Option<Instant>,
+    /// Look mode: tint what a soldier at the cursor could see
+    peek_sightlines: bool,
+    /// Command mode: show what a step onto each neighbouring tile would cost
+    show_step_costs: bool,
+    /// Command mode: tint the player's forward vision cone
+    show_fire_cone: bool,
+    /// Paused: the battle timeline is open with this entry selected
+    timeline_selection: Option<usize>,
+    /// Movement keys crawl instead of walking
+    crawling: bool,
+    /// Targeting mode: aiming a grenade rather than a shot
+    throwing_grenade: bool,
+    /// Tile the player has already been warned is danger close
+    danger_close_confirmed: Option<BattlefieldPos>,
 }
 
-fn spawn_soldiers(
-    world: &mut World,
-    battlefield: &Battlefield,
-    config: &GameConfig,
-    soldier_count: usize,
-) -> BattlefieldPos {
-    let ally_positions = battlefield.get_spawn_positions(true, soldier_count + 1);
-    let enemy_positions = battlefield.get_spawn_positions(false, soldier_count);
-
-    if ally_positions.is_empty() {
-        panic!("Failed to generate ally spawn positions!");
-    }
-
-    let mut rng = rand::rng();
-
-    let player_pos = ally_positions[0];
-    let player_rank = Rank::Sergeant;
-    let player_stats = generate_soldier_stats(player_rank, &mut rng);
-    let player_base_stats = player_rank.base_stats();
-    let player_name = generate_name(Faction::Allies, player_rank);
-
-    world
-        .create_entity()
-        .with(Position::new(player_pos.x, player_pos.y))
-        .with(Soldier {
-            name: player_name,
-            faction: Faction::Allies,
-            rank: player_rank,
-        })
-        .with(Player)
-        .with(SoldierStats {
-            accuracy_modifier: player_stats.accuracy_modifier,
-            movement_speed_modifier: player_stats.movement_speed_modifier,
-            max_hp_modifier: player_stats.max_hp_modifier,
-            carrying_capacity: player_stats.carrying_capacity,
-        })
-        .with(TimeBudget::new(config.time_budget_seconds))
-        .with(Vision::new(player_base_stats.vision_range))
-        .with(Weapon::rifle())
-        .with(Health::new(player_base_stats.base_hp + player_stats.max_hp_modifier))
-        .with(Facing::new(Direction8::N))
-        .build();
+/// How long the event log flashes after an auto-pause
+const LOG_FLASH_DURATION: Duration = Duration::from_millis(1500);
 
-    for i in 0..soldier_count.min(ally_positions.len() - 1) {
-        let pos = ally_positions[i + 1];
+/// Tiles the commander's camera moves per key press
+const COMMANDER_PAN_STEP: i32 = 5;
 
-        let rank = if i == 0 {
-            Rank::Sergeant
-        } else {
-            select_random_rank(&mut rng)
-        };
-
-        let stats = generate_soldier_stats(rank, &mut rng);
-        let base_stats = rank.base_stats();
-        let name = generate_name(Faction::Allies, rank);
-
-        world
-            .create_entity()
-            .with(Position::new(pos.x, pos.y))
-            .with(Soldier {
-                name,
-                faction: Faction::Allies,
-                rank,
-            })
-            .with(SoldierStats {
-                accuracy_modifier: stats.accuracy_modifier,
-                movement_speed_modifier: stats.movement_speed_modifier,
-                max_hp_modifier: stats.max_hp_modifier,
-                carrying_capacity: stats.carrying_capacity,
-            })
-            .with(TimeBudget::new(config.time_budget_seconds))
-            .with(Vision::new(base_stats.vision_range))
-            .with(Weapon::rifle())
-            .with(Health::new(base_stats.base_hp + stats.max_hp_modifier))
-            .with(Facing::new(Direction8::W))
-            .build();
-    }
-
-    for i in 0..soldier_count.min(enemy_positions.len()) {
-        let pos = enemy_positions[i];
-
-        let rank = if i == 0 {
-            Rank::Sergeant
-        } else {
-            select_random_rank(&mut rng)
-        };
-
-        let stats = generate_soldier_stats(rank, &mut rng);
-        let base_stats = rank.base_stats();
-        let name = generate_name(Faction::CentralPowers, rank);
-
-        world
-            .create_entity()
-            .with(Position::new(pos.x, pos.y))
-            .with(Soldier {
-                name,
-                faction: Faction::CentralPowers,
-                rank,
-            })
-            .with(SoldierStats {
-                accuracy_modifier: stats.accuracy_modifier,
-                movement_speed_modifier: stats.movement_speed_modifier,
-                max_hp_modifier: stats.max_hp_modifier,
-                carrying_capacity: stats.carrying_capacity,
-            })
-            .with(TimeBudget::new(config.time_budget_seconds))
-            .with(Vision::new(base_stats.vision_range))
-            .with(Weapon::rifle())
-            .with(Health::new(base_stats.base_hp + stats.max_hp_modifier))
-            .with(Facing::new(Direction8::E))
-            .build();
-    }
-
-    player_pos
+/// Map Shift+digit characters (US layout) to bookmark slots 1-9
+fn bookmark_slot_for_shifted_digit(c: char) -> Option<usize> {
+    "!@#$%^&*(".find(c).map(|i| i + 1)
 }
 
 impl GameState {
@@ -208,6 +171,7 @@ impl GameState {
             GameConfig::default(),
             BattlefieldGenerationConfig::default(),
             2,
+            2,
         )
     }
 
@@ -216,61 +180,97 @@ impl GameState {
         viewport_height: usize,
         config: GameConfig,
         battlefield_config: argue_the_toss::config::battlefield_config::BattlefieldGenerationConfig,
-        soldier_count: usize,
+        ally_count: usize,
+        enemy_count: usize,
     ) -> Self {
         let mut world = World::new();
 
-        world.register::<Position>();
-        world.register::<Soldier>();
-        world.register::<SoldierStats>();
-        world.register::<Player>();
-        world.register::<TimeBudget>();
-        world.register::<QueuedAction>();
-        world.register::<OngoingAction>();
-        world.register::<Vision>();
-        world.register::<PlannedPath>();
-        world.register::<Weapon>();
-        world.register::<Health>();
-        world.register::<Dead>();
-        world.register::<Facing>();
-        world.register::<LastSeenMarker>();
-        world.register::<MuzzleFlash>();
+        register_components(&mut world);
 
         let mut event_log = EventLog::new();
         event_log.add("Welcome to Argue the Toss!".to_string());
         event_log.add("WWI Trench Warfare Roguelike".to_string());
 
-        world.insert(TurnState::new_with_mode(config.turn_order_mode));
+        let config = match RankStatsTable::load(RANK_STATS_PATH) {
+            Ok(rank_stats) => config.with_rank_stats(rank_stats),
+            Err(e) => {
+                event_log.add(format!("{} - using default rank stats", e));
+                config
+            }
+        };
+        let config = match PersonalityLibrary::load(PERSONALITIES_PATH) {
+            Ok(personalities) => config.with_personalities(personalities),
+            Err(e) => {
+                event_log.add(format!("{} - using default AI personalities", e));
+                config
+            }
+        };
+        let config = match GlyphTheme::load(GLYPH_THEME_PATH) {
+            Ok(glyphs) => config.with_glyphs(glyphs),
+            Err(e) => {
+                event_log.add(format!("{} - using default glyphs", e));
+                config
+            }
+        };
+        let config = match load_scenario(SCENARIO_PATH) {
+            Ok(scenario) => scenario.apply_to(config),
+            Err(e) => {
+                event_log.add(format!("{} - playing without scenario triggers", e));
+                config
+            }
+        };
+
         world.insert(event_log);
+        let mut game_rng = insert_match_resources(&mut world, &config, &battlefield_config);
 
-        use argue_the_toss::game_logic::terrain_generation::BattlefieldGenerator;
-        let mut generator = BattlefieldGenerator::new(battlefield_config);
-        let battlefield = generator.generate();
+        let battlefield = generate_battlefield(battlefield_config, &config);
         world.insert(battlefield.clone());
 
-        let player_start_pos = spawn_soldiers(&mut world, &battlefield, &config, soldier_count);
-        let camera = Camera::new(player_start_pos, viewport_width, viewport_height);
-
-        let mut objectives = Objectives::new();
-        let (ally_flag_pos, enemy_flag_pos) = argue_the_toss::game_logic::objectives::create_strategic_objectives(&battlefield);
-        let allies_flag = ObjectiveFlag::new(ally_flag_pos, Faction::Allies);
-        let central_flag = ObjectiveFlag::new(enemy_flag_pos, Faction::CentralPowers);
-        objectives.add_flag("allies".to_string(), allies_flag);
-        objectives.add_flag("central".to_string(), central_flag);
-        world.insert(objectives);
-
+        let player_start_pos =
+            spawn_forces(&mut world, &battlefield, &config, ally_count, enemy_count, &mut game_rng);
+        assign_ai_roles(&world);
+        world.insert(game_rng);
+        world.insert(PositionIndex::default());
+        PositionIndex::refresh(&world);
+        let camera = Camera::new(player_start_pos, viewport_width, viewport_height)
+            .with_deadzone_ratio(config.camera_deadzone);
+
+        insert_objectives(&mut world, &battlefield, &config.objectives);
+
+        let auto_pause = AutoPause::new(config.auto_pause);
+        let impact_flash = ImpactFlash::new(config.impact_flash);
+        let camera_follow = CameraFollow::new(config.watch_orders);
+        let show_ai_scores = config.show_ai_scores;
         Self {
             world,
             battlefield,
             camera,
+            bookmarks: CameraBookmarks::new(),
             running: true,
             input_mode: InputMode::default(),
             cursor_pos: player_start_pos,
             config,
             peripheral_tiles: HashMap::new(),
+            vision_cache: VisionCache::new(),
             spotter_map: HashMap::new(),
             last_seen_markers: HashMap::new(),
             visible_entities: HashSet::new(),
+            enemy_spotted: false,
+            reveal_map: false,
+            show_ai_scores,
+            advance_to_contact: AdvanceToContact::default(),
+            commander_turn: None,
+            auto_pause,
+            impact_flash,
+            camera_follow,
+            log_flash_until: None,
+            peek_sightlines: false,
+            show_step_costs: false,
+            show_fire_cone: false,
+            timeline_selection: None,
+            crawling: false,
+            throwing_grenade: false,
+            danger_close_confirmed: None,
         }
     }
 
@@ -298,6 +298,22 @@ impl GameState {
     fn handle_command_mode(&mut self, key: KeyEvent) {
         use crossterm::event::KeyModifiers;
 
+        // Without a soldier of their own, the player commands from above
+        if self.get_player_entity().is_none() && self.handle_commander_key(key) {
+            return;
+        }
+
+        // Actions this battle rules out are ignored, with a word as to why
+        if let KeyCode::Char(c) = key.code
+            && !key.modifiers.contains(KeyModifiers::CONTROL)
+            && !self
+                .config
+                .player_actions
+                .check_key(c, &mut self.world.write_resource::<EventLog>())
+        {
+            return;
+        }
+
         match key.code {
             // Quit
             KeyCode::Char('Q') if key.modifiers.contains(KeyModifiers::SHIFT) => {
@@ -323,6 +339,7 @@ impl GameState {
             // Center camera
             KeyCode::Char('v') => {
                 // Center camera on player
+                self.camera_follow.stop();
                 if let Some(player_pos) = self.get_player_position() {
                     self.camera.center_on(player_pos);
                     self.camera
@@ -330,28 +347,118 @@ impl GameState {
                 }
             }
 
-            // Advance turn
+            // Camera bookmarks: Shift+1..9 stores the current view, 1..9 jumps back
+            KeyCode::Char(c) if bookmark_slot_for_shifted_digit(c).is_some() => {
+                if let Some(slot) = bookmark_slot_for_shifted_digit(c) {
+                    self.bookmarks.set(slot, self.camera.center);
+                    self.world
+                        .write_resource::<EventLog>()
+                        .add(format!("Camera bookmark {} set", slot));
+                }
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                let slot = c.to_digit(10).unwrap_or(0) as usize;
+                self.bookmarks.jump(
+                    slot,
+                    &mut self.camera,
+                    self.battlefield.width(),
+                    self.battlefield.height(),
+                );
+            }
+
+            // Debug: toggle full map reveal (rendering only)
+            KeyCode::F(12) if cfg!(debug_assertions) => {
+                self.reveal_map = !self.reveal_map;
+                let status = if self.reveal_map { "ON" } else { "OFF" };
+                self.world
+                    .write_resource::<EventLog>()
+                    .add(format!("[DEBUG] Map reveal {}", status));
+            }
+
+            // Debug: toggle AI scoring overlay (records snapshots while on)
+            KeyCode::F(11) if cfg!(debug_assertions) => {
+                self.show_ai_scores = !self.show_ai_scores;
+                let mut snapshots = self.world.write_resource::<ScoringSnapshots>();
+                snapshots.recording = self.show_ai_scores;
+                if !self.show_ai_scores {
+                    snapshots.clear();
+                }
+            }
+
+            // Advance turn (just the player, or the whole friendly side)
             KeyCode::Char(' ') => {
-                self.advance_turn();
+                self.advance_turn(EndTurnScope::SelfOnly);
+            }
+            KeyCode::Char('T') => {
+                self.advance_turn(EndTurnScope::Faction);
+            }
+            KeyCode::Char('A') => {
+                self.start_advance_to_contact();
             }
 
             // Fire
             KeyCode::Char('f') => {
-                // Enter targeting mode for shooting
-                self.input_mode = InputMode::Targeting;
-                // Set cursor to center of viewport (not player which might be off-screen)
-                let top_left = self.camera.top_left();
-                let viewport_center = BattlefieldPos::new(
-                    top_left.x + (self.camera.viewport_width / 2) as i32,
-                    top_left.y + (self.camera.viewport_height / 2) as i32,
-                );
-                self.cursor_pos = viewport_center;
+                self.start_targeting(false);
+            }
+            KeyCode::Char('G') => {
+                self.start_targeting(true);
             }
 
             // Reload
             KeyCode::Char('r') => {
                 self.player_reload();
             }
+            KeyCode::Char('p') => {
+                self.player_swap_weapon();
+            }
+            KeyCode::Char('g') => {
+                self.player_loot();
+            }
+            KeyCode::Char('i') => {
+                self.player_aim();
+            }
+            KeyCode::Char('W') => {
+                self.player_lay_wire();
+            }
+
+            // Rules of engagement for friendly AI
+            KeyCode::Char('o') => {
+                let faction = self.commanded_faction();
+                let mut orders = self.world.write_resource::<StandingOrders>();
+                let roe = &mut orders.get_mut(faction).roe;
+                *roe = roe.toggle();
+                let message = format!("Orders to the line: {}!", roe.name());
+                drop(orders);
+                self.world.write_resource::<EventLog>().add(message);
+            }
+
+            // Whole side falls back to the spawn zone for a few turns
+            KeyCode::Char('R') => {
+                let turn = self.world.read_resource::<TurnState>().current_turn;
+                let faction = self.commanded_faction();
+                let mut orders = self.world.write_resource::<StandingOrders>();
+                let retreat = &mut orders.get_mut(faction).retreat;
+                let message = if retreat.is_active(turn) {
+                    retreat.cancel();
+                    "Retreat called off!".to_string()
+                } else {
+                    retreat.issue(turn);
+                    format!("Fall back! ({} turns)", retreat.turns_left(turn))
+                };
+                drop(orders);
+                self.world.write_resource::<EventLog>().add(message);
+            }
+
+            // Crawl instead of walking (slow, but a small target)
+            KeyCode::Char('b') => {
+                self.crawling = !self.crawling;
+                let message = if self.crawling {
+                    "You get down and crawl."
+                } else {
+                    "You will stand up on your next move."
+                };
+                self.world.write_resource::<EventLog>().add(message.to_string());
+            }
 
             // Rotation
             KeyCode::Char(',') => {
@@ -361,6 +468,16 @@ impl GameState {
                 self.player_rotate(true); // Clockwise
             }
 
+            // Toggle the step cost preview
+            KeyCode::Char('m') => {
+                self.show_step_costs = !self.show_step_costs;
+            }
+
+            // Toggle the fire cone overlay
+            KeyCode::Char('y') => {
+                self.show_fire_cone = !self.show_fire_cone;
+            }
+
             // Movement keys - qweasdzxc layout
             KeyCode::Char('q') => self.commit_player_action(-1, -1), // NW
             KeyCode::Char('w') => self.commit_player_action(0, -1),  // N
@@ -376,17 +493,105 @@ impl GameState {
         }
     }
 
+    /// Commander-only keys: roam the camera and end the turn for the allied side
+    ///
+    /// Returns false for keys that behave as in normal command mode.
+    fn handle_commander_key(&mut self, key: KeyEvent) -> bool {
+        use crossterm::event::KeyModifiers;
+
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return false;
+        }
+
+        let (dx, dy) = match key.code {
+            KeyCode::Char('q') => (-1, -1),
+            KeyCode::Char('w') => (0, -1),
+            KeyCode::Char('e') => (1, -1),
+            KeyCode::Char('a') => (-1, 0),
+            KeyCode::Char('d') => (1, 0),
+            KeyCode::Char('z') => (-1, 1),
+            KeyCode::Char('x') => (0, 1),
+            KeyCode::Char('c') => (1, 1),
+            KeyCode::Char(' ') | KeyCode::Char('T') => {
+                self.end_commander_turn();
+                return true;
+            }
+            _ => return false,
+        };
+
+        self.camera.pan(dx * COMMANDER_PAN_STEP, dy * COMMANDER_PAN_STEP);
+        self.camera
+            .constrain(self.battlefield.width(), self.battlefield.height());
+        true
+    }
+
+    /// Hand the turn to the allied AI and keep dispatching until it resolves
+    fn end_commander_turn(&mut self) {
+        let mut turn_state = self.world.write_resource::<TurnState>();
+        turn_state.mark_commander_ready();
+        self.commander_turn = Some(turn_state.current_turn);
+    }
+
+    /// Act on a pending auto-pause: stop advancing, log why, beep and flash the log
+    fn apply_auto_pause(&mut self) {
+        let Some(reason) = self.auto_pause.take() else {
+            return;
+        };
+        self.advance_to_contact.stop();
+        self.world
+            .write_resource::<EventLog>()
+            .add(reason.message().to_string());
+        self.log_flash_until = Some(Instant::now() + LOG_FLASH_DURATION);
+        // Terminal bell; a failed beep is not worth interrupting play for
+        let mut stdout = io::stdout();
+        let _ = stdout.write_all(b"\x07").and_then(|_| stdout.flush());
+    }
+
+    /// Keep a commander's turn resolving; returns true if the world needs a dispatch
+    fn continue_commander_turn(&mut self) -> bool {
+        let Some(turn) = self.commander_turn else {
+            return false;
+        };
+
+        if self.world.read_resource::<TurnState>().current_turn > turn {
+            self.commander_turn = None;
+            return false;
+        }
+        true
+    }
+
     fn handle_look_mode(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Esc => {
                 // Exit Look mode back to Command
                 self.input_mode = InputMode::Command;
+                self.peek_sightlines = false;
+            }
+            KeyCode::Char('p') => {
+                // Peek: what could an enemy standing here see?
+                self.peek_sightlines = !self.peek_sightlines;
             }
+            KeyCode::Char('o') => {
+                // Send the allied squad to the cursor in formation
+                self.order_squad_to_cursor();
+                self.input_mode = InputMode::Command;
+                self.peek_sightlines = false;
+            }
+            KeyCode::Enter
+                if !self
+                    .config
+                    .player_actions
+                    .check(PlayerAction::Move, &mut self.world.write_resource::<EventLog>()) => {}
             KeyCode::Enter => {
                 // Calculate path from player to cursor position
                 if let Some(player_pos) = self.get_player_position() {
                     if let Some(player_entity) = self.get_player_entity() {
-                        let path = calculate_path(&player_pos, &self.cursor_pos, &self.battlefield);
+                        let path = calculate_path_avoiding(
+                            &player_pos,
+                            &self.cursor_pos,
+                            &self.battlefield,
+                            &corpse_obstacles(&self.world),
+                        );
 
                         if let Some(steps) = path {
                             // Calculate total estimated time cost
@@ -421,9 +626,11 @@ impl GameState {
 
                 // Return to Command mode
                 self.input_mode = InputMode::Command;
+                self.peek_sightlines = false;
             }
             KeyCode::Char('c') => {
                 // Center camera on player
+                self.camera_follow.stop();
                 if let Some(player_pos) = self.get_player_position() {
                     self.camera.center_on(player_pos);
                     self.camera
@@ -463,6 +670,219 @@ impl GameState {
         }
     }
 
+    /// Begin placing the friendly squad, if its side has a spawn zone
+    fn start_deployment(&mut self) -> Option<Deployment> {
+        let faction = self.commanded_faction();
+        let deployment = Deployment::new(&self.world, &self.battlefield, faction)?;
+        self.select_for_deployment(&deployment);
+        self.world.write_resource::<EventLog>().add(
+            "Deploy your squad: Enter places the selected soldier, Space begins the battle"
+                .to_string(),
+        );
+        Some(deployment)
+    }
+
+    /// Open the battle timeline on its latest entry
+    fn open_timeline(&mut self) {
+        let len = self.world.read_resource::<Timeline>().len();
+        self.timeline_selection = Some(len.saturating_sub(1));
+        self.jump_to_timeline_selection();
+    }
+
+    /// Step through the open battle timeline, or close it
+    fn handle_timeline_input(&mut self, key: KeyEvent) {
+        let Some(selected) = self.timeline_selection else {
+            return;
+        };
+        let last = self.world.read_resource::<Timeline>().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('t') => {
+                self.timeline_selection = None;
+                return;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.timeline_selection = Some(selected.saturating_sub(1));
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.timeline_selection = Some((selected + 1).min(last));
+            }
+            KeyCode::Home => self.timeline_selection = Some(0),
+            KeyCode::End => self.timeline_selection = Some(last),
+            _ => return,
+        }
+        self.jump_to_timeline_selection();
+    }
+
+    /// Put the cursor and camera where the selected timeline entry happened
+    fn jump_to_timeline_selection(&mut self) {
+        let pos = self.timeline_selection.and_then(|selected| {
+            self.world
+                .read_resource::<Timeline>()
+                .events()
+                .get(selected)
+                .map(|event| event.position)
+        });
+        if let Some(pos) = pos {
+            self.cursor_pos = pos;
+            self.camera.center_on(pos);
+            self.camera
+                .constrain(self.battlefield.width(), self.battlefield.height());
+        }
+    }
+
+    /// Put the cursor and camera on the soldier being placed
+    fn select_for_deployment(&mut self, deployment: &Deployment) {
+        let pos = deployment
+            .selected()
+            .and_then(|entity| self.world.read_storage::<Position>().get(entity).copied());
+        if let Some(pos) = pos {
+            self.cursor_pos = *pos.as_battlefield_pos();
+            self.camera.center_on(self.cursor_pos);
+            self.camera
+                .constrain(self.battlefield.width(), self.battlefield.height());
+        }
+    }
+
+    /// Deployment keys; returns true once the player starts the battle
+    fn handle_deployment_input(&mut self, deployment: &mut Deployment, key: KeyEvent) -> bool {
+        let (dx, dy) = match key.code {
+            KeyCode::Char(' ') => return true,
+            KeyCode::Tab => {
+                deployment.select_next();
+                self.select_for_deployment(deployment);
+                return false;
+            }
+            KeyCode::Enter => {
+                let placed = deployment.place(&self.world, &self.battlefield, self.cursor_pos);
+                match placed {
+                    Ok(()) => self.select_for_deployment(deployment),
+                    Err(e) => self
+                        .world
+                        .write_resource::<EventLog>()
+                        .add(format!("Can't deploy there: {}", e)),
+                }
+                return false;
+            }
+            KeyCode::Up | KeyCode::Char('k') => (0, -1),
+            KeyCode::Down | KeyCode::Char('j') => (0, 1),
+            KeyCode::Left | KeyCode::Char('h') => (-1, 0),
+            KeyCode::Right | KeyCode::Char('l') => (1, 0),
+            _ => return false,
+        };
+        self.cursor_pos.x += dx;
+        self.cursor_pos.y += dy;
+        self.constrain_cursor();
+        self.camera.pan(dx, dy);
+        self.camera
+            .constrain(self.battlefield.width(), self.battlefield.height());
+        false
+    }
+
+    /// Order the allied squad to the cursor, each member to its own slot
+    fn order_squad_to_cursor(&mut self) {
+        let squad = {
+            let members = self.world.read_storage::<SquadMember>();
+            let soldiers = self.world.read_storage::<Soldier>();
+            (&members, &soldiers)
+                .join()
+                .find(|(_, soldier)| soldier.faction == COMMANDER_FACTION)
+                .map(|(member, _)| member.squad)
+        };
+
+        let message = match squad {
+            Some(squad) => match order_formation_move(&self.world, squad, self.cursor_pos) {
+                0 => "Squad has no path to that position!".to_string(),
+                ordered => {
+                    if let Some(leader) = self.squad_leader_on_the_move(squad) {
+                        self.camera_follow.watch(leader);
+                    }
+                    format!("Squad moving in formation ({} soldiers)", ordered)
+                }
+            },
+            None => "No squad to order".to_string(),
+        };
+        self.world.write_resource::<EventLog>().add(message);
+    }
+
+    /// The lowest-indexed member of `squad` with a path to walk
+    fn squad_leader_on_the_move(&self, squad: u32) -> Option<Entity> {
+        let entities = self.world.entities();
+        let members = self.world.read_storage::<SquadMember>();
+        let paths = self.world.read_storage::<PlannedPath>();
+        (&entities, &members, &paths)
+            .join()
+            .filter(|(_, member, _)| member.squad == squad)
+            .min_by_key(|(_, member, _)| member.index)
+            .map(|(entity, _, _)| entity)
+    }
+
+    /// Enter targeting mode to shoot, or to throw a grenade
+    fn start_targeting(&mut self, throwing_grenade: bool) {
+        self.input_mode = InputMode::Targeting;
+        self.throwing_grenade = throwing_grenade;
+        self.danger_close_confirmed = None;
+        // Set cursor to center of viewport (not player which might be off-screen)
+        let top_left = self.camera.top_left();
+        let viewport_center = BattlefieldPos::new(
+            top_left.x + (self.camera.viewport_width / 2) as i32,
+            top_left.y + (self.camera.viewport_height / 2) as i32,
+        );
+        self.cursor_pos = viewport_center;
+    }
+
+    /// Whether a grenade landing at the cursor would catch the player's own side
+    fn grenade_danger_close(&self) -> bool {
+        let faction = self.commanded_faction();
+        danger_close(&self.world, faction, self.cursor_pos, GRENADE_BLAST_RADIUS)
+    }
+
+    /// Whether the player may throw a grenade at the cursor
+    fn validate_grenade_cursor(&self) -> TargetValidation {
+        match self.get_player_position() {
+            Some(from) => validate_grenade_target(
+                &self.battlefield,
+                &from,
+                self.cursor_pos,
+                self.config.grenade_throw,
+            ),
+            None => TargetValidation::NoTarget,
+        }
+    }
+
+    /// Throw a grenade at the cursor; danger close throws need a second Enter
+    fn throw_grenade_at_cursor(&mut self) {
+        use argue_the_toss::components::action::ActionType;
+
+        let target = self.cursor_pos;
+        let refusal = match self.validate_grenade_cursor() {
+            TargetValidation::OutOfRange => Some("Too far to throw!"),
+            TargetValidation::NoLineOfSight => Some("Can't see where it would land!"),
+            _ => None,
+        };
+        if let Some(message) = refusal {
+            self.world.write_resource::<EventLog>().add(message.to_string());
+            return;
+        }
+        if self.grenade_danger_close() && self.danger_close_confirmed != Some(target) {
+            self.danger_close_confirmed = Some(target);
+            self.world
+                .write_resource::<EventLog>()
+                .add("DANGER CLOSE - friendlies in the blast! Enter again to throw.".to_string());
+            return;
+        }
+
+        self.queue_player_action(
+            ActionType::ThrowGrenade {
+                target_x: target.x,
+                target_y: target.y,
+            },
+            "Grenade",
+        );
+        self.throwing_grenade = false;
+        self.danger_close_confirmed = None;
+        self.input_mode = InputMode::Command;
+    }
+
     fn handle_targeting_mode(&mut self, key: KeyEvent) {
         use argue_the_toss::components::action::{ActionType, QueuedAction};
         use specs::{Join, WorldExt};
@@ -470,8 +890,13 @@ impl GameState {
         match key.code {
             KeyCode::Esc => {
                 // Cancel targeting and return to Command mode
+                self.throwing_grenade = false;
+                self.danger_close_confirmed = None;
                 self.input_mode = InputMode::Command;
             }
+            KeyCode::Enter if self.throwing_grenade => {
+                self.throw_grenade_at_cursor();
+            }
             KeyCode::Enter => {
                 // Find entity at cursor position and shoot at it
                 let target_entity = {
@@ -499,6 +924,19 @@ impl GameState {
                             }
                         }
 
+                        // A barrel too hot to fire refuses before any time is spent
+                        let too_hot = self
+                            .world
+                            .read_storage::<Weapon>()
+                            .get(player_entity)
+                            .filter(|weapon| weapon.is_overheated())
+                            .map(|weapon| format!("Your {} is too hot to fire!", weapon.stats.name));
+                        if let Some(message) = too_hot {
+                            self.world.write_resource::<EventLog>().add(message);
+                            self.input_mode = InputMode::Command;
+                            return;
+                        }
+
                         let action_type = ActionType::Shoot { target };
                         let time_cost = action_type.base_time_cost();
 
@@ -536,6 +974,7 @@ impl GameState {
             }
             KeyCode::Char('c') => {
                 // Center camera on player
+                self.camera_follow.stop();
                 if let Some(player_pos) = self.get_player_position() {
                     self.camera.center_on(player_pos);
                     self.camera
@@ -575,47 +1014,74 @@ impl GameState {
         }
     }
 
-    fn player_reload(&mut self) {
-        use argue_the_toss::components::action::{ActionType, QueuedAction};
-        use specs::WorldExt;
+    /// String wire across the tile the player is facing
+    fn player_lay_wire(&mut self) {
+        use argue_the_toss::components::action::ActionType;
 
-        if let Some(player_entity) = self.get_player_entity() {
-            // Safety check: Don't allow dead player to act
-            {
-                let deads = self.world.read_storage::<Dead>();
-                if deads.get(player_entity).is_some() {
-                    self.world.write_resource::<EventLog>()
-                        .add("You are dead!".to_string());
-                    return;
-                }
-            }
+        let Some(player) = self.get_player_entity() else {
+            return;
+        };
+        let coils = self
+            .world
+            .read_storage::<Inventory>()
+            .get(player)
+            .map_or(0, |inventory| inventory.wire_coils);
+        if coils == 0 {
+            self.world
+                .write_resource::<EventLog>()
+                .add("You have no wire to lay.".to_string());
+            return;
+        }
+        let Some((dx, dy)) = self
+            .world
+            .read_storage::<Facing>()
+            .get(player)
+            .map(|facing| facing.direction.to_vector())
+        else {
+            return;
+        };
+        self.queue_player_action(ActionType::LayWire { dx, dy }, "Lay wire");
+    }
 
-            let action_type = ActionType::Reload;
-            let time_cost = action_type.base_time_cost();
+    fn player_reload(&mut self) {
+        use argue_the_toss::components::action::ActionType;
+        self.queue_player_action(ActionType::Reload, "Reload");
+    }
 
-            // Consume time budget and queue action
-            let mut time_budgets = self.world.write_storage::<TimeBudget>();
-            let mut queued_actions = self.world.write_storage::<QueuedAction>();
+    fn player_swap_weapon(&mut self) {
+        use argue_the_toss::components::action::ActionType;
 
-            if let Some(budget) = time_budgets.get_mut(player_entity) {
-                budget.consume_time(time_cost);
+        let has_secondary = self
+            .get_player_entity()
+            .is_some_and(|player| self.world.read_storage::<SecondaryWeapon>().contains(player));
+        if !has_secondary {
+            self.world
+                .write_resource::<EventLog>()
+                .add("No secondary weapon to swap to.".to_string());
+            return;
+        }
 
-                queued_actions
-                    .insert(player_entity, QueuedAction::new(action_type))
-                    .ok();
+        self.queue_player_action(ActionType::SwapWeapon, "Swap weapon");
+    }
 
-                self.world.write_resource::<EventLog>()
-                    .add(format!("Reload action queued ({:.1}s)", time_cost));
+    fn player_loot(&mut self) {
+        use argue_the_toss::components::action::ActionType;
+        self.queue_player_action(ActionType::Loot, "Loot");
+    }
 
-                // Check if turn should end (budget exhausted or in debt)
-                if budget.available_time() <= 0.0 {
-                    let mut turn_state = self.world.write_resource::<TurnState>();
-                    turn_state.mark_entity_ready(player_entity);
+    fn player_aim(&mut self) {
+        use argue_the_toss::components::action::ActionType;
+        self.queue_player_action(ActionType::Aim, "Aim");
+    }
 
-                    self.world.write_resource::<EventLog>()
-                        .add("Time budget exhausted. Waiting for others...".to_string());
-                }
-            }
+    /// Queue a fixed-cost player action, consuming time from the budget
+    fn queue_player_action(
+        &mut self,
+        action_type: argue_the_toss::components::action::ActionType,
+        label: &str,
+    ) {
+        if let Some(player_entity) = self.get_player_entity() {
+            queue_player_action(&self.world, player_entity, action_type, label);
         }
     }
 
@@ -688,21 +1154,22 @@ impl GameState {
         }
     }
 
-    fn advance_turn(&mut self) {
-        use argue_the_toss::game_logic::turn_state::TurnState;
-        use specs::WorldExt;
-
+    fn advance_turn(&mut self, scope: EndTurnScope) {
         let player_entity = match self.get_player_entity() {
             Some(e) => e,
             None => return,
         };
 
-        // Mark player as ready to advance turn
+        // Mark player (and optionally all living friendlies) as ready to advance turn
         // PathExecutionSystem will automatically create action from PlannedPath (if exists)
         // Otherwise, this just ends the player's turn
-        let mut turn_state = self.world.write_resource::<TurnState>();
-        turn_state.mark_entity_ready(player_entity);
-        drop(turn_state);
+        end_turn(&self.world, player_entity, scope);
+
+        if scope == EndTurnScope::Faction {
+            self.world
+                .write_resource::<EventLog>()
+                .add("Ending turn for all friendly units".to_string());
+        }
 
         // Check if player has a planned path
         let paths = self.world.read_storage::<PlannedPath>();
@@ -720,8 +1187,73 @@ impl GameState {
         }
     }
 
+    /// Start marching along the planned path, ending turns automatically
+    fn start_advance_to_contact(&mut self) {
+        let player_entity = match self.get_player_entity() {
+            Some(e) => e,
+            None => return,
+        };
+
+        if self.world.read_storage::<PlannedPath>().get(player_entity).is_none() {
+            self.world
+                .write_resource::<EventLog>()
+                .add("No planned path - use look mode to plan one".to_string());
+            return;
+        }
+
+        let current_turn = self.world.read_resource::<TurnState>().current_turn;
+        self.advance_to_contact.start(current_turn);
+        self.enemy_spotted = false;
+        self.world
+            .write_resource::<EventLog>()
+            .add("Advancing to contact...".to_string());
+        self.advance_turn(EndTurnScope::SelfOnly);
+    }
+
+    /// Keep an advance to contact going; returns true if the world needs a dispatch
+    fn continue_advance_to_contact(&mut self) -> bool {
+        if !self.advance_to_contact.is_active() {
+            return false;
+        }
+
+        let player_entity = match self.get_player_entity() {
+            Some(e) if !self.world.read_storage::<Dead>().contains(e) => e,
+            _ => {
+                self.advance_to_contact.stop();
+                return false;
+            }
+        };
+
+        // Still resolving the last turn: keep the systems running
+        {
+            let turn_state = self.world.read_resource::<TurnState>();
+            if turn_state.phase != TurnPhase::Planning || turn_state.is_entity_ready(player_entity) {
+                return true;
+            }
+        }
+
+        self.update_visibility();
+        let newly_spotted = std::mem::take(&mut self.enemy_spotted);
+        let last_shot_at = self
+            .world
+            .read_storage::<UnderFire>()
+            .get(player_entity)
+            .map(|marker| marker.turn);
+        let has_path = self.world.read_storage::<PlannedPath>().contains(player_entity);
+
+        if let Some(reason) = self.advance_to_contact.check(newly_spotted, last_shot_at, has_path) {
+            self.world
+                .write_resource::<EventLog>()
+                .add(reason.message().to_string());
+            return false;
+        }
+
+        self.advance_turn(EndTurnScope::SelfOnly);
+        true
+    }
+
     fn commit_player_action(&mut self, dx: i32, dy: i32) {
-        use argue_the_toss::components::action::{ActionType, QueuedAction};
+        use argue_the_toss::components::action::QueuedAction;
         use argue_the_toss::game_logic::turn_state::TurnState;
         use specs::WorldExt;
 
@@ -753,27 +1285,16 @@ impl GameState {
         // Get current position to calculate terrain cost
         let positions = self.world.read_storage::<Position>();
         let current_pos = match positions.get(player_entity) {
-            Some(pos) => *pos,
+            Some(pos) => *pos.as_battlefield_pos(),
             None => return,
         };
         drop(positions);
 
-        // Calculate target position and get terrain cost
-        let new_x = current_pos.x() + dx;
-        let new_y = current_pos.y() + dy;
-        let new_pos = BattlefieldPos::new(new_x, new_y);
-
-        // Check if new position is valid
-        if !self.battlefield.in_bounds(&new_pos) {
+        // Check if new position is valid, and price the step by its terrain
+        let Some(action_type) = step_action(&self.battlefield, current_pos, dx, dy, self.crawling) else {
             self.world.write_resource::<EventLog>().add("Cannot move out of bounds!".to_string());
             return;
-        }
-
-        let terrain_cost = self
-            .battlefield
-            .get_tile(&new_pos)
-            .map(|t| t.terrain.movement_cost())
-            .unwrap_or(1.0);
+        };
 
         // Auto-facing: Update facing direction based on movement
         {
@@ -783,13 +1304,11 @@ impl GameState {
             }
         }
 
-        // Create movement action
-        let action_type = ActionType::Move {
-            dx,
-            dy,
-            terrain_cost,
+        let time_cost = {
+            let healths = self.world.read_storage::<Health>();
+            let load = effective_load(&self.world, player_entity, &self.config.fatigue);
+            action_type.time_cost_for(healths.get(player_entity), load, &self.config.wounded)
         };
-        let time_cost = action_type.base_time_cost();
 
         // Commit action
         let mut time_budgets = self.world.write_storage::<TimeBudget>();
@@ -799,7 +1318,14 @@ impl GameState {
             budget.consume_time(time_cost);
 
             queued_actions
-                .insert(player_entity, QueuedAction::new(action_type))
+                .insert(
+                    player_entity,
+                    QueuedAction {
+                        action_type,
+                        time_cost,
+                        committed: true,
+                    },
+                )
                 .ok();
 
             self.world.write_resource::<EventLog>()
@@ -851,6 +1377,13 @@ impl GameState {
         None
     }
 
+    /// The side the player gives orders to: their soldier's, or the commander's
+    fn commanded_faction(&self) -> Faction {
+        self.get_player_entity()
+            .and_then(|player| self.world.read_storage::<Soldier>().get(player).map(|s| s.faction))
+            .unwrap_or(COMMANDER_FACTION)
+    }
+
     fn get_player_entity(&self) -> Option<specs::Entity> {
         use specs::Join;
         let players = self.world.read_storage::<Player>();
@@ -912,6 +1445,7 @@ impl GameState {
         None
     }
 
+    /// Recompute fog of war and last-seen markers, flagging any new enemy contact
     fn update_visibility(&mut self) {
         use specs::Join;
 
@@ -934,14 +1468,10 @@ impl GameState {
             let facings = self.world.read_storage::<Facing>();
             let soldiers = self.world.read_storage::<Soldier>();
 
-            calculate_faction_vision(
-                &entities,
-                &positions,
-                &visions,
-                &facings,
-                &soldiers,
-                Faction::Allies,
+            update_faction_vision(
+                faction_lookouts(&entities, &positions, &visions, &facings, &soldiers, Faction::Allies),
                 &self.battlefield,
+                &mut self.vision_cache,
             )
         };
 
@@ -1021,6 +1551,14 @@ impl GameState {
             }
         }
 
+        // Muzzle flashes spotted at night mark shooters nobody could see
+        let flashes = self.world.write_resource::<FlashSightings>().take(Faction::Allies);
+        for marker in flashes {
+            if !currently_visible.contains(&marker.tracked_entity) {
+                self.last_seen_markers.insert(marker.tracked_entity, marker);
+            }
+        }
+
         // Update turn counter for existing markers and remove expired ones
         let max_turns = 10; // Markers expire after 10 turns
         self.last_seen_markers.retain(|_, marker| {
@@ -1028,744 +1566,19 @@ impl GameState {
             !marker.should_expire(max_turns)
         });
 
-        // Update visible entities set for next turn
-        self.visible_entities = currently_visible;
-    }
-}
-
-fn ui(f: &mut Frame, state: &GameState) {
-    // Main layout: Top (battlefield + right pane) and Bottom (info panel)
-    let main_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Min(10),      // Top: battlefield + right pane
-            Constraint::Length(7),    // Bottom: info panel
-        ])
-        .split(f.area());
-
-    // Top split: Battlefield (left), Event Log + Context Info (right)
-    let top_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(75),  // Battlefield
-            Constraint::Percentage(25),  // Right pane (event log + context)
-        ])
-        .split(main_chunks[0]);
-
-    // Split right pane vertically into Event Log (top) and Context Info (bottom)
-    let right_pane_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage(60),  // Event Log
-            Constraint::Percentage(40),  // Context Info (cursor/target)
-        ])
-        .split(top_chunks[1]);
-
-    // Render battlefield
-    let battlefield_block = Block::default()
-        .title("Battlefield")
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::White));
-
-    let inner_area = battlefield_block.inner(top_chunks[0]);
-    f.render_widget(battlefield_block, top_chunks[0]);
-
-    let objectives = state.world.fetch::<Objectives>();
-    let battlefield_widget = BattlefieldWidget::new(&state.battlefield, &state.camera)
-        .with_peripheral_tiles(&state.peripheral_tiles)
-        .with_objectives(&objectives);
-    f.render_widget(battlefield_widget, inner_area);
-    drop(objectives);
-
-    // Render planned paths (before soldiers so they appear underneath)
-    render_paths(f, inner_area, state);
-
-    // Render last-seen markers (before soldiers, so they appear underneath)
-    render_last_seen_markers(f, inner_area, state);
-
-    // Render soldiers on top
-    render_soldiers(f, inner_area, state);
-
-    // Render muzzle flashes (on top of soldiers)
-    render_muzzle_flashes(f, inner_area, state);
-
-    // Render cursor in Look mode or Targeting mode
-    if state.input_mode == InputMode::Look {
-        render_cursor(f, inner_area, state);
-    } else if state.input_mode == InputMode::Targeting {
-        render_targeting_cursor(f, inner_area, state);
-    }
-
-    // Render event log (top of right pane)
-    let event_log_block = Block::default()
-        .title("Event Log")
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
-
-    let event_lines: Vec<Line> = {
-        let event_log = state.world.fetch::<EventLog>();
-        event_log
-            .recent(15)
-            .iter()
-            .map(|e| Line::from(e.to_string()))
-            .collect()
-    };
-
-    let event_paragraph = Paragraph::new(Text::from(event_lines))
-        .block(event_log_block)
-        .wrap(Wrap { trim: true });
-    f.render_widget(event_paragraph, right_pane_chunks[0]);
-
-    // Render context info (bottom of right pane)
-    render_context_info(f, right_pane_chunks[1], state);
-
-    // Render player info panel (bottom)
-    let mode_color = match state.input_mode {
-        InputMode::Command => Color::Green,
-        InputMode::Look => Color::Yellow,
-        InputMode::Targeting => Color::Red,
-    };
-
-    let info_block = Block::default()
-        .title(format!("Mode: {} | Player Info", state.input_mode.name()))
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(mode_color));
-
-    let mut info_lines = vec![
-        Line::from(state.input_mode.help_text()),
-        Line::from(""),
-    ];
-
-    // Show player info
-    if let Some(player_entity) = state.get_player_entity() {
-        let positions = state.world.read_storage::<Position>();
-        let time_budgets = state.world.read_storage::<TimeBudget>();
-        let weapons = state.world.read_storage::<Weapon>();
-        let healths = state.world.read_storage::<Health>();
-        let turn_state = state.world.fetch::<TurnState>();
-
-        // Player position
-        if let Some(pos) = positions.get(player_entity) {
-            info_lines.push(Line::from(format!(
-                "Position: ({}, {})",
-                pos.x(), pos.y()
-            )));
-        }
-
-        // Player HP with color coding
-        if let Some(health) = healths.get(player_entity) {
-            let hp_percent = health.percentage();
-            let hp_color_name = if hp_percent > 0.7 {
-                "GREEN"
-            } else if hp_percent > 0.3 {
-                "YELLOW"
-            } else {
-                "RED"
-            };
-
-            info_lines.push(Line::from(format!(
-                "HP: {}/{} ({}%) [{}]",
-                health.current,
-                health.maximum,
-                health.percentage_display(),
-                hp_color_name
-            )));
-        }
-
-        // Weapon info
-        if let Some(weapon) = weapons.get(player_entity) {
-            info_lines.push(Line::from(format!(
-                "Weapon: {} | Ammo: {}/{} ({:.0}%)",
-                weapon.stats.name,
-                weapon.ammo.current,
-                weapon.ammo.max_capacity,
-                weapon.ammo.percentage()
-            )));
-        }
-
-        // Time budget
-        if let Some(budget) = time_budgets.get(player_entity) {
-            let available = budget.available_time();
-            let budget_status = if budget.time_debt < 0.0 {
-                "DEBT"
-            } else if available > 3.0 {
-                "Good"
-            } else if available > 1.0 {
-                "Low"
-            } else {
-                "Critical"
-            };
-
-            let time_info = if budget.time_debt < 0.0 {
-                format!(
-                    "Turn {} | Time: {:.1}s ({}) | Debt: {:.1}s",
-                    turn_state.current_turn, available, budget_status, -budget.time_debt
-                )
-            } else {
-                format!(
-                    "Turn {} | Time: {:.1}s ({})",
-                    turn_state.current_turn, available, budget_status
-                )
-            };
-
-            info_lines.push(Line::from(time_info));
-        }
-    }
-
-    let info_paragraph = Paragraph::new(Text::from(info_lines)).block(info_block);
-    f.render_widget(info_paragraph, main_chunks[1]);
-}
-
-/// Render context-sensitive information (cursor/target details)
-fn render_context_info(f: &mut Frame, area: Rect, state: &GameState) {
-    use specs::{Join, WorldExt};
-
-    let title = match state.input_mode {
-        InputMode::Look => "Cursor Info",
-        InputMode::Targeting => "Target Info",
-        InputMode::Command => "Context",
-    };
-
-    let context_block = Block::default()
-        .title(title)
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Magenta));
-
-    let mut context_lines = vec![];
-
-    // Get the position to inspect
-    let inspect_pos = if state.input_mode == InputMode::Look || state.input_mode == InputMode::Targeting {
-        state.cursor_pos
-    } else {
-        state.get_player_position().unwrap_or(state.cursor_pos)
-    };
-
-    // Show position
-    context_lines.push(Line::from(format!(
-        "Position: ({}, {})",
-        inspect_pos.x, inspect_pos.y
-    )));
-
-    // Show terrain info
-    context_lines.push(Line::from(format!(
-        "Terrain: {}",
-        state.get_terrain_info(&inspect_pos)
-    )));
-
-    context_lines.push(Line::from(""));
-
-    // Show entity info at cursor/target position
-    let positions = state.world.read_storage::<Position>();
-    let soldiers = state.world.read_storage::<Soldier>();
-    let healths = state.world.read_storage::<Health>();
-    let weapons = state.world.read_storage::<Weapon>();
-    let players = state.world.read_storage::<Player>();
-    let entities = state.world.entities();
-
-    // Find entity at inspect position
-    let entity_at_pos = (&entities, &positions, &soldiers)
-        .join()
-        .find(|(_, pos, _)| pos.x() == inspect_pos.x && pos.y() == inspect_pos.y);
-
-    if let Some((entity, _, soldier)) = entity_at_pos {
-        let is_player = players.contains(entity);
-        let player_marker = if is_player { " (YOU)" } else { "" };
-
-        context_lines.push(Line::from(format!(
-            "Unit: {}{}",
-            soldier.name, player_marker
-        )));
-        context_lines.push(Line::from(format!(
-            "Faction: {:?}",
-            soldier.faction
-        )));
-        context_lines.push(Line::from(format!(
-            "Rank: {}",
-            soldier.rank.as_str()
-        )));
-
-        // Show HP if entity is visible (or if it's the player)
-        if is_player {
-            if let Some(health) = healths.get(entity) {
-                let hp_percent = health.percentage();
-                let hp_status = if hp_percent > 0.7 {
-                    "Healthy"
-                } else if hp_percent > 0.3 {
-                    "Wounded"
-                } else {
-                    "Critical"
-                };
-
-                context_lines.push(Line::from(format!(
-                    "HP: {}/{} ({}%)",
-                    health.current,
-                    health.maximum,
-                    health.percentage_display()
-                )));
-                context_lines.push(Line::from(format!("Status: {}", hp_status)));
-            }
-        } else {
-            // For other entities, check if tile is visible
-            if let Some(tile) = state.battlefield.get_tile(&inspect_pos) {
-                if tile.visible {
-                    if let Some(health) = healths.get(entity) {
-                        context_lines.push(Line::from(format!(
-                            "HP: {}/{} ({}%)",
-                            health.current,
-                            health.maximum,
-                            health.percentage_display()
-                        )));
-                    }
-                } else {
-                    context_lines.push(Line::from("HP: ???"));
-                }
-            }
-        }
-
-        // Show weapon info if visible or player
-        if is_player {
-            if let Some(weapon) = weapons.get(entity) {
-                context_lines.push(Line::from(format!(
-                    "Weapon: {}",
-                    weapon.stats.name
-                )));
-            }
-        } else if let Some(tile) = state.battlefield.get_tile(&inspect_pos) {
-            if tile.visible {
-                if let Some(weapon) = weapons.get(entity) {
-                    context_lines.push(Line::from(format!(
-                        "Weapon: {}",
-                        weapon.stats.name
-                    )));
-                }
-            }
-        }
-    } else {
-        context_lines.push(Line::from("No entity here"));
-
-        // Check for last-seen marker at this position
-        let marker_at_pos = state.last_seen_markers.values().find(|marker| {
-            marker.position.x() == inspect_pos.x && marker.position.y() == inspect_pos.y
-        });
-
-        if let Some(marker) = marker_at_pos {
-            context_lines.push(Line::from(""));
-            context_lines.push(Line::from("--- Last Seen ---"));
-            context_lines.push(Line::from(format!(
-                "Enemy last seen: {} turns ago",
-                marker.turns_ago
-            )));
-            context_lines.push(Line::from(format!(
-                "Faction: {:?}",
-                marker.faction
-            )));
-            context_lines.push(Line::from(format!(
-                "Rank: {}",
-                marker.rank.as_str()
-            )));
-        }
-    }
-
-    // Show spotter information if tile is visible
-    if let Some(spotter_entity) = state.spotter_map.get(&inspect_pos) {
-        let soldiers = state.world.read_storage::<Soldier>();
-        let players = state.world.read_storage::<Player>();
-
-        if let Some(soldier) = soldiers.get(*spotter_entity) {
-            let spotter_name = if players.contains(*spotter_entity) {
-                "You"
-            } else {
-                &soldier.name
-            };
-
-            context_lines.push(Line::from(""));
-            context_lines.push(Line::from(format!("Spotted by: {}", spotter_name)));
-        }
-    }
-
-    // In Targeting mode, show additional targeting info
-    if state.input_mode == InputMode::Targeting {
-        context_lines.push(Line::from(""));
-        context_lines.push(Line::from("--- Targeting ---"));
-
-        let validation = validate_target(state);
-        let status_msg = match validation {
-            TargetValidation::Valid => "VALID TARGET (X)",
-            TargetValidation::NoTarget => "No target (+)",
-            TargetValidation::Friendly => "FRIENDLY (!)",
-            TargetValidation::OutOfRange => "OUT OF RANGE (?)",
-            TargetValidation::NoLineOfSight => "NO LINE OF SIGHT (/)",
-        };
-        context_lines.push(Line::from(format!("Status: {}", status_msg)));
-
-        // Show weapon range
-        if let Some(player_entity) = state.get_player_entity() {
-            let weapons = state.world.read_storage::<Weapon>();
-            if let Some(weapon) = weapons.get(player_entity) {
-                context_lines.push(Line::from(format!(
-                    "Range: {}/{} tiles",
-                    weapon.stats.effective_range,
-                    weapon.stats.max_range
-                )));
-            }
-        }
-    }
-
-    let context_paragraph = Paragraph::new(Text::from(context_lines)).block(context_block);
-    f.render_widget(context_paragraph, area);
-}
-
-fn render_paths(f: &mut Frame, area: Rect, state: &GameState) {
-    let entities = state.world.entities();
-    let paths = state.world.read_storage::<PlannedPath>();
-
-    let top_left = state.camera.top_left();
-
-    for (_entity, path) in (&entities, &paths).join() {
-        // Only render paths with preview enabled
-        if !path.show_preview {
-            continue;
-        }
-
-        for (i, pos) in path.steps.iter().enumerate() {
-            let screen_x = pos.x - top_left.x;
-            let screen_y = pos.y - top_left.y;
-
-            // Only render if within viewport
-            if screen_x >= 0
-                && screen_x < area.width as i32
-                && screen_y >= 0
-                && screen_y < area.height as i32
-            {
-                let buf_x = area.x + screen_x as u16;
-                let buf_y = area.y + screen_y as u16;
-
-                if buf_x < area.right() && buf_y < area.bottom() {
-                    // Show numbered path (1-9, then +)
-                    let ch = if i < 9 {
-                        char::from_digit((i + 1) as u32, 10).unwrap()
-                    } else {
-                        '+'
-                    };
-
-                    f.buffer_mut()[(buf_x, buf_y)]
-                        .set_char(ch)
-                        .set_style(Style::default().fg(Color::Cyan).bg(Color::DarkGray));
-                }
-            }
-        }
-    }
-}
-
-fn render_soldiers(f: &mut Frame, area: Rect, state: &GameState) {
-    let entities = state.world.entities();
-    let positions = state.world.read_storage::<Position>();
-    let soldiers = state.world.read_storage::<Soldier>();
-    let players = state.world.read_storage::<Player>();
-    let dead_markers = state.world.read_storage::<Dead>();
-    let muzzle_flashes = state.world.read_storage::<MuzzleFlash>();
-
-    let top_left = state.camera.top_left();
-
-    // First pass: render dead soldiers (corpses at bottom z-level)
-    for (entity, pos, soldier) in (&entities, &positions, &soldiers).join() {
-        if !dead_markers.contains(entity) {
-            continue; // Skip living soldiers in this pass
-        }
-
-        // Only render if visible to player (FOV check)
-        // Allied corpses always visible (you know where your fallen are)
-        let is_ally = soldier.faction == Faction::Allies;
-        if !is_ally && !state.visible_entities.contains(&entity) {
-            continue;
-        }
-
-        let screen_x = pos.x() - top_left.x;
-        let screen_y = pos.y() - top_left.y;
-
-        // Only render if within viewport
-        if screen_x >= 0
-            && screen_x < area.width as i32
-            && screen_y >= 0
-            && screen_y < area.height as i32
-        {
-            let buf_x = area.x + screen_x as u16;
-            let buf_y = area.y + screen_y as u16;
-
-            if buf_x < area.right() && buf_y < area.bottom() {
-                f.buffer_mut()[(buf_x, buf_y)]
-                    .set_char('X') // Dead bodies shown as X
-                    .set_style(Style::default().fg(Color::DarkGray));
-            }
-        }
-    }
-
-    // Second pass: render living soldiers (on top of corpses)
-    for (entity, pos, soldier) in (&entities, &positions, &soldiers).join() {
-        if dead_markers.contains(entity) {
-            continue; // Skip dead soldiers in this pass
-        }
-
-        // Only render if:
-        // 1. Player (always visible)
-        // 2. Allied unit (always visible - you know where your allies are)
-        // 3. Enemy in visible_entities (FOV check)
-        // 4. Entity has muzzle flash (revealed by firing)
-        let is_ally = soldier.faction == Faction::Allies;
-        let recently_fired = muzzle_flashes.contains(entity);
-        if !players.contains(entity) && !is_ally && !recently_fired && !state.visible_entities.contains(&entity) {
-            continue;
-        }
-
-        let screen_x = pos.x() - top_left.x;
-        let screen_y = pos.y() - top_left.y;
-
-        // Only render if within viewport
-        if screen_x >= 0
-            && screen_x < area.width as i32
-            && screen_y >= 0
-            && screen_y < area.height as i32
-        {
-            let buf_x = area.x + screen_x as u16;
-            let buf_y = area.y + screen_y as u16;
-
-            if buf_x < area.right() && buf_y < area.bottom() {
-                let ch = if players.contains(entity) {
-                    '@' // Player character
-                } else {
-                    soldier.rank.to_icon() // Rank icon
-                };
-
-                let color = if players.contains(entity) {
-                    Color::Rgb(0, 255, 255) // Player is bright cyan (unique color)
-                } else {
-                    match soldier.faction {
-                        Faction::Allies => Color::Blue,
-                        Faction::CentralPowers => Color::Red,
-                    }
-                };
-
-                f.buffer_mut()[(buf_x, buf_y)]
-                    .set_char(ch)
-                    .set_style(Style::default().fg(color));
-            }
-        }
-    }
-}
-
-fn render_muzzle_flashes(f: &mut Frame, area: Rect, state: &GameState) {
-    let entities = state.world.entities();
-    let muzzle_flashes = state.world.read_storage::<MuzzleFlash>();
-    let top_left = state.camera.top_left();
-
-    for (_entity, flash) in (&entities, &muzzle_flashes).join() {
-        let screen_x = flash.position.x() - top_left.x;
-        let screen_y = flash.position.y() - top_left.y;
-
-        // Only render if within viewport
-        if screen_x >= 0
-            && screen_x < area.width as i32
-            && screen_y >= 0
-            && screen_y < area.height as i32
-        {
-            let buf_x = area.x + screen_x as u16;
-            let buf_y = area.y + screen_y as u16;
-
-            if buf_x < area.right() && buf_y < area.bottom() {
-                // Render muzzle flash as bright yellow '*'
-                f.buffer_mut()[(buf_x, buf_y)]
-                    .set_char('*')
-                    .set_style(Style::default().fg(Color::Rgb(255, 255, 0)));
-            }
+        // New contacts are noted here, wherever the update was asked for, so
+        // neither the auto-pause nor an advance to contact can miss one
+        if !currently_visible.is_subset(&self.visible_entities) {
+            self.enemy_spotted = true;
+            self.auto_pause.trigger(PauseReason::EnemySpotted);
         }
-    }
-}
-
-fn render_last_seen_markers(f: &mut Frame, area: Rect, state: &GameState) {
-    let top_left = state.camera.top_left();
-
-    // Render ghost markers for last-seen enemy positions
-    for marker in state.last_seen_markers.values() {
-        let screen_x = marker.position.x() - top_left.x;
-        let screen_y = marker.position.y() - top_left.y;
-
-        // Only render if within viewport
-        if screen_x >= 0
-            && screen_x < area.width as i32
-            && screen_y >= 0
-            && screen_y < area.height as i32
-        {
-            let buf_x = area.x + screen_x as u16;
-            let buf_y = area.y + screen_y as u16;
-
-            if buf_x < area.right() && buf_y < area.bottom() {
-                // Use rank icon but dimmed/ghostly
-                let ch = marker.rank.to_icon();
-
-                // Dark gray color for ghost markers (old intel)
-                let color = Color::DarkGray;
-
-                f.buffer_mut()[(buf_x, buf_y)]
-                    .set_char(ch)
-                    .set_style(Style::default().fg(color));
-            }
-        }
-    }
-}
-
-fn render_cursor(f: &mut Frame, area: Rect, state: &GameState) {
-    let top_left = state.camera.top_left();
-    let screen_x = state.cursor_pos.x - top_left.x;
-    let screen_y = state.cursor_pos.y - top_left.y;
-
-    // Only render if within viewport
-    if screen_x >= 0
-        && screen_x < area.width as i32
-        && screen_y >= 0
-        && screen_y < area.height as i32
-    {
-        let buf_x = area.x + screen_x as u16;
-        let buf_y = area.y + screen_y as u16;
-
-        if buf_x < area.right() && buf_y < area.bottom() {
-            // Render cursor as a highlighted square with X character
-            // Set both char and background to ensure visibility
-            let current_char = f.buffer_mut()[(buf_x, buf_y)].symbol().chars().next().unwrap_or(' ');
-            f.buffer_mut()[(buf_x, buf_y)]
-                .set_char(if current_char == ' ' { 'X' } else { current_char })
-                .set_style(Style::default().fg(Color::Black).bg(Color::Yellow));
-        }
-    }
-}
-
-/// Validation result for targeting
-enum TargetValidation {
-    Valid,          // Enemy in range with LOS
-    NoTarget,       // No entity at cursor
-    Friendly,       // Friendly/self at cursor
-    OutOfRange,     // Target exists but out of weapon range
-    NoLineOfSight,  // Target exists but no LOS
-}
-
-/// Check if the cursor position is a valid target for shooting
-fn validate_target(state: &GameState) -> TargetValidation {
-    use argue_the_toss::game_logic::line_of_sight::calculate_fov;
-    use specs::{Join, WorldExt};
-
-    // Get player info
-    let player_entity = match state.get_player_entity() {
-        Some(e) => e,
-        None => return TargetValidation::NoTarget,
-    };
-
-    let positions = state.world.read_storage::<Position>();
-    let soldiers = state.world.read_storage::<Soldier>();
-    let weapons = state.world.read_storage::<Weapon>();
-    let visions = state.world.read_storage::<Vision>();
-    let entities = state.world.entities();
-
-    // Get player position, weapon, and vision
-    let player_pos = match positions.get(player_entity) {
-        Some(p) => p,
-        None => return TargetValidation::NoTarget,
-    };
-
-    let player_weapon = match weapons.get(player_entity) {
-        Some(w) => w,
-        None => return TargetValidation::NoTarget,
-    };
-
-    let player_faction = match soldiers.get(player_entity) {
-        Some(s) => s.faction,
-        None => return TargetValidation::NoTarget,
-    };
-
-    let player_vision = visions.get(player_entity)
-        .map(|v| v.range)
-        .unwrap_or(10);
-
-    // Check if there's an entity at cursor position
-    let target_at_cursor = (&entities, &positions, &soldiers)
-        .join()
-        .find(|(_, pos, _)| pos.x() == state.cursor_pos.x && pos.y() == state.cursor_pos.y);
-
-    let (target_entity, target_pos, target_soldier) = match target_at_cursor {
-        Some((e, p, s)) => (e, p, s),
-        None => return TargetValidation::NoTarget,
-    };
-
-    // Don't allow shooting self or friendlies
-    if target_entity == player_entity || target_soldier.faction == player_faction {
-        return TargetValidation::Friendly;
-    }
-
-    // Calculate distance
-    let dx = (player_pos.x() - target_pos.x()) as f32;
-    let dy = (player_pos.y() - target_pos.y()) as f32;
-    let distance = (dx * dx + dy * dy).sqrt().ceil() as i32;
-
-    // Check range
-    if distance > player_weapon.stats.max_range {
-        return TargetValidation::OutOfRange;
-    }
-
-    // Check line of sight using FOV calculation
-    let player_battlefield_pos = BattlefieldPos::new(player_pos.x(), player_pos.y());
-    let visible_tiles = calculate_fov(&player_battlefield_pos, player_vision, &state.battlefield);
-    let target_battlefield_pos = BattlefieldPos::new(target_pos.x(), target_pos.y());
-
-    if !visible_tiles.contains(&target_battlefield_pos) {
-        return TargetValidation::NoLineOfSight;
-    }
-
-    TargetValidation::Valid
-}
 
-fn render_targeting_cursor(f: &mut Frame, area: Rect, state: &GameState) {
-    let top_left = state.camera.top_left();
-    let screen_x = state.cursor_pos.x - top_left.x;
-    let screen_y = state.cursor_pos.y - top_left.y;
-
-    // Only render if within viewport
-    if screen_x >= 0
-        && screen_x < area.width as i32
-        && screen_y >= 0
-        && screen_y < area.height as i32
-    {
-        let buf_x = area.x + screen_x as u16;
-        let buf_y = area.y + screen_y as u16;
-
-        if buf_x < area.right() && buf_y < area.bottom() {
-            // Validate the target and choose style accordingly
-            let validation = validate_target(state);
-
-            let (cursor_char, cursor_style) = match validation {
-                TargetValidation::Valid => {
-                    // Valid target: bright red background with crosshair
-                    ('X', Style::default().fg(Color::White).bg(Color::Red))
-                }
-                TargetValidation::NoTarget => {
-                    // No target: dim red background
-                    ('+', Style::default().fg(Color::White).bg(Color::DarkGray))
-                }
-                TargetValidation::Friendly => {
-                    // Friendly: yellow/amber warning
-                    ('!', Style::default().fg(Color::Black).bg(Color::Yellow))
-                }
-                TargetValidation::OutOfRange => {
-                    // Out of range: orange/amber
-                    ('?', Style::default().fg(Color::White).bg(Color::Rgb(255, 140, 0)))
-                }
-                TargetValidation::NoLineOfSight => {
-                    // No LOS: magenta/purple
-                    ('/', Style::default().fg(Color::White).bg(Color::Magenta))
-                }
-            };
+        // Update visible entities set for next turn
+        self.visible_entities = currently_visible;
 
-            // Set both character and style for clear visual feedback
-            f.buffer_mut()[(buf_x, buf_y)]
-                .set_char(cursor_char)
-                .set_style(cursor_style);
+        // Debug reveal overrides fog of war for rendering only
+        if self.reveal_map {
+            self.battlefield.reveal_all();
         }
     }
 }
@@ -1787,26 +1600,9 @@ fn main() -> Result<(), io::Error> {
     let mut settings_menu_state = SettingsMenuState::new();
     let mut running = true;
 
-    // CRITICAL: System execution order matters!
-    // TurnManagerSystem MUST run BEFORE ActionExecutionSystem to ensure
-    // phase transitions (Planning -> Execution) happen before actions execute.
-    // Without this ordering, the "movement bug" occurs where actions are logged
-    // but positions don't update because ActionExecutionSystem sees the old phase
-    // and returns early.
-    //
-    // Correct order:
-    // 1. PathExecutionSystem: Processes movement paths
-    // 2. AIActionPlannerSystem: AI decides actions (depends on paths)
-    // 3. TurnManagerSystem: Manages phase transitions (Planning/Execution/Resolution)
-    // 4. ActionExecutionSystem: Executes committed actions (MUST run after phase transition)
-    // 5. ObjectiveCaptureSystem: Checks for objective captures after actions execute
-    // 6. PositionValidationSystem: Debug validation to catch movement bug (no-op in release)
-    let mut dispatcher = DispatcherBuilder::new()
-        .with(PathExecutionSystem, "path_execution", &[])
-        .with(AIActionPlannerSystem, "ai_planner", &["path_execution"])
-        .with(TurnManagerSystem, "turn_manager", &["ai_planner"])
-        .with(ActionExecutionSystem, "action_execution", &["turn_manager"])
-        .with(ObjectiveCaptureSystem, "objective_capture", &["action_execution"])
+    // CRITICAL: System execution order matters! See match_systems for the order;
+    // PositionValidationSystem then checks for the movement bug (no-op in release)
+    let mut dispatcher = match_systems()
         .with(
             PositionValidationSystem::new(),
             "position_validation",
@@ -1849,18 +1645,37 @@ fn main() -> Result<(), io::Error> {
                             }
                             KeyCode::Enter => {
                                 if new_game_config_state.is_start_selected() {
-                                    let game_config = new_game_config_state.to_game_config();
+                                    let game_config = new_game_config_state
+                                        .apply_to(settings_menu_state.to_game_config());
                                     let battlefield_config = new_game_config_state.to_battlefield_config();
-                                    let soldier_count = new_game_config_state.soldier_count();
+                                    let ally_count = new_game_config_state.ally_count();
+                                    let enemy_count = new_game_config_state.enemy_count();
 
-                                    let game_state = GameState::with_config(
+                                    let mut game_state = GameState::with_config(
                                         initial_width,
                                         initial_height,
                                         game_config,
                                         battlefield_config,
-                                        soldier_count,
+                                        ally_count,
+                                        enemy_count,
                                     );
-                                    app_state = AppState::InGame(game_state);
+                                    if let Err(e) =
+                                        new_game_config_state.save_defaults(NEW_GAME_DEFAULTS_PATH)
+                                    {
+                                        game_state
+                                            .world
+                                            .write_resource::<EventLog>()
+                                            .add(format!("{} - setup not remembered", e));
+                                    }
+                                    let deployment = game_state
+                                        .config
+                                        .deployment
+                                        .then(|| game_state.start_deployment())
+                                        .flatten();
+                                    app_state = match deployment {
+                                        Some(deployment) => AppState::Deployment(game_state, deployment),
+                                        None => AppState::InGame(game_state),
+                                    };
                                 } else if new_game_config_state.is_back_selected() {
                                     app_state = AppState::MainMenu;
                                 }
@@ -1889,12 +1704,19 @@ fn main() -> Result<(), io::Error> {
                                 app_state = AppState::MainMenu;
                             }
                             KeyCode::Enter => {
-                                if settings_menu_state.selected_index == 2 {
-                                    app_state = AppState::MainMenu;
-                                } else if settings_menu_state.selected_index == 3 {
-                                    app_state = AppState::MainMenu;
+                                match settings_menu_state.selected_option() {
+                                    SettingsOption::SaveAndReturn | SettingsOption::Cancel => {
+                                        app_state = AppState::MainMenu;
+                                    }
+                                    _ => {}
                                 }
                             }
+                            KeyCode::Tab => {
+                                settings_menu_state.next_tab();
+                            }
+                            KeyCode::BackTab => {
+                                settings_menu_state.prev_tab();
+                            }
                             KeyCode::Up | KeyCode::Char('k') => {
                                 settings_menu_state.select_prev();
                             }
@@ -1910,7 +1732,18 @@ fn main() -> Result<(), io::Error> {
                             _ => {}
                         }
                     }
+                    AppState::Deployment(game_state, deployment) => {
+                        if game_state.handle_deployment_input(deployment, key) {
+                            let current_state = std::mem::replace(&mut app_state, AppState::MainMenu);
+                            if let AppState::Deployment(gs, _) = current_state {
+                                app_state = AppState::InGame(gs);
+                            }
+                        }
+                    }
                     AppState::InGame(game_state) => {
+                        // Any key press takes back control from an advance to contact
+                        game_state.advance_to_contact.stop();
+
                         match key.code {
                             KeyCode::Esc => {
                                 // Only pause from Command mode, let other modes handle ESC themselves
@@ -1931,11 +1764,7 @@ fn main() -> Result<(), io::Error> {
                                     turn_state.turn_order_mode,
                                     argue_the_toss::game_logic::turn_state::TurnOrderMode::PlayerFirst
                                 ) {
-                                    if let Some(player_entity) = game_state.get_player_entity() {
-                                        !turn_state.is_entity_ready(player_entity)
-                                    } else {
-                                        false
-                                    }
+                                    !turn_state.is_player_side_ready(game_state.get_player_entity())
                                 } else {
                                     true
                                 };
@@ -1947,18 +1776,30 @@ fn main() -> Result<(), io::Error> {
                             }
                         }
                     }
-                    AppState::Paused(_game_state) => {
-                        match key.code {
-                            KeyCode::Esc | KeyCode::Char('r') => {
-                                let current_state = std::mem::replace(&mut app_state, AppState::MainMenu);
-                                if let AppState::Paused(gs) = current_state {
-                                    app_state = AppState::InGame(gs);
+                    AppState::Paused(game_state) => {
+                        if game_state.timeline_selection.is_some() {
+                            game_state.handle_timeline_input(key);
+                        } else {
+                            match key.code {
+                                KeyCode::Esc | KeyCode::Char('r') => {
+                                    let current_state = std::mem::replace(&mut app_state, AppState::MainMenu);
+                                    if let AppState::Paused(gs) = current_state {
+                                        app_state = AppState::InGame(gs);
+                                    }
                                 }
+                                KeyCode::Char('t') => {
+                                    game_state.open_timeline();
+                                }
+                                KeyCode::Char('q') | KeyCode::Char('Q') => {
+                                    app_state = AppState::MainMenu;
+                                }
+                                _ => {}
                             }
-                            KeyCode::Char('q') | KeyCode::Char('Q') => {
-                                app_state = AppState::MainMenu;
-                            }
-                            _ => {}
+                        }
+                    }
+                    AppState::GameOver(..) => {
+                        if matches!(key.code, KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q')) {
+                            app_state = AppState::MainMenu;
                         }
                     }
                 }
@@ -1968,13 +1809,63 @@ fn main() -> Result<(), io::Error> {
         // Type transitions to NeedDispatch - input processing complete
         let guard = guard.input_processed();
 
+        // An advance to contact keeps the turns coming without input
+        if let AppState::InGame(game_state) = &mut app_state
+            && !input_occurred
+            && (game_state.continue_advance_to_contact() || game_state.continue_commander_turn())
+        {
+            input_occurred = true;
+        }
+
         // Second: Update game state ONLY if input occurred (turn-based game!)
         // Don't dispatch every frame - only when player/AI actions change game state
         if input_occurred {
+            if let AppState::Deployment(game_state, _) = &mut app_state {
+                game_state.update_visibility();
+                // Nothing to pause while the troops are still being placed
+                game_state.auto_pause.take();
+            }
             if let AppState::InGame(game_state) = &mut app_state {
                 game_state.update_visibility();
+                let before = BattleSnapshot::capture(&game_state.world, COMMANDER_FACTION);
                 dispatcher.dispatch(&game_state.world);
                 game_state.world.maintain();
+                spawn_reinforcements(&mut game_state.world, &game_state.config);
+                spawn_triggered_reinforcements(&mut game_state.world, &game_state.config);
+                assign_ai_roles(&game_state.world);
+                {
+                    let mut detonations = game_state.world.write_resource::<Detonations>();
+                    game_state.impact_flash.observe(&detonations, &game_state.camera);
+                    detonations.clear();
+                }
+                game_state
+                    .camera_follow
+                    .update(&game_state.world, &mut game_state.camera);
+                {
+                    let turn = game_state.world.read_resource::<TurnState>().current_turn;
+                    game_state.world.write_resource::<HeardShots>().prune(turn);
+                }
+                // Explosions reshape the ground; keep the drawn map in step
+                game_state
+                    .battlefield
+                    .sync_terrain_from(&game_state.world.read_resource::<Battlefield>());
+                let after = BattleSnapshot::capture(&game_state.world, COMMANDER_FACTION);
+                game_state.auto_pause.compare(&before, &after);
+                game_state.apply_auto_pause();
+            }
+
+            // A decided match (all objectives or the turn limit) ends play
+            let outcome = match &app_state {
+                AppState::InGame(game_state) => {
+                    check_game_over(&game_state.world, game_state.config.turn_limit)
+                }
+                _ => None,
+            };
+            if let Some(outcome) = outcome {
+                let current_state = std::mem::replace(&mut app_state, AppState::MainMenu);
+                if let AppState::InGame(gs) = current_state {
+                    app_state = AppState::GameOver(gs, outcome);
+                }
             }
         }
 
@@ -1996,12 +1887,28 @@ fn main() -> Result<(), io::Error> {
                     let widget = SettingsMenuWidget::new(&settings_menu_state);
                     f.render_widget(widget, f.area());
                 }
+                AppState::Deployment(game_state, deployment) => {
+                    game_state.update_viewport_size(f.area());
+                    ui(f, game_state);
+                    render_deployment(f, game_state, deployment);
+                }
                 AppState::InGame(game_state) => {
                     game_state.update_viewport_size(f.area());
                     ui(f, game_state);
+                    if game_state.impact_flash.is_lit() {
+                        f.render_widget(ImpactFlashWidget, f.area());
+                    }
                 }
                 AppState::Paused(game_state) => {
                     ui(f, game_state);
+                    if let Some(selected) = game_state.timeline_selection {
+                        let timeline = game_state.world.read_resource::<Timeline>();
+                        f.render_widget(TimelineViewerWidget::new(&timeline, selected), f.area());
+                    }
+                }
+                AppState::GameOver(game_state, outcome) => {
+                    ui(f, game_state);
+                    f.render_widget(GameOverWidget::new(outcome), f.area());
                 }
             }
         })?;
@@ -2012,6 +1919,12 @@ fn main() -> Result<(), io::Error> {
         // Frame complete - guard consumed
         guard.frame_complete();
 
+        // Tracers and the impact flash are drawn for the one frame after their shots
+        if let AppState::InGame(game_state) = &mut app_state {
+            game_state.world.write_resource::<ShotTracers>().clear();
+            game_state.impact_flash.frame_drawn();
+        }
+
         // Muzzle flashes are now cleaned up at start of Planning phase
         // (see TurnManagerSystem) so they persist into player's turn
     }
@@ -0,0 +1,84 @@
+// AI scoring debug overlay
+// Shows the inspected soldier's last planning pass, action by action
+
+use crate::ai::scoring_snapshot::ScoringSnapshot;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+
+/// Widget listing scored actions and their consideration contributions
+pub struct ScoringSnapshotWidget<'a> {
+    soldier_name: Option<&'a str>,
+    snapshot: Option<&'a ScoringSnapshot>,
+}
+
+impl<'a> ScoringSnapshotWidget<'a> {
+    pub fn new(soldier_name: Option<&'a str>, snapshot: Option<&'a ScoringSnapshot>) -> Self {
+        Self {
+            soldier_name,
+            snapshot,
+        }
+    }
+
+    fn lines(&self) -> Vec<Line<'a>> {
+        let snapshot = match (self.soldier_name, self.snapshot) {
+            (None, _) => return vec![Line::from("Move the cursor onto an AI soldier")],
+            (Some(_), None) => return vec![Line::from("No planning pass recorded yet")],
+            (Some(_), Some(snapshot)) => snapshot,
+        };
+
+        let mut lines = vec![Line::from(format!(
+            "{} | turn {}",
+            snapshot.personality, snapshot.turn
+        ))];
+
+        let mut actions: Vec<_> = snapshot.actions.iter().collect();
+        actions.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+
+        let chosen = snapshot.best();
+        for entry in actions {
+            let style = if chosen.is_some_and(|chosen| std::ptr::eq(chosen, entry)) {
+                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let evaluator = entry.evaluator.as_deref().unwrap_or("-");
+            lines.push(Line::from(Span::styled(
+                format!("{:.3} {} {:?}", entry.score, evaluator, entry.action_type),
+                style,
+            )));
+
+            for (name, score) in &entry.considerations {
+                lines.push(Line::from(Span::styled(
+                    format!("    {:<18} {:.2}", name, score),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+
+        lines
+    }
+}
+
+impl<'a> Widget for ScoringSnapshotWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = match self.soldier_name {
+            Some(name) => format!("AI Scores: {}", name),
+            None => "AI Scores".to_string(),
+        };
+
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::LightRed));
+
+        Paragraph::new(Text::from(self.lines()))
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,114 @@
+// Camera follow
+// Keeps the camera on a unit the player has ordered to move
+//
+// With "Watch Orders" on, ordering a squad makes its leading soldier the
+// follow target. After each turn the game loop re-centres the camera on where
+// that soldier now stands, until they arrive (the path runs out), are
+// interrupted (the path is dropped) or fall.
+
+use crate::components::{dead::Dead, pathfinding::PlannedPath, position::Position};
+use crate::game_logic::battlefield::Battlefield;
+use crate::rendering::viewport::Camera;
+use specs::{Entity, World, WorldExt};
+
+/// The unit the camera is watching, if any
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CameraFollow {
+    pub enabled: bool,
+    target: Option<Entity>,
+}
+
+impl CameraFollow {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            target: None,
+        }
+    }
+
+    /// Start watching `entity`, if watching orders is turned on
+    pub fn watch(&mut self, entity: Entity) {
+        if self.enabled {
+            self.target = Some(entity);
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.target = None;
+    }
+
+    pub fn target(&self) -> Option<Entity> {
+        self.target
+    }
+
+    /// Centre `camera` on the watched unit; returns whether it is still being watched
+    ///
+    /// The camera takes in the unit's last step even when that step ends the
+    /// watch, so it settles where the unit stopped.
+    pub fn update(&mut self, world: &World, camera: &mut Camera) -> bool {
+        let Some(target) = self.target else {
+            return false;
+        };
+
+        if let Some(pos) = world.read_storage::<Position>().get(target) {
+            let battlefield = world.read_resource::<Battlefield>();
+            camera.center_on(*pos.as_battlefield_pos());
+            camera.constrain(battlefield.width(), battlefield.height());
+        }
+
+        let still_moving = world.entities().is_alive(target)
+            && !world.read_storage::<Dead>().contains(target)
+            && world
+                .read_storage::<PlannedPath>()
+                .get(target)
+                .is_some_and(|path| !path.steps.is_empty());
+        if !still_moving {
+            self.target = None;
+        }
+        still_moving
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::battlefield::Position as BattlefieldPos;
+    use crate::game_logic::world_setup::register_components;
+    use specs::Builder;
+
+    #[test]
+    fn test_camera_tracks_followed_unit_until_it_arrives() {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(Battlefield::new(60, 60));
+        let steps = vec![BattlefieldPos::new(31, 30), BattlefieldPos::new(32, 30)];
+        let unit = world
+            .create_entity()
+            .with(Position::new(30, 30))
+            .with(PlannedPath::new(steps, 2.0, true))
+            .build();
+        let mut camera = Camera::new(BattlefieldPos::new(10, 10), 20, 10);
+        let mut follow = CameraFollow::new(true);
+        follow.watch(unit);
+
+        // Each turn the unit takes a step along its path
+        for x in 31..=32 {
+            *world.write_storage::<Position>().get_mut(unit).unwrap() = Position::new(x, 30);
+            world
+                .write_storage::<PlannedPath>()
+                .get_mut(unit)
+                .unwrap()
+                .steps
+                .remove(0);
+            let watching = follow.update(&world, &mut camera);
+            assert_eq!(camera.center, BattlefieldPos::new(x, 30));
+            assert_eq!(watching, x < 32, "the watch ends on arrival");
+        }
+        assert!(follow.target().is_none());
+
+        // Once the watch has ended the camera stays put
+        *world.write_storage::<Position>().get_mut(unit).unwrap() = Position::new(40, 40);
+        assert!(!follow.update(&world, &mut camera));
+        assert_eq!(camera.center, BattlefieldPos::new(32, 30));
+    }
+}
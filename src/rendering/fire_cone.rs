@@ -0,0 +1,89 @@
+// Fire cone overlay
+// The player's forward vision cone: where they can see and shoot without turning
+
+use crate::components::facing::Direction8;
+use crate::game_logic::battlefield::{Battlefield, Position};
+use crate::game_logic::vision_cone::calculate_vision_cone;
+use crate::rendering::viewport::Camera;
+use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+use std::collections::HashSet;
+
+/// Background tint for tiles inside the player's cone, kept faint
+const FIRE_CONE_TINT: Color = Color::Rgb(20, 35, 20);
+
+/// Main vision cone from a position and facing
+#[derive(Debug, Clone)]
+pub struct FireConeOverlay {
+    pub origin: Position,
+    pub facing: Direction8,
+    pub range: i32,
+    pub tiles: HashSet<Position>,
+}
+
+impl FireConeOverlay {
+    pub fn new(origin: Position, facing: Direction8, range: i32, battlefield: &Battlefield) -> Self {
+        let (main_vision, _peripheral) = calculate_vision_cone(&origin, facing, range, battlefield);
+        Self {
+            origin,
+            facing,
+            range,
+            tiles: main_vision,
+        }
+    }
+
+    pub fn contains(&self, pos: &Position) -> bool {
+        self.tiles.contains(pos)
+    }
+}
+
+/// Tints the cone's tiles without touching what is drawn on them
+pub struct FireConeOverlayWidget<'a> {
+    overlay: &'a FireConeOverlay,
+    camera: &'a Camera,
+}
+
+impl<'a> FireConeOverlayWidget<'a> {
+    pub fn new(overlay: &'a FireConeOverlay, camera: &'a Camera) -> Self {
+        Self { overlay, camera }
+    }
+}
+
+impl<'a> Widget for FireConeOverlayWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let top_left = self.camera.top_left();
+
+        for pos in &self.overlay.tiles {
+            let screen_x = pos.x - top_left.x;
+            let screen_y = pos.y - top_left.y;
+            if screen_x < 0
+                || screen_y < 0
+                || screen_x >= area.width as i32
+                || screen_y >= area.height as i32
+            {
+                continue;
+            }
+
+            buf[(area.x + screen_x as u16, area.y + screen_y as u16)].set_bg(FIRE_CONE_TINT);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cone_matches_vision_cone_for_facing() {
+        let battlefield = Battlefield::new(40, 40);
+        let origin = Position::new(20, 20);
+
+        let north = FireConeOverlay::new(origin, Direction8::N, 8, &battlefield);
+        let (main_vision, _) = calculate_vision_cone(&origin, Direction8::N, 8, &battlefield);
+        assert_eq!(north.tiles, main_vision);
+        assert!(north.contains(&Position::new(20, 14)));
+        assert!(!north.contains(&Position::new(20, 26)), "nothing behind the player");
+
+        let south = FireConeOverlay::new(origin, Direction8::S, 8, &battlefield);
+        assert!(south.contains(&Position::new(20, 26)), "turning swings the cone round");
+    }
+}
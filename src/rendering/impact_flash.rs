@@ -0,0 +1,87 @@
+// Impact flash
+// A one-frame border flash when a detonation lands in view
+//
+// After the systems run, the game loop shows ImpactFlash the turn's
+// detonations; one inside the camera's view lights the screen border for the
+// next frame drawn. Blasts off-screen are left to the event log.
+
+use crate::components::detonation::Detonations;
+use crate::rendering::viewport::Camera;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Widget},
+};
+
+/// Border color while the flash is lit
+pub const FLASH_COLOR: Color = Color::Rgb(255, 150, 60);
+
+/// Whether the next frame gets the impact border
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImpactFlash {
+    pub enabled: bool,
+    lit: bool,
+}
+
+impl ImpactFlash {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled, lit: false }
+    }
+
+    /// Light the flash if any detonation landed inside the camera's view
+    pub fn observe(&mut self, detonations: &Detonations, camera: &Camera) {
+        if self.enabled && detonations.iter().any(|pos| camera.is_visible(pos)) {
+            self.lit = true;
+        }
+    }
+
+    pub fn is_lit(&self) -> bool {
+        self.lit
+    }
+
+    /// Put the flash out once a frame has shown it
+    pub fn frame_drawn(&mut self) {
+        self.lit = false;
+    }
+}
+
+/// The flash itself: a bright border around the whole screen
+pub struct ImpactFlashWidget;
+
+impl Widget for ImpactFlashWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(FLASH_COLOR))
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::battlefield::Position;
+
+    #[test]
+    fn test_blast_in_view_flashes_for_one_frame_and_off_screen_does_not() {
+        let camera = Camera::new(Position::new(20, 20), 20, 10);
+        let mut flash = ImpactFlash::new(true);
+
+        let mut far_away = Detonations::new();
+        far_away.record(Position::new(60, 60));
+        flash.observe(&far_away, &camera);
+        assert!(!flash.is_lit());
+
+        let mut in_view = Detonations::new();
+        in_view.record(Position::new(22, 18));
+        flash.observe(&in_view, &camera);
+        assert!(flash.is_lit());
+        flash.frame_drawn();
+        assert!(!flash.is_lit(), "the flash lasts a single frame");
+
+        let mut disabled = ImpactFlash::new(false);
+        disabled.observe(&in_view, &camera);
+        assert!(!disabled.is_lit());
+    }
+}
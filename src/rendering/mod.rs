@@ -1,6 +1,11 @@
 // Rendering Module
 // Handles all UI and TUI rendering using ratatui
 
+pub mod ai_debug;
+pub mod camera_follow;
+pub mod fire_cone;
+pub mod impact_flash;
+pub mod sightlines;
 pub mod viewport;
 pub mod widgets;
 
@@ -0,0 +1,89 @@
+// Sightline peek overlay
+// What a soldier standing at the Look cursor could see, for judging ambush positions
+
+use crate::components::soldier::Rank;
+use crate::game_logic::battlefield::{Battlefield, Position};
+use crate::game_logic::line_of_sight::calculate_fov;
+use crate::rendering::viewport::Camera;
+use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+use std::collections::HashSet;
+
+/// Background tint for tiles inside the peeked field of view
+const SIGHTLINE_TINT: Color = Color::Rgb(70, 20, 20);
+
+/// Hypothetical field of view from a tile
+#[derive(Debug, Clone)]
+pub struct SightlineOverlay {
+    pub origin: Position,
+    pub range: i32,
+    pub tiles: HashSet<Position>,
+}
+
+impl SightlineOverlay {
+    pub fn new(origin: Position, range: i32, battlefield: &Battlefield) -> Self {
+        Self {
+            origin,
+            range,
+            tiles: calculate_fov(&origin, range, battlefield),
+        }
+    }
+
+    /// Peek with a private's vision range, the most common enemy
+    pub fn with_default_range(origin: Position, battlefield: &Battlefield) -> Self {
+        Self::new(origin, Rank::Private.base_stats().vision_range, battlefield)
+    }
+
+    pub fn contains(&self, pos: &Position) -> bool {
+        self.tiles.contains(pos)
+    }
+}
+
+/// Tints the overlay's tiles without touching what is drawn on them
+pub struct SightlineOverlayWidget<'a> {
+    overlay: &'a SightlineOverlay,
+    camera: &'a Camera,
+}
+
+impl<'a> SightlineOverlayWidget<'a> {
+    pub fn new(overlay: &'a SightlineOverlay, camera: &'a Camera) -> Self {
+        Self { overlay, camera }
+    }
+}
+
+impl<'a> Widget for SightlineOverlayWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let top_left = self.camera.top_left();
+
+        for pos in &self.overlay.tiles {
+            let screen_x = pos.x - top_left.x;
+            let screen_y = pos.y - top_left.y;
+            if screen_x < 0
+                || screen_y < 0
+                || screen_x >= area.width as i32
+                || screen_y >= area.height as i32
+            {
+                continue;
+            }
+
+            buf[(area.x + screen_x as u16, area.y + screen_y as u16)].set_bg(SIGHTLINE_TINT);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlay_matches_fov_from_cursor() {
+        let battlefield = Battlefield::new(40, 40);
+        let cursor = Position::new(12, 20);
+
+        let overlay = SightlineOverlay::with_default_range(cursor, &battlefield);
+
+        let range = Rank::Private.base_stats().vision_range;
+        assert_eq!(overlay.range, range);
+        assert_eq!(overlay.tiles, calculate_fov(&cursor, range, &battlefield));
+        assert!(overlay.contains(&cursor));
+    }
+}
@@ -1,5 +1,6 @@
 // Viewport and camera system for battlefield rendering
 
+use crate::config::game_config::DEFAULT_CAMERA_DEADZONE;
 use crate::game_logic::battlefield::Position;
 
 /// Camera that controls what portion of the battlefield is visible
@@ -11,30 +12,36 @@ pub struct Camera {
     pub viewport_width: usize,
     /// Height of the viewport in tiles
     pub viewport_height: usize,
-}
-
-impl Camera {
     /// Deadzone size as fraction of viewport (0.0 to 1.0)
     /// Player can move within this zone without camera following
-    const DEADZONE_RATIO: f32 = 0.33;
+    pub deadzone_ratio: f32,
+}
 
+impl Camera {
     /// Creates a new camera centered at the given position
     pub fn new(center: Position, viewport_width: usize, viewport_height: usize) -> Self {
         Self {
             center,
             viewport_width,
             viewport_height,
+            deadzone_ratio: DEFAULT_CAMERA_DEADZONE,
         }
     }
 
+    /// Set the deadzone size as a fraction of the viewport (clamped to 0.0-1.0)
+    pub fn with_deadzone_ratio(mut self, ratio: f32) -> Self {
+        self.deadzone_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
     /// Gets the adaptive deadzone width based on current viewport
     pub fn deadzone_width(&self) -> i32 {
-        (self.viewport_width as f32 * Self::DEADZONE_RATIO) as i32
+        (self.viewport_width as f32 * self.deadzone_ratio) as i32
     }
 
     /// Gets the adaptive deadzone height based on current viewport
     pub fn deadzone_height(&self) -> i32 {
-        (self.viewport_height as f32 * Self::DEADZONE_RATIO) as i32
+        (self.viewport_height as f32 * self.deadzone_ratio) as i32
     }
 
     /// Checks if a position is within the camera's deadzone
@@ -113,6 +120,56 @@ impl Camera {
     }
 }
 
+/// Numbered camera bookmarks (slots 1-9) for jumping around large maps
+#[derive(Debug, Clone, Default)]
+pub struct CameraBookmarks {
+    slots: [Option<Position>; 9],
+}
+
+impl CameraBookmarks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores a position in a slot (1-9); returns false for invalid slots
+    pub fn set(&mut self, slot: usize, pos: Position) -> bool {
+        match slot.checked_sub(1).and_then(|i| self.slots.get_mut(i)) {
+            Some(entry) => {
+                *entry = Some(pos);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Gets the position stored in a slot (1-9)
+    pub fn get(&self, slot: usize) -> Option<Position> {
+        slot.checked_sub(1)
+            .and_then(|i| self.slots.get(i))
+            .copied()
+            .flatten()
+    }
+
+    /// Centers the camera on a stored bookmark, keeping it within bounds
+    /// Returns false if the slot is empty
+    pub fn jump(
+        &self,
+        slot: usize,
+        camera: &mut Camera,
+        battlefield_width: usize,
+        battlefield_height: usize,
+    ) -> bool {
+        match self.get(slot) {
+            Some(pos) => {
+                camera.center_on(pos);
+                camera.constrain(battlefield_width, battlefield_height);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,4 +203,46 @@ mod tests {
         assert!(camera.is_visible(&Position::new(0, 0)));
         assert!(!camera.is_visible(&Position::new(25, 25)));
     }
+
+    #[test]
+    fn test_larger_deadzone_delays_pan() {
+        let start = Position::new(50, 50);
+        let mut tight = Camera::new(start, 40, 40).with_deadzone_ratio(0.2);
+        let mut loose = Camera::new(start, 40, 40).with_deadzone_ratio(0.6);
+
+        // 6 tiles out: past the tight half-width (4) but inside the loose one (12)
+        let target = Position::new(56, 50);
+        tight.follow_target(&target);
+        loose.follow_target(&target);
+
+        assert_eq!(tight.center, target);
+        assert_eq!(loose.center, start);
+
+        // Further out than the loose deadzone allows, it pans too
+        let far = Position::new(63, 50);
+        loose.follow_target(&far);
+        assert_eq!(loose.center, far);
+    }
+
+    #[test]
+    fn test_bookmark_jump_centers_camera() {
+        let mut bookmarks = CameraBookmarks::new();
+        let mut camera = Camera::new(Position::new(10, 10), 20, 20);
+
+        assert!(bookmarks.set(3, Position::new(60, 45)));
+        assert!(bookmarks.jump(3, &mut camera, 100, 100));
+
+        assert_eq!(camera.center, Position::new(60, 45));
+    }
+
+    #[test]
+    fn test_bookmark_empty_and_invalid_slots() {
+        let mut bookmarks = CameraBookmarks::new();
+        let mut camera = Camera::new(Position::new(10, 10), 20, 20);
+
+        assert!(!bookmarks.jump(1, &mut camera, 100, 100));
+        assert!(!bookmarks.set(0, Position::new(5, 5)));
+        assert!(!bookmarks.set(10, Position::new(5, 5)));
+        assert_eq!(camera.center, Position::new(10, 10));
+    }
 }
@@ -1,17 +1,73 @@
 // Custom ratatui widgets for battlefield rendering
 
 use crate::components::soldier::Faction;
+use crate::config::glyph_theme::GlyphTheme;
 use crate::game_logic::battlefield::{Battlefield, Position};
-use crate::game_logic::objectives::Objectives;
+use crate::game_logic::objectives::{FlagStatus, ObjectiveFlag, Objectives};
 use crate::rendering::viewport::Camera;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     widgets::Widget,
 };
 use std::collections::HashMap;
 
+/// How objective flags are coloured, by who holds them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlagStyle {
+    pub allies: Style,
+    pub central_powers: Style,
+    /// A flag with a capture under way, whoever holds it
+    pub contested: Style,
+    pub neutral: Style,
+}
+
+impl FlagStyle {
+    pub fn with_allies(mut self, style: Style) -> Self {
+        self.allies = style;
+        self
+    }
+
+    pub fn with_central_powers(mut self, style: Style) -> Self {
+        self.central_powers = style;
+        self
+    }
+
+    pub fn with_contested(mut self, style: Style) -> Self {
+        self.contested = style;
+        self
+    }
+
+    pub fn with_neutral(mut self, style: Style) -> Self {
+        self.neutral = style;
+        self
+    }
+
+    /// Style for a flag in its current state
+    pub fn style_for(&self, flag: &ObjectiveFlag) -> Style {
+        match (flag.status(), flag.owning_faction) {
+            (FlagStatus::Contested, _) => self.contested,
+            (_, Some(Faction::Allies)) => self.allies,
+            (_, Some(Faction::CentralPowers)) => self.central_powers,
+            (_, None) => self.neutral,
+        }
+    }
+}
+
+impl Default for FlagStyle {
+    fn default() -> Self {
+        Self {
+            allies: Style::default().fg(Color::Blue),
+            central_powers: Style::default().fg(Color::Red),
+            contested: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD | Modifier::SLOW_BLINK),
+            neutral: Style::default().fg(Color::White),
+        }
+    }
+}
+
 /// Widget that renders the battlefield viewport
 pub struct BattlefieldWidget<'a> {
     battlefield: &'a Battlefield,
@@ -19,6 +75,8 @@ pub struct BattlefieldWidget<'a> {
     show_fog_of_war: bool,
     peripheral_tiles: Option<&'a HashMap<Position, bool>>,
     objectives: Option<&'a Objectives>,
+    flag_style: FlagStyle,
+    glyphs: Option<&'a GlyphTheme>,
 }
 
 impl<'a> BattlefieldWidget<'a> {
@@ -29,6 +87,8 @@ impl<'a> BattlefieldWidget<'a> {
             show_fog_of_war: true,
             peripheral_tiles: None,
             objectives: None,
+            flag_style: FlagStyle::default(),
+            glyphs: None,
         }
     }
 
@@ -46,6 +106,17 @@ impl<'a> BattlefieldWidget<'a> {
         self.objectives = Some(objectives);
         self
     }
+
+    /// Colours for objective flags (held by each side, contested, neutral)
+    pub fn with_flag_style(mut self, style: FlagStyle) -> Self {
+        self.flag_style = style;
+        self
+    }
+
+    pub fn with_glyphs(mut self, glyphs: &'a GlyphTheme) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
 }
 
 impl<'a> Widget for BattlefieldWidget<'a> {
@@ -63,6 +134,9 @@ impl<'a> Widget for BattlefieldWidget<'a> {
                 // Get the tile at this position
                 if let Some(tile) = self.battlefield.get_tile(&world_pos) {
                     let props = tile.terrain.properties();
+                    let glyph = self
+                        .glyphs
+                        .map_or(props.character, |glyphs| glyphs.terrain(tile.terrain));
                     let (ch, style) = if self.show_fog_of_war {
                         if tile.visible {
                             // Check if this is peripheral vision (dimmed)
@@ -73,21 +147,21 @@ impl<'a> Widget for BattlefieldWidget<'a> {
 
                             if is_peripheral {
                                 // Peripheral vision: dimmed (50% brightness via gray color)
-                                (props.character, Style::default().fg(Color::Gray))
+                                (glyph, Style::default().fg(Color::Gray))
                             } else {
                                 // Main vision: full brightness with terrain-specific color
-                                (props.character, Style::default().fg(props.color))
+                                (glyph, Style::default().fg(props.color))
                             }
                         } else if tile.explored {
                             // Explored but not currently visible (dark gray)
-                            (props.character, Style::default().fg(Color::DarkGray))
+                            (glyph, Style::default().fg(Color::DarkGray))
                         } else {
                             // Unexplored (black/hidden)
                             (' ', Style::default())
                         }
                     } else {
                         // No fog of war, always visible with terrain-specific color
-                        (props.character, Style::default().fg(props.color))
+                        (glyph, Style::default().fg(props.color))
                     };
 
                     // Calculate buffer position
@@ -126,18 +200,82 @@ impl<'a> Widget for BattlefieldWidget<'a> {
                     let buf_y = area.y + screen_y as u16;
 
                     if buf_x < area.right() && buf_y < area.bottom() {
-                        let flag_char = '⚑';
-                        let flag_color = match flag.owning_faction {
-                            Faction::Allies => Color::Blue,
-                            Faction::CentralPowers => Color::Red,
-                        };
+                        let flag_char = self
+                            .glyphs
+                            .map_or('⚑', |glyphs| glyphs.flag(flag.status()));
 
                         buf[(buf_x, buf_y)]
                             .set_char(flag_char)
-                            .set_style(Style::default().fg(flag_color));
+                            .set_style(self.flag_style.style_for(flag));
                     }
                 }
             }
+
+            // Aid posts, in the colour of the side that evacuates through them
+            for post in &objectives.evac_points {
+                let screen_x = post.position.x - top_left.x;
+                let screen_y = post.position.y - top_left.y;
+
+                if screen_x >= 0
+                    && screen_x < area.width as i32
+                    && screen_y >= 0
+                    && screen_y < area.height as i32
+                {
+                    let post_color = match post.faction {
+                        Faction::Allies => Color::Blue,
+                        Faction::CentralPowers => Color::Red,
+                    };
+                    buf[(area.x + screen_x as u16, area.y + screen_y as u16)]
+                        .set_char('+')
+                        .set_style(Style::default().fg(post_color));
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::battlefield::TerrainType;
+
+    #[test]
+    fn test_ascii_glyphs_replace_water_and_tree() {
+        let mut battlefield = Battlefield::new(5, 5);
+        battlefield.set_terrain(Position::new(0, 0), TerrainType::Water);
+        battlefield.set_terrain(Position::new(1, 0), TerrainType::Tree);
+        let camera = Camera::new(Position::new(2, 2), 5, 5);
+        let area = Rect::new(0, 0, 5, 5);
+
+        let render = |glyphs: &GlyphTheme| {
+            let mut buf = Buffer::empty(area);
+            BattlefieldWidget::new(&battlefield, &camera)
+                .show_fog_of_war(false)
+                .with_glyphs(glyphs)
+                .render(area, &mut buf);
+            (buf[(0, 0)].symbol().to_string(), buf[(1, 0)].symbol().to_string())
+        };
+
+        assert_eq!(render(&GlyphTheme::unicode()), ("≈".to_string(), "♣".to_string()));
+        assert_eq!(render(&GlyphTheme::ascii()), ("~".to_string(), "T".to_string()));
+    }
+
+    #[test]
+    fn test_contested_flag_stands_out_from_an_owned_one() {
+        let style = FlagStyle::default();
+        let held = ObjectiveFlag::new(Position::new(3, 3), Faction::Allies);
+        let mut contested = held.clone();
+        contested.increment_progress(Faction::CentralPowers);
+
+        assert_eq!(contested.status(), FlagStatus::Contested);
+        assert_ne!(style.style_for(&contested), style.style_for(&held));
+        assert_ne!(
+            style.style_for(&held),
+            style.style_for(&ObjectiveFlag::new(Position::new(3, 3), Faction::CentralPowers))
+        );
+        assert_eq!(style.style_for(&ObjectiveFlag::neutral(Position::new(3, 3))), style.neutral);
+
+        let custom = style.with_contested(Style::default().fg(Color::Magenta));
+        assert_eq!(custom.style_for(&contested).fg, Some(Color::Magenta));
+    }
+}
@@ -0,0 +1,79 @@
+// Random Events
+// Mines, grenade duds and weapon misfires, all gated by RandomEventsConfig
+
+use crate::config::game_config::RandomEventsConfig;
+use crate::game_logic::battlefield::Position;
+use crate::game_logic::blast::blast_damage;
+use crate::utils::game_rng::GameRng;
+
+/// Roll whether a thrown grenade fails to explode
+pub fn grenade_is_dud(config: &RandomEventsConfig, rng: &mut GameRng) -> bool {
+    config.enabled && rng.roll(config.grenade_dud_chance)
+}
+
+/// Roll whether a weapon misfires instead of shooting
+pub fn weapon_misfires(config: &RandomEventsConfig, rng: &mut GameRng) -> bool {
+    config.enabled && rng.roll(config.misfire_chance)
+}
+
+/// Damage a mine detonating at `center` deals to a soldier at `pos`
+///
+/// Full damage on the mine tile, falling off linearly to the edge of the
+/// blast radius. Returns None outside the radius.
+pub fn mine_blast_damage(config: &RandomEventsConfig, center: &Position, pos: &Position) -> Option<i32> {
+    blast_damage(*center, config.mine_radius, config.mine_damage, *pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled_config() -> RandomEventsConfig {
+        RandomEventsConfig {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dud_probability_respected() {
+        let config = RandomEventsConfig {
+            grenade_dud_chance: 0.2,
+            ..enabled_config()
+        };
+        let mut rng = GameRng::new(42);
+
+        let samples = 10_000;
+        let duds = (0..samples).filter(|_| grenade_is_dud(&config, &mut rng)).count();
+        let rate = duds as f32 / samples as f32;
+
+        assert!((rate - 0.2).abs() < 0.02, "dud rate {} too far from 0.2", rate);
+    }
+
+    #[test]
+    fn test_disabled_events_never_fire() {
+        let config = RandomEventsConfig {
+            enabled: false,
+            grenade_dud_chance: 1.0,
+            misfire_chance: 1.0,
+            ..Default::default()
+        };
+        let mut rng = GameRng::new(42);
+
+        assert!(!grenade_is_dud(&config, &mut rng));
+        assert!(!weapon_misfires(&config, &mut rng));
+    }
+
+    #[test]
+    fn test_mine_blast_falloff() {
+        let config = enabled_config();
+        let center = Position::new(10, 10);
+
+        let direct = mine_blast_damage(&config, &center, &center).unwrap();
+        let adjacent = mine_blast_damage(&config, &center, &Position::new(11, 11)).unwrap();
+
+        assert_eq!(direct, config.mine_damage);
+        assert!(adjacent > 0 && adjacent < direct);
+        assert!(mine_blast_damage(&config, &center, &Position::new(13, 10)).is_none());
+    }
+}
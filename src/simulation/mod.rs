@@ -1,8 +1,10 @@
 // Simulation Module
 // Environmental and world simulation systems
 
+pub mod events;
+pub mod headless_match;
+pub mod weather;
+
 // Future simulation submodules:
-// pub mod weather;
 // pub mod time;
-// pub mod events;
 // pub mod physics;
@@ -0,0 +1,142 @@
+// Weather Simulation
+// Wind resource deflecting long-range fire, and the time of day
+
+use crate::components::facing::Direction8;
+use crate::game_logic::battlefield::Position;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Time of day for the current match
+///
+/// At night a shot's muzzle flash gives the shooter's position away to
+/// nearby enemies, even ones that can't otherwise see them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimeOfDay {
+    #[default]
+    Day,
+    Night,
+}
+
+impl TimeOfDay {
+    pub fn is_night(&self) -> bool {
+        matches!(self, TimeOfDay::Night)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeOfDay::Day => "Day",
+            TimeOfDay::Night => "Night",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            TimeOfDay::Day => TimeOfDay::Night,
+            TimeOfDay::Night => TimeOfDay::Day,
+        }
+    }
+}
+
+/// Wind for the current match
+///
+/// `direction` is where the wind blows TOWARD (downwind), `strength` is 0.0-1.0.
+/// Only long-range shots feel it: there is no gas or smoke yet for it to carry.
+#[derive(Debug, Clone, Copy)]
+pub struct Wind {
+    pub direction: Direction8,
+    pub strength: f32,
+}
+
+impl Wind {
+    /// Shots shorter than this (in tiles) are unaffected by wind
+    pub const LONG_RANGE_THRESHOLD: f32 = 20.0;
+    /// Aim-point drift per tile beyond the threshold at full strength
+    const DRIFT_PER_TILE: f32 = 0.15;
+
+    pub fn new(direction: Direction8, strength: f32) -> Self {
+        Self {
+            direction,
+            strength: strength.clamp(0.0, 1.0),
+        }
+    }
+
+    pub fn calm() -> Self {
+        Self::new(Direction8::N, 0.0)
+    }
+
+    /// Roll a random wind for a new match
+    pub fn random(rng: &mut impl Rng) -> Self {
+        let directions = [
+            Direction8::N,
+            Direction8::NE,
+            Direction8::E,
+            Direction8::SE,
+            Direction8::S,
+            Direction8::SW,
+            Direction8::W,
+            Direction8::NW,
+        ];
+        let direction = directions[rng.random_range(0..directions.len())];
+        Self::new(direction, rng.random_range(0.0..0.8))
+    }
+
+    /// Unit vector pointing downwind
+    fn unit_vector(&self) -> (f32, f32) {
+        let (dx, dy) = self.direction.to_vector();
+        let len = ((dx * dx + dy * dy) as f32).sqrt();
+        (dx as f32 / len, dy as f32 / len)
+    }
+
+    /// Point the bullet actually travels toward when aiming at `target`
+    ///
+    /// Below LONG_RANGE_THRESHOLD this is the target itself; beyond it the
+    /// aim point drifts downwind proportionally to the extra distance.
+    pub fn effective_aim_point(&self, shooter: &Position, target: &Position) -> (f32, f32) {
+        let drift = self.drift_tiles(shooter.distance_to(target));
+        let (wx, wy) = self.unit_vector();
+        (target.x as f32 + wx * drift, target.y as f32 + wy * drift)
+    }
+
+    /// How many tiles the aim point drifts at a given shot distance
+    pub fn drift_tiles(&self, distance: f32) -> f32 {
+        (distance - Self::LONG_RANGE_THRESHOLD).max(0.0) * Self::DRIFT_PER_TILE * self.strength
+    }
+
+    /// Short label for the HUD, e.g. "→ E 40%"
+    pub fn display(&self) -> String {
+        if self.strength <= 0.0 {
+            return "Calm".to_string();
+        }
+        format!(
+            "{} {:?} {:.0}%",
+            self.direction.to_char(),
+            self.direction,
+            self.strength * 100.0
+        )
+    }
+}
+
+impl Default for Wind {
+    fn default() -> Self {
+        Self::calm()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wind_shifts_aim_at_long_range() {
+        let wind = Wind::new(Direction8::S, 1.0);
+        let shooter = Position::new(0, 0);
+
+        let near = Position::new(10, 0);
+        assert_eq!(wind.effective_aim_point(&shooter, &near), (10.0, 0.0));
+
+        let far = Position::new(30, 0);
+        let (aim_x, aim_y) = wind.effective_aim_point(&shooter, &far);
+        assert_eq!(aim_x, 30.0);
+        assert!(aim_y > 1.0, "expected southward drift, got {}", aim_y);
+    }
+}
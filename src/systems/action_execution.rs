@@ -20,23 +20,51 @@
 
 use crate::components::{
     action::{ActionType, OngoingAction, QueuedAction},
+    aiming::Aiming,
+    blood_trail::BloodTrail,
     dead::Dead,
     facing::Facing,
     health::Health,
+    heard_shot::{misjudge_origin, HeardShots, HEARING_RANGE},
+    inventory::Inventory,
+    last_seen::{FlashSightings, LastSeenMarker},
+    last_stand::LastStand,
     muzzle_flash::MuzzleFlash,
     player::Player,
     position::Position,
-    soldier::Soldier,
+    prone::Prone,
+    rested::Rested,
+    soldier::{Faction, Rank, Soldier},
     soldier_stats::SoldierStats,
+    surrendered::Surrendered,
+    time_budget::TimeBudget,
+    detonation::Detonations,
+    tracer::ShotTracers,
+    under_fire::UnderFire,
     vision::Vision,
-    weapon::Weapon,
+    weapon::{Looted, SecondaryWeapon, Weapon},
+};
+use crate::config::game_config::{
+    BloodTrailConfig, CorpseRule, CoverModel, FatigueConfig, LogVerbosity, MoveConflictRule, ReloadInterrupt,
+    RandomEventsConfig, WoundedConfig,
+};
+use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos, TerrainType};
+use crate::game_logic::blast::{blast_damage, crater_ground, GRENADE_BLAST_RADIUS, GRENADE_DAMAGE};
+use crate::game_logic::combat::{
+    apply_damage, calculate_shot, ShotModifiers, ShotParams, MOVING_FIRE_ACCURACY, PANIC_FIRE_ACCURACY,
+    PANIC_FIRE_ROUNDS,
 };
-use crate::game_logic::battlefield::Battlefield;
-use crate::game_logic::combat::{apply_damage, calculate_shot};
 use crate::game_logic::line_of_sight::calculate_fov;
+use crate::game_logic::obstacles::lay_wire;
+use crate::game_logic::spatial_index::PositionIndex;
 use crate::game_logic::turn_state::{TurnPhase, TurnState};
+use crate::simulation::events::{grenade_is_dud, weapon_misfires};
+use crate::simulation::weather::{TimeOfDay, Wind};
 use crate::utils::event_log::EventLog;
-use specs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
+use crate::utils::game_rng::GameRng;
+use rand::Rng;
+use specs::{Entities, Entity, Join, Read, ReadStorage, System, Write, WriteStorage};
+use std::collections::{HashMap, HashSet};
 
 pub struct ActionExecutionSystem;
 
@@ -48,16 +76,49 @@ impl<'a> System<'a> for ActionExecutionSystem {
         WriteStorage<'a, Facing>,
         WriteStorage<'a, OngoingAction>,
         WriteStorage<'a, Weapon>,
+        WriteStorage<'a, SecondaryWeapon>,
         WriteStorage<'a, Health>,
         WriteStorage<'a, Dead>,
+        WriteStorage<'a, Inventory>,
         ReadStorage<'a, Vision>,
         ReadStorage<'a, Soldier>,
         ReadStorage<'a, SoldierStats>,
         ReadStorage<'a, Player>,
+        ReadStorage<'a, Surrendered>,
         WriteStorage<'a, MuzzleFlash>,
+        WriteStorage<'a, UnderFire>,
         Write<'a, EventLog>,
         Read<'a, TurnState>,
-        Read<'a, Battlefield>,
+        Write<'a, Battlefield>,
+        // Nested: shred caps SystemData tuples at 26 elements
+        (
+            Read<'a, RandomEventsConfig>,
+            Read<'a, WoundedConfig>,
+            Read<'a, BloodTrailConfig>,
+            Read<'a, MoveConflictRule>,
+            Read<'a, CorpseRule>,
+            Read<'a, CoverModel>,
+            Read<'a, LogVerbosity>,
+            Read<'a, FatigueConfig>,
+            Read<'a, ReloadInterrupt>,
+        ),
+        Write<'a, GameRng>,
+        (Read<'a, Wind>, Read<'a, TimeOfDay>),
+        Write<'a, PositionIndex>,
+        (
+            Write<'a, FlashSightings>,
+            Write<'a, ShotTracers>,
+            Write<'a, Detonations>,
+            Write<'a, HeardShots>,
+        ),
+        (
+            WriteStorage<'a, Prone>,
+            WriteStorage<'a, BloodTrail>,
+            WriteStorage<'a, TimeBudget>,
+            WriteStorage<'a, Aiming>,
+            ReadStorage<'a, LastStand>,
+            WriteStorage<'a, Rested>,
+        ),
     );
 
     fn run(
@@ -67,18 +128,38 @@ impl<'a> System<'a> for ActionExecutionSystem {
             mut queued,
             mut positions,
             mut facings,
-            mut _ongoing,
+            mut ongoing,
             mut weapons,
+            mut secondaries,
             mut healths,
             mut dead_markers,
+            mut inventories,
             visions,
             soldiers,
             soldier_stats,
             players,
+            surrendered,
             mut muzzle_flashes,
+            mut under_fire,
             mut log,
             turn_state,
-            battlefield,
+            mut battlefield,
+            (
+                random_events,
+                wounded,
+                blood_config,
+                conflict_rule,
+                corpse_rule,
+                cover_model,
+                log_verbosity,
+                fatigue,
+                reload_interrupt,
+            ),
+            mut game_rng,
+            (wind, time_of_day),
+            mut occupancy,
+            (mut flash_sightings, mut tracers, mut detonations, mut heard_shots),
+            (mut prone, mut blood_trails, mut budgets, mut aiming, last_stands, mut rested),
         ): Self::SystemData,
     ) {
         // Only execute during Execution phase
@@ -86,6 +167,58 @@ impl<'a> System<'a> for ActionExecutionSystem {
             return;
         }
 
+        // Occupants by tile, kept current as moves resolve below
+        occupancy.rebuild(
+            (&entities, &positions)
+                .join()
+                .map(|(entity, pos)| (entity, *pos.as_battlefield_pos())),
+        );
+
+        // Soldiers racing for the same empty tile: settle who gets it up front
+        // rather than letting join order decide
+        let mut contested: HashMap<BattlefieldPos, Vec<(Entity, Rank)>> = HashMap::new();
+        for (entity, action, pos) in (&entities, &queued, &positions).join() {
+            let (ActionType::Move { dx, dy, .. }
+            | ActionType::Crawl { dx, dy, .. }
+            | ActionType::MoveAndFire { dx, dy, .. }) = action.action_type
+            else {
+                continue;
+            };
+            if !action.committed || dead_markers.get(entity).is_some() {
+                continue;
+            }
+            let target = BattlefieldPos::new(pos.x() + dx, pos.y() + dy);
+            let rank = soldiers.get(entity).map_or(Rank::Private, |s| s.rank);
+            contested.entry(target).or_default().push((entity, rank));
+        }
+        let mut move_losers = HashSet::new();
+        for (target, mut contenders) in contested {
+            let target_empty = !occupancy
+                .occupants(target)
+                .iter()
+                .any(|other| corpse_rule.blocks(dead_markers.contains(*other)));
+            if contenders.len() < 2 || !target_empty {
+                continue;
+            }
+            contenders.sort_by_key(|(entity, _)| entity.id());
+            let winner = pick_move_winner(&contenders, *conflict_rule, &mut game_rng);
+            move_losers.extend(
+                contenders
+                    .iter()
+                    .map(|(entity, _)| *entity)
+                    .filter(|entity| *entity != winner),
+            );
+        }
+
+        // The player's view is taken when first needed and kept until they move
+        let player = (&entities, &players).join().map(|(entity, _)| entity).next();
+        let mut player_sight = None;
+
+        // Old blood dries up before this turn's moves add fresh drops
+        for trail in (&mut blood_trails).join() {
+            trail.prune(turn_state.current_turn, blood_config.duration_turns);
+        }
+
         // Execute ALL committed actions (player, allies, enemies)
         for (entity, action) in (&entities, &queued).join() {
             if !action.committed {
@@ -97,45 +230,43 @@ impl<'a> System<'a> for ActionExecutionSystem {
                 continue;
             }
 
-            match &action.action_type {
-                ActionType::Move {
-                    dx,
-                    dy,
-                    terrain_cost: _,
-                } => {
-                    if let Some(pos) = positions.get(entity) {
-                        let old_x = pos.x();
-                        let old_y = pos.y();
-                        let new_x = old_x + dx;
-                        let new_y = old_y + dy;
-
-                        // Boundary check
-                        if new_x >= 0 && new_x < 100 && new_y >= 0 && new_y < 100 {
-                            let new_pos = Position::new(new_x, new_y);
-
-                            // Collision check: ensure no other entity occupies target tile
-                            let tile_occupied = (&entities, &positions, !&dead_markers)
-                                .join()
-                                .any(|(other_entity, other_pos, _)| {
-                                    other_entity != entity && *other_pos == new_pos
-                                });
+            // Doing anything but carry on reloading, or stand still, abandons a reload
+            if !matches!(action.action_type, ActionType::Reload | ActionType::Wait)
+                && ongoing.get(entity).is_some_and(|reload| reload.is_reload())
+            {
+                ongoing.remove(entity);
+            }
 
-                            if !tile_occupied {
-                                // Now get mutable access to update position
-                                if let Some(pos_mut) = positions.get_mut(entity) {
-                                    *pos_mut = new_pos;
-                                }
-                                // Movement logging removed from event log (clutters UI)
-                                // Movement can still be tracked via debug logs if needed
-                            } else {
-                                // Move blocked by another unit - silent
-                                // Optional: add debug log if needed for troubleshooting
-                            }
-                        } else {
-                            // Move blocked - out of bounds
+            match &action.action_type {
+                ActionType::Move { dx, dy, .. } | ActionType::Crawl { dx, dy, .. } => {
+                    // Lost the race for this tile: stay put and get the time back
+                    if move_losers.contains(&entity) {
+                        if let Some(budget) = budgets.get_mut(entity) {
+                            budget.refund_time(action.time_cost);
                         }
+                        continue;
+                    }
+
+                    // Any step throws off a steadied aim
+                    aiming.remove(entity);
+
+                    // Crawling puts the soldier down; a normal move stands them up
+                    if matches!(action.action_type, ActionType::Crawl { .. }) {
+                        prone.insert(entity, Prone).ok();
                     } else {
-                        // Move failed - no position component
+                        prone.remove(entity);
+                    }
+
+                    if let Some(left) = take_step(
+                        entity,
+                        (*dx, *dy),
+                        &battlefield,
+                        &mut positions,
+                        &mut occupancy,
+                        &dead_markers,
+                        *corpse_rule,
+                    ) {
+                        leave_blood(entity, left, &healths, &mut blood_trails, &blood_config, turn_state.current_turn);
                     }
                 }
                 ActionType::Rotate { clockwise } => {
@@ -150,11 +281,82 @@ impl<'a> System<'a> for ActionExecutionSystem {
                     }
                 }
                 ActionType::Wait => {
-                    // Waiting is a no-op execution
+                    // Standing still counts as a rest against battle fatigue
+                    if let Ok(entry) = rested.entry(entity) {
+                        entry.or_insert_with(Rested::default).rests += 1;
+                    }
+                }
+                ActionType::Aim => {
+                    aiming.insert(entity, Aiming).ok();
+                    log.add(format!("{} takes careful aim.", soldier_name(&soldiers, entity)));
                 }
-                ActionType::Shoot { target } => {
+                ActionType::Shoot { target }
+                | ActionType::MoveAndFire { target, .. }
+                | ActionType::PanicFire { target } => {
+                    // Firing on the move: take the step first, then shoot from the new tile
+                    let handling =
+                        if let ActionType::MoveAndFire { dx, dy, .. } = action.action_type {
+                            if move_losers.contains(&entity) {
+                                if let Some(budget) = budgets.get_mut(entity) {
+                                    budget.refund_time(action.time_cost);
+                                }
+                                continue;
+                            }
+                            prone.remove(entity);
+                            aiming.remove(entity);
+                            if let Some(left) = take_step(
+                                entity,
+                                (dx, dy),
+                                &battlefield,
+                                &mut positions,
+                                &mut occupancy,
+                                &dead_markers,
+                                *corpse_rule,
+                            ) {
+                                leave_blood(
+                                    entity,
+                                    left,
+                                    &healths,
+                                    &mut blood_trails,
+                                    &blood_config,
+                                    turn_state.current_turn,
+                                );
+                            }
+                            MOVING_FIRE_ACCURACY
+                        } else if matches!(action.action_type, ActionType::PanicFire { .. }) {
+                            PANIC_FIRE_ACCURACY
+                        } else {
+                            1.0
+                        };
+                    let modifiers = ShotModifiers::default()
+                        .with_wounds(&wounded, healths.get(entity))
+                        .with_last_stand(last_stands.contains(entity))
+                        .with_fatigue(&fatigue, turn_state.current_turn, rested.get(entity))
+                        .with_handling(handling)
+                        .with_prone_target(prone.contains(*target));
+                    let target_hp = healths.get(*target).map(|health| health.current);
+
+                    if surrendered.get(*target).is_some() {
+                        log.add(format!(
+                            "{} holds fire - {} has surrendered.",
+                            soldier_name(&soldiers, entity),
+                            soldier_name(&soldiers, *target)
+                        ));
+                        continue;
+                    }
+
+                    let view = player_view(
+                        &mut player_sight,
+                        player,
+                        &occupancy,
+                        &positions,
+                        &visions,
+                        &soldiers,
+                        &battlefield,
+                    );
+
                     // Execute shooting action
-                    execute_shoot(
+                    let fired = execute_shoot(
                         entity,
                         *target,
                         &positions,
@@ -164,30 +366,235 @@ impl<'a> System<'a> for ActionExecutionSystem {
                         &visions,
                         &soldiers,
                         &soldier_stats,
-                        &mut log,
-                        &battlefield,
                         &mut muzzle_flashes,
-                        &players,
-                        &entities,
+                        &mut under_fire,
+                        &mut aiming,
+                        modifiers,
+                        &mut ShotSurroundings {
+                            battlefield: &battlefield,
+                            log: &mut log,
+                            player_view: view,
+                            random_events: &random_events,
+                            game_rng: &mut game_rng,
+                            wind: &wind,
+                            tracers: &mut tracers,
+                            cover_model: *cover_model,
+                            current_turn: turn_state.current_turn,
+                        },
                     );
+
+                    // A panicked burst sprays away the rest of its rounds
+                    if fired
+                        && matches!(action.action_type, ActionType::PanicFire { .. })
+                        && let Some(weapon) = weapons.get_mut(entity)
+                    {
+                        for _ in 1..PANIC_FIRE_ROUNDS {
+                            weapon.fire();
+                        }
+                        log.add(format!("{} fires wildly!", soldier_name(&soldiers, entity)));
+                    }
+
+                    if fired
+                        && log_verbosity.is_detailed()
+                        && players.contains(entity)
+                        && let Some(weapon) = weapons.get(entity)
+                        && weapon.ammo.is_low()
+                    {
+                        log.add(low_ammo_warning(weapon));
+                    }
+
+                    // A hit in the middle of a reload makes the target fumble it
+                    let hit = target_hp
+                        .zip(healths.get(*target))
+                        .is_some_and(|(before, health)| health.current < before);
+                    if hit
+                        && *reload_interrupt == ReloadInterrupt::Fumble
+                        && let Some(reload) = ongoing.get_mut(*target)
+                        && reload.is_reload()
+                        && reload.time_completed > 0.0
+                    {
+                        reload.reset_progress();
+                        log.add(format!("{} fumbles the reload!", soldier_name(&soldiers, *target)));
+                    }
+
+                    if fired {
+                        hear_gunshot(
+                            entity,
+                            view,
+                            &positions,
+                            &soldiers,
+                            &mut heard_shots,
+                            &mut game_rng,
+                            turn_state.current_turn,
+                        );
+                    }
+
+                    if fired
+                        && time_of_day.is_night()
+                        && let Some(marker) = muzzle_flash_sighting(
+                            entity,
+                            &positions,
+                            &weapons,
+                            &soldiers,
+                            &dead_markers,
+                            &surrendered,
+                            turn_state.current_turn,
+                        )
+                    {
+                        flash_sightings.record(marker.faction.opposing(), marker);
+                    }
                 }
                 ActionType::Reload => {
                     // Execute reload action
                     if let Some(weapon) = weapons.get_mut(entity) {
+                        // A bolt-action rifle takes a charger per action, each
+                        // pushing in its share of the magazine
+                        if weapon.weapon_type.is_bolt_action() {
+                            if let Ok(entry) = ongoing.entry(entity) {
+                                let reload = entry.or_insert_with(|| OngoingAction {
+                                    total_time: weapon.stats.reload_time,
+                                    ..OngoingAction::new(ActionType::Reload)
+                                });
+                                let stage = action.action_type.base_time_cost();
+                                reload.progress_time(stage);
+                                weapon.load_share(stage / reload.total_time);
+                                if !reload.is_complete() && weapon.ammo.can_reload() {
+                                    continue;
+                                }
+                            }
+                            ongoing.remove(entity);
+                        }
                         weapon.reload();
-                        if let Some(soldier) = soldiers.get(entity) {
-                            log.add(format!("{} reloads.", soldier.name));
-                        } else {
-                            log.add("Entity reloads.".to_string());
+                        // The player hears their own reload; anyone else's only if seen
+                        let seen = player == Some(entity)
+                            || player_view(
+                                &mut player_sight,
+                                player,
+                                &occupancy,
+                                &positions,
+                                &visions,
+                                &soldiers,
+                                &battlefield,
+                            )
+                            .zip(positions.get(entity))
+                            .is_some_and(|(view, pos)| view.sees(pos));
+                        if seen && log_verbosity.is_detailed() {
+                            log.add(format!(
+                                "{} reloads ({}/{} rounds).",
+                                soldier_name(&soldiers, entity),
+                                weapon.ammo.current,
+                                weapon.ammo.max_capacity
+                            ));
+                        } else if seen {
+                            log.add(format!("{} reloads.", soldier_name(&soldiers, entity)));
+                        }
+                    }
+                }
+                ActionType::SwapWeapon => {
+                    if let (Some(active), Some(secondary)) =
+                        (weapons.get_mut(entity), secondaries.get_mut(entity))
+                    {
+                        secondary.swap_with(active);
+                        log.add(format!(
+                            "{} switches to {}.",
+                            soldier_name(&soldiers, entity),
+                            active.stats.name
+                        ));
+                    }
+                }
+                ActionType::ThrowGrenade { target_x, target_y } => {
+                    let name = soldier_name(&soldiers, entity);
+                    if !inventories.get_mut(entity).is_some_and(|inv| inv.use_grenade()) {
+                        log.add(format!("{} has no grenades left.", name));
+                        continue;
+                    }
+
+                    // A dud still leaves a crater where it lands, it just never goes off
+                    let impact = BattlefieldPos::new(*target_x, *target_y);
+                    crater_ground(&mut battlefield, impact);
+                    if grenade_is_dud(&random_events, &mut game_rng) {
+                        log.add(format!("{} throws a grenade... it's a dud!", name));
+                        continue;
+                    }
+                    log.add(format!("{} throws a grenade!", name));
+                    detonations.record(impact);
+
+                    // The blast spares nobody in reach, friend or foe
+                    let caught: Vec<(Entity, i32)> = (&entities, &positions, &soldiers, !&dead_markers)
+                        .join()
+                        .filter_map(|(victim, pos, _, _)| {
+                            let damage =
+                                blast_damage(impact, GRENADE_BLAST_RADIUS, GRENADE_DAMAGE, *pos.as_battlefield_pos())?;
+                            Some((victim, damage))
+                        })
+                        .collect();
+                    for (victim, damage) in caught {
+                        if let Some(health) = healths.get_mut(victim)
+                            && !apply_damage(health, damage)
+                        {
+                            log.add(format!("{} is killed by the grenade!", soldier_name(&soldiers, victim)));
+                            dead_markers.insert(victim, Dead).ok();
                         }
                     }
                 }
-                ActionType::ThrowGrenade {
-                    target_x: _,
-                    target_y: _,
-                } => {
-                    // Placeholder for future grenade system
-                    log.add("Entity throws grenade!".to_string());
+                ActionType::LayWire { dx, dy } => {
+                    let name = soldier_name(&soldiers, entity);
+                    if inventories.get(entity).is_none_or(|inv| inv.wire_coils == 0) {
+                        log.add(format!("{} has no wire left.", name));
+                        continue;
+                    }
+                    let Some(here) = positions.get(entity).map(|pos| *pos.as_battlefield_pos()) else {
+                        continue;
+                    };
+                    let spot = BattlefieldPos::new(here.x + dx, here.y + dy);
+                    let occupied = !occupancy.occupants(spot).is_empty();
+                    if lay_wire(&mut battlefield, spot, occupied) {
+                        if let Some(inventory) = inventories.get_mut(entity) {
+                            inventory.use_wire();
+                        }
+                        log.add(format!("{} strings barbed wire.", name));
+                    } else {
+                        log.add(format!("{} can't wire that ground.", name));
+                    }
+                }
+                ActionType::Loot => {
+                    let name = soldier_name(&soldiers, entity);
+                    let Some(here) = positions.get(entity).map(|pos| *pos.as_battlefield_pos()) else {
+                        continue;
+                    };
+                    // A corpse that blocks its tile is looted from beside it
+                    let reach = if corpse_rule.blocks(true) { 1 } else { 0 };
+                    let corpse = (-reach..=reach)
+                        .flat_map(|dy| (-reach..=reach).map(move |dx| BattlefieldPos::new(here.x + dx, here.y + dy)))
+                        .flat_map(|tile| occupancy.occupants(tile).iter().copied())
+                        .find(|other| {
+                            *other != entity
+                                && dead_markers.get(*other).is_some()
+                                && weapons.get(*other).is_some_and(|w| !w.ammo.is_empty())
+                        });
+                    let Some(corpse) = corpse else {
+                        log.add(format!("{} finds nothing worth taking.", name));
+                        continue;
+                    };
+
+                    // Lift the corpse's weapon out so both can be borrowed mutably
+                    let Some(mut corpse_weapon) = weapons.remove(corpse) else {
+                        continue;
+                    };
+                    let looted = weapons
+                        .get_mut(entity)
+                        .map(|weapon| (weapon.loot_from(&mut corpse_weapon), weapon.stats.name.clone()));
+                    weapons.insert(corpse, corpse_weapon).ok();
+
+                    match looted {
+                        Some((Looted::Rounds(rounds), _)) => {
+                            log.add(format!("{} scavenges {} rounds from the fallen.", name, rounds));
+                        }
+                        Some((Looted::Weapon, weapon_name)) => {
+                            log.add(format!("{} picks up a {} from the fallen.", name, weapon_name));
+                        }
+                        _ => log.add(format!("{} finds nothing worth taking.", name)),
+                    }
                 }
             }
         }
@@ -206,7 +613,267 @@ impl<'a> System<'a> for ActionExecutionSystem {
     }
 }
 
+/// Which of several soldiers moving into the same tile gets it
+///
+/// `contenders` must be in a stable order (e.g. by entity id) so a seeded
+/// RNG settles ties the same way every time.
+pub fn pick_move_winner(
+    contenders: &[(Entity, Rank)],
+    rule: MoveConflictRule,
+    rng: &mut GameRng,
+) -> Entity {
+    let pool: Vec<Entity> = match rule {
+        MoveConflictRule::Rank => {
+            let best = contenders.iter().map(|(_, rank)| *rank).max().unwrap_or(Rank::Private);
+            contenders
+                .iter()
+                .filter(|(_, rank)| *rank == best)
+                .map(|(entity, _)| *entity)
+                .collect()
+        }
+        MoveConflictRule::Random => contenders.iter().map(|(entity, _)| *entity).collect(),
+    };
+    if pool.len() == 1 {
+        return pool[0];
+    }
+    pool[rng.random_range(0..pool.len())]
+}
+
+/// Display name for log messages
+fn soldier_name(soldiers: &ReadStorage<Soldier>, entity: specs::Entity) -> String {
+    soldiers
+        .get(entity)
+        .map(|s| s.name.clone())
+        .unwrap_or_else(|| "Entity".to_string())
+}
+
+/// Tile one step from the shooter toward the target, where the flash is drawn
+///
+/// Kept on the map, and None if that tile is a wall (the flash would render
+/// inside it) or the target shares the shooter's tile.
+fn muzzle_flash_position(
+    shooter_pos: &Position,
+    target_pos: &Position,
+    battlefield: &Battlefield,
+) -> Option<Position> {
+    // Calculate direction vector from shooter to target
+    let dx = target_pos.x() - shooter_pos.x();
+    let dy = target_pos.y() - shooter_pos.y();
+    let distance = ((dx * dx + dy * dy) as f32).sqrt();
+    if distance <= 0.1 {
+        return None;
+    }
+
+    // Normalize and offset by 1 tile in direction of shot
+    let norm_dx = (dx as f32 / distance).round() as i32;
+    let norm_dy = (dy as f32 / distance).round() as i32;
+
+    let flash_x = (shooter_pos.x() + norm_dx).clamp(0, battlefield.width() as i32 - 1);
+    let flash_y = (shooter_pos.y() + norm_dy).clamp(0, battlefield.height() as i32 - 1);
+    let flash_pos = Position::new(flash_x, flash_y);
+
+    let in_wall = battlefield
+        .get_tile(flash_pos.as_battlefield_pos())
+        .is_some_and(|tile| tile.terrain == TerrainType::BuildingWall);
+    (!in_wall).then_some(flash_pos)
+}
+
+/// The player's field of view, shared by every shot resolved this turn
+struct PlayerView {
+    origin: BattlefieldPos,
+    faction: Option<Faction>,
+    terrain_revision: u64,
+    tiles: HashSet<BattlefieldPos>,
+}
+
+impl PlayerView {
+    fn sees(&self, pos: &Position) -> bool {
+        self.tiles.contains(pos.as_battlefield_pos())
+    }
+}
+
+/// The player's current view, taken afresh only once the position index
+/// shows they have left the tile it was taken from, or the ground changed
+fn player_view<'v>(
+    sight: &'v mut Option<PlayerView>,
+    player: Option<Entity>,
+    occupancy: &PositionIndex,
+    positions: &WriteStorage<Position>,
+    visions: &ReadStorage<Vision>,
+    soldiers: &ReadStorage<Soldier>,
+    battlefield: &Battlefield,
+) -> Option<&'v PlayerView> {
+    let player = player?;
+    let stale = sight.as_ref().is_none_or(|view| {
+        view.terrain_revision != battlefield.terrain_revision()
+            || !occupancy.occupants(view.origin).contains(&player)
+    });
+    if stale {
+        let (Some(pos), Some(vision)) = (positions.get(player), visions.get(player)) else {
+            *sight = None;
+            return None;
+        };
+        let origin = *pos.as_battlefield_pos();
+        *sight = Some(PlayerView {
+            origin,
+            faction: soldiers.get(player).map(|soldier| soldier.faction),
+            terrain_revision: battlefield.terrain_revision(),
+            tiles: calculate_fov(&origin, vision.range, battlefield),
+        });
+    }
+    sight.as_ref()
+}
+
+/// The marker a night shooter's flash leaves for the enemy, if any enemy
+/// is within flash range to see it
+fn muzzle_flash_sighting(
+    shooter: specs::Entity,
+    positions: &WriteStorage<Position>,
+    weapons: &WriteStorage<Weapon>,
+    soldiers: &ReadStorage<Soldier>,
+    dead_markers: &WriteStorage<Dead>,
+    surrendered: &ReadStorage<Surrendered>,
+    current_turn: u32,
+) -> Option<LastSeenMarker> {
+    let shooter_pos = positions.get(shooter)?;
+    let weapon = weapons.get(shooter)?;
+    let shooter_soldier = soldiers.get(shooter)?;
+    let flash_range = weapon.stats.flash_range as f32;
+
+    let seen = (positions, soldiers, !dead_markers, !surrendered)
+        .join()
+        .any(|(pos, soldier, _, _)| {
+            soldier.faction != shooter_soldier.faction
+                && pos.as_battlefield_pos().distance_to(shooter_pos.as_battlefield_pos()) <= flash_range
+        });
+    seen.then(|| {
+        LastSeenMarker::new(
+            *shooter_pos,
+            shooter_soldier.faction,
+            shooter_soldier.rank,
+            current_turn,
+            shooter,
+        )
+    })
+}
+
+/// Let the player hear a shot fired by an enemy they cannot see
+///
+/// Within HEARING_RANGE, a shooter outside the player's view leaves a fading
+/// marker roughly where the shot came from.
+fn hear_gunshot(
+    shooter: specs::Entity,
+    player_view: Option<&PlayerView>,
+    positions: &WriteStorage<Position>,
+    soldiers: &ReadStorage<Soldier>,
+    heard_shots: &mut HeardShots,
+    game_rng: &mut GameRng,
+    current_turn: u32,
+) {
+    let (Some(view), Some(shooter_pos), Some(shooter_soldier)) =
+        (player_view, positions.get(shooter), soldiers.get(shooter))
+    else {
+        return;
+    };
+    let origin = shooter_pos.as_battlefield_pos();
+    if view.faction.is_none_or(|faction| faction == shooter_soldier.faction)
+        || view.origin.distance_to(origin) > HEARING_RANGE
+        || view.sees(shooter_pos)
+    {
+        return;
+    }
+    heard_shots.record(misjudge_origin(*origin, view.origin, game_rng), current_turn);
+}
+
+/// Move an entity one tile, if the tile is on the map and free
+///
+/// Keeps the position index current. Returns the tile left behind, or None
+/// if the step was blocked.
+fn take_step(
+    entity: specs::Entity,
+    (dx, dy): (i32, i32),
+    battlefield: &Battlefield,
+    positions: &mut WriteStorage<Position>,
+    occupancy: &mut PositionIndex,
+    dead_markers: &WriteStorage<Dead>,
+    corpse_rule: CorpseRule,
+) -> Option<BattlefieldPos> {
+    let old_pos = *positions.get(entity)?.as_battlefield_pos();
+    let new_pos = Position::new(old_pos.x + dx, old_pos.y + dy);
+    if !battlefield.in_bounds(new_pos.as_battlefield_pos()) {
+        return None;
+    }
+
+    // Collision check: ensure no other entity occupies target tile
+    let tile_occupied = occupancy
+        .occupants(*new_pos.as_battlefield_pos())
+        .iter()
+        .any(|other| *other != entity && corpse_rule.blocks(dead_markers.contains(*other)));
+    if tile_occupied {
+        return None;
+    }
+
+    occupancy.move_entity(entity, old_pos, *new_pos.as_battlefield_pos());
+    *positions.get_mut(entity)? = new_pos;
+    // Movement logging removed from event log (clutters UI)
+    Some(old_pos)
+}
+
+/// Mark the tile a badly wounded soldier has just left with their blood
+fn leave_blood(
+    entity: specs::Entity,
+    left: BattlefieldPos,
+    healths: &WriteStorage<Health>,
+    blood_trails: &mut WriteStorage<BloodTrail>,
+    blood_config: &BloodTrailConfig,
+    current_turn: u32,
+) {
+    let bleeding = healths
+        .get(entity)
+        .is_some_and(|health| blood_config.is_bleeding(health.percentage()));
+    if !bleeding {
+        return;
+    }
+    match blood_trails.get_mut(entity) {
+        Some(trail) => trail.record(left, current_turn),
+        None => {
+            let mut trail = BloodTrail::new();
+            trail.record(left, current_turn);
+            blood_trails.insert(entity, trail).ok();
+        }
+    }
+}
+
+/// Log line for the player's magazine running low (or dry)
+fn low_ammo_warning(weapon: &Weapon) -> String {
+    if weapon.ammo.is_empty() {
+        format!("Out of ammo - {} needs reloading!", weapon.stats.name)
+    } else {
+        format!(
+            "Ammo low: {}/{} rounds left in the {}.",
+            weapon.ammo.current, weapon.ammo.max_capacity, weapon.stats.name
+        )
+    }
+}
+
+/// Match-wide resources a shot reads, and the log and tracers it records into
+struct ShotSurroundings<'a> {
+    battlefield: &'a Battlefield,
+    log: &'a mut EventLog,
+    player_view: Option<&'a PlayerView>,
+    random_events: &'a RandomEventsConfig,
+    game_rng: &'a mut GameRng,
+    wind: &'a Wind,
+    tracers: &'a mut ShotTracers,
+    cover_model: CoverModel,
+    current_turn: u32,
+}
+
 /// Execute a shooting action from shooter to target
+///
+/// `handling` scales the shooter's steadiness for how the shot is taken:
+/// 1.0 for a set shot, less on the move, in a panic or worn out by a long battle.
+/// Returns whether a round was actually fired.
 fn execute_shoot(
     shooter: specs::Entity,
     target: specs::Entity,
@@ -217,74 +884,84 @@ fn execute_shoot(
     visions: &ReadStorage<Vision>,
     soldiers: &ReadStorage<Soldier>,
     soldier_stats: &ReadStorage<SoldierStats>,
-    log: &mut EventLog,
-    battlefield: &Battlefield,
     muzzle_flashes: &mut WriteStorage<MuzzleFlash>,
-    players: &ReadStorage<Player>,
-    entities: &Entities,
-) {
+    under_fire: &mut WriteStorage<UnderFire>,
+    aiming: &mut WriteStorage<Aiming>,
+    modifiers: ShotModifiers,
+    surroundings: &mut ShotSurroundings,
+) -> bool {
+    let ShotSurroundings {
+        battlefield,
+        log,
+        player_view,
+        random_events,
+        game_rng,
+        wind,
+        tracers,
+        cover_model,
+        current_turn,
+    } = surroundings;
+
     // Get shooter's weapon
     let shooter_weapon = match weapons.get_mut(shooter) {
         Some(weapon) => weapon,
         None => {
             log.add("Shooter has no weapon!".to_string());
-            return;
+            return false;
         }
     };
 
-    // Check if weapon has ammo
+    // Check if weapon has ammo and isn't overheated
+    if shooter_weapon.is_overheated() {
+        let name = soldiers
+            .get(shooter)
+            .map(|s| s.name.clone())
+            .unwrap_or_else(|| "Entity".to_string());
+        log.add(format!("{}'s {} is too hot to fire!", name, shooter_weapon.stats.name));
+        return false;
+    }
     if !shooter_weapon.can_fire() {
         if let Some(soldier) = soldiers.get(shooter) {
             log.add(format!("{} is out of ammo!", soldier.name));
         } else {
             log.add("Out of ammo!".to_string());
         }
-        return;
+        return false;
     }
 
     // Get positions
     let shooter_pos = match positions.get(shooter) {
         Some(pos) => pos,
-        None => return,
+        None => return false,
     };
 
     let target_pos = match positions.get(target) {
         Some(pos) => pos,
         None => {
             log.add("Target not found!".to_string());
-            return;
+            return false;
         }
     };
 
-    // Check if this event should be logged (visible to player)
-    let should_log = {
-        let mut visible_to_player = false;
-
-        // Find player and check if shooter or target is in their FOV
-        for (player_entity, _player) in (entities, players).join() {
-            if let Some(player_pos) = positions.get(player_entity) {
-                if let Some(player_vision) = visions.get(player_entity) {
-                    let player_fov = calculate_fov(
-                        &player_pos.as_battlefield_pos(),
-                        player_vision.range,
-                        battlefield,
-                    );
+    // Check if this event should be logged (shooter or target visible to player)
+    let should_log =
+        player_view.is_some_and(|view| view.sees(shooter_pos) || view.sees(target_pos));
 
-                    // Check if shooter or target position is in player's FOV
-                    if player_fov.contains(&shooter_pos.as_battlefield_pos()) {
-                        visible_to_player = true;
-                        break;
-                    }
-                    if player_fov.contains(&target_pos.as_battlefield_pos()) {
-                        visible_to_player = true;
-                        break;
-                    }
-                }
-            }
-        }
+    // The barrel gets no rest this turn, whether the round goes off or not
+    shooter_weapon.fired_turn = Some(*current_turn);
 
-        visible_to_player
-    };
+    // Random events: a misfire wastes the round and the shot
+    if weapon_misfires(random_events, game_rng) {
+        shooter_weapon.fire();
+        if should_log {
+            let shooter_name = soldiers
+                .get(shooter)
+                .map(|s| s.name.clone())
+                .unwrap_or_else(|| "Entity".to_string());
+            log.add(format!("{}'s weapon misfires!", shooter_name));
+        }
+        return false;
+    }
 
     // Get shooter vision for LOS check
     let shooter_vision = visions.get(shooter).map(|v| v.range).unwrap_or(10);
@@ -292,37 +969,33 @@ fn execute_shoot(
     // Get shooter accuracy modifier from stats
     let shooter_accuracy = soldier_stats.get(shooter).map(|stats| stats.accuracy_modifier);
 
+    // A steadied aim is spent on this shot
+    let modifiers = modifiers.with_aim(aiming.remove(shooter).is_some());
+
     // Calculate shot result
-    let result = calculate_shot(
-        shooter_weapon,
-        shooter_pos,
-        target_pos,
-        battlefield,
-        shooter_vision,
-        shooter_accuracy,
-    );
+    let shot = ShotParams::new(shooter_weapon, shooter_pos, target_pos, battlefield, shooter_vision)
+        .with_accuracy(shooter_accuracy)
+        .with_wind(wind)
+        .with_modifiers(modifiers)
+        .with_cover_model(*cover_model);
+    let result = calculate_shot(&shot, game_rng);
 
     // Consume ammo
     shooter_weapon.fire();
 
     // Create muzzle flash effect in direction of target
-    if let (Some(shooter_pos), Some(target_pos)) = (positions.get(shooter), positions.get(target)) {
-        // Calculate direction vector from shooter to target
-        let dx = target_pos.x() - shooter_pos.x();
-        let dy = target_pos.y() - shooter_pos.y();
-        let distance = ((dx * dx + dy * dy) as f32).sqrt();
-
-        if distance > 0.1 {
-            // Normalize and offset by 1 tile in direction of shot
-            let norm_dx = (dx as f32 / distance).round() as i32;
-            let norm_dy = (dy as f32 / distance).round() as i32;
-
-            let flash_x = shooter_pos.x() + norm_dx;
-            let flash_y = shooter_pos.y() + norm_dy;
-            let flash_pos = Position::new(flash_x, flash_y);
-
-            muzzle_flashes.insert(shooter, MuzzleFlash::new(flash_pos)).ok();
-        }
+    if let (Some(shooter_pos), Some(target_pos)) = (positions.get(shooter), positions.get(target))
+        && let Some(flash_pos) = muzzle_flash_position(shooter_pos, target_pos, battlefield)
+    {
+        muzzle_flashes.insert(shooter, MuzzleFlash::new(flash_pos)).ok();
+    }
+    if let Some(soldier) = soldiers.get(shooter) {
+        tracers.record(
+            shooter,
+            soldier.faction,
+            shooter_pos.as_battlefield_pos(),
+            target_pos.as_battlefield_pos(),
+        );
     }
 
     // Get names for logging
@@ -335,6 +1008,13 @@ fn execute_shoot(
         .map(|s| s.name.clone())
         .unwrap_or_else(|| "Target".to_string());
 
+    // The target knows it is being shot at unless the round never got near it
+    if !result.blocked_by_los {
+        under_fire.insert(target, UnderFire::new(*current_turn)).ok();
+        // Incoming rounds spoil the target's aim
+        aiming.remove(target);
+    }
+
     // Handle result
     if result.blocked_by_los {
         if should_log {
@@ -379,4 +1059,906 @@ fn execute_shoot(
             ));
         }
     }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::{Faction, Rank};
+    use crate::components::weapon::WeaponType;
+    use crate::game_logic::world_setup::register_components;
+    use specs::{Builder, RunNow, World, WorldExt};
+
+    fn setup(inventory: Inventory) -> (World, specs::Entity) {
+        let mut world = World::new();
+        register_components(&mut world);
+
+        let mut turn_state = TurnState::new();
+        turn_state.phase = TurnPhase::Execution;
+
+        world.insert(Battlefield::new(20, 20));
+        world.insert(turn_state);
+        world.insert(EventLog::new());
+        world.insert(GameRng::new(1));
+        world.insert(WoundedConfig::default());
+        world.insert(FatigueConfig::default());
+        world.insert(BloodTrailConfig::default());
+        world.insert(MoveConflictRule::default());
+        world.insert(CorpseRule::default());
+        world.insert(CoverModel::default());
+        world.insert(ReloadInterrupt::default());
+        world.insert(LogVerbosity::default());
+        world.insert(Wind::calm());
+        world.insert(TimeOfDay::Day);
+        world.insert(FlashSightings::default());
+        world.insert(HeardShots::new(2));
+        world.insert(ShotTracers::default());
+        world.insert(Detonations::new());
+        world.insert(RandomEventsConfig::default());
+        world.insert(PositionIndex::default());
+
+        let soldier = world
+            .create_entity()
+            .with(Position::new(5, 5))
+            .with(Soldier {
+                name: "Thrower".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Private,
+            })
+            .with(Health::new(100))
+            .with(inventory)
+            .with(QueuedAction::new(ActionType::ThrowGrenade {
+                target_x: 8,
+                target_y: 5,
+            }))
+            .build();
+
+        (world, soldier)
+    }
+
+    #[test]
+    fn test_only_seen_reloads_are_logged_with_rounds_in_detail() {
+        for (verbosity, expected) in [
+            (LogVerbosity::Detailed, "Thrower reloads (10/10 rounds)."),
+            (LogVerbosity::Normal, "Thrower reloads."),
+        ] {
+            let (mut world, soldier) = setup(Inventory::empty());
+            world.insert(verbosity);
+            world.write_storage::<Player>().insert(soldier, Player).unwrap();
+            world.write_storage::<Vision>().insert(soldier, Vision::new(5)).unwrap();
+            let unseen = world
+                .create_entity()
+                .with(Position::new(19, 19))
+                .with(Soldier {
+                    name: "Unseen".to_string(),
+                    faction: Faction::CentralPowers,
+                    rank: Rank::Private,
+                })
+                .build();
+            for entity in [soldier, unseen] {
+                let mut rifle = Weapon::rifle();
+                rifle.ammo.current = 1;
+                world.write_storage::<Weapon>().insert(entity, rifle).unwrap();
+            }
+            // A rifle goes in a charger at a time, one reload action each
+            for _ in 0..2 {
+                for entity in [soldier, unseen] {
+                    world
+                        .write_storage::<QueuedAction>()
+                        .insert(entity, QueuedAction::new(ActionType::Reload))
+                        .unwrap();
+                }
+                ActionExecutionSystem.run_now(&world);
+                world.maintain();
+            }
+
+            assert!(world.read_storage::<Weapon>().get(soldier).unwrap().ammo.is_full());
+            let log = world.read_resource::<EventLog>();
+            let reloads: Vec<_> = log.all().iter().filter(|line| line.contains("reloads")).collect();
+            assert_eq!(reloads, vec![expected], "{:?}", verbosity);
+        }
+    }
+
+    #[test]
+    fn test_swapped_weapon_is_used_for_next_shot() {
+        let (mut world, soldier) = setup(Inventory::empty());
+        let target = world
+            .create_entity()
+            .with(Position::new(6, 5))
+            .with(Soldier {
+                name: "Target".to_string(),
+                faction: Faction::CentralPowers,
+                rank: Rank::Private,
+            })
+            .with(Health::new(100))
+            .build();
+
+        let mut empty_rifle = Weapon::rifle();
+        empty_rifle.ammo.current = 0;
+        world.write_storage::<Weapon>().insert(soldier, empty_rifle).unwrap();
+        world
+            .write_storage::<SecondaryWeapon>()
+            .insert(soldier, SecondaryWeapon::sidearm())
+            .unwrap();
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::SwapWeapon))
+            .unwrap();
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        {
+            let weapons = world.read_storage::<Weapon>();
+            let secondaries = world.read_storage::<SecondaryWeapon>();
+            assert_eq!(weapons.get(soldier).unwrap().weapon_type, WeaponType::Pistol);
+            assert_eq!(secondaries.get(soldier).unwrap().weapon.weapon_type, WeaponType::Rifle);
+        }
+
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::Shoot { target }))
+            .unwrap();
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        let weapons = world.read_storage::<Weapon>();
+        let pistol = weapons.get(soldier).unwrap();
+        assert_eq!(pistol.ammo.current, pistol.ammo.max_capacity - 1);
+    }
+
+    #[test]
+    fn test_shot_tracer_follows_line_of_sight() {
+        use crate::game_logic::line_of_sight::sight_line;
+
+        let (mut world, soldier) = setup(Inventory::empty());
+        world.insert(ShotTracers::new(true));
+        let target = world
+            .create_entity()
+            .with(Position::new(11, 8))
+            .with(Soldier {
+                name: "Target".to_string(),
+                faction: Faction::CentralPowers,
+                rank: Rank::Private,
+            })
+            .with(Health::new(100))
+            .build();
+        world.write_storage::<Weapon>().insert(soldier, Weapon::rifle()).unwrap();
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::Shoot { target }))
+            .unwrap();
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        let tracers = world.read_resource::<ShotTracers>();
+        let tracer = tracers.iter().next().expect("the shot left a tracer");
+        assert_eq!(tracer.shooter, soldier);
+        assert_eq!(tracer.faction, Faction::Allies);
+        assert_eq!(
+            tracer.path,
+            sight_line(&BattlefieldPos::new(5, 5), &BattlefieldPos::new(11, 8))
+        );
+    }
+
+    #[test]
+    fn test_looting_transfers_corpse_ammo() {
+        let (mut world, soldier) = setup(Inventory::empty());
+        let corpse = world
+            .create_entity()
+            .with(Position::new(5, 5))
+            .with(Dead)
+            .build();
+
+        let mut empty_rifle = Weapon::rifle();
+        empty_rifle.ammo.current = 0;
+        let mut fallen_rifle = Weapon::rifle();
+        fallen_rifle.ammo.current = 7;
+        world.write_storage::<Weapon>().insert(soldier, empty_rifle).unwrap();
+        world.write_storage::<Weapon>().insert(corpse, fallen_rifle).unwrap();
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::Loot))
+            .unwrap();
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        let weapons = world.read_storage::<Weapon>();
+        assert_eq!(weapons.get(soldier).unwrap().ammo.current, 7);
+        assert_eq!(weapons.get(corpse).unwrap().ammo.current, 0);
+    }
+
+    #[test]
+    fn test_corpse_rule_decides_whether_a_corpse_blocks_a_step() {
+        for (rule, expected) in [(CorpseRule::Passable, (6, 5)), (CorpseRule::Blocking, (5, 5))] {
+            let (mut world, soldier) = setup(Inventory::empty());
+            world.insert(rule);
+            world.create_entity().with(Position::new(6, 5)).with(Dead).build();
+            world
+                .write_storage::<QueuedAction>()
+                .insert(
+                    soldier,
+                    QueuedAction::new(ActionType::Move {
+                        dx: 1,
+                        dy: 0,
+                        terrain_cost: 1.0,
+                    }),
+                )
+                .unwrap();
+
+            ActionExecutionSystem.run_now(&world);
+            world.maintain();
+
+            let positions = world.read_storage::<Position>();
+            let pos = positions.get(soldier).unwrap();
+            assert_eq!((pos.x(), pos.y()), expected, "under {:?}", rule);
+        }
+    }
+
+    #[test]
+    fn test_blocking_corpse_is_looted_from_beside_it() {
+        let (mut world, soldier) = setup(Inventory::empty());
+        world.insert(CorpseRule::Blocking);
+        let corpse = world
+            .create_entity()
+            .with(Position::new(6, 5))
+            .with(Dead)
+            .build();
+
+        let mut empty_rifle = Weapon::rifle();
+        empty_rifle.ammo.current = 0;
+        let mut fallen_rifle = Weapon::rifle();
+        fallen_rifle.ammo.current = 4;
+        world.write_storage::<Weapon>().insert(soldier, empty_rifle).unwrap();
+        world.write_storage::<Weapon>().insert(corpse, fallen_rifle).unwrap();
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::Loot))
+            .unwrap();
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        let weapons = world.read_storage::<Weapon>();
+        assert_eq!(weapons.get(soldier).unwrap().ammo.current, 4);
+    }
+
+    #[test]
+    fn test_crowded_moves_never_share_a_tile() {
+        let (mut world, _) = setup(Inventory::empty());
+        world.insert(Battlefield::new(100, 100));
+
+        // A packed 20x20 block where everyone tries to step east at once;
+        // only the right-hand column has room
+        for y in 0..20 {
+            for x in 0..20 {
+                world
+                    .create_entity()
+                    .with(Position::new(x + 40, y + 40))
+                    .with(QueuedAction::new(ActionType::Move {
+                        dx: 1,
+                        dy: 0,
+                        terrain_cost: 1.0,
+                    }))
+                    .build();
+            }
+        }
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        let positions = world.read_storage::<Position>();
+        let dead = world.read_storage::<Dead>();
+        let index = world.read_resource::<PositionIndex>();
+        let mut seen = std::collections::HashSet::new();
+        for (entity, pos, _) in (&world.entities(), &positions, !&dead).join() {
+            assert!(seen.insert(*pos), "two soldiers share {:?}", pos);
+            assert_eq!(index.occupants(*pos.as_battlefield_pos()), &[entity]);
+        }
+        assert!(positions.join().any(|pos| pos.x() == 60));
+        // The east column stepped out, vacating its old tiles
+        assert!(!index.contains_entity_at(crate::game_logic::battlefield::Position::new(59, 40)));
+    }
+
+    /// A shot across the map at a target the shooter can't see; returns
+    /// (world, shooter)
+    fn long_shot(time_of_day: TimeOfDay) -> (World, specs::Entity) {
+        let (mut world, soldier) = setup(Inventory::empty());
+        world.insert(time_of_day);
+        let target = world
+            .create_entity()
+            .with(Position::new(15, 15))
+            .with(Soldier {
+                name: "Sentry".to_string(),
+                faction: Faction::CentralPowers,
+                rank: Rank::Private,
+            })
+            .with(Health::new(100))
+            .build();
+        world.write_storage::<Weapon>().insert(soldier, Weapon::rifle()).unwrap();
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::Shoot { target }))
+            .unwrap();
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+        (world, soldier)
+    }
+
+    #[test]
+    fn test_night_muzzle_flash_marks_shooter_for_enemies() {
+        let (world, shooter) = long_shot(TimeOfDay::Night);
+
+        let sightings = world.read_resource::<FlashSightings>();
+        let marker = sightings
+            .get(Faction::CentralPowers, shooter)
+            .expect("enemies saw the flash");
+        assert_eq!(marker.position, Position::new(5, 5));
+        assert!(sightings.get(Faction::Allies, shooter).is_none());
+    }
+
+    #[test]
+    fn test_unseen_enemy_shot_leaves_a_heard_marker() {
+        let (mut world, player) = setup(Inventory::empty());
+        world.write_storage::<QueuedAction>().remove(player);
+        world.write_storage::<Player>().insert(player, Player).unwrap();
+        world.write_storage::<Vision>().insert(player, Vision::new(4)).unwrap();
+        let mut sniper = |x| {
+            world
+                .create_entity()
+                .with(Position::new(x, 5))
+                .with(Soldier {
+                    name: "Sniper".to_string(),
+                    faction: Faction::CentralPowers,
+                    rank: Rank::Private,
+                })
+                .with(Health::new(100))
+                .with(Weapon::rifle())
+                .with(QueuedAction::new(ActionType::Shoot { target: player }))
+                .build()
+        };
+        sniper(15);
+        sniper(7);
+
+        ActionExecutionSystem.run_now(&world);
+
+        let turn = world.read_resource::<TurnState>().current_turn;
+        let heard = world.read_resource::<HeardShots>();
+        let markers: Vec<_> = heard.active(turn).map(|(shot, _)| shot.origin).collect();
+        assert_eq!(markers.len(), 1, "only the shooter out of sight is marked");
+        // Ten tiles off, the shot is placed to within a tile
+        assert!((markers[0].x - 15).abs() <= 1 && (markers[0].y - 5).abs() <= 1);
+        assert_eq!(heard.active(turn + 2).count(), 0, "the marker fades within two turns");
+    }
+
+    #[test]
+    fn test_player_view_follows_a_move_made_mid_turn() {
+        let (mut world, _) = setup(Inventory::empty());
+        world.write_storage::<QueuedAction>().clear();
+        let mut spawn = |name: &str, x| {
+            world
+                .create_entity()
+                .with(Position::new(x, 15))
+                .with(Soldier {
+                    name: name.to_string(),
+                    faction: Faction::CentralPowers,
+                    rank: Rank::Private,
+                })
+                .with(Health::new(100))
+                .with(Weapon::rifle())
+                .build()
+        };
+        let near_target = spawn("Near Target", 6);
+        let near = spawn("Near Shot", 5);
+        let player = spawn("Player", 8);
+        let far = spawn("Far Shot", 13);
+        let far_target = spawn("Far Target", 14);
+        world.write_storage::<Player>().insert(player, Player).unwrap();
+        world.write_storage::<Vision>().insert(player, Vision::new(4)).unwrap();
+
+        // The near shot goes off with the player at x=8; the step then brings
+        // the far shot into view before it is fired
+        let mut queued = world.write_storage::<QueuedAction>();
+        queued.insert(near, QueuedAction::new(ActionType::Shoot { target: near_target })).unwrap();
+        queued
+            .insert(player, QueuedAction::new(ActionType::Move { dx: 1, dy: 0, terrain_cost: 1.0 }))
+            .unwrap();
+        queued.insert(far, QueuedAction::new(ActionType::Shoot { target: far_target })).unwrap();
+        drop(queued);
+
+        ActionExecutionSystem.run_now(&world);
+
+        let log = world.read_resource::<EventLog>();
+        let logged = |name| log.all().iter().any(|line| line.starts_with(name));
+        assert!(logged("Near Shot"), "{:?}", log.all());
+        assert!(logged("Far Shot"), "{:?}", log.all());
+    }
+
+    #[test]
+    fn test_daytime_shot_leaves_no_flash_marker() {
+        let (world, shooter) = long_shot(TimeOfDay::Day);
+
+        let sightings = world.read_resource::<FlashSightings>();
+        assert!(sightings.get(Faction::CentralPowers, shooter).is_none());
+    }
+
+    #[test]
+    fn test_no_muzzle_flash_inside_adjacent_wall() {
+        let (mut world, shooter) = setup(Inventory::empty());
+        world
+            .write_resource::<Battlefield>()
+            .set_terrain(crate::game_logic::battlefield::Position::new(6, 5), TerrainType::BuildingWall);
+        let target = world
+            .create_entity()
+            .with(Position::new(9, 5))
+            .with(Soldier {
+                name: "Behind Wall".to_string(),
+                faction: Faction::CentralPowers,
+                rank: Rank::Private,
+            })
+            .with(Health::new(100))
+            .build();
+        world.write_storage::<Weapon>().insert(shooter, Weapon::rifle()).unwrap();
+        world
+            .write_storage::<QueuedAction>()
+            .insert(shooter, QueuedAction::new(ActionType::Shoot { target }))
+            .unwrap();
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        // The round was fired, but no flash is drawn inside the wall
+        let weapons = world.read_storage::<Weapon>();
+        let rifle = weapons.get(shooter).unwrap();
+        assert_eq!(rifle.ammo.current, rifle.ammo.max_capacity - 1);
+        assert!(world.read_storage::<MuzzleFlash>().get(shooter).is_none());
+    }
+
+    #[test]
+    fn test_muzzle_flash_stays_on_map() {
+        let battlefield = Battlefield::new(20, 20);
+
+        let flash = muzzle_flash_position(&Position::new(0, 0), &Position::new(10, 0), &battlefield);
+        assert_eq!(flash, Some(Position::new(1, 0)));
+
+        let edge = muzzle_flash_position(&Position::new(19, 19), &Position::new(30, 30), &battlefield);
+        assert_eq!(edge, Some(Position::new(19, 19)));
+    }
+
+    #[test]
+    fn test_crawling_leaves_soldier_prone_until_next_move() {
+        let (mut world, soldier) = setup(Inventory::empty());
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::Crawl { dx: 1, dy: 0, terrain_cost: 1.0 }))
+            .unwrap();
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        assert_eq!(world.read_storage::<Position>().get(soldier), Some(&Position::new(6, 5)));
+        assert!(world.read_storage::<Prone>().get(soldier).is_some());
+
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::Move { dx: 1, dy: 0, terrain_cost: 1.0 }))
+            .unwrap();
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        assert!(world.read_storage::<Prone>().get(soldier).is_none());
+    }
+
+    #[test]
+    fn test_steps_reach_the_edge_of_the_map_and_no_further() {
+        let (mut world, soldier) = setup(Inventory::empty());
+        world.insert(Battlefield::new(120, 20));
+        for (x, expected) in [(99, 100), (119, 119)] {
+            world.write_storage::<Position>().insert(soldier, Position::new(x, 5)).unwrap();
+            PositionIndex::refresh(&world);
+            world
+                .write_storage::<QueuedAction>()
+                .insert(soldier, QueuedAction::new(ActionType::Move { dx: 1, dy: 0, terrain_cost: 1.0 }))
+                .unwrap();
+            ActionExecutionSystem.run_now(&world);
+            world.maintain();
+
+            assert_eq!(world.read_storage::<Position>().get(soldier), Some(&Position::new(expected, 5)));
+        }
+    }
+
+    #[test]
+    fn test_moving_spoils_a_steadied_aim() {
+        let (mut world, soldier) = setup(Inventory::empty());
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::Aim))
+            .unwrap();
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+        assert!(world.read_storage::<Aiming>().contains(soldier));
+
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::Move { dx: 1, dy: 0, terrain_cost: 1.0 }))
+            .unwrap();
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+        assert!(!world.read_storage::<Aiming>().contains(soldier), "the step threw off the aim");
+    }
+
+    #[test]
+    fn test_throwing_grenade_decrements_inventory() {
+        let (mut world, soldier) = setup(Inventory::new(2, 0, 0));
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        let inventories = world.read_storage::<Inventory>();
+        assert_eq!(inventories.get(soldier).unwrap().grenades, 1);
+        // Open ground where it landed is now a crater
+        let battlefield = world.read_resource::<Battlefield>();
+        let impact = battlefield.get_tile(&BattlefieldPos::new(8, 5)).unwrap();
+        assert_eq!(impact.terrain, TerrainType::ShellCrater);
+    }
+
+    #[test]
+    fn test_grenade_blast_hurts_everyone_in_reach() {
+        let (mut world, thrower) = setup(Inventory::new(1, 0, 0));
+        let soldier = |world: &mut World, x, faction| {
+            world
+                .create_entity()
+                .with(Position::new(x, 5))
+                .with(Soldier {
+                    name: "Caught".to_string(),
+                    faction,
+                    rank: Rank::Private,
+                })
+                .with(Health::new(100))
+                .build()
+        };
+        // On the impact tile, at the blast's edge, and just beyond it
+        let on_target = soldier(&mut world, 8, Faction::CentralPowers);
+        let at_edge = soldier(&mut world, 10, Faction::Allies);
+        let beyond = soldier(&mut world, 11, Faction::CentralPowers);
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        let healths = world.read_storage::<Health>();
+        let hp = |entity| healths.get(entity).unwrap().current;
+        assert_eq!(hp(on_target), 100 - GRENADE_DAMAGE);
+        assert!(hp(at_edge) < 100 && hp(at_edge) > hp(on_target), "friendlies in reach are hurt too");
+        assert_eq!(hp(beyond), 100);
+        assert_eq!(hp(thrower), 100);
+    }
+
+    #[test]
+    fn test_dud_grenade_craters_the_ground_without_going_off() {
+        let (mut world, _) = setup(Inventory::new(1, 0, 0));
+        world.insert(RandomEventsConfig {
+            enabled: true,
+            grenade_dud_chance: 1.0,
+            ..RandomEventsConfig::default()
+        });
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        let battlefield = world.read_resource::<Battlefield>();
+        let impact = battlefield.get_tile(&BattlefieldPos::new(8, 5)).unwrap();
+        assert_eq!(impact.terrain, TerrainType::ShellCrater);
+        assert_eq!(world.read_resource::<Detonations>().iter().count(), 0);
+    }
+
+    #[test]
+    fn test_empty_inventory_blocks_grenade() {
+        let (mut world, soldier) = setup(Inventory::empty());
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        assert_eq!(world.read_storage::<Inventory>().get(soldier).unwrap().grenades, 0);
+        let log = world.read_resource::<EventLog>();
+        assert!(log.recent(1).iter().any(|msg| msg.contains("no grenades left")));
+    }
+
+    #[test]
+    fn test_laying_wire_uses_a_coil_until_none_are_left() {
+        let (mut world, soldier) = setup(Inventory::empty().with_wire(1));
+        let lay = |world: &mut World, dx, dy| {
+            world
+                .write_storage::<QueuedAction>()
+                .insert(soldier, QueuedAction::new(ActionType::LayWire { dx, dy }))
+                .unwrap();
+            ActionExecutionSystem.run_now(world);
+            world.maintain();
+        };
+
+        lay(&mut world, 1, 0);
+        assert_eq!(world.read_storage::<Inventory>().get(soldier).unwrap().wire_coils, 0);
+        let terrain_at = |world: &World, x, y| {
+            world
+                .read_resource::<Battlefield>()
+                .get_tile(&BattlefieldPos::new(x, y))
+                .unwrap()
+                .terrain
+        };
+        assert_eq!(terrain_at(&world, 6, 5), TerrainType::BarbedWire);
+
+        lay(&mut world, 0, 1);
+        assert_ne!(terrain_at(&world, 5, 6), TerrainType::BarbedWire);
+        let log = world.read_resource::<EventLog>();
+        assert!(log.recent(1).iter().any(|msg| msg.contains("no wire left")));
+    }
+
+    #[test]
+    fn test_badly_wounded_soldier_leaves_fading_blood_trail() {
+        let (mut world, soldier) = setup(Inventory::empty());
+        world.write_storage::<Health>().get_mut(soldier).unwrap().current = 30;
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(ActionType::Move { dx: 1, dy: 0, terrain_cost: 1.0 }))
+            .unwrap();
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        {
+            let trails = world.read_storage::<BloodTrail>();
+            let trail = trails.get(soldier).expect("bleeding soldier should leave a trail");
+            assert_eq!(trail.drops.len(), 1);
+            assert_eq!(trail.drops[0].position, BattlefieldPos::new(5, 5));
+        }
+
+        // Once the trail is older than the configured duration it dries up
+        let duration = BloodTrailConfig::default().duration_turns;
+        world.write_resource::<TurnState>().current_turn += duration;
+        world.write_storage::<QueuedAction>().remove(soldier);
+        ActionExecutionSystem.run_now(&world);
+
+        assert!(world.read_storage::<BloodTrail>().get(soldier).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_contested_tile_goes_to_higher_rank() {
+        let (mut world, private) = setup(Inventory::empty());
+        let mut budget = TimeBudget::new(10.0);
+        let step = ActionType::Move { dx: 1, dy: 0, terrain_cost: 1.0 };
+        budget.consume_time(step.base_time_cost());
+        world.write_storage::<TimeBudget>().insert(private, budget).unwrap();
+        world
+            .write_storage::<QueuedAction>()
+            .insert(private, QueuedAction::new(step))
+            .unwrap();
+
+        // Created later, so plain join order would let the private through first
+        let sergeant = world
+            .create_entity()
+            .with(Position::new(7, 5))
+            .with(Soldier {
+                name: "Sarge".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Sergeant,
+            })
+            .with(Health::new(100))
+            .with(QueuedAction::new(ActionType::Move { dx: -1, dy: 0, terrain_cost: 1.0 }))
+            .build();
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+
+        let positions = world.read_storage::<Position>();
+        assert_eq!(positions.get(sergeant), Some(&Position::new(6, 5)));
+        assert_eq!(positions.get(private), Some(&Position::new(5, 5)));
+        // The blocked move's time is handed back
+        let budgets = world.read_storage::<TimeBudget>();
+        assert_eq!(budgets.get(private).unwrap().time_spent_this_turn, 0.0);
+    }
+
+    #[test]
+    fn test_lost_race_refunds_what_a_tired_step_cost() {
+        let (mut world, private) = setup(Inventory::empty());
+        let step = ActionType::Move { dx: 1, dy: 0, terrain_cost: 1.0 };
+        // Charged above the base cost, as battle fatigue would price it
+        let charged = step.base_time_cost() * 1.3;
+        let mut budget = TimeBudget::new(10.0);
+        budget.consume_time(charged);
+        world.write_storage::<TimeBudget>().insert(private, budget).unwrap();
+        world
+            .write_storage::<QueuedAction>()
+            .insert(
+                private,
+                QueuedAction {
+                    action_type: step,
+                    time_cost: charged,
+                    committed: true,
+                },
+            )
+            .unwrap();
+        world
+            .create_entity()
+            .with(Position::new(7, 5))
+            .with(Soldier {
+                name: "Sarge".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Sergeant,
+            })
+            .with(Health::new(100))
+            .with(QueuedAction::new(ActionType::Move { dx: -1, dy: 0, terrain_cost: 1.0 }))
+            .build();
+
+        ActionExecutionSystem.run_now(&world);
+
+        let budgets = world.read_storage::<TimeBudget>();
+        assert_eq!(budgets.get(private).unwrap().time_spent_this_turn, 0.0);
+    }
+
+    #[test]
+    fn test_random_conflict_rule_is_repeatable() {
+        let mut world = World::new();
+        let contenders: Vec<_> = (0..4)
+            .map(|_| (world.create_entity().build(), Rank::Private))
+            .collect();
+
+        let pick = |seed| pick_move_winner(&contenders, MoveConflictRule::Random, &mut GameRng::new(seed));
+        assert_eq!(pick(9), pick(9));
+        assert!(contenders.iter().any(|(entity, _)| *entity == pick(9)));
+    }
+
+    /// One execution pass of `action` by a rifleman at `from` against a
+    /// sturdy target at (12, 5); returns (world, shooter, target)
+    fn fire_at_range(
+        from: Position,
+        action: impl Fn(specs::Entity) -> ActionType,
+        seed: u64,
+    ) -> (World, specs::Entity, specs::Entity) {
+        let (mut world, soldier) = setup(Inventory::empty());
+        world.insert(GameRng::new(seed));
+        world.write_storage::<Position>().insert(soldier, from).unwrap();
+        let target = world
+            .create_entity()
+            .with(Position::new(12, 5))
+            .with(Soldier {
+                name: "Target".to_string(),
+                faction: Faction::CentralPowers,
+                rank: Rank::Private,
+            })
+            .with(Health::new(10_000))
+            .build();
+        world.write_storage::<Weapon>().insert(soldier, Weapon::rifle()).unwrap();
+        world
+            .write_storage::<QueuedAction>()
+            .insert(soldier, QueuedAction::new(action(target)))
+            .unwrap();
+
+        ActionExecutionSystem.run_now(&world);
+        world.maintain();
+        (world, soldier, target)
+    }
+
+    fn step_and_shoot(target: specs::Entity) -> ActionType {
+        ActionType::MoveAndFire {
+            dx: 1,
+            dy: 0,
+            terrain_cost: 1.0,
+            target,
+        }
+    }
+
+    #[test]
+    fn test_move_and_fire_steps_then_shoots() {
+        let (world, soldier, target) = fire_at_range(Position::new(5, 5), step_and_shoot, 1);
+
+        assert_eq!(*world.read_storage::<Position>().get(soldier).unwrap(), Position::new(6, 5));
+        let weapons = world.read_storage::<Weapon>();
+        let rifle = weapons.get(soldier).unwrap();
+        assert_eq!(rifle.ammo.current, rifle.ammo.max_capacity - 1);
+        assert!(world.read_storage::<UnderFire>().get(target).is_some());
+
+        // Dearer than either half on its own
+        let cost = step_and_shoot(target).base_time_cost();
+        assert!(cost > ActionType::Shoot { target }.base_time_cost());
+        assert!(cost > ActionType::Move { dx: 1, dy: 0, terrain_cost: 1.0 }.base_time_cost());
+    }
+
+    #[test]
+    fn test_firing_on_the_move_is_less_accurate() {
+        let hit = |(world, _, target): (World, specs::Entity, specs::Entity)| {
+            world.read_storage::<Health>().get(target).unwrap().current < 10_000
+        };
+
+        // Same shot from the same tile, standing versus just stepped onto it
+        let mut standing_hits = 0;
+        let mut moving_hits = 0;
+        for seed in 0..200 {
+            let standing = fire_at_range(Position::new(6, 5), |target| ActionType::Shoot { target }, seed);
+            if hit(standing) {
+                standing_hits += 1;
+            }
+            let moving = fire_at_range(Position::new(5, 5), step_and_shoot, seed);
+            assert_eq!(*moving.0.read_storage::<Position>().get(moving.1).unwrap(), Position::new(6, 5));
+            if hit(moving) {
+                moving_hits += 1;
+            }
+        }
+        assert!(
+            moving_hits < standing_hits,
+            "moving {} vs standing {}",
+            moving_hits,
+            standing_hits
+        );
+    }
+
+    #[test]
+    fn test_panic_fire_is_wild_and_wasteful() {
+        let mut steady_hits = 0;
+        let mut panic_hits = 0;
+        for seed in 0..200 {
+            let (world, _, target) =
+                fire_at_range(Position::new(6, 5), |target| ActionType::Shoot { target }, seed);
+            if world.read_storage::<Health>().get(target).unwrap().current < 10_000 {
+                steady_hits += 1;
+            }
+            let (world, soldier, target) =
+                fire_at_range(Position::new(6, 5), |target| ActionType::PanicFire { target }, seed);
+            if world.read_storage::<Health>().get(target).unwrap().current < 10_000 {
+                panic_hits += 1;
+            }
+            let ammo = world.read_storage::<Weapon>().get(soldier).unwrap().ammo.clone();
+            assert_eq!(ammo.current, ammo.max_capacity - PANIC_FIRE_ROUNDS);
+        }
+        assert!(panic_hits < steady_hits, "panic {} vs steady {}", panic_hits, steady_hits);
+    }
+
+    #[test]
+    fn test_hit_mid_reload_resets_it_under_the_fumble_rule() {
+        for (rule, progress_kept) in [(ReloadInterrupt::Fumble, 0.0), (ReloadInterrupt::Steady, 5.0)] {
+            let (mut world, soldier) = setup(Inventory::empty());
+            world.insert(rule);
+            // A marksman's rifle, so the shot from two tiles can't miss
+            let mut rifle = Weapon::rifle();
+            rifle.stats.base_accuracy = 1.0;
+            world.write_storage::<Weapon>().insert(soldier, rifle).unwrap();
+            let mut empty_rifle = Weapon::rifle();
+            empty_rifle.ammo.current = 0;
+            let target = world
+                .create_entity()
+                .with(Position::new(7, 5))
+                .with(Soldier {
+                    name: "Loader".to_string(),
+                    faction: Faction::CentralPowers,
+                    rank: Rank::Private,
+                })
+                .with(Health::new(10_000))
+                .with(empty_rifle)
+                .with(QueuedAction::new(ActionType::Reload))
+                .build();
+            world.write_storage::<QueuedAction>().remove(soldier);
+
+            // First charger goes in
+            ActionExecutionSystem.run_now(&world);
+            world.maintain();
+            assert_eq!(world.read_storage::<OngoingAction>().get(target).unwrap().time_completed, 5.0);
+            assert_eq!(world.read_storage::<Weapon>().get(target).unwrap().ammo.current, 5);
+
+            // Shot at before the second
+            world.write_storage::<QueuedAction>().clear();
+            world
+                .write_storage::<QueuedAction>()
+                .insert(soldier, QueuedAction::new(ActionType::Shoot { target }))
+                .unwrap();
+            ActionExecutionSystem.run_now(&world);
+            world.maintain();
+            assert!(world.read_storage::<Health>().get(target).unwrap().current < 10_000);
+
+            let reload = world.read_storage::<OngoingAction>();
+            assert_eq!(reload.get(target).unwrap().time_completed, progress_kept, "{:?}", rule);
+        }
+    }
 }
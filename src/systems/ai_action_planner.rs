@@ -5,38 +5,91 @@ use crate::ai::{
     actions::{
         create_move_evaluator, create_reload_evaluator, create_seek_cover_evaluator,
         create_seek_objective_evaluator, create_shoot_evaluator, create_wait_evaluator,
-        ActionEvaluator, ScoredAction,
+        select_best_action, ActionEvaluator, ScoredAction,
     },
+    consideration_timing::ConsiderationTimings,
     considerations::ActionContext,
+    contact::ContactTracker,
+    intel::FactionIntel,
+    panic_fire::roll_panic_fire,
     personality::AIPersonality,
-    ActionGenerator, PossibleAction,
+    personality_data::PersonalityLibrary,
+    scoring_snapshot::{ActionScoreEntry, ScoringSnapshot, ScoringSnapshots},
+    standing_orders::StandingOrders,
+    ActionGenerator, GenerationContext, PossibleAction,
 };
 use crate::components::{
-    action::{ActionType, QueuedAction},
+    action::{ActionType, QueuedAction, LAY_WIRE_TIME},
+    ai_role::AiRole,
+    aiming::Aiming,
     dead::Dead,
     facing::Facing,
     health::Health,
+    inventory::Inventory,
+    last_seen::FlashSightings,
+    morale::Morale,
     pathfinding::PlannedPath,
     player::Player,
     position::Position,
+    rested::Rested,
     soldier::{Faction, Rank, Soldier},
+    soldier_stats::SoldierStats,
+    squad::{BoundingRole, SquadMember},
+    surrendered::Surrendered,
     time_budget::TimeBudget,
+    under_fire::UnderFire,
     vision::Vision,
-    weapon::Weapon,
+    weapon::{SecondaryWeapon, Weapon},
 };
+use crate::config::game_config::{CorpseRule, FatigueConfig, PanicFireConfig, WoundedConfig};
 use crate::game_logic::{
-    battlefield::Battlefield,
+    action_preview::fatigued_load,
+    battlefield::{Battlefield, Position as BattlefieldPos},
+    combat::{in_effective_range, range_in_tiles},
+    danger_map::DangerMap,
     line_of_sight::calculate_fov,
     objectives::Objectives,
-    pathfinding::calculate_path,
+    obstacles::{wire_step, wiring_front, WIRE_CLEAR_DISTANCE},
+    pathfinding::{calculate_path_avoiding, calculate_path_through_danger},
+    spatial_index::PositionIndex,
     turn_state::{TurnOrderMode, TurnPhase, TurnState},
 };
-use crate::utils::event_log::EventLog;
+use crate::utils::game_rng::AiRng;
 use specs::{Entities, Entity, Join, Read, ReadStorage, System, Write, WriteStorage};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::fs::OpenOptions;
 use std::io::Write as IoWrite;
 use std::time::Instant;
 
+/// Flat score for drawing the sidearm; only generated when the primary is
+/// empty with an enemy adjacent, so it should beat anything but a kill shot
+const SWAP_WEAPON_SCORE: f32 = 1.0;
+
+/// Flat score for looting a corpse (or stepping onto one to loot it); only
+/// generated when the weapon in hand is empty
+const LOOT_SCORE: f32 = 0.9;
+
+/// Flat score for stringing wire; only generated for defenders with no enemy near
+const LAY_WIRE_SCORE: f32 = 0.7;
+
+/// Aim bonus from which a weapon counts as a precision weapon worth aiming
+///
+/// Only the side's snipers stop to aim: everyone else takes the snap shot.
+const PRECISION_AIM_BONUS: f32 = 0.3;
+
+/// Actions scored by rule rather than by the personality's evaluators
+fn fixed_score(action: &PossibleAction) -> Option<f32> {
+    match action.action_type {
+        ActionType::SwapWeapon => Some(SWAP_WEAPON_SCORE),
+        ActionType::Loot => Some(LOOT_SCORE),
+        ActionType::LayWire { .. } => Some(LAY_WIRE_SCORE),
+        // Only the step toward a corpse carries a target entity
+        ActionType::Move { .. } if action.target_entity.is_some() => Some(LOOT_SCORE),
+        _ => None,
+    }
+}
+
 fn debug_log(msg: &str) {
     if cfg!(debug_assertions) {
         if let Ok(mut file) = OpenOptions::new()
@@ -56,30 +109,24 @@ impl AIActionPlannerSystem {
         Self
     }
 
+    #[cfg(test)]
     fn get_evaluators(&self, rank: Rank) -> Vec<ActionEvaluator> {
-        let personality = self.get_personality_for_rank(rank);
+        let personality = self.get_personality(rank, AiRole::Rifleman);
         personality.evaluators
     }
 
-    fn get_personality_for_rank(&self, rank: Rank) -> AIPersonality {
+    /// Officers and NCOs plan by rank; privates by the role they were given at spawn
+    fn get_personality(&self, rank: Rank, role: AiRole) -> AIPersonality {
         match rank {
             Rank::Captain => AIPersonality::objective_focused(),
             Rank::Lieutenant => AIPersonality::aggressive(),
             Rank::Sergeant => AIPersonality::balanced(),
             Rank::Corporal => AIPersonality::balanced(),
-            Rank::Private => {
-                use rand::Rng;
-                let mut rng = rand::rng();
-                let roll = rng.random_range(0.0..1.0);
-
-                if roll < 0.025 {
-                    AIPersonality::scout()
-                } else if roll < 0.05 {
-                    AIPersonality::rearguard()
-                } else {
-                    AIPersonality::defensive()
-                }
-            }
+            Rank::Private => match role {
+                AiRole::Scout => AIPersonality::scout(),
+                AiRole::Rearguard => AIPersonality::rearguard(),
+                AiRole::Rifleman => AIPersonality::defensive(),
+            },
         }
     }
 
@@ -93,6 +140,7 @@ impl AIActionPlannerSystem {
         soldiers: &ReadStorage<Soldier>,
         healths: &ReadStorage<Health>,
         visions: &ReadStorage<Vision>,
+        surrendered: &ReadStorage<Surrendered>,
         battlefield: &Battlefield,
     ) -> Vec<Entity> {
         let ai_faction = soldier.faction;
@@ -101,7 +149,7 @@ impl AIActionPlannerSystem {
 
         (entities, positions, soldiers, healths)
             .join()
-            .filter(|(e, _, _, _)| *e != entity)
+            .filter(|(e, _, _, _)| *e != entity && surrendered.get(*e).is_none())
             .filter(|(_, target_pos, target_soldier, target_health)| {
                 target_soldier.faction != ai_faction
                     && target_health.is_alive()
@@ -117,6 +165,10 @@ impl AIActionPlannerSystem {
         context: &ActionContext,
         evaluators: &Vec<ActionEvaluator>,
     ) -> f32 {
+        if let Some(score) = fixed_score(action) {
+            return score;
+        }
+
         let mut max_score: f32 = 0.0;
         let mut matched = false;
 
@@ -135,6 +187,41 @@ impl AIActionPlannerSystem {
         max_score
     }
 
+    /// Score an action and keep the winning evaluator's per-consideration breakdown
+    fn score_action_breakdown(
+        &self,
+        action: &PossibleAction,
+        context: &ActionContext,
+        evaluators: &[ActionEvaluator],
+    ) -> ActionScoreEntry {
+        let mut entry = ActionScoreEntry {
+            action_type: action.action_type.clone(),
+            score: 0.0,
+            evaluator: None,
+            considerations: Vec::new(),
+        };
+
+        if let Some(score) = fixed_score(action) {
+            entry.score = score;
+            return entry;
+        }
+
+        for evaluator in evaluators {
+            if !self.evaluator_matches_action(&evaluator.name, &action.action_type) {
+                continue;
+            }
+
+            let (score, breakdown) = evaluator.evaluate_breakdown(context);
+            if entry.evaluator.is_none() || score > entry.score {
+                entry.score = score;
+                entry.evaluator = Some(evaluator.name.clone());
+                entry.considerations = breakdown;
+            }
+        }
+
+        entry
+    }
+
     fn evaluator_matches_action(&self, evaluator_name: &str, action_type: &ActionType) -> bool {
         match action_type {
             ActionType::Shoot { .. } => evaluator_name.contains("Shoot"),
@@ -153,20 +240,19 @@ impl AIActionPlannerSystem {
     fn queue_action(
         &self,
         entity: Entity,
-        action: &ScoredAction,
+        action_type: ActionType,
         queued: &mut WriteStorage<QueuedAction>,
         budget: &mut TimeBudget,
-        event_log: &mut EventLog,
-        soldier_name: Option<&str>,
+        burden: Burden,
     ) {
-        let time_cost = action.action_type.base_time_cost();
+        let time_cost = burden.time_cost(&action_type);
 
         budget.consume_time(time_cost);
         queued
             .insert(
                 entity,
                 QueuedAction {
-                    action_type: action.action_type.clone(),
+                    action_type,
                     time_cost,
                     committed: true,
                 },
@@ -177,20 +263,17 @@ impl AIActionPlannerSystem {
         // This information is available in debug logs at /tmp/argue_ai_debug.log
     }
 
-    fn queue_move_action(
-        &self,
-        entity: Entity,
+    /// A single step onto an adjacent tile, or None if already there
+    fn step_action(
         target_pos: &crate::game_logic::battlefield::Position,
         current_pos: &Position,
         battlefield: &Battlefield,
-        queued: &mut WriteStorage<QueuedAction>,
-        budget: &mut TimeBudget,
-    ) -> bool {
+    ) -> Option<ActionType> {
         let dx = target_pos.x - current_pos.x();
         let dy = target_pos.y - current_pos.y();
 
         if dx == 0 && dy == 0 {
-            return false;
+            return None;
         }
 
         let terrain_cost = battlefield
@@ -198,26 +281,25 @@ impl AIActionPlannerSystem {
             .map(|t| t.terrain.movement_cost())
             .unwrap_or(1.0);
 
-        let action = ActionType::Move {
+        Some(ActionType::Move {
             dx,
             dy,
             terrain_cost,
-        };
-        let time_cost = action.base_time_cost();
+        })
+    }
+}
 
-        budget.consume_time(time_cost);
-        queued
-            .insert(
-                entity,
-                QueuedAction {
-                    action_type: action,
-                    time_cost,
-                    committed: true,
-                },
-            )
-            .ok();
+/// What slows a soldier's actions: their wounds and the load they carry
+#[derive(Clone, Copy)]
+struct Burden<'a> {
+    health: Option<&'a Health>,
+    load: f32,
+    wounded: &'a WoundedConfig,
+}
 
-        true
+impl Burden<'_> {
+    fn time_cost(&self, action: &ActionType) -> f32 {
+        action.time_cost_for(self.health, self.load, self.wounded)
     }
 }
 
@@ -231,14 +313,39 @@ impl<'a> System<'a> for AIActionPlannerSystem {
         ReadStorage<'a, Health>,
         ReadStorage<'a, Dead>,
         ReadStorage<'a, Weapon>,
+        ReadStorage<'a, SecondaryWeapon>,
         ReadStorage<'a, Facing>,
+        ReadStorage<'a, UnderFire>,
+        ReadStorage<'a, Surrendered>,
+        ReadStorage<'a, SquadMember>,
+        WriteStorage<'a, BoundingRole>,
         WriteStorage<'a, TimeBudget>,
         WriteStorage<'a, QueuedAction>,
         WriteStorage<'a, PlannedPath>,
         Read<'a, Battlefield>,
         Read<'a, TurnState>,
         Read<'a, Objectives>,
-        Write<'a, EventLog>,
+        Write<'a, ContactTracker>,
+        Write<'a, ScoringSnapshots>,
+        // Nested: shred caps SystemData tuples at 26 elements
+        (
+            Read<'a, StandingOrders>,
+            Read<'a, WoundedConfig>,
+            Write<'a, FactionIntel>,
+            Read<'a, CorpseRule>,
+            ReadStorage<'a, SoldierStats>,
+            ReadStorage<'a, Inventory>,
+            ReadStorage<'a, Aiming>,
+            ReadStorage<'a, Morale>,
+            Read<'a, PanicFireConfig>,
+            Read<'a, FatigueConfig>,
+            ReadStorage<'a, Rested>,
+            ReadStorage<'a, AiRole>,
+            Write<'a, FlashSightings>,
+            Read<'a, PositionIndex>,
+        ),
+        Read<'a, PersonalityLibrary>,
+        Write<'a, AiRng>,
     );
 
     fn run(
@@ -252,14 +359,38 @@ impl<'a> System<'a> for AIActionPlannerSystem {
             healths,
             dead_markers,
             weapons,
+            secondaries,
             facings,
+            under_fire,
+            surrendered,
+            squad_members,
+            mut bounding_roles,
             mut budgets,
             mut queued,
             mut paths,
             battlefield,
             turn_state,
             objectives,
-            mut event_log,
+            mut contacts,
+            mut snapshots,
+            (
+                standing_orders,
+                wounded,
+                mut intel,
+                corpse_rule,
+                soldier_stats,
+                inventories,
+                aiming,
+                morales,
+                panic_fire,
+                fatigue,
+                rested,
+                roles,
+                mut flash_sightings,
+                occupancy,
+            ),
+            personalities,
+            mut ai_rng,
         ): Self::SystemData,
     ) {
         if !matches!(turn_state.phase, TurnPhase::Planning) {
@@ -267,9 +398,8 @@ impl<'a> System<'a> for AIActionPlannerSystem {
         }
 
         if matches!(turn_state.turn_order_mode, TurnOrderMode::PlayerFirst) {
-            let player_ready = (&entities, &players)
-                .join()
-                .any(|(e, _)| turn_state.is_entity_ready(e));
+            let player_ready =
+                turn_state.is_player_side_ready((&entities, &players).join().map(|(e, _)| e));
 
             if !player_ready {
                 return;
@@ -277,16 +407,69 @@ impl<'a> System<'a> for AIActionPlannerSystem {
         }
 
         let planning_start = if cfg!(debug_assertions) { Some(Instant::now()) } else { None };
+        let timings = cfg!(debug_assertions).then(|| RefCell::new(ConsiderationTimings::from_env()));
+        intel.prune(turn_state.current_turn);
+        // A muzzle flash seen at night tells the whole side where the shooter
+        // was; the player's own side takes its flashes as map markers instead
+        let player_faction = (&players, &soldiers).join().next().map(|(_, soldier)| soldier.faction);
+        for faction in [Faction::Allies, Faction::CentralPowers] {
+            if player_faction == Some(faction) {
+                continue;
+            }
+            for marker in flash_sightings.take(faction) {
+                intel.report(
+                    faction,
+                    marker.tracked_entity,
+                    *marker.position.as_battlefield_pos(),
+                    turn_state.current_turn,
+                    false,
+                );
+            }
+        }
         let mut ai_count = 0;
+        // Evaluators hold boxed considerations, so each personality is built
+        // once per pass and shared by every soldier who plans with it
+        let mut personality_cache: HashMap<(Rank, AiRole), AIPersonality> = HashMap::new();
+        // Each side's danger from the enemies it knows of, mapped when its first soldier falls back
+        let mut danger_maps: HashMap<Faction, DangerMap> = HashMap::new();
         let mut total_actions_evaluated = 0;
 
+        // Fallen soldiers whose weapons still hold rounds
+        let lootable_corpses: Vec<(Entity, BattlefieldPos)> =
+            (&entities, &positions, &dead_markers, &weapons)
+                .join()
+                .filter(|(_, _, _, weapon)| !weapon.ammo.is_empty())
+                .map(|(e, corpse_pos, _, _)| (e, *corpse_pos.as_battlefield_pos()))
+                .collect();
+
+        // Tiles paths must route around when corpses block movement
+        let corpse_tiles: HashSet<BattlefieldPos> = if corpse_rule.blocks(true) {
+            (&positions, &dead_markers)
+                .join()
+                .map(|(corpse_pos, _)| *corpse_pos.as_battlefield_pos())
+                .collect()
+        } else {
+            HashSet::new()
+        };
+
+        let generation = GenerationContext {
+            positions: &positions,
+            soldiers: &soldiers,
+            weapons: &weapons,
+            secondaries: &secondaries,
+            battlefield: &battlefield,
+            objectives: &objectives,
+            lootable_corpses: &lootable_corpses,
+            corpse_rule: *corpse_rule,
+        };
+
         for (entity, pos, soldier, budget) in (&entities, &positions, &soldiers, &mut budgets).join()
         {
             if players.get(entity).is_some() {
                 continue;
             }
 
-            if dead_markers.get(entity).is_some() {
+            if dead_markers.get(entity).is_some() || surrendered.get(entity).is_some() {
                 continue;
             }
 
@@ -294,6 +477,11 @@ impl<'a> System<'a> for AIActionPlannerSystem {
                 continue;
             }
 
+            // Incoming fire this turn shapes the rules of engagement, panic fire and aiming
+            let shot_at = under_fire
+                .get(entity)
+                .is_some_and(|marker| marker.is_recent(turn_state.current_turn));
+
             ai_count += 1;
             if ai_count <= 3 {
                 debug_log(&format!("[AI_PLAN] Processing AI #{}: {} (faction: {:?})", ai_count, soldier.name, soldier.faction));
@@ -308,6 +496,7 @@ impl<'a> System<'a> for AIActionPlannerSystem {
                 &soldiers,
                 &healths,
                 &visions,
+                &surrendered,
                 &battlefield,
             );
 
@@ -315,17 +504,189 @@ impl<'a> System<'a> for AIActionPlannerSystem {
                 debug_log(&format!("[AI_PLAN] {} sees {} enemies", soldier.name, visible_enemies.len()));
             }
 
-            let possible_actions = ActionGenerator::generate_actions(
-                entity,
-                &visible_enemies,
-                &positions,
-                &soldiers,
-                &weapons,
-                &battlefield,
-                &objectives,
-            );
+            contacts.observe(entity, &visible_enemies, turn_state.current_turn);
+
+            // Everyone reports what they see; a scout's report holds for longer
+            let role = roles.get(entity).copied().unwrap_or_default();
+            let personality = personality_cache.entry((soldier.rank, role)).or_insert_with(|| {
+                let compiled = self.get_personality(soldier.rank, role);
+                personalities.get(&compiled.name).unwrap_or(compiled)
+            });
+            let scouting = role.is_scout();
+            for enemy in &visible_enemies {
+                if let Some(enemy_pos) = positions.get(*enemy) {
+                    intel.report(
+                        soldier.faction,
+                        *enemy,
+                        *enemy_pos.as_battlefield_pos(),
+                        turn_state.current_turn,
+                        scouting,
+                    );
+                }
+            }
+
+            let mut possible_actions =
+                ActionGenerator::generate_actions(entity, &visible_enemies, &generation);
 
-            let evaluators = self.get_evaluators(soldier.rank);
+            // Nothing in sight: close on the nearest enemy a comrade reported
+            if visible_enemies.is_empty()
+                && let Some(reported) =
+                    intel.nearest(soldier.faction, pos.as_battlefield_pos(), turn_state.current_turn)
+            {
+                possible_actions.extend(ActionGenerator::generate_intel_moves(
+                    pos.as_battlefield_pos(),
+                    &reported,
+                    &battlefield,
+                ));
+            }
+
+            // Holding their own half with no enemy near: wire the approach
+            let enemy_near = visible_enemies.iter().any(|enemy| {
+                positions.get(*enemy).is_some_and(|enemy_pos| {
+                    enemy_pos.as_battlefield_pos().distance_to(pos.as_battlefield_pos())
+                        <= WIRE_CLEAR_DISTANCE
+                })
+            });
+            if !enemy_near
+                && inventories.get(entity).is_some_and(|inv| inv.wire_coils > 0)
+                && budget.can_afford(LAY_WIRE_TIME)
+                && let Some(front) = wiring_front(&battlefield, soldier.faction, pos.as_battlefield_pos())
+                && let Some((dx, dy)) = wire_step(&battlefield, pos.as_battlefield_pos(), &front, |tile| {
+                    occupancy.contains_entity_at(*tile)
+                })
+            {
+                possible_actions.push(PossibleAction::new(ActionType::LayWire { dx, dy }));
+            }
+
+            // Each side's AI is bound by the orders given to that side alone
+            let orders = standing_orders.get(soldier.faction);
+            possible_actions.retain(|action| match action.action_type {
+                ActionType::Shoot { target } => positions.get(target).is_some_and(|target_pos| {
+                    let distance = pos.as_battlefield_pos().distance_to(target_pos.as_battlefield_pos());
+                    orders.roe.permits_shot(distance, shot_at)
+                }),
+                _ => true,
+            });
+
+            // Falling back: no offensive fire, and path home until inside the spawn zone,
+            // keeping out of the sights of the enemies the side knows about
+            if orders.retreat.is_active(turn_state.current_turn) {
+                possible_actions.retain(|action| {
+                    !matches!(
+                        action.action_type,
+                        ActionType::Shoot { .. } | ActionType::ThrowGrenade { .. }
+                    )
+                });
+
+                let home = match soldier.faction {
+                    Faction::Allies => &battlefield.ally_spawn,
+                    Faction::CentralPowers => &battlefield.enemy_spawn,
+                };
+                let danger = danger_maps.entry(soldier.faction).or_insert_with(|| {
+                    DangerMap::from_threats(
+                        &battlefield,
+                        intel
+                            .known_enemies(soldier.faction, turn_state.current_turn)
+                            .filter_map(|(enemy, report)| {
+                                weapons.get(enemy).map(|weapon| (report.position, weapon.stats.max_range))
+                            }),
+                    )
+                });
+                if let Some(home) = home
+                    && !home.contains(pos.as_battlefield_pos())
+                    && let Some(path_steps) = calculate_path_through_danger(
+                        pos.as_battlefield_pos(),
+                        &home.center,
+                        &battlefield,
+                        &corpse_tiles,
+                        Some(danger),
+                    )
+                {
+                    paths
+                        .insert(entity, PlannedPath::new(path_steps, 0.0, false))
+                        .ok();
+                    continue;
+                }
+                possible_actions.retain(|action| !matches!(action.action_type, ActionType::Move { .. }));
+            }
+
+            // Badly wounded: make for the side's aid post to be carried off
+            let badly_wounded = healths
+                .get(entity)
+                .is_some_and(|health| wounded.is_wounded(health.percentage()));
+            if badly_wounded
+                && let Some(post) = objectives.evac_point_for(soldier.faction)
+                && let Some(path_steps) = calculate_path_avoiding(
+                    pos.as_battlefield_pos(),
+                    &post.position,
+                    &battlefield,
+                    &corpse_tiles,
+                )
+            {
+                paths
+                    .insert(entity, PlannedPath::new(path_steps, 0.0, false))
+                    .ok();
+                continue;
+            }
+
+            // Pinned down: sometimes blaze away at the nearest known enemy instead
+            if weapons.get(entity).is_some_and(Weapon::can_fire) {
+                let known_enemies: Vec<(Entity, BattlefieldPos)> = visible_enemies
+                    .iter()
+                    .filter_map(|enemy| Some((*enemy, *positions.get(*enemy)?.as_battlefield_pos())))
+                    .chain(
+                        intel
+                            .known_enemies(soldier.faction, turn_state.current_turn)
+                            .map(|(enemy, report)| (enemy, report.position)),
+                    )
+                    .collect();
+                if let Some(action_type) = roll_panic_fire(
+                    &panic_fire,
+                    morales.get(entity),
+                    shot_at,
+                    pos.as_battlefield_pos(),
+                    &known_enemies,
+                    &mut *ai_rng,
+                ) {
+                    let burden = Burden {
+                        health: healths.get(entity),
+                        load: fatigued_load(
+                            soldier_stats.get(entity),
+                            inventories.get(entity),
+                            weapons.get(entity),
+                            &fatigue,
+                            turn_state.current_turn,
+                            rested.get(entity),
+                        ),
+                        wounded: &wounded,
+                    };
+                    self.queue_action(entity, action_type, &mut queued, budget, burden);
+                    continue;
+                }
+            }
+
+            // Bound and cover: in contact, half the squad holds and fires while the other half moves
+            let role = squad_members
+                .get(entity)
+                .filter(|_| !visible_enemies.is_empty())
+                .map(|member| member.bounding_role(turn_state.current_turn));
+            match role {
+                Some(role) => {
+                    bounding_roles.insert(entity, role).ok();
+                    apply_bounding_role(role, &mut possible_actions);
+                }
+                None => {
+                    bounding_roles.remove(entity);
+                }
+            }
+
+            let evaluators = &personality.evaluators;
+            let mut snapshot = snapshots.recording.then(|| ScoringSnapshot {
+                turn: turn_state.current_turn,
+                personality: personality.name.clone(),
+                actions: Vec::new(),
+                chosen: None,
+            });
 
             let mut scored_actions = Vec::new();
             for possible_action in &possible_actions {
@@ -343,9 +704,20 @@ impl<'a> System<'a> for AIActionPlannerSystem {
                     objectives: &objectives,
                     entities: &entities,
                     visible_enemies: &visible_enemies,
+                    contacts: &contacts,
+                    current_turn: turn_state.current_turn,
+                    timings: timings.as_ref(),
                 };
 
-                let score = self.score_action(&possible_action, &context, &evaluators);
+                let score = match snapshot.as_mut() {
+                    Some(snapshot) => {
+                        let entry = self.score_action_breakdown(possible_action, &context, evaluators);
+                        let score = entry.score;
+                        snapshot.actions.push(entry);
+                        score
+                    }
+                    None => self.score_action(possible_action, &context, evaluators),
+                };
 
                 if matches!(possible_action.action_type, ActionType::Shoot { .. }) {
                     debug_log(&format!("[AI] {} Shoot action scored: {:.3}", soldier.name, score));
@@ -362,61 +734,98 @@ impl<'a> System<'a> for AIActionPlannerSystem {
 
             total_actions_evaluated += scored_actions.len();
 
+            let best_action =
+                select_best_action(&scored_actions, pos.as_battlefield_pos(), &mut *ai_rng);
+            if let Some(mut snapshot) = snapshot {
+                snapshot.chosen = best_action.and_then(|best| {
+                    scored_actions.iter().position(|action| std::ptr::eq(action, best))
+                });
+                snapshots.record(entity, snapshot);
+            }
+
             // AI action consideration logging removed from event log (clutters UI)
             if cfg!(debug_assertions) && !scored_actions.is_empty() {
                 let shoot_count = scored_actions.iter().filter(|a| matches!(a.action_type, ActionType::Shoot { .. })).count();
                 debug_log(&format!("[AI] {} has {} shoot actions out of {} total", soldier.name, shoot_count, scored_actions.len()));
             }
 
-            if let Some(best_action) = scored_actions
-                .iter()
-                .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(std::cmp::Ordering::Equal))
-            {
+            if let Some(best_action) = best_action {
                 if !visible_enemies.is_empty() {
                     debug_log(&format!("[AI] {} selected {:?} with score {:.3}", soldier.name, best_action.action_type, best_action.score));
                 }
 
+                let burden = Burden {
+                    health: healths.get(entity),
+                    load: fatigued_load(
+                        soldier_stats.get(entity),
+                        inventories.get(entity),
+                        weapons.get(entity),
+                        &fatigue,
+                        turn_state.current_turn,
+                        rested.get(entity),
+                    ),
+                    wounded: &wounded,
+                };
+
+                // An aggressive soldier closes on a distant target, firing as they go
+                let assault = match best_action.action_type {
+                    ActionType::Shoot { target } if personality.assaults() => weapons
+                        .get(entity)
+                        .zip(positions.get(target))
+                        .and_then(|(weapon, target_pos)| {
+                            assault_step(pos, target, target_pos, weapon, &battlefield, &corpse_tiles)
+                        }),
+                    _ => None,
+                };
+                if let Some(assault) = assault {
+                    self.queue_action(entity, assault, &mut queued, budget, burden);
+                    continue;
+                }
+
                 match &best_action.action_type {
                     ActionType::Move { .. } => {
                         if let Some(target_pos) = &best_action.position {
                             let ai_pos = pos.as_battlefield_pos();
                             if ai_pos.distance_to(target_pos) > 1.5 {
                                 if let Some(path_steps) =
-                                    calculate_path(ai_pos, target_pos, &battlefield)
+                                    calculate_path_avoiding(ai_pos, target_pos, &battlefield, &corpse_tiles)
                                 {
                                     paths
                                         .insert(entity, PlannedPath::new(path_steps, 0.0, false))
                                         .ok();
-                                } else if ai_pos.distance_to(target_pos) <= 1.5 {
-                                    self.queue_move_action(
-                                        entity,
-                                        target_pos,
-                                        pos,
-                                        &battlefield,
-                                        &mut queued,
-                                        budget,
-                                    );
+                                } else if ai_pos.distance_to(target_pos) <= 1.5
+                                    && let Some(step) = Self::step_action(target_pos, pos, &battlefield)
+                                {
+                                    self.queue_action(entity, step, &mut queued, budget, burden);
                                 }
-                            } else {
-                                self.queue_move_action(
-                                    entity,
-                                    target_pos,
-                                    pos,
-                                    &battlefield,
-                                    &mut queued,
-                                    budget,
-                                );
+                            } else if let Some(step) = Self::step_action(target_pos, pos, &battlefield) {
+                                self.queue_action(entity, step, &mut queued, budget, burden);
                             }
                         }
                     }
+                    ActionType::Shoot { target }
+                        if weapons.get(entity).zip(positions.get(*target)).is_some_and(
+                            |(weapon, target_pos)| {
+                                aims_before_shooting(
+                                    weapon,
+                                    roles.get(entity).is_some_and(|role| role.is_scout()),
+                                    range_in_tiles(pos.as_battlefield_pos(), target_pos.as_battlefield_pos()),
+                                    aiming.contains(entity),
+                                    shot_at,
+                                )
+                            },
+                        ) =>
+                    {
+                        // Snipers with a precision weapon steady up first and take the shot next turn
+                        self.queue_action(entity, ActionType::Aim, &mut queued, budget, burden);
+                    }
                     _ => {
                         self.queue_action(
                             entity,
-                            best_action,
+                            best_action.action_type.clone(),
                             &mut queued,
                             budget,
-                            &mut event_log,
-                            Some(&soldier.name),
+                            burden,
                         );
                     }
                 }
@@ -434,10 +843,87 @@ impl<'a> System<'a> for AIActionPlannerSystem {
                     elapsed.as_millis()
                 ));
             }
+            if let Some(timings) = timings.as_ref().map(RefCell::borrow)
+                && !timings.is_empty()
+            {
+                debug_log("[PERF] Consideration times this pass:");
+                for line in timings.summary() {
+                    debug_log(&format!("[PERF]   {}", line));
+                }
+            }
         }
     }
 }
 
+/// A step toward a target beyond effective range, firing on the way
+///
+/// None once the target is within effective range, or when the tile toward
+/// it can't be entered.
+fn assault_step(
+    from: &Position,
+    target: Entity,
+    target_pos: &Position,
+    weapon: &Weapon,
+    battlefield: &Battlefield,
+    blocked: &HashSet<BattlefieldPos>,
+) -> Option<ActionType> {
+    let (from, to) = (from.as_battlefield_pos(), target_pos.as_battlefield_pos());
+    if in_effective_range(weapon, from, to) {
+        return None;
+    }
+    let (dx, dy) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+    let step = BattlefieldPos::new(from.x + dx, from.y + dy);
+    let tile = battlefield.get_tile(&step)?;
+    if !tile.terrain.is_passable() || blocked.contains(&step) {
+        return None;
+    }
+    Some(ActionType::MoveAndFire {
+        dx,
+        dy,
+        terrain_cost: tile.terrain.movement_cost(),
+        target,
+    })
+}
+
+/// Whether a shooter should spend a turn aiming before taking this shot
+///
+/// Only precision weapons are worth steadying, only past their effective
+/// range, and not while being shot at (incoming fire spoils the aim).
+fn aims_before_shooting(
+    weapon: &Weapon,
+    sniper: bool,
+    distance: i32,
+    already_aiming: bool,
+    shot_at: bool,
+) -> bool {
+    sniper
+        && weapon.stats.aim_bonus >= PRECISION_AIM_BONUS
+        && distance > weapon.stats.effective_range
+        && !already_aiming
+        && !shot_at
+}
+
+/// Restrict a squad member's options to its half of the bound
+///
+/// Overwatch never moves. The advancing half gives up shooting, waiting and
+/// turning in place, as long as it has somewhere to move to.
+fn apply_bounding_role(role: BoundingRole, possible_actions: &mut Vec<PossibleAction>) {
+    let can_move = possible_actions
+        .iter()
+        .any(|action| matches!(action.action_type, ActionType::Move { .. }));
+
+    possible_actions.retain(|action| match role {
+        BoundingRole::Overwatch => !matches!(action.action_type, ActionType::Move { .. }),
+        BoundingRole::Advance => {
+            !can_move
+                || !matches!(
+                    action.action_type,
+                    ActionType::Shoot { .. } | ActionType::Wait | ActionType::Rotate { .. }
+                )
+        }
+    });
+}
+
 impl Default for AIActionPlannerSystem {
     fn default() -> Self {
         Self::new()
@@ -448,6 +934,7 @@ impl Default for AIActionPlannerSystem {
 mod tests {
     use super::*;
     use crate::components::action::ActionType;
+    use crate::utils::event_log::EventLog;
 
     #[test]
     fn test_evaluator_matches_move_action() {
@@ -472,6 +959,18 @@ mod tests {
         assert!(!system.evaluator_matches_action("Move", &wait_action));
     }
 
+    #[test]
+    fn test_snipers_aim_before_long_shots_only() {
+        let rifle = Weapon::rifle();
+        let long = rifle.stats.effective_range + 5;
+        assert!(aims_before_shooting(&rifle, true, long, false, false));
+        assert!(!aims_before_shooting(&rifle, false, long, false, false), "riflemen take the snap shot");
+        assert!(!aims_before_shooting(&rifle, true, rifle.stats.effective_range, false, false));
+        assert!(!aims_before_shooting(&rifle, true, long, true, false), "already aimed: take the shot");
+        assert!(!aims_before_shooting(&rifle, true, long, false, true), "under fire: no time to aim");
+        assert!(!aims_before_shooting(&Weapon::pistol(), true, long, false, false));
+    }
+
     #[test]
     fn test_evaluator_matches_reload_action() {
         let system = AIActionPlannerSystem::new();
@@ -484,36 +983,211 @@ mod tests {
     #[test]
     fn test_rank_based_personality_assignment() {
         let system = AIActionPlannerSystem::new();
+        let name = |rank, role| system.get_personality(rank, role).name;
+
+        assert_eq!(name(Rank::Captain, AiRole::Rifleman), "ObjectiveFocused");
+        assert_eq!(name(Rank::Lieutenant, AiRole::Rifleman), "Aggressive");
+        assert_eq!(name(Rank::Sergeant, AiRole::Rifleman), "Balanced");
+        assert_eq!(name(Rank::Corporal, AiRole::Rifleman), "Balanced");
+        assert_eq!(name(Rank::Private, AiRole::Rifleman), "Defensive");
+        assert_eq!(name(Rank::Private, AiRole::Scout), "Scout");
+        assert_eq!(name(Rank::Private, AiRole::Rearguard), "RearGuard");
+    }
 
-        let captain_personality = system.get_personality_for_rank(Rank::Captain);
-        assert_eq!(captain_personality.name, "ObjectiveFocused");
+    fn spot_then_shoot_world() -> (specs::World, Entity) {
+        use crate::game_logic::world_setup::register_components;
+        use specs::{Builder, WorldExt};
+
+        let mut world = specs::World::new();
+        register_components(&mut world);
+        world.insert(Battlefield::new(30, 30));
+        world.insert(TurnState::new_with_mode(TurnOrderMode::Simultaneous));
+        world.insert(Objectives::new());
+        world.insert(EventLog::new());
+        world.insert(AiRng::new(1));
+        world.insert(WoundedConfig::default());
+        world.insert(FatigueConfig::default());
+        world.insert(CorpseRule::default());
+        world.insert(ContactTracker::new(1));
+        world.insert(FactionIntel::default());
+        world.insert(FlashSightings::default());
+        world.insert(ScoringSnapshots::default());
+        world.insert(StandingOrders::default());
+        world.insert(PersonalityLibrary::default());
+        world.insert(PanicFireConfig::default());
+        world.insert(PositionIndex::default());
+
+        let soldier = |name: &str, faction| Soldier {
+            name: name.to_string(),
+            faction,
+            rank: Rank::Sergeant,
+        };
 
-        let lieutenant_personality = system.get_personality_for_rank(Rank::Lieutenant);
-        assert_eq!(lieutenant_personality.name, "Aggressive");
+        let shooter = world
+            .create_entity()
+            .with(Position::new(10, 10))
+            .with(soldier("Shooter", Faction::Allies))
+            .with(TimeBudget::new(12.0))
+            .with(Vision::new(20))
+            .with(Weapon::rifle())
+            .with(Health::new(100))
+            .build();
+
+        world
+            .create_entity()
+            .with(Position::new(14, 10))
+            .with(soldier("Target", Faction::CentralPowers))
+            .with(Vision::new(20))
+            .with(Weapon::rifle())
+            .with(Health::new(100))
+            .build();
+        PositionIndex::refresh(&world);
+
+        (world, shooter)
+    }
 
-        let sergeant_personality = system.get_personality_for_rank(Rank::Sergeant);
-        assert_eq!(sergeant_personality.name, "Balanced");
+    #[test]
+    fn test_spot_then_shoot_waits_one_turn() {
+        use specs::{RunNow, WorldExt};
+
+        let (mut world, shooter) = spot_then_shoot_world();
+        let mut planner = AIActionPlannerSystem::new();
+
+        planner.run_now(&world);
+        let first = world.read_storage::<QueuedAction>().get(shooter).cloned();
+        assert!(
+            !matches!(first.map(|q| q.action_type), Some(ActionType::Shoot { .. })),
+            "AI fired on the first turn of contact"
+        );
+
+        world.write_storage::<QueuedAction>().clear();
+        world.write_storage::<PlannedPath>().clear();
+        world.write_storage::<TimeBudget>().get_mut(shooter).unwrap().reset_for_new_turn();
+        world.write_resource::<TurnState>().current_turn += 1;
+
+        planner.run_now(&world);
+        let second = world.read_storage::<QueuedAction>().get(shooter).cloned();
+        assert!(matches!(
+            second.map(|q| q.action_type),
+            Some(ActionType::Shoot { .. })
+        ));
+    }
+
+    #[test]
+    fn test_aggressive_soldier_closes_on_a_distant_target_firing() {
+        use specs::{RunNow, WorldExt};
 
-        let corporal_personality = system.get_personality_for_rank(Rank::Corporal);
-        assert_eq!(corporal_personality.name, "Balanced");
+        let (mut world, shooter) = spot_then_shoot_world();
+        world.insert(ContactTracker::new(0));
+        let target = (&world.entities(), &world.read_storage::<Soldier>())
+            .join()
+            .find(|(_, s)| s.faction == Faction::CentralPowers)
+            .map(|(e, _)| e)
+            .unwrap();
+        world.write_storage::<Soldier>().get_mut(shooter).unwrap().rank = Rank::Lieutenant;
+        world.write_storage::<Vision>().insert(shooter, Vision::new(28)).unwrap();
+        // Well beyond the rifle's effective range, but inside its reach
+        *world.write_storage::<Position>().get_mut(shooter).unwrap() = Position::new(10, 2);
+        *world.write_storage::<Position>().get_mut(target).unwrap() = Position::new(10, 26);
+
+        AIActionPlannerSystem::new().run_now(&world);
+
+        let queued = world.read_storage::<QueuedAction>().get(shooter).cloned();
+        assert!(
+            matches!(
+                queued.as_ref().map(|q| &q.action_type),
+                Some(ActionType::MoveAndFire { dx: 0, dy: 1, target: t, .. }) if *t == target
+            ),
+            "expected an assault step toward the target, got {:?}",
+            queued
+        );
+    }
 
-        let mut scout_count = 0;
-        let mut rearguard_count = 0;
-        let mut defensive_count = 0;
+    #[test]
+    fn test_ai_draws_sidearm_when_rifle_empty_and_enemy_adjacent() {
+        use specs::{RunNow, WorldExt};
 
-        for _ in 0..1000 {
-            let private_personality = system.get_personality_for_rank(Rank::Private);
-            match private_personality.name.as_str() {
-                "Scout" => scout_count += 1,
-                "RearGuard" => rearguard_count += 1,
-                "Defensive" => defensive_count += 1,
-                _ => panic!("Unexpected personality: {}", private_personality.name),
+        let (world, shooter) = spot_then_shoot_world();
+        {
+            let entities = world.entities();
+            let soldiers = world.read_storage::<Soldier>();
+            let mut positions = world.write_storage::<Position>();
+            for (_, soldier, pos) in (&entities, &soldiers, &mut positions).join() {
+                if soldier.faction == Faction::CentralPowers {
+                    *pos = Position::new(11, 10);
+                }
             }
         }
+        world.write_storage::<Weapon>().get_mut(shooter).unwrap().ammo.current = 0;
+        world
+            .write_storage::<SecondaryWeapon>()
+            .insert(shooter, SecondaryWeapon::sidearm())
+            .unwrap();
+
+        AIActionPlannerSystem::new().run_now(&world);
+
+        let queued = world.read_storage::<QueuedAction>().get(shooter).cloned();
+        assert!(matches!(
+            queued.map(|q| q.action_type),
+            Some(ActionType::SwapWeapon)
+        ));
+    }
+
+    #[test]
+    fn test_scoring_snapshot_covers_generated_actions() {
+        use specs::{RunNow, WorldExt};
+
+        let (world, shooter) = spot_then_shoot_world();
+        world.write_resource::<ScoringSnapshots>().recording = true;
+
+        AIActionPlannerSystem::new().run_now(&world);
+
+        let generated = {
+            let positions = world.read_storage::<Position>();
+            let soldiers = world.read_storage::<Soldier>();
+            let weapons = world.read_storage::<Weapon>();
+            let target = (&world.entities(), &soldiers)
+                .join()
+                .find(|(_, s)| s.faction == Faction::CentralPowers)
+                .map(|(e, _)| e)
+                .unwrap();
+            let context = GenerationContext {
+                positions: &positions,
+                soldiers: &soldiers,
+                weapons: &weapons,
+                secondaries: &world.read_storage::<SecondaryWeapon>(),
+                battlefield: &world.read_resource::<Battlefield>(),
+                objectives: &world.read_resource::<Objectives>(),
+                lootable_corpses: &[],
+                corpse_rule: CorpseRule::default(),
+            };
+            ActionGenerator::generate_actions(shooter, &[target], &context)
+        };
 
-        assert!(scout_count > 10 && scout_count < 50);
-        assert!(rearguard_count > 10 && rearguard_count < 50);
-        assert!(defensive_count > 900);
+        let snapshots = world.read_resource::<ScoringSnapshots>();
+        let snapshot = snapshots.get(shooter).expect("no snapshot recorded");
+        assert_eq!(snapshot.actions.len(), generated.len());
+        assert_eq!(snapshot.personality, "Balanced");
+
+        let shoot = snapshot
+            .actions
+            .iter()
+            .find(|a| matches!(a.action_type, ActionType::Shoot { .. }))
+            .expect("no shoot entry");
+        assert_eq!(shoot.score, 0.0);
+        assert!(shoot
+            .considerations
+            .iter()
+            .any(|(name, score)| name == "EstablishedContact" && *score == 0.0));
+
+        // The overlay's pick is the action that was actually queued
+        let queued = world.read_storage::<QueuedAction>().get(shooter).cloned();
+        let queued = queued.expect("nothing queued");
+        let chosen = snapshot.best().expect("no chosen action");
+        assert_eq!(
+            std::mem::discriminant(&chosen.action_type),
+            std::mem::discriminant(&queued.action_type)
+        );
     }
 
     #[test]
@@ -526,4 +1200,286 @@ mod tests {
         let private_evaluators = system.get_evaluators(Rank::Private);
         assert_eq!(private_evaluators.len(), 6);
     }
+
+    #[test]
+    fn test_scout_report_reaches_distant_allies() {
+        use crate::ai::intel::SCOUT_REPORT_TURNS;
+        use specs::{Builder, RunNow, WorldExt};
+
+        let (mut world, scout) = spot_then_shoot_world();
+        world.write_storage::<Soldier>().get_mut(scout).unwrap().rank = Rank::Private;
+        world.write_storage::<AiRole>().insert(scout, AiRole::Scout).unwrap();
+
+        // Far across the map, and too short-sighted to see the enemy itself
+        world
+            .create_entity()
+            .with(Position::new(28, 28))
+            .with(Soldier {
+                name: "Rear".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Corporal,
+            })
+            .with(TimeBudget::new(12.0))
+            .with(Vision::new(3))
+            .with(Weapon::rifle())
+            .with(Health::new(100))
+            .build();
+
+        let mut planner = AIActionPlannerSystem::new();
+        planner.run_now(&world);
+
+        let turn = world.read_resource::<TurnState>().current_turn;
+        let intel = world.read_resource::<FactionIntel>();
+        let reports: Vec<_> = intel.known_enemies(Faction::Allies, turn).collect();
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].1.by_scout);
+        assert_eq!(reports[0].1.position, BattlefieldPos::new(14, 10));
+
+        // Still on the map for the rest of the faction after the scout moves on
+        let later = turn + SCOUT_REPORT_TURNS - 1;
+        assert_eq!(intel.known_enemies(Faction::Allies, later).count(), 1);
+        assert_eq!(intel.known_enemies(Faction::CentralPowers, turn).count(), 0);
+    }
+
+    #[test]
+    fn test_muzzle_flash_reaches_the_ai_side_that_saw_it() {
+        use crate::components::last_seen::LastSeenMarker;
+        use crate::components::player::Player;
+        use specs::{Builder, RunNow, WorldExt};
+
+        let (mut world, shooter) = spot_then_shoot_world();
+        world.write_storage::<Player>().insert(shooter, Player).unwrap();
+        let turn = world.read_resource::<TurnState>().current_turn;
+
+        // Well beyond the enemy's sight, given away only by the flash
+        let sniper_pos = Position::new(28, 28);
+        let sniper = world
+            .create_entity()
+            .with(sniper_pos)
+            .with(Soldier {
+                name: "Sniper".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Private,
+            })
+            .build();
+        let flash = |faction| LastSeenMarker::new(sniper_pos, faction, Rank::Private, turn, sniper);
+        {
+            let mut sightings = world.write_resource::<FlashSightings>();
+            sightings.record(Faction::CentralPowers, flash(Faction::Allies));
+            sightings.record(Faction::Allies, flash(Faction::CentralPowers));
+        }
+
+        AIActionPlannerSystem::new().run_now(&world);
+
+        let intel = world.read_resource::<FactionIntel>();
+        let report = intel
+            .known_enemies(Faction::CentralPowers, turn)
+            .find(|(enemy, _)| *enemy == sniper)
+            .map(|(_, report)| report.position);
+        assert_eq!(report, Some(*sniper_pos.as_battlefield_pos()));
+        // The player's side keeps its sightings for its own map markers
+        assert!(world.read_resource::<FlashSightings>().get(Faction::Allies, sniper).is_some());
+    }
+
+    #[test]
+    fn test_hold_fire_waits_until_shot_at() {
+        use specs::{Builder, RunNow, WorldExt};
+
+        let (mut world, shooter) = spot_then_shoot_world();
+        world.insert(ContactTracker::new(0));
+        world.write_resource::<StandingOrders>().get_mut(Faction::Allies).roe =
+            crate::ai::rules_of_engagement::RulesOfEngagement::HoldFire;
+        world.write_resource::<ScoringSnapshots>().recording = true;
+        world
+            .create_entity()
+            .with(Position::new(0, 0))
+            .with(Soldier {
+                name: "Player".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Lieutenant,
+            })
+            .with(Player)
+            .with(Health::new(100))
+            .build();
+
+        let mut planner = AIActionPlannerSystem::new();
+        planner.run_now(&world);
+        {
+            let snapshots = world.read_resource::<ScoringSnapshots>();
+            let snapshot = snapshots.get(shooter).unwrap();
+            assert!(
+                !snapshot
+                    .actions
+                    .iter()
+                    .any(|entry| matches!(entry.action_type, ActionType::Shoot { .. })),
+                "Shoot generated for a distant target under Hold Fire"
+            );
+        }
+
+        world.write_storage::<QueuedAction>().clear();
+        world.write_storage::<PlannedPath>().clear();
+        world.write_storage::<TimeBudget>().get_mut(shooter).unwrap().reset_for_new_turn();
+        let turn = world.read_resource::<TurnState>().current_turn;
+        world
+            .write_storage::<UnderFire>()
+            .insert(shooter, UnderFire::new(turn))
+            .unwrap();
+
+        planner.run_now(&world);
+        let queued = world.read_storage::<QueuedAction>().get(shooter).cloned();
+        assert!(matches!(
+            queued.map(|q| q.action_type),
+            Some(ActionType::Shoot { .. })
+        ));
+    }
+
+    #[test]
+    fn test_retreat_paths_home_without_firing() {
+        use crate::game_logic::battlefield::SpawnZone;
+        use specs::{RunNow, WorldExt};
+
+        let (mut world, shooter) = spot_then_shoot_world();
+        world.insert(ContactTracker::new(0));
+        world.insert(FactionIntel::default());
+        let home = BattlefieldPos::new(10, 25);
+        world.write_resource::<Battlefield>().set_spawn_zones(
+            SpawnZone::new(home, 3),
+            SpawnZone::new(BattlefieldPos::new(20, 3), 3),
+        );
+        let turn = world.read_resource::<TurnState>().current_turn;
+        world.write_resource::<StandingOrders>().get_mut(Faction::Allies).retreat.issue(turn);
+
+        AIActionPlannerSystem::new().run_now(&world);
+
+        let queued = world.read_storage::<QueuedAction>().get(shooter).cloned();
+        assert!(
+            !matches!(queued.map(|q| q.action_type), Some(ActionType::Shoot { .. })),
+            "AI fired while retreating"
+        );
+        let paths = world.read_storage::<PlannedPath>();
+        let path = paths.get(shooter).expect("retreating AI plans a path");
+        assert_eq!(path.steps.last(), Some(&home));
+    }
+
+    #[test]
+    fn test_retreat_takes_the_less_exposed_escape_tile() {
+        use crate::game_logic::battlefield::{SpawnZone, TerrainType};
+        use specs::{RunNow, WorldExt};
+
+        // The way straight home is walled off, leaving a step north-west or
+        // south-west; the enemy covers whichever lies on its side
+        for (enemy_at, expected_step) in [
+            (BattlefieldPos::new(14, 6), BattlefieldPos::new(9, 11)),
+            (BattlefieldPos::new(14, 14), BattlefieldPos::new(9, 9)),
+        ] {
+            let (world, shooter) = spot_then_shoot_world();
+            {
+                let mut battlefield = world.write_resource::<Battlefield>();
+                battlefield.set_terrain(BattlefieldPos::new(9, 10), TerrainType::BuildingWall);
+                battlefield.set_spawn_zones(
+                    SpawnZone::new(BattlefieldPos::new(2, 10), 1),
+                    SpawnZone::new(BattlefieldPos::new(27, 10), 1),
+                );
+            }
+            let enemy = {
+                let soldiers = world.read_storage::<Soldier>();
+                (&world.entities(), &soldiers)
+                    .join()
+                    .find(|(_, s)| s.faction == Faction::CentralPowers)
+                    .map(|(e, _)| e)
+                    .unwrap()
+            };
+            world
+                .write_storage::<Position>()
+                .insert(enemy, Position::new(enemy_at.x, enemy_at.y))
+                .unwrap();
+            let turn = world.read_resource::<TurnState>().current_turn;
+            world.write_resource::<StandingOrders>().get_mut(Faction::Allies).retreat.issue(turn);
+
+            AIActionPlannerSystem::new().run_now(&world);
+
+            let paths = world.read_storage::<PlannedPath>();
+            let path = paths.get(shooter).expect("retreating AI plans a path");
+            assert_eq!(path.steps.first(), Some(&expected_step), "enemy at {:?}", enemy_at);
+        }
+    }
+
+    #[test]
+    fn test_surrendered_enemy_is_not_a_target() {
+        use specs::{RunNow, WorldExt};
+
+        let (mut world, shooter) = spot_then_shoot_world();
+        world.insert(ContactTracker::new(0));
+        world.write_resource::<ScoringSnapshots>().recording = true;
+        let target = {
+            let soldiers = world.read_storage::<Soldier>();
+            (&world.entities(), &soldiers)
+                .join()
+                .find(|(_, s)| s.faction == Faction::CentralPowers)
+                .map(|(e, _)| e)
+                .unwrap()
+        };
+        world.write_storage::<Surrendered>().insert(target, Surrendered).unwrap();
+
+        AIActionPlannerSystem::new().run_now(&world);
+
+        let snapshots = world.read_resource::<ScoringSnapshots>();
+        let snapshot = snapshots.get(shooter).unwrap();
+        assert!(!snapshot
+            .actions
+            .iter()
+            .any(|entry| matches!(entry.action_type, ActionType::Shoot { .. })));
+    }
+
+    #[test]
+    fn test_squad_in_contact_bounds_and_covers() {
+        use specs::{Builder, RunNow, WorldExt};
+
+        let (mut world, shooter) = spot_then_shoot_world();
+        world.insert(ContactTracker::new(0));
+        let rifleman = world
+            .create_entity()
+            .with(Position::new(10, 12))
+            .with(Soldier {
+                name: "Rifleman".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Corporal,
+            })
+            .with(TimeBudget::new(12.0))
+            .with(Vision::new(20))
+            .with(Weapon::rifle())
+            .with(Health::new(100))
+            .build();
+        {
+            let mut members = world.write_storage::<SquadMember>();
+            members.insert(shooter, SquadMember::new(shooter.id(), 0)).unwrap();
+            members.insert(rifleman, SquadMember::new(shooter.id(), 1)).unwrap();
+        }
+
+        AIActionPlannerSystem::new().run_now(&world);
+
+        let roles = world.read_storage::<BoundingRole>();
+        let queued = world.read_storage::<QueuedAction>();
+        let paths = world.read_storage::<PlannedPath>();
+        let members = [shooter, rifleman];
+
+        let overwatch = members
+            .iter()
+            .find(|&&e| roles.get(e) == Some(&BoundingRole::Overwatch))
+            .expect("no member on overwatch");
+        let advancing = members
+            .iter()
+            .find(|&&e| roles.get(e) == Some(&BoundingRole::Advance))
+            .expect("no member advancing");
+
+        let moved = |e: Entity| {
+            paths.get(e).is_some()
+                || matches!(
+                    queued.get(e).map(|q| &q.action_type),
+                    Some(ActionType::Move { .. })
+                )
+        };
+        assert!(!moved(*overwatch), "overwatch member left its position");
+        assert!(moved(*advancing), "advancing member stayed put");
+    }
 }
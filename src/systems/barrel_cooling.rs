@@ -0,0 +1,168 @@
+// Barrel Cooling System
+// Hot barrels shed heat once a turn, whether or not their holders act
+//
+// Runs AFTER ActionExecutionSystem, at most once per turn during the
+// Execution phase. A weapon fired this turn keeps its heat, so a machine gun
+// that overheats sits out the next turn before it can fire again; every
+// other barrel cools, faster in mud or water. Slung spare weapons cool too.
+
+use crate::components::{
+    position::Position,
+    weapon::{SecondaryWeapon, Weapon, IDLE_COOLING, WET_COOLING_MULTIPLIER},
+};
+use crate::game_logic::battlefield::{Battlefield, TerrainType};
+use crate::game_logic::turn_state::{TurnPhase, TurnState};
+use specs::{Entities, Join, Read, ReadStorage, System, WriteStorage};
+
+pub struct BarrelCoolingSystem {
+    last_applied_turn: Option<u32>,
+}
+
+impl BarrelCoolingSystem {
+    pub fn new() -> Self {
+        Self {
+            last_applied_turn: None,
+        }
+    }
+}
+
+impl Default for BarrelCoolingSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Heat a weapon sheds over `turn`, standing on `terrain`
+fn barrel_cooling(weapon: &Weapon, terrain: Option<TerrainType>, turn: u32) -> f32 {
+    if weapon.fired_turn == Some(turn) {
+        return 0.0;
+    }
+
+    let wet = matches!(
+        terrain,
+        Some(
+            TerrainType::Mud
+                | TerrainType::Water
+                | TerrainType::DeepWater
+                | TerrainType::CraterWater
+        )
+    );
+    if wet {
+        IDLE_COOLING * WET_COOLING_MULTIPLIER
+    } else {
+        IDLE_COOLING
+    }
+}
+
+impl<'a> System<'a> for BarrelCoolingSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Position>,
+        WriteStorage<'a, Weapon>,
+        WriteStorage<'a, SecondaryWeapon>,
+        Read<'a, Battlefield>,
+        Read<'a, TurnState>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, positions, mut weapons, mut secondaries, battlefield, turn_state): Self::SystemData,
+    ) {
+        if !matches!(turn_state.phase, TurnPhase::Execution) {
+            return;
+        }
+        if self.last_applied_turn == Some(turn_state.current_turn) {
+            return;
+        }
+        self.last_applied_turn = Some(turn_state.current_turn);
+
+        let terrain = |entity| {
+            positions
+                .get(entity)
+                .and_then(|pos| battlefield.get_tile(pos.as_battlefield_pos()))
+                .map(|tile| tile.terrain)
+        };
+        let turn = turn_state.current_turn;
+        for (entity, weapon) in (&entities, &mut weapons).join() {
+            weapon.cool(barrel_cooling(weapon, terrain(entity), turn));
+        }
+        for (entity, spare) in (&entities, &mut secondaries).join() {
+            let weapon = &mut spare.weapon;
+            weapon.cool(barrel_cooling(weapon, terrain(entity), turn));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::battlefield::Position as BattlefieldPos;
+    use crate::game_logic::world_setup::register_components;
+    use specs::{Builder, RunNow, World, WorldExt};
+
+    fn setup() -> World {
+        let mut world = World::new();
+        register_components(&mut world);
+        let mut battlefield = Battlefield::new(10, 10);
+        battlefield.set_terrain(BattlefieldPos::new(5, 5), TerrainType::Mud);
+        let mut turn_state = TurnState::new();
+        turn_state.phase = TurnPhase::Execution;
+        world.insert(battlefield);
+        world.insert(turn_state);
+        world
+    }
+
+    fn run_turn(world: &mut World, system: &mut BarrelCoolingSystem, turn: u32) {
+        world.write_resource::<TurnState>().current_turn = turn;
+        system.run_now(world);
+    }
+
+    #[test]
+    fn test_overheated_gun_sits_out_a_turn_then_fires_again() {
+        let mut world = setup();
+        let mut mg = Weapon::machine_gun();
+        while mg.can_fire() {
+            mg.fire();
+        }
+        mg.fired_turn = Some(1);
+        let gunner = world.create_entity().with(Position::new(1, 1)).with(mg).build();
+        let mut system = BarrelCoolingSystem::new();
+
+        // Still too hot at the end of the turn it overheated in
+        run_turn(&mut world, &mut system, 1);
+        assert!(!world.read_storage::<Weapon>().get(gunner).unwrap().can_fire());
+
+        // A turn without firing brings it back
+        run_turn(&mut world, &mut system, 2);
+        assert!(world.read_storage::<Weapon>().get(gunner).unwrap().can_fire());
+    }
+
+    #[test]
+    fn test_idle_barrels_cool_once_a_turn() {
+        let mut world = setup();
+        let mut hot = Weapon::machine_gun();
+        hot.heat = 100.0;
+        let dry = world.create_entity().with(Position::new(1, 1)).with(hot.clone()).build();
+        let wet = world.create_entity().with(Position::new(5, 5)).with(hot.clone()).build();
+        let spare = world
+            .create_entity()
+            .with(Position::new(1, 1))
+            .with(Weapon::rifle())
+            .with(SecondaryWeapon { weapon: hot })
+            .build();
+        let mut system = BarrelCoolingSystem::new();
+
+        run_turn(&mut world, &mut system, 3);
+        // A second pass in the same turn must not cool again
+        run_turn(&mut world, &mut system, 3);
+
+        let weapons = world.read_storage::<Weapon>();
+        assert_eq!(weapons.get(dry).unwrap().heat, 100.0 - IDLE_COOLING);
+        assert_eq!(
+            weapons.get(wet).unwrap().heat,
+            100.0 - IDLE_COOLING * WET_COOLING_MULTIPLIER
+        );
+        let secondaries = world.read_storage::<SecondaryWeapon>();
+        assert_eq!(secondaries.get(spare).unwrap().weapon.heat, 100.0 - IDLE_COOLING);
+    }
+}
@@ -0,0 +1,148 @@
+// Casualty Evacuation System
+// Wounded soldiers who reach their side's aid post are carried off the field
+//
+// Runs AFTER ActionExecutionSystem so positions reflect this turn's moves.
+// An evacuated soldier loses its Position and TimeBudget, so it drops out of
+// the map, the planner and turn readiness, and counts as a save for its side.
+
+use crate::components::{
+    dead::Dead, evacuated::Evacuated, health::Health, player::Player, position::Position,
+    soldier::Soldier, surrendered::Surrendered, time_budget::TimeBudget,
+};
+use crate::config::game_config::WoundedConfig;
+use crate::game_logic::objectives::Objectives;
+use crate::game_logic::spatial_index::PositionIndex;
+use crate::utils::event_log::EventLog;
+use specs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
+
+pub struct CasualtyEvacuationSystem;
+
+impl<'a> System<'a> for CasualtyEvacuationSystem {
+    type SystemData = (
+        Entities<'a>,
+        WriteStorage<'a, Position>,
+        WriteStorage<'a, TimeBudget>,
+        WriteStorage<'a, Evacuated>,
+        ReadStorage<'a, Soldier>,
+        ReadStorage<'a, Health>,
+        ReadStorage<'a, Dead>,
+        ReadStorage<'a, Surrendered>,
+        ReadStorage<'a, Player>,
+        Write<'a, Objectives>,
+        Write<'a, PositionIndex>,
+        Write<'a, EventLog>,
+        Read<'a, WoundedConfig>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            mut positions,
+            mut budgets,
+            mut evacuated,
+            soldiers,
+            healths,
+            dead_markers,
+            surrendered,
+            players,
+            mut objectives,
+            mut occupancy,
+            mut log,
+            wounded,
+        ): Self::SystemData,
+    ) {
+        if objectives.evac_points.is_empty() {
+            return;
+        }
+
+        // The player stays in the fight; everyone else can be carried out
+        let arrivals: Vec<_> = (
+            &entities,
+            &positions,
+            &soldiers,
+            &healths,
+            !&dead_markers,
+            !&players,
+        )
+            .join()
+            .filter(|(entity, _, _, health, _, _)| {
+                surrendered.get(*entity).is_none() && wounded.is_wounded(health.percentage())
+            })
+            .filter_map(|(entity, pos, soldier, _, _, _)| {
+                let post = objectives.evac_points.iter().position(|point| {
+                    point.faction == soldier.faction && point.position == *pos.as_battlefield_pos()
+                })?;
+                Some((
+                    entity,
+                    *pos.as_battlefield_pos(),
+                    post,
+                    soldier.name.clone(),
+                ))
+            })
+            .collect();
+
+        for (entity, tile, post, name) in arrivals {
+            objectives.evac_points[post].saved += 1;
+            evacuated.insert(entity, Evacuated).ok();
+            positions.remove(entity);
+            budgets.remove(entity);
+            occupancy.remove(entity, tile);
+            log.add(format!("{} is carried back from the aid post.", name));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::{Faction, Rank};
+    use crate::game_logic::{battlefield::Position as BattlefieldPos, objectives::EvacPoint};
+    use specs::{Builder, RunNow, World, WorldExt};
+
+    fn spawn(world: &mut World, name: &str, health: Health) -> specs::Entity {
+        world
+            .create_entity()
+            .with(Position::new(3, 18))
+            .with(Soldier {
+                name: name.to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Private,
+            })
+            .with(health)
+            .with(TimeBudget::new(10.0))
+            .build()
+    }
+
+    #[test]
+    fn test_wounded_soldier_at_aid_post_is_evacuated_and_saved() {
+        let mut world = World::new();
+        crate::game_logic::world_setup::register_components(&mut world);
+        world.insert(EventLog::new());
+        world.insert(WoundedConfig::default());
+        world.insert(PositionIndex::default());
+        let mut objectives = Objectives::new();
+        objectives.add_evac_point(EvacPoint::new(BattlefieldPos::new(3, 18), Faction::Allies));
+        world.insert(objectives);
+
+        let mut wounds = Health::new(100);
+        wounds.take_damage(80);
+        let casualty = spawn(&mut world, "Pvt. Barnes", wounds);
+        let stretcher_bearer = spawn(&mut world, "Pvt. Lowe", Health::new(100));
+        PositionIndex::refresh(&world);
+
+        CasualtyEvacuationSystem.run_now(&world);
+        world.maintain();
+
+        assert!(world.read_storage::<Evacuated>().contains(casualty));
+        assert!(world.read_storage::<Position>().get(casualty).is_none());
+        assert!(world.read_storage::<TimeBudget>().get(casualty).is_none());
+        assert!(
+            !world.read_storage::<Evacuated>().contains(stretcher_bearer),
+            "an unhurt soldier stays in the fight"
+        );
+        let objectives = world.read_resource::<Objectives>();
+        assert_eq!(objectives.saved_by(Faction::Allies), 1);
+        assert_eq!(objectives.saved_by(Faction::CentralPowers), 0);
+    }
+}
@@ -0,0 +1,188 @@
+// Mine Detonation System
+// Triggers hidden mines when a living soldier ends a move on a mined tile
+//
+// Runs AFTER ActionExecutionSystem so positions reflect this turn's moves.
+// Detonated mines are removed from the battlefield and leave a shell crater.
+
+use crate::components::{dead::Dead, health::Health, position::Position, soldier::Soldier};
+use crate::components::detonation::Detonations;
+use crate::config::game_config::RandomEventsConfig;
+use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos, TerrainType};
+use crate::game_logic::combat::apply_damage;
+use crate::game_logic::turn_state::{TurnPhase, TurnState};
+use crate::simulation::events::mine_blast_damage;
+use crate::utils::event_log::EventLog;
+use specs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
+
+pub struct MineDetonationSystem;
+
+impl<'a> System<'a> for MineDetonationSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Soldier>,
+        WriteStorage<'a, Health>,
+        WriteStorage<'a, Dead>,
+        Write<'a, Battlefield>,
+        Write<'a, EventLog>,
+        Write<'a, Detonations>,
+        Read<'a, RandomEventsConfig>,
+        Read<'a, TurnState>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            positions,
+            soldiers,
+            mut healths,
+            mut dead_markers,
+            mut battlefield,
+            mut log,
+            mut detonations,
+            config,
+            turn_state,
+        ): Self::SystemData,
+    ) {
+        if !config.enabled || !matches!(turn_state.phase, TurnPhase::Execution) {
+            return;
+        }
+
+        let triggered: Vec<BattlefieldPos> = (&positions, !&dead_markers)
+            .join()
+            .map(|(pos, _)| *pos.as_battlefield_pos())
+            .filter(|pos| battlefield.has_mine(pos))
+            .collect();
+
+        for mine_pos in triggered {
+            if !battlefield.remove_mine(&mine_pos) {
+                continue; // Already detonated by another soldier this turn
+            }
+            battlefield.set_terrain(mine_pos, TerrainType::ShellCrater);
+            detonations.record(mine_pos);
+            log.add(format!("A mine explodes at ({}, {})!", mine_pos.x, mine_pos.y));
+
+            for (entity, pos, soldier) in (&entities, &positions, &soldiers).join() {
+                if dead_markers.get(entity).is_some() {
+                    continue;
+                }
+
+                let damage = match mine_blast_damage(&config, &mine_pos, pos.as_battlefield_pos()) {
+                    Some(damage) => damage,
+                    None => continue,
+                };
+
+                if let Some(health) = healths.get_mut(entity)
+                    && !apply_damage(health, damage)
+                {
+                    log.add(format!("{} is killed by the blast!", soldier.name));
+                    dead_markers.insert(entity, Dead).ok();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::action::{ActionType, QueuedAction};
+    use crate::components::soldier::{Faction, Rank};
+    use crate::config::game_config::{BloodTrailConfig, CorpseRule, CoverModel, FatigueConfig, LogVerbosity, MoveConflictRule, ReloadInterrupt, WoundedConfig};
+    use crate::game_logic::spatial_index::PositionIndex;
+    use crate::game_logic::world_setup::register_components;
+    use crate::systems::action_execution::ActionExecutionSystem;
+    use crate::components::last_seen::FlashSightings;
+    use crate::components::tracer::ShotTracers;
+    use crate::simulation::weather::{TimeOfDay, Wind};
+    use crate::utils::game_rng::GameRng;
+    use specs::{Builder, RunNow, World, WorldExt};
+
+    fn setup(enabled: bool) -> (World, specs::Entity) {
+        let mut world = World::new();
+        register_components(&mut world);
+
+        let mut battlefield = Battlefield::new(20, 20);
+        battlefield.place_mine(BattlefieldPos::new(6, 5));
+
+        let mut turn_state = TurnState::new();
+        turn_state.phase = TurnPhase::Execution;
+
+        world.insert(battlefield);
+        world.insert(turn_state);
+        world.insert(EventLog::new());
+        world.insert(GameRng::new(1));
+        world.insert(WoundedConfig::default());
+        world.insert(FatigueConfig::default());
+        world.insert(BloodTrailConfig::default());
+        world.insert(MoveConflictRule::default());
+        world.insert(CorpseRule::default());
+        world.insert(CoverModel::default());
+        world.insert(ReloadInterrupt::default());
+        world.insert(LogVerbosity::default());
+        world.insert(Wind::calm());
+        world.insert(TimeOfDay::Day);
+        world.insert(FlashSightings::default());
+        world.insert(crate::components::heard_shot::HeardShots::default());
+        world.insert(ShotTracers::default());
+        world.insert(Detonations::new());
+        world.insert(PositionIndex::default());
+        world.insert(RandomEventsConfig {
+            enabled,
+            ..Default::default()
+        });
+
+        let soldier = world
+            .create_entity()
+            .with(Position::new(5, 5))
+            .with(Soldier {
+                name: "Test".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Private,
+            })
+            .with(Health::new(100))
+            .with(QueuedAction::new(ActionType::Move {
+                dx: 1,
+                dy: 0,
+                terrain_cost: 1.0,
+            }))
+            .build();
+
+        (world, soldier)
+    }
+
+    fn run_turn(world: &mut World) {
+        ActionExecutionSystem.run_now(world);
+        MineDetonationSystem.run_now(world);
+        world.maintain();
+    }
+
+    #[test]
+    fn test_stepping_on_mine_damages_soldier() {
+        let (mut world, soldier) = setup(true);
+
+        run_turn(&mut world);
+
+        let health = world.read_storage::<Health>().get(soldier).unwrap().current;
+        let config = world.read_resource::<RandomEventsConfig>().clone();
+        assert_eq!(health, 100 - config.mine_damage);
+
+        let battlefield = world.read_resource::<Battlefield>();
+        assert!(!battlefield.has_mine(&BattlefieldPos::new(6, 5)));
+        assert_eq!(
+            battlefield.get_tile(&BattlefieldPos::new(6, 5)).unwrap().terrain,
+            TerrainType::ShellCrater
+        );
+    }
+
+    #[test]
+    fn test_mines_inert_when_events_disabled() {
+        let (mut world, soldier) = setup(false);
+
+        run_turn(&mut world);
+
+        assert_eq!(world.read_storage::<Health>().get(soldier).unwrap().current, 100);
+        assert!(world.read_resource::<Battlefield>().has_mine(&BattlefieldPos::new(6, 5)));
+    }
+}
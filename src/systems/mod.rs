@@ -3,13 +3,68 @@
 
 pub mod action_execution;
 pub mod ai_action_planner;
+pub mod barrel_cooling;
+pub mod casualty_evacuation;
+pub mod mine_detonation;
+pub mod morale;
 pub mod muzzle_flash_cleanup;
+pub mod nml_attrition;
 pub mod objective_capture;
 pub mod path_execution;
 pub mod position_validation;
+pub mod scenario_triggers;
+pub mod supply_lines;
+pub mod timeline;
 pub mod turn_manager;
 
+use action_execution::ActionExecutionSystem;
+use ai_action_planner::AIActionPlannerSystem;
+use barrel_cooling::BarrelCoolingSystem;
+use casualty_evacuation::CasualtyEvacuationSystem;
+use mine_detonation::MineDetonationSystem;
+use morale::MoraleSystem;
+use nml_attrition::NoMansLandAttritionSystem;
+use objective_capture::ObjectiveCaptureSystem;
+use path_execution::PathExecutionSystem;
+use scenario_triggers::ScenarioTriggerSystem;
+use specs::DispatcherBuilder;
+use supply_lines::SupplyLineSystem;
+use timeline::TimelineSystem;
+use turn_manager::TurnManagerSystem;
+
+/// The systems that play out a match, in dispatch order
+///
+/// TurnManagerSystem MUST run before ActionExecutionSystem so the phase has
+/// moved on to Execution before actions are carried out; otherwise actions
+/// are logged but positions never change (the "movement bug").
+///
+/// 1. PathExecutionSystem: Processes movement paths
+/// 2. AIActionPlannerSystem: AI decides actions (depends on paths)
+/// 3. TurnManagerSystem: Manages phase transitions (Planning/Execution/Resolution)
+/// 4. ActionExecutionSystem: Executes committed actions (MUST run after phase transition)
+/// 5. BarrelCoolingSystem: weapons shed heat once the turn's fire is done;
+///    MineDetonationSystem / NoMansLandAttritionSystem: optional hazards after moves;
+///    MoraleSystem: morale loss and surrenders once the turn's fire has landed
+/// 6. ObjectiveCaptureSystem: Checks for objective captures after actions execute
+///
+/// The game and headless matches both build on this, so they run the same rules.
+pub fn match_systems<'a, 'b>() -> DispatcherBuilder<'a, 'b> {
+    DispatcherBuilder::new()
+        .with(PathExecutionSystem, "path_execution", &[])
+        .with(AIActionPlannerSystem, "ai_planner", &["path_execution"])
+        .with(TurnManagerSystem, "turn_manager", &["ai_planner"])
+        .with(ActionExecutionSystem, "action_execution", &["turn_manager"])
+        .with(BarrelCoolingSystem::new(), "barrel_cooling", &["action_execution"])
+        .with(MineDetonationSystem, "mine_detonation", &["action_execution"])
+        .with(NoMansLandAttritionSystem::new(), "nml_attrition", &["mine_detonation"])
+        .with(CasualtyEvacuationSystem, "casualty_evacuation", &["nml_attrition"])
+        .with(SupplyLineSystem::new(), "supply_lines", &["nml_attrition"])
+        .with(MoraleSystem::new(), "morale", &["nml_attrition"])
+        .with(ObjectiveCaptureSystem, "objective_capture", &["nml_attrition"])
+        .with(ScenarioTriggerSystem, "scenario_triggers", &["objective_capture", "morale"])
+        .with(TimelineSystem, "timeline", &["objective_capture"])
+}
+
 // Future systems will be added here as submodules:
 // pub mod movement;
 // pub mod combat;
-// pub mod morale;
@@ -0,0 +1,251 @@
+// Morale System
+// Wears down morale under fire and lets broken, cut-off soldiers surrender
+//
+// Runs once per turn during the Execution phase, after the hazard systems.
+// The player's soldier never surrenders, and nor does a cut-off sergeant or
+// officer: they make a last stand instead, fighting on a little steadier.
+
+use crate::components::{
+    action::QueuedAction, dead::Dead, health::Health, last_stand::LastStand, morale::Morale,
+    pathfinding::PlannedPath, player::Player, position::Position, soldier::{Faction, Soldier},
+    surrendered::Surrendered, under_fire::UnderFire,
+};
+use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos};
+use crate::game_logic::surrender::{has_escape_route, Captures};
+use crate::game_logic::turn_state::{TurnPhase, TurnState};
+use crate::utils::event_log::EventLog;
+use specs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
+
+/// Morale lost for each turn spent under fire
+pub const MORALE_LOSS_UNDER_FIRE: f32 = 15.0;
+/// Extra morale lost per turn while below half health
+pub const MORALE_LOSS_WOUNDED: f32 = 5.0;
+/// Morale regained in a turn without incoming fire
+pub const MORALE_RECOVERY: f32 = 5.0;
+
+pub struct MoraleSystem {
+    last_applied_turn: Option<u32>,
+}
+
+impl MoraleSystem {
+    pub fn new() -> Self {
+        Self {
+            last_applied_turn: None,
+        }
+    }
+}
+
+impl Default for MoraleSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> System<'a> for MoraleSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Soldier>,
+        ReadStorage<'a, Health>,
+        ReadStorage<'a, Dead>,
+        ReadStorage<'a, Player>,
+        ReadStorage<'a, UnderFire>,
+        WriteStorage<'a, Morale>,
+        WriteStorage<'a, Surrendered>,
+        WriteStorage<'a, QueuedAction>,
+        WriteStorage<'a, PlannedPath>,
+        WriteStorage<'a, LastStand>,
+        Read<'a, Battlefield>,
+        Read<'a, TurnState>,
+        Write<'a, Captures>,
+        Write<'a, EventLog>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            positions,
+            soldiers,
+            healths,
+            dead_markers,
+            players,
+            under_fire,
+            mut morales,
+            mut surrendered,
+            mut queued,
+            mut paths,
+            mut last_stands,
+            battlefield,
+            turn_state,
+            mut captures,
+            mut log,
+        ): Self::SystemData,
+    ) {
+        if !matches!(turn_state.phase, TurnPhase::Execution) {
+            return;
+        }
+        if self.last_applied_turn == Some(turn_state.current_turn) {
+            return;
+        }
+        self.last_applied_turn = Some(turn_state.current_turn);
+
+        let standing: Vec<(Faction, BattlefieldPos)> =
+            (&entities, &positions, &soldiers, !&dead_markers, !&surrendered)
+                .join()
+                .map(|(_, pos, soldier, _, _)| (soldier.faction, *pos.as_battlefield_pos()))
+                .collect();
+        let enemies_of = |faction: Faction| -> Vec<BattlefieldPos> {
+            standing
+                .iter()
+                .filter(|(other, _)| *other != faction)
+                .map(|(_, pos)| *pos)
+                .collect()
+        };
+
+        // Cut-off NCOs and officers dig in for a last stand
+        for (entity, pos, soldier, _, _) in
+            (&entities, &positions, &soldiers, !&dead_markers, !&surrendered).join()
+        {
+            let cornered = soldier.rank.stands_when_cornered()
+                && !has_escape_route(
+                    *pos.as_battlefield_pos(),
+                    soldier.faction,
+                    &enemies_of(soldier.faction),
+                    &battlefield,
+                );
+            if !cornered {
+                last_stands.remove(entity);
+            } else if !last_stands.contains(entity) {
+                last_stands.insert(entity, LastStand).ok();
+                log.add(format!("{} is cut off and makes a last stand!", soldier.name));
+            }
+        }
+
+        let mut broken = Vec::new();
+        for (entity, morale, _, _) in (&entities, &mut morales, !&dead_markers, !&surrendered).join() {
+            if under_fire
+                .get(entity)
+                .is_some_and(|marker| marker.is_recent(turn_state.current_turn))
+            {
+                morale.shake(MORALE_LOSS_UNDER_FIRE);
+            } else {
+                morale.recover(MORALE_RECOVERY);
+            }
+
+            if healths.get(entity).is_some_and(|h| h.percentage() < 0.5) {
+                morale.shake(MORALE_LOSS_WOUNDED);
+            }
+
+            if morale.is_broken() && players.get(entity).is_none() && !last_stands.contains(entity) {
+                broken.push(entity);
+            }
+        }
+
+        for entity in broken {
+            let (Some(pos), Some(soldier)) = (positions.get(entity), soldiers.get(entity)) else {
+                continue;
+            };
+
+            let enemy_positions: Vec<BattlefieldPos> =
+                (&entities, &positions, &soldiers, !&dead_markers, !&surrendered)
+                    .join()
+                    .filter(|(_, _, other, _, _)| other.faction != soldier.faction)
+                    .map(|(_, other_pos, _, _, _)| *other_pos.as_battlefield_pos())
+                    .collect();
+
+            if has_escape_route(
+                *pos.as_battlefield_pos(),
+                soldier.faction,
+                &enemy_positions,
+                &battlefield,
+            ) {
+                continue;
+            }
+
+            surrendered.insert(entity, Surrendered).ok();
+            queued.remove(entity);
+            paths.remove(entity);
+            captures.record(soldier.faction);
+            log.add(format!("{} throws down their rifle and surrenders!", soldier.name));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::{Faction, Rank};
+    use crate::game_logic::battlefield::SpawnZone;
+    use crate::game_logic::world_setup::register_components;
+    use specs::{Builder, Entity, RunNow, World, WorldExt};
+
+    fn soldier(name: &str, faction: Faction) -> Soldier {
+        Soldier {
+            name: name.to_string(),
+            faction,
+            rank: Rank::Private,
+        }
+    }
+
+    /// A world with a broken Central Powers soldier of `rank` surrounded by Allies
+    fn cornered_world(rank: Rank) -> (World, Entity) {
+        let mut world = World::new();
+        register_components(&mut world);
+
+        let mut battlefield = Battlefield::new(20, 20);
+        battlefield.set_spawn_zones(
+            SpawnZone::new(BattlefieldPos::new(2, 2), 2),
+            SpawnZone::new(BattlefieldPos::new(17, 17), 2),
+        );
+        let mut turn_state = TurnState::new();
+        turn_state.phase = TurnPhase::Execution;
+        world.insert(battlefield);
+        world.insert(turn_state);
+        world.insert(Captures::default());
+        world.insert(EventLog::new());
+
+        let cornered = world
+            .create_entity()
+            .with(Position::new(10, 10))
+            .with(Soldier {
+                rank,
+                ..soldier("Cornered", Faction::CentralPowers)
+            })
+            .with(Health::new(100))
+            .with(Morale::new(MORALE_LOSS_UNDER_FIRE))
+            .with(UnderFire::new(0))
+            .build();
+        for (dx, dy) in [(-2, -2), (0, -2), (2, -2), (-2, 0), (2, 0), (-2, 2), (0, 2), (2, 2)] {
+            world
+                .create_entity()
+                .with(Position::new(10 + dx, 10 + dy))
+                .with(soldier("Tommy", Faction::Allies))
+                .with(Health::new(100))
+                .build();
+        }
+        (world, cornered)
+    }
+
+    #[test]
+    fn test_cornered_broken_soldier_surrenders() {
+        let (world, cornered) = cornered_world(Rank::Private);
+
+        MoraleSystem::new().run_now(&world);
+
+        assert!(world.read_storage::<Surrendered>().get(cornered).is_some());
+        assert!(world.read_storage::<LastStand>().get(cornered).is_none());
+        assert_eq!(world.read_resource::<Captures>().count(Faction::CentralPowers), 1);
+    }
+
+    #[test]
+    fn test_cornered_sergeant_makes_a_last_stand() {
+        let (world, cornered) = cornered_world(Rank::Sergeant);
+
+        MoraleSystem::new().run_now(&world);
+
+        assert!(world.read_storage::<Surrendered>().get(cornered).is_none());
+        assert!(world.read_storage::<LastStand>().get(cornered).is_some());
+        assert_eq!(world.read_resource::<Captures>().count(Faction::CentralPowers), 0);
+    }
+}
@@ -0,0 +1,158 @@
+// No-Man's-Land Attrition System
+// Harassing fire wears down soldiers who linger in the open between the lines
+//
+// Runs AFTER MineDetonationSystem so positions reflect this turn's moves.
+// Applies at most once per turn, during the Execution phase.
+
+use crate::components::{dead::Dead, health::Health, position::Position, soldier::Soldier};
+use crate::config::game_config::AttritionConfig;
+use crate::game_logic::battlefield::{Battlefield, TerrainType};
+use crate::game_logic::combat::apply_damage;
+use crate::game_logic::turn_state::{TurnPhase, TurnState};
+use crate::utils::event_log::EventLog;
+use specs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
+
+pub struct NoMansLandAttritionSystem {
+    last_applied_turn: Option<u32>,
+}
+
+impl NoMansLandAttritionSystem {
+    pub fn new() -> Self {
+        Self {
+            last_applied_turn: None,
+        }
+    }
+}
+
+impl Default for NoMansLandAttritionSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a tile is exposed to harassing fire
+fn is_exposed(battlefield: &Battlefield, pos: &Position) -> bool {
+    let pos = pos.as_battlefield_pos();
+    battlefield.in_no_mans_land_band(pos)
+        && battlefield
+            .get_tile(pos)
+            .is_some_and(|tile| tile.terrain == TerrainType::NoMansLand)
+}
+
+impl<'a> System<'a> for NoMansLandAttritionSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Soldier>,
+        WriteStorage<'a, Health>,
+        WriteStorage<'a, Dead>,
+        Read<'a, Battlefield>,
+        Write<'a, EventLog>,
+        Read<'a, AttritionConfig>,
+        Read<'a, TurnState>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, positions, soldiers, mut healths, mut dead_markers, battlefield, mut log, config, turn_state): Self::SystemData,
+    ) {
+        if !config.enabled || !matches!(turn_state.phase, TurnPhase::Execution) {
+            return;
+        }
+        if self.last_applied_turn == Some(turn_state.current_turn) {
+            return;
+        }
+        self.last_applied_turn = Some(turn_state.current_turn);
+
+        let exposed: Vec<_> = (&entities, &positions, &soldiers, !&dead_markers)
+            .join()
+            .filter(|(_, pos, _, _)| is_exposed(&battlefield, pos))
+            .map(|(entity, _, soldier, _)| (entity, soldier.name.clone()))
+            .collect();
+
+        for (entity, name) in exposed {
+            if let Some(health) = healths.get_mut(entity)
+                && !apply_damage(health, config.damage_per_turn)
+            {
+                log.add(format!("{} is cut down by harassing fire in no-man's land!", name));
+                dead_markers.insert(entity, Dead).ok();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::{Faction, Rank};
+    use crate::game_logic::battlefield::Position as BattlefieldPos;
+    use crate::game_logic::world_setup::register_components;
+    use specs::{Builder, RunNow, World, WorldExt};
+
+    fn setup() -> World {
+        let mut world = World::new();
+        register_components(&mut world);
+
+        let mut battlefield = Battlefield::new(20, 20);
+        battlefield.set_no_mans_land_band(8, 12);
+        for y in 8..12 {
+            for x in 0..20 {
+                battlefield.set_terrain(BattlefieldPos::new(x, y), TerrainType::NoMansLand);
+            }
+        }
+        battlefield.set_terrain(BattlefieldPos::new(5, 10), TerrainType::TrenchFloor);
+
+        let mut turn_state = TurnState::new();
+        turn_state.phase = TurnPhase::Execution;
+
+        world.insert(battlefield);
+        world.insert(turn_state);
+        world.insert(EventLog::new());
+        world.insert(AttritionConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        world
+    }
+
+    fn spawn_at(world: &mut World, x: i32, y: i32) -> specs::Entity {
+        world
+            .create_entity()
+            .with(Position::new(x, y))
+            .with(Soldier {
+                name: "Test".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Private,
+            })
+            .with(Health::new(100))
+            .build()
+    }
+
+    #[test]
+    fn test_lingering_in_nml_causes_attrition() {
+        let mut world = setup();
+        let exposed = spawn_at(&mut world, 10, 10);
+        let in_trench = spawn_at(&mut world, 5, 10);
+
+        let mut system = NoMansLandAttritionSystem::new();
+        system.run_now(&world);
+        // A second pass in the same turn must not stack damage
+        system.run_now(&world);
+
+        let damage = world.read_resource::<AttritionConfig>().damage_per_turn;
+        let healths = world.read_storage::<Health>();
+        assert_eq!(healths.get(exposed).unwrap().current, 100 - damage);
+        assert_eq!(healths.get(in_trench).unwrap().current, 100);
+    }
+
+    #[test]
+    fn test_attrition_disabled_by_default() {
+        let mut world = setup();
+        world.insert(AttritionConfig::default());
+        let exposed = spawn_at(&mut world, 10, 10);
+
+        NoMansLandAttritionSystem::new().run_now(&world);
+
+        assert_eq!(world.read_storage::<Health>().get(exposed).unwrap().current, 100);
+    }
+}
@@ -1,7 +1,8 @@
-use crate::components::{dead::Dead, position::Position, soldier::Soldier};
-use crate::game_logic::objectives::Objectives;
+use crate::components::{dead::Dead, position::Position, soldier::Soldier, surrendered::Surrendered};
+use crate::game_logic::objectives::{has_uncontested_attackers, Objectives};
+use crate::game_logic::turn_state::TurnState;
 use crate::utils::event_log::EventLog;
-use specs::{Entities, Join, ReadStorage, System, Write};
+use specs::{Entities, Join, Read, ReadStorage, System, Write};
 
 pub struct ObjectiveCaptureSystem;
 
@@ -11,20 +12,24 @@ impl<'a> System<'a> for ObjectiveCaptureSystem {
         ReadStorage<'a, Position>,
         ReadStorage<'a, Soldier>,
         ReadStorage<'a, Dead>,
+        ReadStorage<'a, Surrendered>,
+        Read<'a, TurnState>,
         Write<'a, Objectives>,
         Write<'a, EventLog>,
     );
 
     fn run(
         &mut self,
-        (entities, positions, soldiers, dead_markers, mut objectives, mut event_log): Self::SystemData,
+        (entities, positions, soldiers, dead_markers, surrendered, turn_state, mut objectives, mut event_log): Self::SystemData,
     ) {
+        let turn = turn_state.current_turn;
         let mut check_victory = false;
 
         for (flag_id, flag) in objectives.flags.iter_mut() {
             let mut entities_in_radius = Vec::new();
 
-            for (entity, pos, soldier) in (&entities, &positions, &soldiers).join() {
+            // Prisoners neither hold a flag nor take one
+            for (entity, pos, soldier, _) in (&entities, &positions, &soldiers, !&surrendered).join() {
                 if dead_markers.get(entity).is_some() {
                     continue;
                 }
@@ -36,23 +41,24 @@ impl<'a> System<'a> for ObjectiveCaptureSystem {
 
             let defenders: Vec<_> = entities_in_radius
                 .iter()
-                .filter(|(_, faction)| *faction == flag.owning_faction)
+                .filter(|(_, faction)| flag.is_held_by(*faction))
                 .collect();
 
             let attackers: Vec<_> = entities_in_radius
                 .iter()
-                .filter(|(_, faction)| *faction != flag.owning_faction)
+                .filter(|(_, faction)| !flag.is_held_by(*faction))
                 .collect();
 
-            if !attackers.is_empty() && defenders.is_empty() {
-                flag.increment_progress();
+            if has_uncontested_attackers(flag, &entities_in_radius) {
+                flag.increment_progress(attackers[0].1);
 
                 if flag.capture_progress == 1 {
                     event_log.add(format!(
                         "{} flag is being contested! ({}/{})",
                         match flag.owning_faction {
-                            crate::components::soldier::Faction::Allies => "Allied",
-                            crate::components::soldier::Faction::CentralPowers => "Central Powers",
+                            Some(crate::components::soldier::Faction::Allies) => "Allied",
+                            Some(crate::components::soldier::Faction::CentralPowers) => "Central Powers",
+                            None => "Neutral",
                         },
                         flag.capture_progress,
                         flag.required_turns
@@ -63,14 +69,7 @@ impl<'a> System<'a> for ObjectiveCaptureSystem {
                     let capturing_faction = attackers[0].1;
                     flag.capture(capturing_faction);
 
-                    event_log.add(format!(
-                        "{} captured {}!",
-                        match capturing_faction {
-                            crate::components::soldier::Faction::Allies => "Allies",
-                            crate::components::soldier::Faction::CentralPowers => "Central Powers",
-                        },
-                        flag_id
-                    ));
+                    event_log.add(format!("{} captured {}!", capturing_faction.name(), flag_id));
 
                     check_victory = true;
                 }
@@ -80,14 +79,21 @@ impl<'a> System<'a> for ObjectiveCaptureSystem {
                 }
                 flag.reset_progress();
             }
+
+            // A capture on the last turn still counts; otherwise time is up
+            if flag.expire(turn) {
+                event_log.add(format!(
+                    "Time is up at {}! {} have held it.",
+                    flag_id,
+                    flag.owning_faction.map_or("Nobody", |owner| owner.name())
+                ));
+                check_victory = true;
+            }
         }
 
         if check_victory {
             if let Some(victor) = objectives.check_victory() {
-                let victor_name = match victor {
-                    crate::components::soldier::Faction::Allies => "Allies",
-                    crate::components::soldier::Faction::CentralPowers => "Central Powers",
-                };
+                let victor_name = victor.name();
 
                 // ALWAYS log victory messages (critical game state information)
                 event_log.add("==========================================".to_string());
@@ -97,3 +103,181 @@ impl<'a> System<'a> for ObjectiveCaptureSystem {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::{Faction, Rank};
+    use crate::game_logic::battlefield::Position as BattlefieldPos;
+    use crate::game_logic::objectives::{FlagStatus, ObjectiveFlag};
+    use crate::game_logic::world_setup::register_components;
+    use specs::{Builder, RunNow, World, WorldExt};
+
+    fn flag_progress_with_attacker_at(radius: i32, attacker: (i32, i32)) -> i32 {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(EventLog::new());
+        world.insert(TurnState::new());
+
+        let mut objectives = Objectives::new();
+        objectives.add_flag(
+            "central".to_string(),
+            ObjectiveFlag::new(BattlefieldPos::new(10, 10), Faction::CentralPowers)
+                .with_radius(radius),
+        );
+        world.insert(objectives);
+
+        world
+            .create_entity()
+            .with(Position::new(attacker.0, attacker.1))
+            .with(Soldier {
+                name: "Tommy".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Private,
+            })
+            .build();
+
+        ObjectiveCaptureSystem.run_now(&world);
+        world.read_resource::<Objectives>().get_flag("central").unwrap().capture_progress
+    }
+
+    #[test]
+    fn test_capture_radius_bounds_contributing_soldiers() {
+        assert_eq!(flag_progress_with_attacker_at(4, (13, 11)), 1);
+        assert_eq!(flag_progress_with_attacker_at(4, (13, 12)), 0);
+
+        // Radius 0: only the flag's own tile counts
+        assert_eq!(flag_progress_with_attacker_at(0, (10, 10)), 1);
+        assert_eq!(flag_progress_with_attacker_at(0, (10, 11)), 0);
+    }
+
+    #[test]
+    fn test_side_taking_over_a_neutral_capture_starts_from_nothing() {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(EventLog::new());
+        world.insert(TurnState::new());
+
+        let mut objectives = Objectives::new();
+        objectives.add_flag(
+            "middle".to_string(),
+            ObjectiveFlag::neutral(BattlefieldPos::new(10, 10)).with_radius(0),
+        );
+        world.insert(objectives);
+
+        let mut spawn = |x, faction| {
+            world
+                .create_entity()
+                .with(Position::new(x, 10))
+                .with(Soldier {
+                    name: "Test".to_string(),
+                    faction,
+                    rank: Rank::Private,
+                })
+                .build()
+        };
+        let tommy = spawn(10, Faction::Allies);
+        let fritz = spawn(11, Faction::CentralPowers);
+        let progress = |world: &World| {
+            let objectives = world.read_resource::<Objectives>();
+            let flag = objectives.get_flag("middle").unwrap();
+            (flag.capture_progress, flag.capturing_faction)
+        };
+
+        ObjectiveCaptureSystem.run_now(&world);
+        ObjectiveCaptureSystem.run_now(&world);
+        assert_eq!(progress(&world), (2, Some(Faction::Allies)));
+
+        // The Allies step off as the Central Powers step on
+        {
+            let mut positions = world.write_storage::<Position>();
+            positions.insert(tommy, Position::new(9, 10)).unwrap();
+            positions.insert(fritz, Position::new(10, 10)).unwrap();
+        }
+        ObjectiveCaptureSystem.run_now(&world);
+        assert_eq!(progress(&world), (1, Some(Faction::CentralPowers)));
+    }
+
+    #[test]
+    fn test_uncaptured_flag_goes_to_its_defenders_when_time_runs_out() {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(EventLog::new());
+        world.insert(TurnState::new());
+
+        let mut objectives = Objectives::new();
+        let start = world.read_resource::<TurnState>().current_turn;
+        objectives.add_flag(
+            "central".to_string(),
+            ObjectiveFlag::new(BattlefieldPos::new(10, 10), Faction::CentralPowers)
+                .with_deadline(start + 2),
+        );
+        world.insert(objectives);
+
+        // An attacker gets a capture going but can't finish it in time
+        world
+            .create_entity()
+            .with(Position::new(10, 10))
+            .with(Soldier {
+                name: "Tommy".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Private,
+            })
+            .build();
+
+        ObjectiveCaptureSystem.run_now(&world);
+        {
+            let objectives = world.read_resource::<Objectives>();
+            let flag = objectives.get_flag("central").unwrap();
+            assert_eq!(flag.status(), FlagStatus::Contested);
+            assert_eq!(flag.turns_left(start), Some(2));
+        }
+
+        for turn in start + 1..=start + 3 {
+            world.write_resource::<TurnState>().current_turn = turn;
+            ObjectiveCaptureSystem.run_now(&world);
+        }
+
+        let objectives = world.read_resource::<Objectives>();
+        let flag = objectives.get_flag("central").unwrap();
+        assert!(flag.secured);
+        assert!(flag.is_held_by(Faction::CentralPowers));
+        assert_eq!(flag.status(), FlagStatus::Held, "the capture under way is lost");
+        assert_eq!(flag.turns_left(start + 3), None);
+    }
+
+    #[test]
+    fn test_surrendered_defender_does_not_hold_the_flag() {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(EventLog::new());
+        world.insert(TurnState::new());
+
+        let mut objectives = Objectives::new();
+        objectives.add_flag(
+            "central".to_string(),
+            ObjectiveFlag::new(BattlefieldPos::new(10, 10), Faction::CentralPowers),
+        );
+        world.insert(objectives);
+
+        let mut spawn = |x, faction| {
+            world
+                .create_entity()
+                .with(Position::new(x, 10))
+                .with(Soldier {
+                    name: "Test".to_string(),
+                    faction,
+                    rank: Rank::Private,
+                })
+                .build()
+        };
+        spawn(10, Faction::Allies);
+        let prisoner = spawn(11, Faction::CentralPowers);
+        world.write_storage::<Surrendered>().insert(prisoner, Surrendered).unwrap();
+
+        ObjectiveCaptureSystem.run_now(&world);
+
+        let objectives = world.read_resource::<Objectives>();
+        assert_eq!(objectives.get_flag("central").unwrap().capture_progress, 1);
+    }
+}
@@ -1,16 +1,43 @@
 // Path Execution System
 // Converts PlannedPath components into individual Move actions step-by-step
+//
+// Paths never stack soldiers on one tile. A soldier whose next tile is held
+// by someone still on the move (or already claimed by another path this turn)
+// waits for it to clear, up to MAX_BLOCKED_TURNS; one blocked by a soldier
+// standing still, or whose destination is taken, stops where it is.
+//
+// Under BlockedStepRule::Reroute a soldier held up by friends (not by a tile
+// another path merely claimed) first looks for
+// a free tile beside it that rejoins the path past them, and steps round.
+// Failing that it waits, even on a friend standing still, before giving up.
 
 use crate::components::{
     action::{ActionType, QueuedAction},
     dead::Dead,
+    health::Health,
+    inventory::Inventory,
     pathfinding::PlannedPath,
     position::Position,
+    rested::Rested,
+    soldier::Soldier,
+    soldier_stats::SoldierStats,
     time_budget::TimeBudget,
+    weapon::Weapon,
+};
+use crate::config::game_config::{BlockedStepRule, CorpseRule, FatigueConfig, WoundedConfig};
+use crate::game_logic::{
+    action_preview::fatigued_load,
+    battlefield::{Battlefield, Position as BattlefieldPos},
+    pathfinding::step_cost,
+    spatial_index::PositionIndex,
+    turn_state::{TurnPhase, TurnState},
 };
-use crate::game_logic::{battlefield::Battlefield, turn_state::{TurnPhase, TurnState}};
 use crate::utils::event_log::EventLog;
 use specs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
+use std::collections::HashSet;
+
+/// Turns a soldier waits on a blocked step before abandoning the path
+pub const MAX_BLOCKED_TURNS: u32 = 2;
 
 pub struct PathExecutionSystem;
 
@@ -19,17 +46,30 @@ impl<'a> System<'a> for PathExecutionSystem {
         Entities<'a>,
         ReadStorage<'a, Position>,
         ReadStorage<'a, Dead>,
+        ReadStorage<'a, Health>,
         WriteStorage<'a, PlannedPath>,
         WriteStorage<'a, QueuedAction>,
         WriteStorage<'a, TimeBudget>,
         Read<'a, Battlefield>,
         Read<'a, TurnState>,
         Write<'a, EventLog>,
+        Read<'a, WoundedConfig>,
+        Read<'a, PositionIndex>,
+        Read<'a, CorpseRule>,
+        (
+            ReadStorage<'a, SoldierStats>,
+            ReadStorage<'a, Inventory>,
+            ReadStorage<'a, Weapon>,
+            ReadStorage<'a, Rested>,
+            Read<'a, FatigueConfig>,
+            ReadStorage<'a, Soldier>,
+            Read<'a, BlockedStepRule>,
+        ),
     );
 
     fn run(
         &mut self,
-        (entities, positions, deads, mut paths, mut queued, mut budgets, battlefield, turn_state, mut _log): Self::SystemData,
+        (entities, positions, deads, healths, mut paths, mut queued, mut budgets, battlefield, turn_state, mut _log, wounded, occupancy, corpse_rule, (stats, inventories, weapons, rested, fatigue, soldiers, blocked_step_rule)): Self::SystemData,
     ) {
         // Only execute during Planning phase (before actions are executed)
         if !matches!(turn_state.phase, TurnPhase::Planning) {
@@ -38,6 +78,10 @@ impl<'a> System<'a> for PathExecutionSystem {
 
         // Track which paths to remove (completed or invalid)
         let mut paths_to_remove = Vec::new();
+        // Tiles that paths have already stepped into this turn
+        let mut claimed = HashSet::new();
+        // Soldiers still following a path, whose tiles should clear soon
+        let moving: HashSet<_> = (&entities, &paths).join().map(|(e, _)| e).collect();
 
         for (entity, pos, path) in (&entities, &positions, &mut paths).join() {
             // Skip if entity is dead
@@ -61,6 +105,16 @@ impl<'a> System<'a> for PathExecutionSystem {
                 continue;
             }
 
+            // Battle fatigue slows a soldier's steps much as a heavy pack does
+            let load = fatigued_load(
+                stats.get(entity),
+                inventories.get(entity),
+                weapons.get(entity),
+                &fatigue,
+                turn_state.current_turn,
+                rested.get(entity),
+            );
+
             // Validate path is still traversable
             if !path.is_valid(pos, &battlefield) {
                 // Path invalidated - mark for removal
@@ -68,8 +122,71 @@ impl<'a> System<'a> for PathExecutionSystem {
                 continue;
             }
 
+            // Never step onto a soldier or a tile another path claimed
+            if let Some(next_pos) = path.steps.first() {
+                let blockers: Vec<_> = occupancy
+                    .occupants(*next_pos)
+                    .iter()
+                    .copied()
+                    .filter(|other| *other != entity && corpse_rule.blocks(deads.contains(*other)))
+                    .collect();
+
+                if !blockers.is_empty() || claimed.contains(next_pos) {
+                    let faction = soldiers.get(entity).map(|soldier| soldier.faction);
+                    let friends_only = faction.is_some()
+                        && !blockers.is_empty()
+                        && blockers
+                            .iter()
+                            .all(|other| soldiers.get(*other).map(|soldier| soldier.faction) == faction);
+                    let reroute = *blocked_step_rule == BlockedStepRule::Reroute && friends_only;
+
+                    let detour = if reroute {
+                        sidestep(*pos.as_battlefield_pos(), &path.steps, &battlefield, |tile| {
+                            !claimed.contains(tile)
+                                && occupancy.occupants(*tile).iter().all(|other| {
+                                    *other == entity || !corpse_rule.blocks(deads.contains(*other))
+                                })
+                        })
+                    } else {
+                        None
+                    };
+
+                    let will_clear = reroute || blockers.iter().all(|other| moving.contains(other));
+                    if let Some(detour) = detour {
+                        // Step round, dropping the blocked tile if the detour skips past it
+                        if path.steps.get(1) == Some(&detour) {
+                            path.steps.remove(0);
+                        } else {
+                            path.steps[0] = detour;
+                        }
+                    } else if path.steps.len() > 1 && will_clear && path.blocked_turns < MAX_BLOCKED_TURNS {
+                        path.blocked_turns += 1;
+                        let action = ActionType::Wait;
+                        let time_cost = action.time_cost_for(healths.get(entity), load, &wounded);
+                        budget.consume_time(time_cost);
+                        queued
+                            .insert(
+                                entity,
+                                QueuedAction {
+                                    action_type: action,
+                                    time_cost,
+                                    committed: true,
+                                },
+                            )
+                            .ok();
+                        continue;
+                    } else {
+                        paths_to_remove.push(entity);
+                        continue;
+                    }
+                }
+            }
+
             // Get next step from path (battlefield::Position)
             if let Some(next_pos) = path.pop_next() {
+                claimed.insert(next_pos);
+                path.blocked_turns = 0;
+
                 // Calculate delta from current position
                 let dx = next_pos.x - pos.x();
                 let dy = next_pos.y - pos.y();
@@ -88,12 +205,20 @@ impl<'a> System<'a> for PathExecutionSystem {
                 };
 
                 // Consume time budget for the action
-                let time_cost = action.base_time_cost();
+                let time_cost = action.time_cost_for(healths.get(entity), load, &wounded);
                 budget.consume_time(time_cost);
 
-                // Queue the action (will be processed by ActionExecutionSystem)
+                // Queue the action (will be processed by ActionExecutionSystem),
+                // keeping what it cost so a lost race for the tile refunds it exactly
                 queued
-                    .insert(entity, QueuedAction::new(action))
+                    .insert(
+                        entity,
+                        QueuedAction {
+                            action_type: action,
+                            time_cost,
+                            committed: true,
+                        },
+                    )
                     .ok();
             }
 
@@ -109,3 +234,168 @@ impl<'a> System<'a> for PathExecutionSystem {
         }
     }
 }
+
+/// A free tile beside `from` that gets round a blocked first step of `steps`
+///
+/// The detour has to border the step after the blocked one, so the rest of
+/// the path still holds; the cheapest such tile wins, the first found on a
+/// tie. None when the blocked step is the last: the destination is taken.
+pub fn sidestep(
+    from: BattlefieldPos,
+    steps: &[BattlefieldPos],
+    battlefield: &Battlefield,
+    free: impl Fn(&BattlefieldPos) -> bool,
+) -> Option<BattlefieldPos> {
+    let (blocked, rejoin) = (steps.first()?, steps.get(1)?);
+
+    (-1..=1)
+        .flat_map(|dy| (-1..=1).map(move |dx| BattlefieldPos::new(from.x + dx, from.y + dy)))
+        .filter(|tile| *tile != from && tile != blocked)
+        .filter(|tile| (tile.x - rejoin.x).abs() <= 1 && (tile.y - rejoin.y).abs() <= 1)
+        .filter(|tile| {
+            battlefield
+                .get_tile(tile)
+                .is_some_and(|t| t.terrain.is_passable())
+                && free(tile)
+        })
+        .map(|tile| {
+            let onward = if tile == *rejoin {
+                0.0
+            } else {
+                step_cost(&tile, rejoin, battlefield)
+            };
+            (tile, step_cost(&from, &tile, battlefield) + onward)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(tile, _)| tile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::pathfinding::PlannedPath;
+    use crate::components::soldier::{Faction, Rank};
+    use crate::game_logic::{battlefield::Position as BattlefieldPos, world_setup::register_components};
+    use specs::{Builder, RunNow, World, WorldExt};
+
+    fn setup() -> World {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(Battlefield::new(20, 20));
+        world.insert(TurnState::new());
+        world.insert(EventLog::new());
+        world.insert(WoundedConfig::default());
+        world.insert(FatigueConfig::default());
+        world.insert(CorpseRule::default());
+        world.insert(BlockedStepRule::default());
+        world.insert(PositionIndex::default());
+        world
+    }
+
+    fn walker(world: &mut World, x: i32, steps: &[(i32, i32)]) -> specs::Entity {
+        let mut builder = world
+            .create_entity()
+            .with(Position::new(x, 5))
+            .with(TimeBudget::new(10.0));
+        if !steps.is_empty() {
+            let steps = steps.iter().map(|&(x, y)| BattlefieldPos::new(x, y)).collect();
+            builder = builder.with(PlannedPath::new(steps, 0.0, false));
+        }
+        builder.build()
+    }
+
+    #[test]
+    fn test_path_never_steps_onto_an_occupied_tile() {
+        let mut world = setup();
+        // Blocked by a soldier who is also moving on: wait for the tile to clear
+        let follower = walker(&mut world, 5, &[(6, 5), (7, 5)]);
+        let leader = walker(&mut world, 6, &[(6, 6)]);
+        // Blocked by a soldier standing still: give up the path
+        let stuck = walker(&mut world, 10, &[(11, 5), (12, 5)]);
+        walker(&mut world, 11, &[]);
+        PositionIndex::refresh(&world);
+
+        PathExecutionSystem.run_now(&world);
+
+        let queued = world.read_storage::<QueuedAction>();
+        let paths = world.read_storage::<PlannedPath>();
+        assert!(matches!(queued.get(follower).unwrap().action_type, ActionType::Wait));
+        assert_eq!(paths.get(follower).unwrap().steps.len(), 2);
+        assert!(matches!(queued.get(leader).unwrap().action_type, ActionType::Move { .. }));
+        assert!(queued.get(stuck).is_none());
+        assert!(paths.get(stuck).is_none());
+    }
+
+    fn soldier(world: &mut World, x: i32, steps: &[(i32, i32)], faction: Faction) -> specs::Entity {
+        let entity = walker(world, x, steps);
+        world
+            .write_storage::<Soldier>()
+            .insert(
+                entity,
+                Soldier {
+                    name: "Tommy".to_string(),
+                    faction,
+                    rank: Rank::Private,
+                },
+            )
+            .unwrap();
+        entity
+    }
+
+    #[test]
+    fn test_soldier_halts_behind_a_friend_standing_in_the_way() {
+        let mut world = setup();
+        let mover = soldier(&mut world, 5, &[(6, 5), (7, 5), (8, 5)], Faction::Allies);
+        soldier(&mut world, 6, &[], Faction::Allies);
+        PositionIndex::refresh(&world);
+
+        PathExecutionSystem.run_now(&world);
+
+        assert!(world.read_storage::<QueuedAction>().get(mover).is_none());
+        assert!(world.read_storage::<PlannedPath>().get(mover).is_none());
+    }
+
+    #[test]
+    fn test_soldier_steps_round_a_friend_standing_in_the_way() {
+        let mut world = setup();
+        world.insert(BlockedStepRule::Reroute);
+        let mover = soldier(&mut world, 5, &[(6, 5), (7, 5), (8, 5)], Faction::Allies);
+        soldier(&mut world, 6, &[], Faction::Allies);
+        PositionIndex::refresh(&world);
+
+        PathExecutionSystem.run_now(&world);
+
+        let queued = world.read_storage::<QueuedAction>();
+        match queued.get(mover).unwrap().action_type {
+            ActionType::Move { dx, dy, .. } => {
+                assert_eq!(dx, 1, "still heads along the path");
+                assert_ne!(dy, 0, "steps off the friend's tile");
+            }
+            ref other => panic!("expected a detour step, got {:?}", other),
+        }
+        let paths = world.read_storage::<PlannedPath>();
+        assert_eq!(
+            paths.get(mover).unwrap().steps,
+            vec![BattlefieldPos::new(7, 5), BattlefieldPos::new(8, 5)],
+            "rejoins the path past the friend"
+        );
+    }
+
+    #[test]
+    fn test_tile_claimed_by_an_enemy_path_is_not_stepped_round() {
+        let mut world = setup();
+        world.insert(BlockedStepRule::Reroute);
+        // The German claims (6, 5) first; nobody stands on it
+        soldier(&mut world, 7, &[(6, 5)], Faction::CentralPowers);
+        let mover = soldier(&mut world, 5, &[(6, 5), (7, 5), (8, 5)], Faction::Allies);
+        PositionIndex::refresh(&world);
+
+        PathExecutionSystem.run_now(&world);
+
+        let queued = world.read_storage::<QueuedAction>();
+        assert!(
+            !matches!(queued.get(mover).map(|queued| &queued.action_type), Some(ActionType::Move { .. })),
+            "waits rather than detouring round a claimed tile"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+// Scenario trigger system
+// Checks the scenario's triggers each pass and carries out what fired
+
+use crate::components::{
+    dead::Dead,
+    soldier::{Faction, Soldier},
+    surrendered::Surrendered,
+};
+use crate::game_logic::{
+    objectives::Objectives,
+    triggers::{ScriptedEnd, TriggerEffect, Triggers},
+    turn_state::TurnState,
+};
+use crate::utils::event_log::EventLog;
+use specs::{Entities, Join, Read, ReadStorage, System, Write};
+
+pub struct ScenarioTriggerSystem;
+
+impl<'a> System<'a> for ScenarioTriggerSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Soldier>,
+        ReadStorage<'a, Dead>,
+        ReadStorage<'a, Surrendered>,
+        Read<'a, TurnState>,
+        Read<'a, Objectives>,
+        Write<'a, Triggers>,
+        Write<'a, EventLog>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, soldiers, dead, surrendered, turn_state, objectives, mut triggers, mut event_log): Self::SystemData,
+    ) {
+        if triggers.is_empty() || triggers.ended.is_some() {
+            return;
+        }
+
+        let casualties = |faction: Faction| {
+            (&entities, &soldiers)
+                .join()
+                .filter(|(entity, soldier)| {
+                    soldier.faction == faction
+                        && (dead.contains(*entity) || surrendered.contains(*entity))
+                })
+                .count()
+        };
+        let turn = turn_state.current_turn;
+
+        for effect in triggers.fire(turn, &objectives, casualties) {
+            match effect {
+                TriggerEffect::SpawnReinforcements { faction, count } => {
+                    triggers.pending_waves.push((faction, count));
+                }
+                TriggerEffect::EndGame { winner } => {
+                    triggers.ended.get_or_insert(ScriptedEnd { winner, turn });
+                }
+                TriggerEffect::LogMessage(message) => event_log.add(message),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::triggers::{Trigger, TriggerCondition};
+    use crate::game_logic::world_setup::register_components;
+    use specs::{RunNow, World, WorldExt};
+
+    #[test]
+    fn test_turn_trigger_ends_game_on_turn_five() {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(TurnState::new());
+        world.insert(Objectives::new());
+        world.insert(EventLog::new());
+        world.insert(Triggers::new(vec![Trigger::new(
+            TriggerCondition::TurnAtLeast(5),
+            TriggerEffect::EndGame { winner: None },
+        )]));
+
+        for turn in 1..5 {
+            world.write_resource::<TurnState>().current_turn = turn;
+            ScenarioTriggerSystem.run_now(&world);
+            assert_eq!(world.read_resource::<Triggers>().ended, None, "fired on turn {}", turn);
+        }
+
+        world.write_resource::<TurnState>().current_turn = 5;
+        ScenarioTriggerSystem.run_now(&world);
+        assert_eq!(
+            world.read_resource::<Triggers>().ended,
+            Some(ScriptedEnd { winner: None, turn: 5 })
+        );
+    }
+}
@@ -0,0 +1,186 @@
+// Supply Line System
+// Soldiers cut off from their spawn zone run short of ammunition
+//
+// Runs AFTER NoMansLandAttritionSystem so positions reflect this turn's moves.
+// Applies at most once per turn, during the Execution phase: every side's
+// supplied ground is flooded out from its spawn zone, soldiers inside it are
+// resupplied and those outside lose reserve rounds.
+
+use crate::components::{
+    dead::Dead, position::Position, soldier::{Faction, Soldier}, surrendered::Surrendered,
+    weapon::Weapon,
+};
+use crate::config::game_config::SupplyConfig;
+use crate::game_logic::battlefield::{Battlefield, Position as BattlefieldPos};
+use crate::game_logic::supply::supplied_tiles;
+use crate::game_logic::turn_state::{TurnPhase, TurnState, COMMANDER_FACTION};
+use crate::utils::event_log::EventLog;
+use specs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
+use std::collections::HashSet;
+
+pub struct SupplyLineSystem {
+    last_applied_turn: Option<u32>,
+}
+
+impl SupplyLineSystem {
+    pub fn new() -> Self {
+        Self {
+            last_applied_turn: None,
+        }
+    }
+}
+
+impl Default for SupplyLineSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> System<'a> for SupplyLineSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Soldier>,
+        ReadStorage<'a, Dead>,
+        ReadStorage<'a, Surrendered>,
+        WriteStorage<'a, Weapon>,
+        Read<'a, Battlefield>,
+        Write<'a, EventLog>,
+        Read<'a, SupplyConfig>,
+        Read<'a, TurnState>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            positions,
+            soldiers,
+            dead_markers,
+            surrendered,
+            mut weapons,
+            battlefield,
+            mut log,
+            config,
+            turn_state,
+        ): Self::SystemData,
+    ) {
+        if !config.enabled || !matches!(turn_state.phase, TurnPhase::Execution) {
+            return;
+        }
+        if self.last_applied_turn == Some(turn_state.current_turn) {
+            return;
+        }
+        self.last_applied_turn = Some(turn_state.current_turn);
+
+        // Standing soldiers, by side, block the other side's supply
+        let standing: Vec<(Faction, BattlefieldPos)> =
+            (&entities, &positions, &soldiers, !&dead_markers)
+                .join()
+                .filter(|(entity, _, _, _)| !surrendered.contains(*entity))
+                .map(|(_, pos, soldier, _)| (soldier.faction, *pos.as_battlefield_pos()))
+                .collect();
+        let supplied_for = |faction: Faction| {
+            let zone = match faction {
+                Faction::Allies => battlefield.ally_spawn.as_ref(),
+                Faction::CentralPowers => battlefield.enemy_spawn.as_ref(),
+            }?;
+            let blocked: HashSet<BattlefieldPos> = standing
+                .iter()
+                .filter(|(other, _)| *other != faction)
+                .map(|(_, pos)| *pos)
+                .collect();
+            Some(supplied_tiles(&battlefield, zone, &blocked))
+        };
+        let allied = supplied_for(Faction::Allies);
+        let central = supplied_for(Faction::CentralPowers);
+
+        for (entity, pos, soldier, weapon, _) in
+            (&entities, &positions, &soldiers, &mut weapons, !&dead_markers).join()
+        {
+            if surrendered.contains(entity) {
+                continue;
+            }
+            let supplied = match soldier.faction {
+                Faction::Allies => &allied,
+                Faction::CentralPowers => &central,
+            };
+            // A side with no spawn zone has no line to lose
+            let Some(supplied) = supplied else {
+                continue;
+            };
+
+            if supplied.contains(pos.as_battlefield_pos()) {
+                weapon.ammo.resupply();
+            } else {
+                let was_supplied = !weapon.ammo.is_short_of_supply();
+                weapon.ammo.cut_reserve(config.rounds_lost_per_turn);
+                if was_supplied && soldier.faction == COMMANDER_FACTION {
+                    log.add(format!("{} is cut off from supply!", soldier.name));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::Rank;
+    use crate::game_logic::battlefield::{SpawnZone, TerrainType};
+    use crate::game_logic::world_setup::register_components;
+    use specs::{Builder, RunNow, World, WorldExt};
+
+    fn soldier(world: &mut World, x: i32, y: i32) -> specs::Entity {
+        world
+            .create_entity()
+            .with(Position::new(x, y))
+            .with(Soldier {
+                name: "Pvt. Hale".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Private,
+            })
+            .with(Weapon::rifle())
+            .build()
+    }
+
+    #[test]
+    fn test_isolated_soldier_loses_reserve_while_connected_one_does_not() {
+        let mut world = World::new();
+        register_components(&mut world);
+
+        // A walled pocket in the corner with no way out
+        let mut battlefield = Battlefield::new(30, 20);
+        for i in 0..5 {
+            battlefield.set_terrain(BattlefieldPos::new(i, 4), TerrainType::BuildingWall);
+            battlefield.set_terrain(BattlefieldPos::new(4, i), TerrainType::BuildingWall);
+        }
+        battlefield.ally_spawn = Some(SpawnZone::new(BattlefieldPos::new(20, 15), 3));
+        world.insert(battlefield);
+        world.insert(EventLog::new());
+        world.insert(SupplyConfig {
+            enabled: true,
+            rounds_lost_per_turn: 1,
+        });
+        let mut turn_state = TurnState::new();
+        turn_state.phase = TurnPhase::Execution;
+        world.insert(turn_state);
+
+        let isolated = soldier(&mut world, 1, 1);
+        let connected = soldier(&mut world, 12, 10);
+
+        let mut system = SupplyLineSystem::new();
+        for turn in 1..=3 {
+            world.write_resource::<TurnState>().current_turn = turn;
+            system.run_now(&world);
+            // A second pass in the same turn changes nothing
+            system.run_now(&world);
+        }
+
+        let weapons = world.read_storage::<Weapon>();
+        let isolated_ammo = &weapons.get(isolated).unwrap().ammo;
+        assert_eq!(isolated_ammo.reserve, isolated_ammo.max_capacity - 3);
+        let connected_ammo = &weapons.get(connected).unwrap().ammo;
+        assert_eq!(connected_ammo.reserve, connected_ammo.max_capacity);
+    }
+}
@@ -0,0 +1,127 @@
+// Timeline System
+// Notes kills and flag captures on the battle timeline
+//
+// Runs AFTER ObjectiveCaptureSystem so the turn's fire, hazards and captures
+// have all landed. Fallen soldiers keep their Position, so each kill is
+// placed where the soldier lies.
+
+use crate::components::{dead::Dead, position::Position, soldier::Soldier};
+use crate::game_logic::objectives::Objectives;
+use crate::game_logic::timeline::{Timeline, TimelineEventKind};
+use crate::game_logic::turn_state::TurnState;
+use specs::{Entities, Join, Read, ReadStorage, System, Write};
+
+pub struct TimelineSystem;
+
+impl<'a> System<'a> for TimelineSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Soldier>,
+        ReadStorage<'a, Dead>,
+        Read<'a, Objectives>,
+        Read<'a, TurnState>,
+        Write<'a, Timeline>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, positions, soldiers, dead_markers, objectives, turn_state, mut timeline): Self::SystemData,
+    ) {
+        let turn = turn_state.current_turn;
+
+        for (entity, pos, soldier, _) in (&entities, &positions, &soldiers, &dead_markers).join() {
+            timeline.record_kill(
+                entity,
+                turn,
+                *pos.as_battlefield_pos(),
+                format!("{} ({}) killed", soldier.name, soldier.faction.name()),
+            );
+        }
+
+        for (flag_id, flag) in objectives.flags.iter() {
+            if timeline.observe_flag(flag_id, flag.owning_faction)
+                && let Some(owner) = flag.owning_faction
+            {
+                timeline.record(
+                    turn,
+                    flag.position,
+                    TimelineEventKind::Capture,
+                    format!("{} captured {}", owner.name(), flag_id),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::{Faction, Rank};
+    use crate::game_logic::battlefield::Position as BattlefieldPos;
+    use crate::game_logic::objectives::ObjectiveFlag;
+    use crate::game_logic::world_setup::register_components;
+    use specs::{Builder, RunNow, World, WorldExt};
+
+    fn world() -> World {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(Objectives::new());
+        world.insert(TurnState::default());
+        world.insert(Timeline::new());
+        world
+    }
+
+    #[test]
+    fn test_recorded_kill_has_its_turn_and_position() {
+        let mut world = world();
+        let soldier = world
+            .create_entity()
+            .with(Position::new(7, 3))
+            .with(Soldier {
+                name: "Pvt. Hale".to_string(),
+                faction: Faction::Allies,
+                rank: Rank::Private,
+            })
+            .build();
+        world.write_resource::<TurnState>().current_turn = 4;
+        TimelineSystem.run_now(&world);
+        assert!(world.read_resource::<Timeline>().is_empty());
+
+        world.write_storage::<Dead>().insert(soldier, Dead).unwrap();
+        TimelineSystem.run_now(&world);
+        world.write_resource::<TurnState>().current_turn = 5;
+        TimelineSystem.run_now(&world);
+
+        let timeline = world.read_resource::<Timeline>();
+        assert_eq!(timeline.len(), 1, "a kill is recorded once");
+        let kill = &timeline.events()[0];
+        assert_eq!(kill.kind, TimelineEventKind::Kill);
+        assert_eq!(kill.turn, 4);
+        assert_eq!(kill.position, BattlefieldPos::new(7, 3));
+        assert!(kill.description.contains("Pvt. Hale"), "{}", kill.description);
+    }
+
+    #[test]
+    fn test_capture_is_recorded_at_the_flag() {
+        let world = world();
+        world.write_resource::<Objectives>().add_flag(
+            "hill".to_string(),
+            ObjectiveFlag::new(BattlefieldPos::new(12, 9), Faction::CentralPowers),
+        );
+        TimelineSystem.run_now(&world);
+
+        world
+            .write_resource::<Objectives>()
+            .flags
+            .get_mut("hill")
+            .unwrap()
+            .capture(Faction::Allies);
+        TimelineSystem.run_now(&world);
+
+        let timeline = world.read_resource::<Timeline>();
+        assert_eq!(timeline.len(), 1);
+        assert_eq!(timeline.events()[0].kind, TimelineEventKind::Capture);
+        assert_eq!(timeline.events()[0].position, BattlefieldPos::new(12, 9));
+    }
+}
@@ -24,7 +24,7 @@
 
 use crate::components::{
     action::QueuedAction, dead::Dead, muzzle_flash::MuzzleFlash, player::Player,
-    time_budget::TimeBudget,
+    soldier::Soldier, surrendered::Surrendered, time_budget::TimeBudget,
 };
 use crate::game_logic::turn_state::{TurnOrderMode, TurnPhase, TurnState};
 use crate::utils::event_log::EventLog;
@@ -40,13 +40,15 @@ impl<'a> System<'a> for TurnManagerSystem {
         WriteStorage<'a, QueuedAction>,
         ReadStorage<'a, Player>,
         ReadStorage<'a, Dead>,
+        ReadStorage<'a, Surrendered>,
         Write<'a, EventLog>,
         WriteStorage<'a, MuzzleFlash>,
+        ReadStorage<'a, Soldier>,
     );
 
     fn run(
         &mut self,
-        (entities, mut turn_state, mut budgets, mut actions, players, dead_markers, mut log, mut muzzle_flashes): Self::SystemData,
+        (entities, mut turn_state, mut budgets, mut actions, players, dead_markers, surrendered, mut log, mut muzzle_flashes, soldiers): Self::SystemData,
     ) {
         match turn_state.phase {
             TurnPhase::Planning => {
@@ -54,21 +56,27 @@ impl<'a> System<'a> for TurnManagerSystem {
                 let all_ready = match turn_state.turn_order_mode {
                     TurnOrderMode::PlayerFirst => {
                         // In PlayerFirst: check if player is ready, then if all NPCs are ready
-                        let player_ready = (&entities, &players)
-                            .join()
-                            .any(|(e, _)| turn_state.is_entity_ready(e));
+                        // (a commander without a soldier readies their faction instead)
+                        let player_ready = turn_state
+                            .is_player_side_ready((&entities, &players).join().map(|(e, _)| e));
 
                         if !player_ready {
                             return; // Wait for player
                         }
 
                         // Player ready, check if all NPCs have actions or are out of budget
+                        // (a side the player declared done doesn't hold up the turn)
                         (&entities, &budgets)
                             .join()
                             .filter(|(e, _)| players.get(*e).is_none()) // NPCs only
                             .filter(|(e, _)| dead_markers.get(*e).is_none()) // Exclude dead
+                            .filter(|(e, _)| surrendered.get(*e).is_none()) // Exclude prisoners
                             .all(|(e, budget)| {
-                                actions.get(e).is_some() || budget.available_time() <= 0.0
+                                actions.get(e).is_some()
+                                    || budget.available_time() <= 0.0
+                                    || soldiers
+                                        .get(e)
+                                        .is_some_and(|s| turn_state.is_faction_ready(s.faction))
                             })
                     }
                     TurnOrderMode::Simultaneous => {
@@ -76,6 +84,7 @@ impl<'a> System<'a> for TurnManagerSystem {
                         (&entities, &budgets)
                             .join()
                             .filter(|(e, _)| dead_markers.get(*e).is_none()) // Exclude dead
+                            .filter(|(e, _)| surrendered.get(*e).is_none()) // Exclude prisoners
                             .all(|(e, budget)| {
                                 turn_state.is_entity_ready(e) || budget.available_time() <= 0.0
                             })
@@ -132,3 +141,46 @@ impl<'a> System<'a> for TurnManagerSystem {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::soldier::{Faction, Rank};
+    use crate::game_logic::turn_state::{end_turn, EndTurnScope};
+    use crate::game_logic::world_setup::register_components;
+    use specs::{Builder, Entity, RunNow, World, WorldExt};
+
+    fn spawn(world: &mut World, faction: Faction) -> Entity {
+        world
+            .create_entity()
+            .with(Soldier {
+                name: "Test".to_string(),
+                faction,
+                rank: Rank::Private,
+            })
+            .with(TimeBudget::new(6.0))
+            .build()
+    }
+
+    /// Player plus one idle friendly; returns the phase after the player ends their turn
+    fn phase_after_end_turn(scope: EndTurnScope) -> TurnPhase {
+        let mut world = World::new();
+        register_components(&mut world);
+        world.insert(TurnState::new());
+        world.insert(EventLog::new());
+
+        let player = spawn(&mut world, Faction::Allies);
+        world.write_storage::<Player>().insert(player, Player).unwrap();
+        spawn(&mut world, Faction::Allies);
+
+        end_turn(&world, player, scope);
+        TurnManagerSystem.run_now(&world);
+        world.read_resource::<TurnState>().phase
+    }
+
+    #[test]
+    fn test_ending_the_side_does_not_wait_on_idle_friendlies() {
+        assert_eq!(phase_after_end_turn(EndTurnScope::SelfOnly), TurnPhase::Planning);
+        assert_eq!(phase_after_end_turn(EndTurnScope::Faction), TurnPhase::Execution);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::config::game_config::EnemyForce;
+use crate::game_logic::soldier_spawning::RankDistribution;
+
+const MAX_RANK_SHARE: u32 = 100;
+pub const RANK_SHARE_STEP: u32 = 5;
+
+/// A rank whose share of the enemy force can be edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankShare {
+    Privates,
+    Corporals,
+    Sergeants,
+    Officers,
+}
+
+impl RankShare {
+    pub const ALL: [Self; 4] = [
+        Self::Privates,
+        Self::Corporals,
+        Self::Sergeants,
+        Self::Officers,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Privates => "  Privates",
+            Self::Corporals => "  Corporals",
+            Self::Sergeants => "  Sergeants",
+            Self::Officers => "  Officers",
+        }
+    }
+
+    pub fn next(self) -> Option<Self> {
+        match self {
+            Self::Privates => Some(Self::Corporals),
+            Self::Corporals => Some(Self::Sergeants),
+            Self::Sergeants => Some(Self::Officers),
+            Self::Officers => None,
+        }
+    }
+
+    pub fn prev(self) -> Option<Self> {
+        match self {
+            Self::Privates => None,
+            Self::Corporals => Some(Self::Privates),
+            Self::Sergeants => Some(Self::Corporals),
+            Self::Officers => Some(Self::Sergeants),
+        }
+    }
+}
+
+/// Enemy force preset plus the rank shares edited on top of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnemyRanksEditor {
+    /// The last force preset picked
+    pub force: EnemyForce,
+    /// Enemy rank shares: the preset's until edited
+    pub ranks: RankDistribution,
+}
+
+impl EnemyRanksEditor {
+    pub fn new(force: EnemyForce) -> Self {
+        Self {
+            force,
+            ranks: force.ranks(),
+        }
+    }
+
+    /// Restore a saved force, keeping its edited shares if it had any
+    pub fn restore(force: EnemyForce, ranks: Option<RankDistribution>) -> Self {
+        Self {
+            force,
+            ranks: ranks.unwrap_or_else(|| force.ranks()),
+        }
+    }
+
+    pub fn next_force(&mut self) {
+        *self = Self::new(self.force.next());
+    }
+
+    pub fn prev_force(&mut self) {
+        *self = Self::new(self.force.prev());
+    }
+
+    pub fn share(&self, rank: RankShare) -> u32 {
+        match rank {
+            RankShare::Privates => self.ranks.privates,
+            RankShare::Corporals => self.ranks.corporals,
+            RankShare::Sergeants => self.ranks.sergeants,
+            RankShare::Officers => self.ranks.officers,
+        }
+    }
+
+    /// Step one rank share up or down
+    pub fn adjust(&mut self, rank: RankShare, up: bool) {
+        let share = match rank {
+            RankShare::Privates => &mut self.ranks.privates,
+            RankShare::Corporals => &mut self.ranks.corporals,
+            RankShare::Sergeants => &mut self.ranks.sergeants,
+            RankShare::Officers => &mut self.ranks.officers,
+        };
+        *share = if up {
+            (*share + RANK_SHARE_STEP).min(MAX_RANK_SHARE)
+        } else {
+            share.saturating_sub(RANK_SHARE_STEP)
+        };
+    }
+
+    /// The force preset's name, or Custom once its shares have been edited
+    pub fn name(&self) -> &'static str {
+        if self.ranks == self.force.ranks() {
+            self.force.name()
+        } else {
+            "Custom"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shares_stay_within_bounds() {
+        let mut editor = EnemyRanksEditor::new(EnemyForce::Conscripts);
+        editor.adjust(RankShare::Privates, true);
+        assert_eq!(editor.share(RankShare::Privates), MAX_RANK_SHARE);
+        editor.adjust(RankShare::Officers, false);
+        assert_eq!(editor.share(RankShare::Officers), 0);
+        assert_eq!(editor.name(), "Conscripts");
+
+        editor.adjust(RankShare::Officers, true);
+        assert_eq!(editor.name(), "Custom");
+        editor.next_force();
+        assert_eq!(editor, EnemyRanksEditor::new(EnemyForce::Veterans));
+    }
+}
@@ -0,0 +1,48 @@
+use ratatui::{buffer::Buffer, layout::Rect};
+use std::cell::Cell;
+
+/// Rows a form may take before it is scrolled into the screen
+const FORM_ROWS: u16 = 128;
+
+/// Scrolls a form taller than the screen so its selected field stays in view
+#[derive(Debug, Default)]
+pub struct FormScroll {
+    /// Form row of the selected field, noted as the form is drawn
+    selected_row: Cell<u16>,
+}
+
+impl FormScroll {
+    pub fn note_selected(&self, is_selected: bool, y: u16) {
+        if is_selected {
+            self.selected_row.set(y);
+        }
+    }
+
+    /// Draw the whole form off screen with `draw`, which returns the rows it
+    /// used, then copy into `view` the stretch of it that holds the selected
+    /// field, keeping the row after it in sight too
+    pub fn render(
+        &self,
+        view: Rect,
+        buf: &mut Buffer,
+        draw: impl FnOnce(Rect, &mut Buffer) -> u16,
+    ) {
+        let form_area = Rect {
+            x: view.x,
+            y: 0,
+            width: view.width,
+            height: FORM_ROWS,
+        };
+        let mut form = Buffer::empty(form_area);
+        let form_height = draw(form_area, &mut form);
+
+        let view_height = view.height.min(form_height);
+        let wanted = (self.selected_row.get() + 2).min(form_height);
+        let offset = wanted.saturating_sub(view_height);
+        for row in 0..view_height {
+            for x in view.left()..view.right() {
+                buf[(x, view.y + row)] = form[(x, offset + row)].clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::components::soldier::Faction;
+use crate::game_logic::scoring::{GameOutcome, GameOverReason, SideScore};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+const PANEL_WIDTH: u16 = 68;
+const PANEL_HEIGHT: u16 = 12;
+
+/// Result panel drawn over the final battlefield view
+pub struct GameOverWidget<'a> {
+    outcome: &'a GameOutcome,
+}
+
+impl<'a> GameOverWidget<'a> {
+    pub fn new(outcome: &'a GameOutcome) -> Self {
+        Self { outcome }
+    }
+
+    fn score_line(name: &str, score: &SideScore) -> Line<'static> {
+        Line::from(format!(
+            "{:<15} {:>2} flags  {:>3} casualties  {:>3.0}% ground  = {:>4}",
+            name,
+            score.objectives_held,
+            score.casualties_inflicted,
+            score.territory * 100.0,
+            score.points()
+        ))
+    }
+}
+
+impl<'a> Widget for GameOverWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = PANEL_WIDTH.min(area.width);
+        let height = PANEL_HEIGHT.min(area.height);
+        let panel = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        Clear.render(panel, buf);
+        let block = Block::default()
+            .title("Game Over")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(panel);
+        block.render(panel, buf);
+
+        let headline = match self.outcome.winner {
+            Some(Faction::Allies) => "ALLIED VICTORY",
+            Some(Faction::CentralPowers) => "CENTRAL POWERS VICTORY",
+            None => "DRAW",
+        };
+        let reason = match self.outcome.reason {
+            GameOverReason::Objectives => "All objectives captured".to_string(),
+            GameOverReason::Annihilation => match self.outcome.winner {
+                Some(winner) => format!("The {} have nobody left standing", winner.opposing().name()),
+                None => "Nobody left standing".to_string(),
+            },
+            GameOverReason::TurnLimit => {
+                format!("Decided on points after {} turns", self.outcome.turn)
+            }
+            GameOverReason::Scripted => format!("Scenario ended on turn {}", self.outcome.turn),
+        };
+
+        let lines = vec![
+            Line::from(Span::styled(
+                headline,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(reason, Style::default().fg(Color::Gray))),
+            Line::from(""),
+            Self::score_line("Allies", &self.outcome.allies),
+            Self::score_line("Central Powers", &self.outcome.central_powers),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Enter/ESC", Style::default().fg(Color::Green)),
+                Span::raw(": Main menu"),
+            ]),
+        ];
+
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .render(inner, buf);
+    }
+}
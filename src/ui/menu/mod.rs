@@ -1,11 +1,17 @@
+pub mod enemy_ranks;
+pub mod form_scroll;
+pub mod game_over;
 pub mod main_menu;
 pub mod menu_state;
 pub mod new_game_config;
 pub mod settings_menu;
+pub mod timeline_viewer;
 pub mod widgets;
 
+pub use game_over::GameOverWidget;
 pub use main_menu::{MainMenuItem, MainMenuState, MainMenuWidget};
 pub use menu_state::MenuState;
 pub use new_game_config::{NewGameConfigState, NewGameConfigWidget};
-pub use settings_menu::{SettingsMenuState, SettingsMenuWidget};
+pub use settings_menu::{SettingsMenuState, SettingsMenuWidget, SettingsOption, SettingsTab};
+pub use timeline_viewer::TimelineViewerWidget;
 pub use widgets::{ConfigSliderWidget, MenuAction, MenuItem, MenuWidget};
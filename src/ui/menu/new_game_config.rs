@@ -1,7 +1,15 @@
 use crate::config::battlefield_config::{
-    BattlefieldGenerationConfig, FortificationLevel, TrenchDensity,
+    BattlefieldGenerationConfig, FortificationCounts, FortificationLevel, TrenchDensity,
 };
-use crate::config::game_config::GameConfig;
+use crate::config::game_config::{
+    Difficulty, EnemyForce, GameConfig, ObjectiveLayout, ObjectivesConfig,
+};
+use crate::config::new_game_defaults::{
+    NewGameDefaults, NewGameDefaultsError, NEW_GAME_DEFAULTS_PATH,
+};
+use crate::simulation::weather::TimeOfDay;
+use crate::ui::menu::enemy_ranks::{EnemyRanksEditor, RankShare};
+use crate::ui::menu::form_scroll::FormScroll;
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Rect},
@@ -9,11 +17,18 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Widget},
 };
+use std::path::Path;
 
 const SOLDIER_COUNT_OPTIONS: &[usize] = &[5, 10, 15, 20, 30, 50, 75, 100, 150, 200, 300, 500];
 const DEFAULT_SOLDIER_COUNT_INDEX: usize = 2;
 const DEFAULT_TIME_BUDGET: f32 = 12.0;
 const MAP_SIZE_OPTIONS: &[usize] = &[50, 75, 100, 125, 150, 200];
+const MAX_FORTIFICATION_COUNT: usize = 50;
+const MAX_WIRE_COILS: u32 = 3;
+const MAX_SUPPORT_TRENCH_LINES: usize = 3;
+const TURN_LIMIT_OPTIONS: &[Option<u32>] = &[None, Some(25), Some(50), Some(100), Some(200)];
+const OBJECTIVE_TIMER_OPTIONS: &[Option<u32>] = &[None, Some(20), Some(40), Some(60)];
+const MAX_CAPTURE_RADIUS: i32 = 5;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ConfigField {
@@ -21,14 +36,41 @@ enum ConfigField {
     MapWidth,
     MapHeight,
     TrenchDensity,
+    SupportLines,
     FortificationLevel,
+    Sandbags,
+    Bunkers,
+    MgNests,
     MudCoverage,
     CraterDensity,
     ForestCoverage,
     BuildingDensity,
     BarbedWireCoverage,
+    NoiseScale,
+    NoiseOctaves,
+    NoisePersistence,
     Seed,
-    SoldierCount,
+    AiSeed,
+    AllyCount,
+    EnemyCount,
+    DugIn,
+    WireCoils,
+    PlayAs,
+    Objectives,
+    CaptureRadius,
+    CasualtyEvac,
+    ObjectiveTimer,
+    TurnLimit,
+    TimeOfDay,
+    Difficulty,
+    EnemyForce,
+    EnemyRank(RankShare),
+    Reinforcements,
+    SupplyLines,
+    PanicFire,
+    Fatigue,
+    Deployment,
+    RankBudgets,
     TimeBudget,
     StartGame,
     BackToMenu,
@@ -40,15 +82,42 @@ impl ConfigField {
             Self::BattlefieldPreset => Self::MapWidth,
             Self::MapWidth => Self::MapHeight,
             Self::MapHeight => Self::TrenchDensity,
-            Self::TrenchDensity => Self::FortificationLevel,
-            Self::FortificationLevel => Self::MudCoverage,
+            Self::TrenchDensity => Self::SupportLines,
+            Self::SupportLines => Self::FortificationLevel,
+            Self::FortificationLevel => Self::Sandbags,
+            Self::Sandbags => Self::Bunkers,
+            Self::Bunkers => Self::MgNests,
+            Self::MgNests => Self::MudCoverage,
             Self::MudCoverage => Self::CraterDensity,
             Self::CraterDensity => Self::ForestCoverage,
             Self::ForestCoverage => Self::BuildingDensity,
             Self::BuildingDensity => Self::BarbedWireCoverage,
-            Self::BarbedWireCoverage => Self::Seed,
-            Self::Seed => Self::SoldierCount,
-            Self::SoldierCount => Self::TimeBudget,
+            Self::BarbedWireCoverage => Self::NoiseScale,
+            Self::NoiseScale => Self::NoiseOctaves,
+            Self::NoiseOctaves => Self::NoisePersistence,
+            Self::NoisePersistence => Self::Seed,
+            Self::Seed => Self::AiSeed,
+            Self::AiSeed => Self::AllyCount,
+            Self::AllyCount => Self::EnemyCount,
+            Self::EnemyCount => Self::DugIn,
+            Self::DugIn => Self::WireCoils,
+            Self::WireCoils => Self::PlayAs,
+            Self::PlayAs => Self::Objectives,
+            Self::Objectives => Self::CaptureRadius,
+            Self::CaptureRadius => Self::CasualtyEvac,
+            Self::CasualtyEvac => Self::ObjectiveTimer,
+            Self::ObjectiveTimer => Self::TurnLimit,
+            Self::TurnLimit => Self::TimeOfDay,
+            Self::TimeOfDay => Self::Difficulty,
+            Self::Difficulty => Self::EnemyForce,
+            Self::EnemyForce => Self::EnemyRank(RankShare::Privates),
+            Self::EnemyRank(rank) => rank.next().map_or(Self::Reinforcements, Self::EnemyRank),
+            Self::Reinforcements => Self::SupplyLines,
+            Self::SupplyLines => Self::PanicFire,
+            Self::PanicFire => Self::Fatigue,
+            Self::Fatigue => Self::Deployment,
+            Self::Deployment => Self::RankBudgets,
+            Self::RankBudgets => Self::TimeBudget,
             Self::TimeBudget => Self::StartGame,
             Self::StartGame => Self::BackToMenu,
             Self::BackToMenu => Self::BattlefieldPreset,
@@ -61,15 +130,42 @@ impl ConfigField {
             Self::MapWidth => Self::BattlefieldPreset,
             Self::MapHeight => Self::MapWidth,
             Self::TrenchDensity => Self::MapHeight,
-            Self::FortificationLevel => Self::TrenchDensity,
-            Self::MudCoverage => Self::FortificationLevel,
+            Self::SupportLines => Self::TrenchDensity,
+            Self::FortificationLevel => Self::SupportLines,
+            Self::Sandbags => Self::FortificationLevel,
+            Self::Bunkers => Self::Sandbags,
+            Self::MgNests => Self::Bunkers,
+            Self::MudCoverage => Self::MgNests,
             Self::CraterDensity => Self::MudCoverage,
             Self::ForestCoverage => Self::CraterDensity,
             Self::BuildingDensity => Self::ForestCoverage,
             Self::BarbedWireCoverage => Self::BuildingDensity,
-            Self::Seed => Self::BarbedWireCoverage,
-            Self::SoldierCount => Self::Seed,
-            Self::TimeBudget => Self::SoldierCount,
+            Self::NoiseScale => Self::BarbedWireCoverage,
+            Self::NoiseOctaves => Self::NoiseScale,
+            Self::NoisePersistence => Self::NoiseOctaves,
+            Self::Seed => Self::NoisePersistence,
+            Self::AiSeed => Self::Seed,
+            Self::AllyCount => Self::AiSeed,
+            Self::EnemyCount => Self::AllyCount,
+            Self::DugIn => Self::EnemyCount,
+            Self::WireCoils => Self::DugIn,
+            Self::PlayAs => Self::WireCoils,
+            Self::Objectives => Self::PlayAs,
+            Self::CaptureRadius => Self::Objectives,
+            Self::CasualtyEvac => Self::CaptureRadius,
+            Self::ObjectiveTimer => Self::CasualtyEvac,
+            Self::TurnLimit => Self::ObjectiveTimer,
+            Self::TimeOfDay => Self::TurnLimit,
+            Self::Difficulty => Self::TimeOfDay,
+            Self::EnemyForce => Self::Difficulty,
+            Self::EnemyRank(rank) => rank.prev().map_or(Self::EnemyForce, Self::EnemyRank),
+            Self::Reinforcements => Self::EnemyRank(RankShare::Officers),
+            Self::SupplyLines => Self::Reinforcements,
+            Self::PanicFire => Self::SupplyLines,
+            Self::Fatigue => Self::PanicFire,
+            Self::Deployment => Self::Fatigue,
+            Self::RankBudgets => Self::Deployment,
+            Self::TimeBudget => Self::RankBudgets,
             Self::StartGame => Self::TimeBudget,
             Self::BackToMenu => Self::StartGame,
         }
@@ -155,50 +251,225 @@ pub struct NewGameConfigState {
     map_width_index: usize,
     map_height_index: usize,
     trench_density: TrenchDensity,
+    /// Parallel trench lines behind each front line
+    support_trench_lines: usize,
     fortification_level: FortificationLevel,
+    /// Exact fortification counts; None follows the fortification level
+    fortification_counts: Option<FortificationCounts>,
     mud_coverage: f32,
     crater_density: f32,
     forest_coverage: f32,
     building_density: f32,
     barbed_wire_coverage: f32,
+    noise_scale: f32,
+    noise_octaves: u32,
+    noise_persistence: f32,
     seed: u64,
-    soldier_count_index: usize,
+    /// Seed for AI decisions; None reuses the map seed
+    ai_seed: Option<u64>,
+    ally_count_index: usize,
+    enemy_count_index: usize,
+    dug_in_defenders: bool,
+    wire_coils: u32,
+    commander_mode: bool,
+    objective_layout: ObjectiveLayout,
+    capture_radius: i32,
+    casualty_evac: bool,
+    objective_timer_index: usize,
+    turn_limit_index: usize,
+    time_of_day: TimeOfDay,
+    difficulty: Difficulty,
+    enemy_ranks: EnemyRanksEditor,
+    reinforcements: bool,
+    supply_lines: bool,
+    panic_fire: bool,
+    fatigue: bool,
+    deployment: bool,
+    rank_time_budgets: bool,
     time_budget: f32,
     selected_field: ConfigField,
 }
 
 impl NewGameConfigState {
+    /// Open on the last setup the player started, or Verdun if there is none
     pub fn new() -> Self {
+        Self::load(NEW_GAME_DEFAULTS_PATH)
+    }
+
+    /// Open on the setup saved at `path`; a missing or unreadable file gives Verdun
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let mut state = Self::verdun();
+        if let Ok(Some(saved)) = NewGameDefaults::load(path) {
+            state.apply_defaults(&saved);
+        }
+        state
+    }
+
+    /// The built-in starting setup: the Verdun preset
+    fn verdun() -> Self {
         let config = BattlefieldGenerationConfig::verdun();
         Self {
             selected_preset: BattlefieldPreset::Verdun,
             map_width_index: MAP_SIZE_OPTIONS.iter().position(|&s| s == config.width).unwrap_or(2),
             map_height_index: MAP_SIZE_OPTIONS.iter().position(|&s| s == config.height).unwrap_or(2),
             trench_density: config.trench_density,
+            support_trench_lines: config.support_trench_lines,
             fortification_level: config.fortification_level,
+            fortification_counts: None,
             mud_coverage: config.mud_coverage,
             crater_density: config.crater_density,
             forest_coverage: config.forest_coverage,
             building_density: config.building_density,
             barbed_wire_coverage: config.barbed_wire_coverage,
+            noise_scale: config.noise_scale,
+            noise_octaves: config.noise_octaves,
+            noise_persistence: config.noise_persistence,
             seed: config.seed,
-            soldier_count_index: DEFAULT_SOLDIER_COUNT_INDEX,
+            ai_seed: None,
+            ally_count_index: DEFAULT_SOLDIER_COUNT_INDEX,
+            enemy_count_index: DEFAULT_SOLDIER_COUNT_INDEX,
+            dug_in_defenders: false,
+            wire_coils: 0,
+            commander_mode: false,
+            objective_layout: ObjectiveLayout::default(),
+            capture_radius: ObjectivesConfig::default().capture_radius,
+            casualty_evac: false,
+            objective_timer_index: 0,
+            turn_limit_index: 0,
+            time_of_day: TimeOfDay::default(),
+            difficulty: Difficulty::default(),
+            enemy_ranks: EnemyRanksEditor::new(EnemyForce::default()),
+            reinforcements: false,
+            supply_lines: false,
+            panic_fire: false,
+            fatigue: false,
+            deployment: false,
+            rank_time_budgets: false,
             time_budget: DEFAULT_TIME_BUDGET,
             selected_field: ConfigField::BattlefieldPreset,
         }
     }
 
+    /// Restore a saved setup; values the menu no longer offers keep the current choice
+    fn apply_defaults(&mut self, saved: &NewGameDefaults) {
+        let index_of = |options: &[usize], value: usize, fallback: usize| {
+            options.iter().position(|&option| option == value).unwrap_or(fallback)
+        };
+
+        if let Some(preset) = BattlefieldPreset::ALL.iter().find(|p| p.name() == saved.preset) {
+            self.selected_preset = *preset;
+        }
+        self.map_width_index = index_of(MAP_SIZE_OPTIONS, saved.map_width, self.map_width_index);
+        self.map_height_index = index_of(MAP_SIZE_OPTIONS, saved.map_height, self.map_height_index);
+        self.trench_density = saved.trench_density;
+        self.support_trench_lines = saved.support_trench_lines.min(MAX_SUPPORT_TRENCH_LINES);
+        self.fortification_level = saved.fortification_level;
+        self.fortification_counts = saved.fortification_counts;
+        self.mud_coverage = saved.mud_coverage;
+        self.crater_density = saved.crater_density;
+        self.forest_coverage = saved.forest_coverage;
+        self.building_density = saved.building_density;
+        self.barbed_wire_coverage = saved.barbed_wire_coverage;
+        self.noise_scale = saved.noise_scale;
+        self.noise_octaves = saved.noise_octaves;
+        self.noise_persistence = saved.noise_persistence;
+        self.seed = saved.seed;
+        self.ai_seed = saved.ai_seed;
+        self.ally_count_index =
+            index_of(SOLDIER_COUNT_OPTIONS, saved.ally_count, self.ally_count_index);
+        self.enemy_count_index =
+            index_of(SOLDIER_COUNT_OPTIONS, saved.enemy_count, self.enemy_count_index);
+        self.dug_in_defenders = saved.dug_in_defenders;
+        self.wire_coils = saved.wire_coils.min(MAX_WIRE_COILS);
+        self.commander_mode = saved.commander_mode;
+        self.objective_layout = saved.objective_layout;
+        self.capture_radius = saved.capture_radius.clamp(0, MAX_CAPTURE_RADIUS);
+        self.casualty_evac = saved.casualty_evac;
+        self.objective_timer_index = OBJECTIVE_TIMER_OPTIONS
+            .iter()
+            .position(|&turn| turn == saved.objective_timer)
+            .unwrap_or(self.objective_timer_index);
+        self.turn_limit_index = TURN_LIMIT_OPTIONS
+            .iter()
+            .position(|&limit| limit == saved.turn_limit)
+            .unwrap_or(self.turn_limit_index);
+        self.time_of_day = saved.time_of_day;
+        self.difficulty = saved.difficulty;
+        self.enemy_ranks = EnemyRanksEditor::restore(saved.enemy_force, saved.enemy_ranks);
+        self.reinforcements = saved.reinforcements;
+        self.supply_lines = saved.supply_lines;
+        self.panic_fire = saved.panic_fire;
+        self.fatigue = saved.fatigue;
+        self.deployment = saved.deployment;
+        self.rank_time_budgets = saved.rank_time_budgets;
+        self.time_budget = saved.time_budget;
+    }
+
+    /// The current setup in its saved form
+    pub fn to_defaults(&self) -> NewGameDefaults {
+        NewGameDefaults {
+            preset: self.selected_preset.name().to_string(),
+            map_width: self.map_width(),
+            map_height: self.map_height(),
+            trench_density: self.trench_density,
+            support_trench_lines: self.support_trench_lines,
+            fortification_level: self.fortification_level,
+            fortification_counts: self.fortification_counts,
+            mud_coverage: self.mud_coverage,
+            crater_density: self.crater_density,
+            forest_coverage: self.forest_coverage,
+            building_density: self.building_density,
+            barbed_wire_coverage: self.barbed_wire_coverage,
+            noise_scale: self.noise_scale,
+            noise_octaves: self.noise_octaves,
+            noise_persistence: self.noise_persistence,
+            seed: self.seed,
+            ai_seed: self.ai_seed,
+            ally_count: self.ally_count(),
+            enemy_count: self.enemy_count(),
+            dug_in_defenders: self.dug_in_defenders,
+            wire_coils: self.wire_coils,
+            commander_mode: self.commander_mode,
+            objective_layout: self.objective_layout,
+            capture_radius: self.capture_radius,
+            casualty_evac: self.casualty_evac,
+            objective_timer: self.objective_timer(),
+            turn_limit: self.turn_limit(),
+            time_of_day: self.time_of_day,
+            difficulty: self.difficulty,
+            enemy_force: self.enemy_ranks.force,
+            enemy_ranks: Some(self.enemy_ranks.ranks),
+            reinforcements: self.reinforcements,
+            supply_lines: self.supply_lines,
+            panic_fire: self.panic_fire,
+            fatigue: self.fatigue,
+            deployment: self.deployment,
+            rank_time_budgets: self.rank_time_budgets,
+            time_budget: self.time_budget,
+        }
+    }
+
+    /// Remember the current setup as the default for the next launch
+    pub fn save_defaults(&self, path: impl AsRef<Path>) -> Result<(), NewGameDefaultsError> {
+        self.to_defaults().save(path)
+    }
+
     fn load_preset(&mut self, preset: BattlefieldPreset) {
         let config = preset.to_config();
         self.map_width_index = MAP_SIZE_OPTIONS.iter().position(|&s| s == config.width).unwrap_or(2);
         self.map_height_index = MAP_SIZE_OPTIONS.iter().position(|&s| s == config.height).unwrap_or(2);
         self.trench_density = config.trench_density;
+        self.support_trench_lines = config.support_trench_lines;
         self.fortification_level = config.fortification_level;
+        self.fortification_counts = None;
         self.mud_coverage = config.mud_coverage;
         self.crater_density = config.crater_density;
         self.forest_coverage = config.forest_coverage;
         self.building_density = config.building_density;
         self.barbed_wire_coverage = config.barbed_wire_coverage;
+        self.noise_scale = config.noise_scale;
+        self.noise_octaves = config.noise_octaves;
+        self.noise_persistence = config.noise_persistence;
         self.seed = config.seed;
     }
 
@@ -208,8 +479,34 @@ impl NewGameConfigState {
         }
     }
 
-    pub fn soldier_count(&self) -> usize {
-        SOLDIER_COUNT_OPTIONS[self.soldier_count_index]
+    /// Per-side fortification counts shown in the editor
+    fn fortification_counts(&self) -> FortificationCounts {
+        self.fortification_counts
+            .unwrap_or_else(|| self.fortification_level.counts())
+    }
+
+    /// Nudge one fortification count, pinning the others at their current values
+    fn adjust_fortification_count(&mut self, field: ConfigField, delta: isize) {
+        let mut counts = self.fortification_counts();
+        let count = match field {
+            ConfigField::Sandbags => &mut counts.sandbags,
+            ConfigField::Bunkers => &mut counts.bunkers,
+            ConfigField::MgNests => &mut counts.mg_nests,
+            _ => return,
+        };
+        *count = count.saturating_add_signed(delta).min(MAX_FORTIFICATION_COUNT);
+        self.fortification_counts = Some(counts);
+        self.switch_to_custom_if_needed();
+    }
+
+    /// Allied soldiers to spawn, not counting the player
+    pub fn ally_count(&self) -> usize {
+        SOLDIER_COUNT_OPTIONS[self.ally_count_index]
+    }
+
+    /// Enemy soldiers to spawn
+    pub fn enemy_count(&self) -> usize {
+        SOLDIER_COUNT_OPTIONS[self.enemy_count_index]
     }
 
     fn map_width(&self) -> usize {
@@ -263,6 +560,10 @@ impl NewGameConfigState {
                 };
                 self.switch_to_custom_if_needed();
             }
+            ConfigField::SupportLines => {
+                self.support_trench_lines = self.support_trench_lines.saturating_sub(1);
+                self.switch_to_custom_if_needed();
+            }
             ConfigField::FortificationLevel => {
                 self.fortification_level = match self.fortification_level {
                     FortificationLevel::Fortress => FortificationLevel::Heavy,
@@ -271,8 +572,15 @@ impl NewGameConfigState {
                     FortificationLevel::Light => FortificationLevel::None,
                     FortificationLevel::None => FortificationLevel::None,
                 };
+                self.fortification_counts = None;
                 self.switch_to_custom_if_needed();
             }
+            ConfigField::Sandbags | ConfigField::Bunkers | ConfigField::MgNests => {
+                self.adjust_fortification_count(self.selected_field, -1);
+            }
+            ConfigField::EnemyRank(rank) => {
+                self.enemy_ranks.adjust(rank, false);
+            }
             ConfigField::MudCoverage => {
                 self.mud_coverage = (self.mud_coverage - 0.05).max(0.0);
                 self.switch_to_custom_if_needed();
@@ -293,14 +601,82 @@ impl NewGameConfigState {
                 self.barbed_wire_coverage = (self.barbed_wire_coverage - 0.05).max(0.0);
                 self.switch_to_custom_if_needed();
             }
+            ConfigField::NoiseScale => {
+                self.noise_scale = (self.noise_scale - 0.01).max(0.01);
+                self.switch_to_custom_if_needed();
+            }
+            ConfigField::NoiseOctaves => {
+                self.noise_octaves = self.noise_octaves.saturating_sub(1).max(1);
+                self.switch_to_custom_if_needed();
+            }
+            ConfigField::NoisePersistence => {
+                self.noise_persistence = (self.noise_persistence - 0.05).max(0.1);
+                self.switch_to_custom_if_needed();
+            }
             ConfigField::Seed => {
                 self.seed = self.seed.saturating_sub(100);
                 self.switch_to_custom_if_needed();
             }
-            ConfigField::SoldierCount => {
-                if self.soldier_count_index > 0 {
-                    self.soldier_count_index -= 1;
-                }
+            ConfigField::AiSeed => {
+                // Stepping below the first seed hands the AI back the map seed
+                self.ai_seed = self.ai_seed.and_then(|seed| seed.checked_sub(100));
+            }
+            ConfigField::AllyCount => {
+                self.ally_count_index = self.ally_count_index.saturating_sub(1);
+            }
+            ConfigField::EnemyCount => {
+                self.enemy_count_index = self.enemy_count_index.saturating_sub(1);
+            }
+            ConfigField::DugIn => {
+                self.dug_in_defenders = !self.dug_in_defenders;
+            }
+            ConfigField::WireCoils => {
+                self.wire_coils = self.wire_coils.saturating_sub(1);
+            }
+            ConfigField::PlayAs => {
+                self.commander_mode = !self.commander_mode;
+            }
+            ConfigField::Objectives => {
+                self.objective_layout = self.objective_layout.prev();
+            }
+            ConfigField::CaptureRadius => {
+                self.capture_radius = (self.capture_radius - 1).max(0);
+            }
+            ConfigField::ObjectiveTimer if self.objective_timer_index > 0 => {
+                self.objective_timer_index -= 1;
+            }
+            ConfigField::TurnLimit if self.turn_limit_index > 0 => {
+                self.turn_limit_index -= 1;
+            }
+            ConfigField::TimeOfDay => {
+                self.time_of_day = self.time_of_day.toggle();
+            }
+            ConfigField::Difficulty => {
+                self.difficulty = self.difficulty.toggle();
+            }
+            ConfigField::EnemyForce => {
+                self.enemy_ranks.prev_force();
+            }
+            ConfigField::CasualtyEvac => {
+                self.casualty_evac = !self.casualty_evac;
+            }
+            ConfigField::Reinforcements => {
+                self.reinforcements = !self.reinforcements;
+            }
+            ConfigField::SupplyLines => {
+                self.supply_lines = !self.supply_lines;
+            }
+            ConfigField::PanicFire => {
+                self.panic_fire = !self.panic_fire;
+            }
+            ConfigField::Fatigue => {
+                self.fatigue = !self.fatigue;
+            }
+            ConfigField::Deployment => {
+                self.deployment = !self.deployment;
+            }
+            ConfigField::RankBudgets => {
+                self.rank_time_budgets = !self.rank_time_budgets;
             }
             ConfigField::TimeBudget => {
                 self.time_budget = (self.time_budget - 1.0).clamp(5.0, 30.0);
@@ -340,6 +716,10 @@ impl NewGameConfigState {
                 };
                 self.switch_to_custom_if_needed();
             }
+            ConfigField::SupportLines => {
+                self.support_trench_lines = (self.support_trench_lines + 1).min(MAX_SUPPORT_TRENCH_LINES);
+                self.switch_to_custom_if_needed();
+            }
             ConfigField::FortificationLevel => {
                 self.fortification_level = match self.fortification_level {
                     FortificationLevel::None => FortificationLevel::Light,
@@ -348,8 +728,15 @@ impl NewGameConfigState {
                     FortificationLevel::Heavy => FortificationLevel::Fortress,
                     FortificationLevel::Fortress => FortificationLevel::Fortress,
                 };
+                self.fortification_counts = None;
                 self.switch_to_custom_if_needed();
             }
+            ConfigField::Sandbags | ConfigField::Bunkers | ConfigField::MgNests => {
+                self.adjust_fortification_count(self.selected_field, 1);
+            }
+            ConfigField::EnemyRank(rank) => {
+                self.enemy_ranks.adjust(rank, true);
+            }
             ConfigField::MudCoverage => {
                 self.mud_coverage = (self.mud_coverage + 0.05).min(1.0);
                 self.switch_to_custom_if_needed();
@@ -370,14 +757,83 @@ impl NewGameConfigState {
                 self.barbed_wire_coverage = (self.barbed_wire_coverage + 0.05).min(1.0);
                 self.switch_to_custom_if_needed();
             }
+            ConfigField::NoiseScale => {
+                self.noise_scale = (self.noise_scale + 0.01).min(0.3);
+                self.switch_to_custom_if_needed();
+            }
+            ConfigField::NoiseOctaves => {
+                self.noise_octaves = (self.noise_octaves + 1).min(6);
+                self.switch_to_custom_if_needed();
+            }
+            ConfigField::NoisePersistence => {
+                self.noise_persistence = (self.noise_persistence + 0.05).min(0.9);
+                self.switch_to_custom_if_needed();
+            }
             ConfigField::Seed => {
                 self.seed = self.seed.saturating_add(100);
                 self.switch_to_custom_if_needed();
             }
-            ConfigField::SoldierCount => {
-                if self.soldier_count_index < SOLDIER_COUNT_OPTIONS.len() - 1 {
-                    self.soldier_count_index += 1;
-                }
+            ConfigField::AiSeed => {
+                self.ai_seed = Some(self.ai_seed.map_or(0, |seed| seed.saturating_add(100)));
+            }
+            ConfigField::AllyCount if self.ally_count_index < SOLDIER_COUNT_OPTIONS.len() - 1 => {
+                self.ally_count_index += 1;
+            }
+            ConfigField::EnemyCount if self.enemy_count_index < SOLDIER_COUNT_OPTIONS.len() - 1 => {
+                self.enemy_count_index += 1;
+            }
+            ConfigField::DugIn => {
+                self.dug_in_defenders = !self.dug_in_defenders;
+            }
+            ConfigField::WireCoils => {
+                self.wire_coils = (self.wire_coils + 1).min(MAX_WIRE_COILS);
+            }
+            ConfigField::PlayAs => {
+                self.commander_mode = !self.commander_mode;
+            }
+            ConfigField::Objectives => {
+                self.objective_layout = self.objective_layout.next();
+            }
+            ConfigField::CaptureRadius => {
+                self.capture_radius = (self.capture_radius + 1).min(MAX_CAPTURE_RADIUS);
+            }
+            ConfigField::ObjectiveTimer
+                if self.objective_timer_index < OBJECTIVE_TIMER_OPTIONS.len() - 1 =>
+            {
+                self.objective_timer_index += 1;
+            }
+            ConfigField::TurnLimit if self.turn_limit_index < TURN_LIMIT_OPTIONS.len() - 1 => {
+                self.turn_limit_index += 1;
+            }
+            ConfigField::TimeOfDay => {
+                self.time_of_day = self.time_of_day.toggle();
+            }
+            ConfigField::Difficulty => {
+                self.difficulty = self.difficulty.toggle();
+            }
+            ConfigField::EnemyForce => {
+                self.enemy_ranks.next_force();
+            }
+            ConfigField::CasualtyEvac => {
+                self.casualty_evac = !self.casualty_evac;
+            }
+            ConfigField::Reinforcements => {
+                self.reinforcements = !self.reinforcements;
+            }
+            ConfigField::SupplyLines => {
+                self.supply_lines = !self.supply_lines;
+            }
+            ConfigField::PanicFire => {
+                self.panic_fire = !self.panic_fire;
+            }
+            ConfigField::Fatigue => {
+                self.fatigue = !self.fatigue;
+            }
+            ConfigField::Deployment => {
+                self.deployment = !self.deployment;
+            }
+            ConfigField::RankBudgets => {
+                self.rank_time_budgets = !self.rank_time_budgets;
             }
             ConfigField::TimeBudget => {
                 self.time_budget = (self.time_budget + 1.0).clamp(5.0, 30.0);
@@ -395,7 +851,43 @@ impl NewGameConfigState {
     }
 
     pub fn to_game_config(&self) -> GameConfig {
-        GameConfig::new().with_time_budget(self.time_budget)
+        self.apply_to(GameConfig::new())
+    }
+
+    /// Lay this setup's choices over `config`, e.g. the one built from Settings
+    pub fn apply_to(&self, config: GameConfig) -> GameConfig {
+        let config = config
+            .with_time_budget(self.time_budget)
+            .with_dug_in_defenders(self.dug_in_defenders)
+            .with_wire_coils(self.wire_coils)
+            .with_commander_mode(self.commander_mode)
+            .with_objective_layout(self.objective_layout)
+            .with_capture_radius(self.capture_radius)
+            .with_casualty_evac(self.casualty_evac)
+            .with_objective_timer(self.objective_timer())
+            .with_turn_limit(self.turn_limit())
+            .with_time_of_day(self.time_of_day)
+            .with_difficulty(self.difficulty)
+            .with_enemy_ranks(self.enemy_ranks.ranks)
+            .with_reinforcements(self.reinforcements)
+            .with_supply_lines(self.supply_lines)
+            .with_panic_fire(self.panic_fire)
+            .with_fatigue(self.fatigue)
+            .with_deployment(self.deployment)
+            .with_rank_time_budgets(self.rank_time_budgets);
+        match self.ai_seed {
+            Some(seed) => config.with_ai_seed(seed),
+            None => config,
+        }
+    }
+
+    /// Turn by which each home flag must fall (None = no timer)
+    pub fn objective_timer(&self) -> Option<u32> {
+        OBJECTIVE_TIMER_OPTIONS[self.objective_timer_index]
+    }
+
+    pub fn turn_limit(&self) -> Option<u32> {
+        TURN_LIMIT_OPTIONS[self.turn_limit_index]
     }
 
     pub fn to_battlefield_config(&self) -> BattlefieldGenerationConfig {
@@ -403,13 +895,18 @@ impl NewGameConfigState {
             width: self.map_width(),
             height: self.map_height(),
             trench_density: self.trench_density,
+            support_trench_lines: self.support_trench_lines,
             fortification_level: self.fortification_level,
             mud_coverage: self.mud_coverage,
             crater_density: self.crater_density,
             forest_coverage: self.forest_coverage,
             building_density: self.building_density,
             barbed_wire_coverage: self.barbed_wire_coverage,
+            noise_scale: self.noise_scale,
+            noise_octaves: self.noise_octaves,
+            noise_persistence: self.noise_persistence,
             seed: self.seed,
+            fortification_counts: self.fortification_counts,
             ..self.selected_preset.to_config()
         }
     }
@@ -423,11 +920,15 @@ impl Default for NewGameConfigState {
 
 pub struct NewGameConfigWidget<'a> {
     state: &'a NewGameConfigState,
+    scroll: FormScroll,
 }
 
 impl<'a> NewGameConfigWidget<'a> {
     pub fn new(state: &'a NewGameConfigState) -> Self {
-        Self { state }
+        Self {
+            state,
+            scroll: FormScroll::default(),
+        }
     }
 
     fn render_field(
@@ -439,6 +940,8 @@ impl<'a> NewGameConfigWidget<'a> {
         area: Rect,
         buf: &mut Buffer,
     ) {
+        self.scroll.note_selected(is_selected, y);
+
         let (prefix, label_style, value_style) = if is_selected {
             (
                 "> ",
@@ -500,6 +1003,8 @@ impl<'a> NewGameConfigWidget<'a> {
         area: Rect,
         buf: &mut Buffer,
     ) {
+        self.scroll.note_selected(is_selected, y);
+
         let (prefix, label_style, value_style) = if is_selected {
             (
                 "> ",
@@ -554,6 +1059,8 @@ impl<'a> NewGameConfigWidget<'a> {
         area: Rect,
         buf: &mut Buffer,
     ) {
+        self.scroll.note_selected(is_selected, y);
+
         let (prefix, label_style, value_style) = if is_selected {
             (
                 "> ",
@@ -606,6 +1113,8 @@ impl<'a> NewGameConfigWidget<'a> {
         area: Rect,
         buf: &mut Buffer,
     ) {
+        self.scroll.note_selected(is_selected, y);
+
         let (prefix, style) = if is_selected {
             (
                 "> ",
@@ -634,6 +1143,7 @@ impl<'a> NewGameConfigWidget<'a> {
 
     fn render_slider(&self, y: u16, area: Rect, buf: &mut Buffer) {
         let is_selected = matches!(self.state.selected_field, ConfigField::TimeBudget);
+        self.scroll.note_selected(is_selected, y);
 
         let (label_style, bar_color) = if is_selected {
             (
@@ -694,45 +1204,16 @@ impl<'a> NewGameConfigWidget<'a> {
         }
     }
 
-    fn trench_density_name(density: TrenchDensity) -> &'static str {
-        match density {
-            TrenchDensity::None => "None",
-            TrenchDensity::Sparse => "Sparse",
-            TrenchDensity::Moderate => "Moderate",
-            TrenchDensity::Dense => "Dense",
-            TrenchDensity::VeryDense => "Very Dense",
-        }
-    }
-
-    fn fortification_level_name(level: FortificationLevel) -> &'static str {
-        match level {
-            FortificationLevel::None => "None",
-            FortificationLevel::Light => "Light",
-            FortificationLevel::Moderate => "Moderate",
-            FortificationLevel::Heavy => "Heavy",
-            FortificationLevel::Fortress => "Fortress",
-        }
-    }
-}
-
-impl<'a> Widget for NewGameConfigWidget<'a> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let block = Block::default()
-            .title("New Game - Configuration")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan));
-
-        let inner = block.inner(area);
-        block.render(area, buf);
-
-        let mut y = inner.y;
+    /// Draw every field from the top of `area`; returns the rows used
+    fn render_form(&self, area: Rect, buf: &mut Buffer) -> u16 {
+        let mut y = area.y;
 
         self.render_field(
             "Preset",
             self.state.selected_preset.name().to_string(),
             matches!(self.state.selected_field, ConfigField::BattlefieldPreset),
             y,
-            inner,
+            area,
             buf,
         );
         y += 1;
@@ -743,15 +1224,15 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
         )))
         .alignment(Alignment::Left);
         let desc_area = Rect {
-            x: inner.x,
+            x: area.x,
             y,
-            width: inner.width,
+            width: area.width,
             height: 2,
         };
         description.render(desc_area, buf);
         y += 3;
 
-        self.render_section_header("Map Settings", y, inner, buf);
+        self.render_section_header("Map Settings", y, area, buf);
         y += 1;
 
         self.render_field(
@@ -759,7 +1240,7 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             format!("{} x {}", self.state.map_width(), self.state.map_height()),
             matches!(self.state.selected_field, ConfigField::MapWidth),
             y,
-            inner,
+            area,
             buf,
         );
         y += 1;
@@ -769,7 +1250,7 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             format!("{}", self.state.map_width()),
             matches!(self.state.selected_field, ConfigField::MapWidth),
             y,
-            inner,
+            area,
             buf,
         );
         y += 1;
@@ -779,7 +1260,7 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             format!("{}", self.state.map_height()),
             matches!(self.state.selected_field, ConfigField::MapHeight),
             y,
-            inner,
+            area,
             buf,
         );
         y += 1;
@@ -789,12 +1270,24 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             format!("{}", self.state.seed),
             matches!(self.state.selected_field, ConfigField::Seed),
             y,
-            inner,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "AI Seed",
+            self.state
+                .ai_seed
+                .map_or_else(|| "Same as map".to_string(), |seed| seed.to_string()),
+            matches!(self.state.selected_field, ConfigField::AiSeed),
+            y,
+            area,
             buf,
         );
         y += 2;
 
-        self.render_section_header("Terrain Density", y, inner, buf);
+        self.render_section_header("Terrain Density", y, area, buf);
         y += 1;
 
         self.render_field(
@@ -802,7 +1295,17 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             Self::trench_density_name(self.state.trench_density).to_string(),
             matches!(self.state.selected_field, ConfigField::TrenchDensity),
             y,
-            inner,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Support Lines",
+            format!("{}", self.state.support_trench_lines),
+            matches!(self.state.selected_field, ConfigField::SupportLines),
+            y,
+            area,
             buf,
         );
         y += 1;
@@ -812,17 +1315,35 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             Self::fortification_level_name(self.state.fortification_level).to_string(),
             matches!(self.state.selected_field, ConfigField::FortificationLevel),
             y,
-            inner,
+            area,
             buf,
         );
         y += 1;
 
+        let counts = self.state.fortification_counts();
+        let auto = if self.state.fortification_counts.is_none() { " (auto)" } else { "" };
+        for (label, count, field) in [
+            ("  Sandbags", counts.sandbags, ConfigField::Sandbags),
+            ("  Bunkers", counts.bunkers, ConfigField::Bunkers),
+            ("  MG Nests", counts.mg_nests, ConfigField::MgNests),
+        ] {
+            self.render_field(
+                label,
+                format!("{}{}", count, auto),
+                self.state.selected_field == field,
+                y,
+                area,
+                buf,
+            );
+            y += 1;
+        }
+
         self.render_percentage_bar(
             "Mud Coverage",
             self.state.mud_coverage,
             matches!(self.state.selected_field, ConfigField::MudCoverage),
             y,
-            inner,
+            area,
             buf,
         );
         y += 1;
@@ -833,7 +1354,7 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             10.0,
             matches!(self.state.selected_field, ConfigField::CraterDensity),
             y,
-            inner,
+            area,
             buf,
         );
         y += 1;
@@ -843,7 +1364,7 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             self.state.forest_coverage,
             matches!(self.state.selected_field, ConfigField::ForestCoverage),
             y,
-            inner,
+            area,
             buf,
         );
         y += 1;
@@ -854,7 +1375,7 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             10.0,
             matches!(self.state.selected_field, ConfigField::BuildingDensity),
             y,
-            inner,
+            area,
             buf,
         );
         y += 1;
@@ -864,32 +1385,269 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             self.state.barbed_wire_coverage,
             matches!(self.state.selected_field, ConfigField::BarbedWireCoverage),
             y,
-            inner,
+            area,
             buf,
         );
         y += 2;
 
-        self.render_section_header("Game Settings", y, inner, buf);
+        self.render_section_header("Terrain Noise", y, area, buf);
+        y += 1;
+
+        self.render_field(
+            "Feature Scale",
+            format!("{:.2}", self.state.noise_scale),
+            matches!(self.state.selected_field, ConfigField::NoiseScale),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Octaves",
+            self.state.noise_octaves.to_string(),
+            matches!(self.state.selected_field, ConfigField::NoiseOctaves),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Persistence",
+            format!("{:.2}", self.state.noise_persistence),
+            matches!(self.state.selected_field, ConfigField::NoisePersistence),
+            y,
+            area,
+            buf,
+        );
+        y += 2;
+
+        self.render_section_header("Game Settings", y, area, buf);
+        y += 1;
+
+        self.render_field(
+            "Allied Soldiers",
+            self.state.ally_count().to_string(),
+            matches!(self.state.selected_field, ConfigField::AllyCount),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Enemy Soldiers",
+            self.state.enemy_count().to_string(),
+            matches!(self.state.selected_field, ConfigField::EnemyCount),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Dug-In Defenders",
+            if self.state.dug_in_defenders { "On" } else { "Off" }.to_string(),
+            matches!(self.state.selected_field, ConfigField::DugIn),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Wire Coils",
+            match self.state.wire_coils {
+                0 => "None".to_string(),
+                coils => format!("{} per soldier", coils),
+            },
+            matches!(self.state.selected_field, ConfigField::WireCoils),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Play As",
+            if self.state.commander_mode { "Commander" } else { "Sergeant" }.to_string(),
+            matches!(self.state.selected_field, ConfigField::PlayAs),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Objectives",
+            self.state.objective_layout.name().to_string(),
+            matches!(self.state.selected_field, ConfigField::Objectives),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Capture Radius",
+            match self.state.capture_radius {
+                0 => "Flag tile only".to_string(),
+                radius => format!("{} tiles", radius),
+            },
+            matches!(self.state.selected_field, ConfigField::CaptureRadius),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Casualty Evac",
+            if self.state.casualty_evac { "On" } else { "Off" }.to_string(),
+            matches!(self.state.selected_field, ConfigField::CasualtyEvac),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Objective Timer",
+            match self.state.objective_timer() {
+                Some(turn) => format!("By turn {}", turn),
+                None => "Off".to_string(),
+            },
+            matches!(self.state.selected_field, ConfigField::ObjectiveTimer),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Turn Limit",
+            match self.state.turn_limit() {
+                Some(turns) => format!("{} turns", turns),
+                None => "Off".to_string(),
+            },
+            matches!(self.state.selected_field, ConfigField::TurnLimit),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Time of Day",
+            self.state.time_of_day.name().to_string(),
+            matches!(self.state.selected_field, ConfigField::TimeOfDay),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Difficulty",
+            self.state.difficulty.name().to_string(),
+            matches!(self.state.selected_field, ConfigField::Difficulty),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Enemy Force",
+            self.state.enemy_ranks.name().to_string(),
+            matches!(self.state.selected_field, ConfigField::EnemyForce),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        for rank in RankShare::ALL {
+            self.render_field(
+                rank.label(),
+                format!("{}", self.state.enemy_ranks.share(rank)),
+                self.state.selected_field == ConfigField::EnemyRank(rank),
+                y,
+                area,
+                buf,
+            );
+            y += 1;
+        }
+
+        self.render_field(
+            "Reinforcements",
+            if self.state.reinforcements { "On" } else { "Off" }.to_string(),
+            matches!(self.state.selected_field, ConfigField::Reinforcements),
+            y,
+            area,
+            buf,
+        );
         y += 1;
 
         self.render_field(
-            "Soldiers/Team",
-            self.state.soldier_count().to_string(),
-            matches!(self.state.selected_field, ConfigField::SoldierCount),
+            "Supply Lines",
+            if self.state.supply_lines { "On" } else { "Off" }.to_string(),
+            matches!(self.state.selected_field, ConfigField::SupplyLines),
             y,
-            inner,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Panic Fire",
+            if self.state.panic_fire { "On" } else { "Off" }.to_string(),
+            matches!(self.state.selected_field, ConfigField::PanicFire),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Battle Fatigue",
+            if self.state.fatigue { "On" } else { "Off" }.to_string(),
+            matches!(self.state.selected_field, ConfigField::Fatigue),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Deployment",
+            if self.state.deployment { "On" } else { "Off" }.to_string(),
+            matches!(self.state.selected_field, ConfigField::Deployment),
+            y,
+            area,
+            buf,
+        );
+        y += 1;
+
+        self.render_field(
+            "Rank Budgets",
+            if self.state.rank_time_budgets { "On" } else { "Off" }.to_string(),
+            matches!(self.state.selected_field, ConfigField::RankBudgets),
+            y,
+            area,
             buf,
         );
         y += 2;
 
-        self.render_slider(y, inner, buf);
+        self.render_slider(y, area, buf);
         y += 3;
 
         self.render_button(
             "Start Game",
             matches!(self.state.selected_field, ConfigField::StartGame),
             y,
-            inner,
+            area,
             buf,
         );
         y += 1;
@@ -898,12 +1656,52 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
             "Back to Main Menu",
             matches!(self.state.selected_field, ConfigField::BackToMenu),
             y,
-            inner,
+            area,
             buf,
         );
 
+        y + 1 - area.y
+    }
+
+    fn trench_density_name(density: TrenchDensity) -> &'static str {
+        match density {
+            TrenchDensity::None => "None",
+            TrenchDensity::Sparse => "Sparse",
+            TrenchDensity::Moderate => "Moderate",
+            TrenchDensity::Dense => "Dense",
+            TrenchDensity::VeryDense => "Very Dense",
+        }
+    }
+
+    fn fortification_level_name(level: FortificationLevel) -> &'static str {
+        match level {
+            FortificationLevel::None => "None",
+            FortificationLevel::Light => "Light",
+            FortificationLevel::Moderate => "Moderate",
+            FortificationLevel::Heavy => "Heavy",
+            FortificationLevel::Fortress => "Fortress",
+        }
+    }
+}
+
+impl<'a> Widget for NewGameConfigWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .title("New Game - Configuration")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
         let help_y = inner.bottom().saturating_sub(2);
-        if help_y > y {
+        let view = Rect {
+            height: help_y.saturating_sub(inner.y + 1),
+            ..inner
+        };
+        self.scroll.render(view, buf, |form_area, form| self.render_form(form_area, form));
+
+        if help_y > inner.y {
             let help_text = Line::from(vec![
                 Span::styled("↑↓", Style::default().fg(Color::Yellow)),
                 Span::raw(" Navigate  "),
@@ -929,3 +1727,211 @@ impl<'a> Widget for NewGameConfigWidget<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::soldier_spawning::RankDistribution;
+    use crate::game_logic::turn_state::TurnOrderMode;
+    use crate::ui::menu::enemy_ranks::RANK_SHARE_STEP;
+    use crate::ui::menu::settings_menu::SettingsMenuState;
+
+    #[test]
+    fn test_new_game_keeps_every_setting() {
+        let state = NewGameConfigState::verdun();
+        let mut settings = SettingsMenuState::new();
+        settings.show_ai_scores = !settings.show_ai_scores;
+        settings.shot_tracers = !settings.shot_tracers;
+        settings.impact_flash = !settings.impact_flash;
+        settings.heard_shot_turns += 3;
+        settings.watch_orders = !settings.watch_orders;
+        settings.commander_stats = !settings.commander_stats;
+        settings.log_verbosity = settings.log_verbosity.toggle();
+        settings.hud_thresholds.hp_wounded -= 0.1;
+        settings.hud_thresholds.time_low += 1.0;
+        settings.camera_deadzone += 0.1;
+        settings.turn_order_mode = TurnOrderMode::Simultaneous;
+        settings.random_events = !settings.random_events;
+        settings.nml_attrition = !settings.nml_attrition;
+        settings.auto_pause = !settings.auto_pause;
+        settings.enemy_info = settings.enemy_info.next();
+        settings.cover_model = settings.cover_model.toggle();
+        settings.reload_interrupt = settings.reload_interrupt.toggle();
+        settings.diagonal_los = settings.diagonal_los.toggle();
+        settings.grenade_throw = settings.grenade_throw.toggle();
+        settings.path_heuristic = settings.path_heuristic.next();
+        settings.blocked_step_rule = settings.blocked_step_rule.toggle();
+        settings.spot_then_shoot_turns += 2;
+        // The time budget is the new-game menu's to set
+        settings.default_time_budget = state.time_budget + 4.0;
+
+        let config = state.apply_to(settings.to_game_config());
+
+        assert_eq!(config.time_budget_seconds, state.time_budget);
+        settings.default_time_budget = state.time_budget;
+        assert_eq!(SettingsMenuState::from_game_config(&config), settings);
+    }
+
+    #[test]
+    fn test_hud_thresholds_from_settings_label_the_new_game() {
+        use crate::config::hud_thresholds::HpStatus;
+
+        let mut settings = SettingsMenuState::new();
+        assert_eq!(settings.hud_thresholds.hp_status(0.75), HpStatus::Healthy);
+        settings.hud_thresholds.adjust_hp_wounded(0.1);
+
+        let config = NewGameConfigState::new().apply_to(settings.to_game_config());
+        assert_eq!(config.hud_thresholds.hp_status(0.75), HpStatus::Wounded);
+    }
+
+    #[test]
+    fn test_capture_radius_reaches_the_game() {
+        let mut state = NewGameConfigState::verdun();
+        state.selected_field = ConfigField::CaptureRadius;
+        for _ in 0..=MAX_CAPTURE_RADIUS {
+            state.handle_left();
+        }
+        assert_eq!(state.to_game_config().objectives.capture_radius, 0);
+
+        state.handle_right();
+        assert_eq!(state.to_game_config().objectives.capture_radius, 1);
+        assert_eq!(state.to_defaults().capture_radius, 1);
+    }
+
+    #[test]
+    fn test_support_lines_reach_the_generator() {
+        let mut state = NewGameConfigState::verdun();
+        state.selected_field = ConfigField::SupportLines;
+        for _ in 0..=MAX_SUPPORT_TRENCH_LINES {
+            state.handle_right();
+        }
+        assert_eq!(state.to_battlefield_config().support_trench_lines, MAX_SUPPORT_TRENCH_LINES);
+        assert_eq!(state.selected_preset, BattlefieldPreset::Custom);
+
+        state.handle_left();
+        assert_eq!(state.to_defaults().support_trench_lines, MAX_SUPPORT_TRENCH_LINES - 1);
+    }
+
+    #[test]
+    fn test_edited_rank_shares_reach_the_game() {
+        let mut state = NewGameConfigState::verdun();
+        state.selected_field = ConfigField::EnemyForce;
+        state.handle_right();
+        assert_eq!(state.enemy_ranks.force, EnemyForce::Conscripts);
+        assert_eq!(state.enemy_ranks.name(), "Conscripts");
+
+        state.selected_field = ConfigField::EnemyRank(RankShare::Officers);
+        state.handle_right();
+        state.selected_field = ConfigField::EnemyRank(RankShare::Privates);
+        state.handle_left();
+
+        let edited = RankDistribution::new(100 - RANK_SHARE_STEP, 0, 0, RANK_SHARE_STEP);
+        assert_eq!(state.to_game_config().enemy_ranks, edited);
+        assert_eq!(state.enemy_ranks.name(), "Custom");
+        assert_eq!(state.to_defaults().enemy_ranks, Some(edited));
+    }
+
+    #[test]
+    fn test_ai_seed_reaches_the_game() {
+        let mut state = NewGameConfigState::verdun();
+        assert_eq!(state.to_game_config().ai_seed, None);
+
+        state.selected_field = ConfigField::AiSeed;
+        state.handle_right();
+        state.handle_right();
+        assert_eq!(state.to_game_config().ai_seed, Some(100));
+        assert_eq!(state.to_defaults().ai_seed, Some(100));
+
+        // Back down past the first seed: the AI follows the map again
+        state.handle_left();
+        state.handle_left();
+        assert_eq!(state.to_game_config().ai_seed, None);
+    }
+
+    #[test]
+    fn test_explicit_fortification_counts_override_level() {
+        let mut state = NewGameConfigState::verdun();
+        let level_counts = state.fortification_level.counts();
+        assert_eq!(
+            state.to_battlefield_config().effective_fortification_counts(),
+            level_counts
+        );
+
+        state.selected_field = ConfigField::MgNests;
+        state.handle_right();
+        state.selected_field = ConfigField::Bunkers;
+        state.handle_left();
+
+        let config = state.to_battlefield_config();
+        let expected = FortificationCounts {
+            sandbags: level_counts.sandbags,
+            bunkers: level_counts.bunkers - 1,
+            mg_nests: level_counts.mg_nests + 1,
+        };
+        assert_eq!(config.fortification_counts, Some(expected));
+        assert_eq!(config.effective_fortification_counts(), expected);
+        assert_eq!(state.selected_preset, BattlefieldPreset::Custom);
+
+        // Picking a level again returns to its derived counts
+        state.selected_field = ConfigField::FortificationLevel;
+        state.handle_left();
+        assert_eq!(state.to_battlefield_config().fortification_counts, None);
+    }
+
+    #[test]
+    fn test_saved_setup_becomes_next_default() {
+        let path = std::env::temp_dir().join(format!("argue_new_game_{}.toml", std::process::id()));
+
+        let mut state = NewGameConfigState::verdun();
+        state.selected_field = ConfigField::AllyCount;
+        state.handle_right();
+        state.selected_field = ConfigField::EnemyCount;
+        state.handle_left();
+        state.selected_field = ConfigField::TurnLimit;
+        state.handle_right();
+        state.selected_field = ConfigField::Difficulty;
+        state.handle_right();
+        state.seed = 4242;
+        state.save_defaults(&path).unwrap();
+
+        let reloaded = NewGameConfigState::load(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(reloaded.to_defaults(), state.to_defaults());
+        assert_eq!(reloaded.ally_count(), SOLDIER_COUNT_OPTIONS[DEFAULT_SOLDIER_COUNT_INDEX + 1]);
+        assert_eq!(reloaded.enemy_count(), SOLDIER_COUNT_OPTIONS[DEFAULT_SOLDIER_COUNT_INDEX - 1]);
+        assert_eq!(reloaded.turn_limit(), TURN_LIMIT_OPTIONS[1]);
+        assert_eq!(reloaded.difficulty, Difficulty::Hard);
+        assert_eq!(reloaded.to_battlefield_config().seed, 4242);
+
+        // Nothing saved: back to the built-in setup
+        let fresh = NewGameConfigState::load(path.with_extension("missing"));
+        assert_eq!(fresh.to_defaults(), NewGameConfigState::verdun().to_defaults());
+    }
+
+    /// Text of each row `state`'s form draws on an 80x30 screen
+    fn screen_rows(state: &NewGameConfigState) -> Vec<String> {
+        let area = Rect::new(0, 0, 80, 30);
+        let mut buf = Buffer::empty(area);
+        NewGameConfigWidget::new(state).render(area, &mut buf);
+        (0..area.height)
+            .map(|y| (0..area.width).map(|x| buf[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_form_scrolls_to_keep_the_selected_field_on_screen() {
+        let mut state = NewGameConfigState::verdun();
+        let shows = |rows: &[String], text: &str| rows.iter().any(|row| row.contains(text));
+
+        let top = screen_rows(&state);
+        assert!(shows(&top, "Preset"));
+        assert!(!shows(&top, "Start Game"));
+
+        state.selected_field = ConfigField::BackToMenu;
+        let bottom = screen_rows(&state);
+        assert!(shows(&bottom, "Start Game"));
+        assert!(shows(&bottom, "Back to Main Menu"));
+        assert!(shows(&bottom, "Navigate"));
+    }
+}
@@ -6,29 +6,200 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Widget},
 };
 
-use crate::config::game_config::GameConfig;
+use crate::config::game_config::{BlockedStepRule, CoverModel, GameConfig, LogVerbosity, ReloadInterrupt};
+use crate::config::hud_thresholds::HudThresholds;
+use crate::game_logic::fog_of_war::EnemyInfoLevel;
+use crate::game_logic::line_of_sight::DiagonalLos;
+use crate::game_logic::pathfinding::PathHeuristic;
+use crate::game_logic::targeting::GrenadeThrow;
 use crate::game_logic::turn_state::TurnOrderMode;
 
-#[derive(Debug, Clone)]
+/// Settings category, cycled with Tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsTab {
+    Display,
+    Gameplay,
+    Ai,
+    Controls,
+}
+
+impl SettingsTab {
+    pub const ALL: [SettingsTab; 4] = [
+        SettingsTab::Display,
+        SettingsTab::Gameplay,
+        SettingsTab::Ai,
+        SettingsTab::Controls,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SettingsTab::Display => "Display",
+            SettingsTab::Gameplay => "Gameplay",
+            SettingsTab::Ai => "AI",
+            SettingsTab::Controls => "Controls",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            SettingsTab::Display => SettingsTab::Gameplay,
+            SettingsTab::Gameplay => SettingsTab::Ai,
+            SettingsTab::Ai => SettingsTab::Controls,
+            SettingsTab::Controls => SettingsTab::Display,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            SettingsTab::Display => SettingsTab::Controls,
+            SettingsTab::Gameplay => SettingsTab::Display,
+            SettingsTab::Ai => SettingsTab::Gameplay,
+            SettingsTab::Controls => SettingsTab::Ai,
+        }
+    }
+
+    /// Adjustable options on this tab (buttons excluded)
+    fn options(&self) -> &'static [SettingsOption] {
+        match self {
+            SettingsTab::Display => &[
+                SettingsOption::ShowAiScores,
+                SettingsOption::ShotTracers,
+                SettingsOption::ImpactFlash,
+                SettingsOption::HeardShots,
+                SettingsOption::WatchOrders,
+                SettingsOption::CommanderStats,
+                SettingsOption::LogVerbosity,
+                SettingsOption::CameraDeadzone,
+                SettingsOption::HpWounded,
+                SettingsOption::HpCritical,
+                SettingsOption::TimeLow,
+                SettingsOption::TimeCritical,
+            ],
+            SettingsTab::Gameplay => &[
+                SettingsOption::TurnOrder,
+                SettingsOption::TimeBudget,
+                SettingsOption::RandomEvents,
+                SettingsOption::NmlAttrition,
+                SettingsOption::AutoPause,
+                SettingsOption::EnemyInfo,
+                SettingsOption::CoverModel,
+                SettingsOption::ReloadInterrupt,
+                SettingsOption::DiagonalLos,
+                SettingsOption::GrenadeThrow,
+                SettingsOption::PathHeuristic,
+                SettingsOption::BlockedSteps,
+            ],
+            SettingsTab::Ai => &[SettingsOption::SpotThenShoot],
+            SettingsTab::Controls => &[],
+        }
+    }
+}
+
+/// A selectable row in the settings menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsOption {
+    ShowAiScores,
+    ShotTracers,
+    ImpactFlash,
+    HeardShots,
+    WatchOrders,
+    CommanderStats,
+    LogVerbosity,
+    CameraDeadzone,
+    HpWounded,
+    HpCritical,
+    TimeLow,
+    TimeCritical,
+    TurnOrder,
+    TimeBudget,
+    RandomEvents,
+    NmlAttrition,
+    AutoPause,
+    EnemyInfo,
+    CoverModel,
+    ReloadInterrupt,
+    DiagonalLos,
+    GrenadeThrow,
+    PathHeuristic,
+    BlockedSteps,
+    SpotThenShoot,
+    SaveAndReturn,
+    Cancel,
+}
+
+/// Longest a heard shot's marker can be set to linger
+const MAX_HEARD_SHOT_TURNS: u32 = 5;
+
+const KEYBINDINGS: &[&str] = &[
+    "Movement: qweasdzxc (8-direction)",
+    "Look Mode: l",
+    "Fire: f",
+    "Aim: i",
+    "Reload: r",
+    "Swap Weapon: p",
+    "Lay Wire: W (ahead)",
+    "Fire Cone: y",
+    "Battle Timeline: t (paused)",
+];
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct SettingsMenuState {
+    pub active_tab: SettingsTab,
+    pub show_ai_scores: bool,
+    pub shot_tracers: bool,
+    pub impact_flash: bool,
+    pub heard_shot_turns: u32,
+    pub watch_orders: bool,
+    pub commander_stats: bool,
+    pub log_verbosity: LogVerbosity,
+    pub hud_thresholds: HudThresholds,
+    pub camera_deadzone: f32,
     pub turn_order_mode: TurnOrderMode,
     pub default_time_budget: f32,
+    pub random_events: bool,
+    pub nml_attrition: bool,
+    pub auto_pause: bool,
+    pub enemy_info: EnemyInfoLevel,
+    pub cover_model: CoverModel,
+    pub reload_interrupt: ReloadInterrupt,
+    pub diagonal_los: DiagonalLos,
+    pub grenade_throw: GrenadeThrow,
+    pub path_heuristic: PathHeuristic,
+    pub blocked_step_rule: BlockedStepRule,
+    pub spot_then_shoot_turns: u32,
     pub selected_index: usize,
 }
 
 impl SettingsMenuState {
     pub fn new() -> Self {
-        Self {
-            turn_order_mode: TurnOrderMode::PlayerFirst,
-            default_time_budget: 12.0,
-            selected_index: 0,
-        }
+        Self::from_game_config(&GameConfig::new())
     }
 
     pub fn from_game_config(config: &GameConfig) -> Self {
         Self {
+            active_tab: SettingsTab::Gameplay,
+            show_ai_scores: config.show_ai_scores,
+            shot_tracers: config.shot_tracers,
+            impact_flash: config.impact_flash,
+            heard_shot_turns: config.heard_shot_turns,
+            watch_orders: config.watch_orders,
+            commander_stats: config.commander_stats,
+            log_verbosity: config.log_verbosity,
+            hud_thresholds: config.hud_thresholds,
+            camera_deadzone: config.camera_deadzone,
             turn_order_mode: config.turn_order_mode,
             default_time_budget: config.time_budget_seconds,
+            random_events: config.random_events.enabled,
+            nml_attrition: config.attrition.enabled,
+            auto_pause: config.auto_pause,
+            enemy_info: config.enemy_info,
+            cover_model: config.cover_model,
+            reload_interrupt: config.reload_interrupt,
+            diagonal_los: config.diagonal_los,
+            grenade_throw: config.grenade_throw,
+            path_heuristic: config.path_heuristic,
+            blocked_step_rule: config.blocked_step_rule,
+            spot_then_shoot_turns: config.spot_then_shoot_turns,
             selected_index: 0,
         }
     }
@@ -37,10 +208,52 @@ impl SettingsMenuState {
         GameConfig::new()
             .with_turn_order_mode(self.turn_order_mode)
             .with_time_budget(self.default_time_budget)
+            .with_random_events(self.random_events)
+            .with_nml_attrition(self.nml_attrition)
+            .with_auto_pause(self.auto_pause)
+            .with_enemy_info(self.enemy_info)
+            .with_cover_model(self.cover_model)
+            .with_reload_interrupt(self.reload_interrupt)
+            .with_diagonal_los(self.diagonal_los)
+            .with_grenade_throw(self.grenade_throw)
+            .with_path_heuristic(self.path_heuristic)
+            .with_blocked_step_rule(self.blocked_step_rule)
+            .with_spot_then_shoot(self.spot_then_shoot_turns)
+            .with_hud_thresholds(self.hud_thresholds)
+            .with_camera_deadzone(self.camera_deadzone)
+            .with_shot_tracers(self.shot_tracers)
+            .with_impact_flash(self.impact_flash)
+            .with_heard_shot_turns(self.heard_shot_turns)
+            .with_watch_orders(self.watch_orders)
+            .with_commander_stats(self.commander_stats)
+            .with_ai_scores(self.show_ai_scores)
+            .with_log_verbosity(self.log_verbosity)
+    }
+
+    /// Rows on the active tab, including the Save/Cancel buttons
+    pub fn options(&self) -> Vec<SettingsOption> {
+        let mut options = self.active_tab.options().to_vec();
+        options.push(SettingsOption::SaveAndReturn);
+        options.push(SettingsOption::Cancel);
+        options
+    }
+
+    pub fn selected_option(&self) -> SettingsOption {
+        self.options()[self.selected_index]
+    }
+
+    pub fn next_tab(&mut self) {
+        self.active_tab = self.active_tab.next();
+        self.selected_index = 0;
+    }
+
+    pub fn prev_tab(&mut self) {
+        self.active_tab = self.active_tab.prev();
+        self.selected_index = 0;
     }
 
     pub fn select_next(&mut self) {
-        if self.selected_index < 3 {
+        if self.selected_index + 1 < self.options().len() {
             self.selected_index += 1;
         }
     }
@@ -52,33 +265,83 @@ impl SettingsMenuState {
     }
 
     pub fn handle_left(&mut self) {
-        match self.selected_index {
-            0 => {
+        match self.selected_option() {
+            SettingsOption::TurnOrder => {
                 self.turn_order_mode = match self.turn_order_mode {
                     TurnOrderMode::PlayerFirst => TurnOrderMode::InitiativeBased,
                     TurnOrderMode::Simultaneous => TurnOrderMode::PlayerFirst,
                     TurnOrderMode::InitiativeBased => TurnOrderMode::Simultaneous,
                 };
             }
-            1 => {
+            SettingsOption::TimeBudget => {
                 self.default_time_budget = (self.default_time_budget - 1.0).clamp(5.0, 30.0);
             }
-            _ => {}
+            SettingsOption::EnemyInfo => self.enemy_info = self.enemy_info.prev(),
+            SettingsOption::PathHeuristic => self.path_heuristic = self.path_heuristic.prev(),
+            SettingsOption::SpotThenShoot => {
+                self.spot_then_shoot_turns = self.spot_then_shoot_turns.saturating_sub(1);
+            }
+            SettingsOption::HeardShots => {
+                self.heard_shot_turns = self.heard_shot_turns.saturating_sub(1);
+            }
+            SettingsOption::CameraDeadzone => {
+                self.camera_deadzone = (self.camera_deadzone - 0.05).clamp(0.1, 0.9);
+            }
+            SettingsOption::HpWounded => self.hud_thresholds.adjust_hp_wounded(-0.05),
+            SettingsOption::HpCritical => self.hud_thresholds.adjust_hp_critical(-0.05),
+            SettingsOption::TimeLow => self.hud_thresholds.adjust_time_low(-0.5),
+            SettingsOption::TimeCritical => self.hud_thresholds.adjust_time_critical(-0.5),
+            _ => self.toggle_selected(),
         }
     }
 
     pub fn handle_right(&mut self) {
-        match self.selected_index {
-            0 => {
+        match self.selected_option() {
+            SettingsOption::TurnOrder => {
                 self.turn_order_mode = match self.turn_order_mode {
                     TurnOrderMode::PlayerFirst => TurnOrderMode::Simultaneous,
                     TurnOrderMode::Simultaneous => TurnOrderMode::InitiativeBased,
                     TurnOrderMode::InitiativeBased => TurnOrderMode::PlayerFirst,
                 };
             }
-            1 => {
+            SettingsOption::TimeBudget => {
                 self.default_time_budget = (self.default_time_budget + 1.0).clamp(5.0, 30.0);
             }
+            SettingsOption::EnemyInfo => self.enemy_info = self.enemy_info.next(),
+            SettingsOption::PathHeuristic => self.path_heuristic = self.path_heuristic.next(),
+            SettingsOption::SpotThenShoot => {
+                self.spot_then_shoot_turns = (self.spot_then_shoot_turns + 1).min(3);
+            }
+            SettingsOption::HeardShots => {
+                self.heard_shot_turns = (self.heard_shot_turns + 1).min(MAX_HEARD_SHOT_TURNS);
+            }
+            SettingsOption::CameraDeadzone => {
+                self.camera_deadzone = (self.camera_deadzone + 0.05).clamp(0.1, 0.9);
+            }
+            SettingsOption::HpWounded => self.hud_thresholds.adjust_hp_wounded(0.05),
+            SettingsOption::HpCritical => self.hud_thresholds.adjust_hp_critical(0.05),
+            SettingsOption::TimeLow => self.hud_thresholds.adjust_time_low(0.5),
+            SettingsOption::TimeCritical => self.hud_thresholds.adjust_time_critical(0.5),
+            _ => self.toggle_selected(),
+        }
+    }
+
+    fn toggle_selected(&mut self) {
+        match self.selected_option() {
+            SettingsOption::ShowAiScores => self.show_ai_scores = !self.show_ai_scores,
+            SettingsOption::ShotTracers => self.shot_tracers = !self.shot_tracers,
+            SettingsOption::ImpactFlash => self.impact_flash = !self.impact_flash,
+            SettingsOption::WatchOrders => self.watch_orders = !self.watch_orders,
+            SettingsOption::CommanderStats => self.commander_stats = !self.commander_stats,
+            SettingsOption::LogVerbosity => self.log_verbosity = self.log_verbosity.toggle(),
+            SettingsOption::RandomEvents => self.random_events = !self.random_events,
+            SettingsOption::NmlAttrition => self.nml_attrition = !self.nml_attrition,
+            SettingsOption::AutoPause => self.auto_pause = !self.auto_pause,
+            SettingsOption::CoverModel => self.cover_model = self.cover_model.toggle(),
+            SettingsOption::ReloadInterrupt => self.reload_interrupt = self.reload_interrupt.toggle(),
+            SettingsOption::DiagonalLos => self.diagonal_los = self.diagonal_los.toggle(),
+            SettingsOption::GrenadeThrow => self.grenade_throw = self.grenade_throw.toggle(),
+            SettingsOption::BlockedSteps => self.blocked_step_rule = self.blocked_step_rule.toggle(),
             _ => {}
         }
     }
@@ -99,6 +362,32 @@ impl<'a> SettingsMenuWidget<'a> {
         Self { state }
     }
 
+    fn render_tab_bar(&self, y: u16, area: Rect, buf: &mut Buffer) {
+        let mut spans = Vec::new();
+        for tab in SettingsTab::ALL {
+            let style = if tab == self.state.active_tab {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            spans.push(Span::styled(format!(" {} ", tab.name()), style));
+            spans.push(Span::raw(" "));
+        }
+        spans.push(Span::styled("(Tab to switch)", Style::default().fg(Color::DarkGray)));
+
+        let paragraph = Paragraph::new(Line::from(spans));
+        let line_area = Rect {
+            x: area.x,
+            y,
+            width: area.width,
+            height: 1,
+        };
+        paragraph.render(line_area, buf);
+    }
+
     fn render_category_header(&self, title: &str, y: u16, area: Rect, buf: &mut Buffer) {
         let line = Line::from(vec![
             Span::styled(title, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
@@ -234,54 +523,284 @@ impl<'a> Widget for SettingsMenuWidget<'a> {
 
         let mut y = inner.y;
 
-        self.render_category_header("Gameplay", y, inner, buf);
-        y += 1;
-
-        let turn_order_text = match self.state.turn_order_mode {
-            TurnOrderMode::PlayerFirst => "PlayerFirst",
-            TurnOrderMode::Simultaneous => "Simultaneous",
-            TurnOrderMode::InitiativeBased => "InitiativeBased",
-        };
-        self.render_choice_item(
-            "Turn Order",
-            turn_order_text,
-            self.state.selected_index == 0,
-            y,
-            inner,
-            buf,
-        );
-        y += 1;
-
-        self.render_slider_item(
-            "Default Time Budget",
-            self.state.default_time_budget,
-            5.0,
-            30.0,
-            self.state.selected_index == 1,
-            y,
-            inner,
-            buf,
-        );
+        self.render_tab_bar(y, inner, buf);
         y += 2;
 
-        self.render_category_header("Controls", y, inner, buf);
+        self.render_category_header(self.state.active_tab.name(), y, inner, buf);
         y += 1;
 
-        self.render_text_line("  Movement: qweasdzxc (8-direction)", y, inner, buf);
-        y += 1;
-
-        self.render_text_line("  Look Mode: l", y, inner, buf);
-        y += 1;
+        for (i, option) in self.state.options().into_iter().enumerate() {
+            let is_selected = self.state.selected_index == i;
+            match option {
+                SettingsOption::ShowAiScores => {
+                    self.render_choice_item(
+                        "Show AI Scores",
+                        on_off(self.state.show_ai_scores),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::ShotTracers => {
+                    self.render_choice_item(
+                        "Shot Tracers",
+                        on_off(self.state.shot_tracers),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::ImpactFlash => {
+                    self.render_choice_item(
+                        "Impact Flash",
+                        on_off(self.state.impact_flash),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::HeardShots => {
+                    let turns = match self.state.heard_shot_turns {
+                        0 => "Off".to_string(),
+                        turns => format!("{} turn(s)", turns),
+                    };
+                    self.render_choice_item("Heard Shot Markers", &turns, is_selected, y, inner, buf);
+                }
+                SettingsOption::WatchOrders => {
+                    self.render_choice_item(
+                        "Watch Orders",
+                        on_off(self.state.watch_orders),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::CommanderStats => {
+                    self.render_choice_item(
+                        "Commander Stats",
+                        on_off(self.state.commander_stats),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::LogVerbosity => {
+                    self.render_choice_item(
+                        "Event Log Detail",
+                        self.state.log_verbosity.name(),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::CameraDeadzone => {
+                    let value = format!("{:.0}% of view", self.state.camera_deadzone * 100.0);
+                    self.render_choice_item("Camera Deadzone", &value, is_selected, y, inner, buf);
+                }
+                SettingsOption::HpWounded => {
+                    let value = format!("{:.0}% HP", self.state.hud_thresholds.hp_wounded * 100.0);
+                    self.render_choice_item("Wounded Below", &value, is_selected, y, inner, buf);
+                }
+                SettingsOption::HpCritical => {
+                    let value = format!("{:.0}% HP", self.state.hud_thresholds.hp_critical * 100.0);
+                    self.render_choice_item("Critical Below", &value, is_selected, y, inner, buf);
+                }
+                SettingsOption::TimeLow => {
+                    let value = format!("{:.1}s", self.state.hud_thresholds.time_low);
+                    self.render_choice_item("Time Low Below", &value, is_selected, y, inner, buf);
+                }
+                SettingsOption::TimeCritical => {
+                    let value = format!("{:.1}s", self.state.hud_thresholds.time_critical);
+                    self.render_choice_item("Time Critical Below", &value, is_selected, y, inner, buf);
+                }
+                SettingsOption::TurnOrder => {
+                    let turn_order_text = match self.state.turn_order_mode {
+                        TurnOrderMode::PlayerFirst => "PlayerFirst",
+                        TurnOrderMode::Simultaneous => "Simultaneous",
+                        TurnOrderMode::InitiativeBased => "InitiativeBased",
+                    };
+                    self.render_choice_item("Turn Order", turn_order_text, is_selected, y, inner, buf);
+                }
+                SettingsOption::TimeBudget => {
+                    self.render_slider_item(
+                        "Default Time Budget",
+                        self.state.default_time_budget,
+                        5.0,
+                        30.0,
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::RandomEvents => {
+                    self.render_choice_item(
+                        "Random Events",
+                        on_off(self.state.random_events),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::NmlAttrition => {
+                    self.render_choice_item(
+                        "No-Man's-Land Attrition",
+                        on_off(self.state.nml_attrition),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::AutoPause => {
+                    self.render_choice_item(
+                        "Auto-Pause on Events",
+                        on_off(self.state.auto_pause),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::EnemyInfo => {
+                    self.render_choice_item(
+                        "Enemy Intel",
+                        self.state.enemy_info.name(),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::CoverModel => {
+                    self.render_choice_item(
+                        "Cover",
+                        self.state.cover_model.name(),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::ReloadInterrupt => {
+                    self.render_choice_item(
+                        "Reload Under Fire",
+                        self.state.reload_interrupt.name(),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::DiagonalLos => {
+                    self.render_choice_item(
+                        "Diagonal Sight",
+                        self.state.diagonal_los.name(),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::GrenadeThrow => {
+                    self.render_choice_item(
+                        "Grenade Throws",
+                        self.state.grenade_throw.name(),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::BlockedSteps => {
+                    self.render_choice_item(
+                        "Blocked Steps",
+                        self.state.blocked_step_rule.name(),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::PathHeuristic => {
+                    self.render_choice_item(
+                        "Path Heuristic",
+                        self.state.path_heuristic.name(),
+                        is_selected,
+                        y,
+                        inner,
+                        buf,
+                    );
+                }
+                SettingsOption::SpotThenShoot => {
+                    let turns = format!("{} turn(s)", self.state.spot_then_shoot_turns);
+                    self.render_choice_item("Spot-Then-Shoot Delay", &turns, is_selected, y, inner, buf);
+                }
+                SettingsOption::SaveAndReturn => {
+                    // Controls tab lists the bindings above its buttons
+                    if self.state.active_tab == SettingsTab::Controls {
+                        for binding in KEYBINDINGS {
+                            self.render_text_line(&format!("  {}", binding), y, inner, buf);
+                            y += 1;
+                        }
+                    }
+                    y += 1;
+                    self.render_button("[Save & Return]", is_selected, y, inner, buf);
+                }
+                SettingsOption::Cancel => {
+                    self.render_button("[Cancel]", is_selected, y, inner, buf);
+                }
+            }
+            y += 1;
+        }
+    }
+}
 
-        self.render_text_line("  Fire: f", y, inner, buf);
-        y += 1;
+fn on_off(value: bool) -> &'static str {
+    if value { "On" } else { "Off" }
+}
 
-        self.render_text_line("  Reload: r", y, inner, buf);
-        y += 2;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_switching_tabs_changes_options() {
+        let mut state = SettingsMenuState::new();
+        assert_eq!(state.active_tab, SettingsTab::Gameplay);
+        assert!(state.options().contains(&SettingsOption::TurnOrder));
+
+        state.select_next();
+        state.next_tab();
+        assert_eq!(state.active_tab, SettingsTab::Ai);
+        assert_eq!(state.selected_index, 0);
+        assert_eq!(state.selected_option(), SettingsOption::SpotThenShoot);
+        assert!(!state.options().contains(&SettingsOption::TurnOrder));
+
+        state.next_tab();
+        assert_eq!(
+            state.options(),
+            vec![SettingsOption::SaveAndReturn, SettingsOption::Cancel]
+        );
 
-        self.render_button("[Save & Return]", self.state.selected_index == 2, y, inner, buf);
-        y += 1;
+        state.next_tab();
+        assert_eq!(state.active_tab, SettingsTab::Display);
+        assert_eq!(state.selected_option(), SettingsOption::ShowAiScores);
+    }
 
-        self.render_button("[Cancel]", self.state.selected_index == 3, y, inner, buf);
+    #[test]
+    fn test_adjusting_option_on_active_tab() {
+        let mut state = SettingsMenuState::new();
+        state.next_tab();
+        state.handle_right();
+        assert_eq!(state.spot_then_shoot_turns, GameConfig::new().spot_then_shoot_turns + 1);
+        assert_eq!(state.to_game_config().spot_then_shoot_turns, state.spot_then_shoot_turns);
     }
 }
@@ -0,0 +1,102 @@
+use crate::game_logic::timeline::{Timeline, TimelineEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+const PANEL_WIDTH: u16 = 48;
+
+/// Battle timeline drawn down the right of the paused battlefield view
+pub struct TimelineViewerWidget<'a> {
+    timeline: &'a Timeline,
+    selected: usize,
+}
+
+impl<'a> TimelineViewerWidget<'a> {
+    pub fn new(timeline: &'a Timeline, selected: usize) -> Self {
+        Self { timeline, selected }
+    }
+
+    fn kind_color(kind: TimelineEventKind) -> Color {
+        match kind {
+            TimelineEventKind::Kill => Color::Red,
+            TimelineEventKind::Capture => Color::Yellow,
+            TimelineEventKind::Reinforcements => Color::Cyan,
+        }
+    }
+}
+
+impl<'a> Widget for TimelineViewerWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = PANEL_WIDTH.min(area.width);
+        let panel = Rect {
+            x: area.right() - width,
+            y: area.y,
+            width,
+            height: area.height,
+        };
+
+        Clear.render(panel, buf);
+        let block = Block::default()
+            .title(format!("Battle Timeline ({})", self.timeline.len()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(panel);
+        block.render(panel, buf);
+
+        let events = self.timeline.events();
+        let mut lines = Vec::new();
+        if events.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "Nothing of note yet",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
+        // Keep the selected entry in view, leaving a row for the help line
+        let rows = inner.height.saturating_sub(1).max(1) as usize;
+        let first = self.selected.saturating_sub(rows - 1);
+        for (i, event) in events.iter().enumerate().skip(first).take(rows) {
+            let selected = i == self.selected;
+            let text_style = if selected {
+                Style::default().fg(Color::Black).bg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("T{:<3} ", event.turn), text_style),
+                Span::styled(
+                    format!("{:<4} ", event.kind.label()),
+                    if selected {
+                        text_style
+                    } else {
+                        Style::default()
+                            .fg(Self::kind_color(event.kind))
+                            .add_modifier(Modifier::BOLD)
+                    },
+                ),
+                Span::styled(event.description.clone(), text_style),
+            ]));
+        }
+        Paragraph::new(lines).render(inner, buf);
+
+        if inner.height > 1 {
+            let help = Line::from(vec![
+                Span::styled("↑↓", Style::default().fg(Color::Yellow)),
+                Span::raw(" Jump  "),
+                Span::styled("ESC", Style::default().fg(Color::Yellow)),
+                Span::raw(" Close"),
+            ]);
+            let help_area = Rect {
+                x: inner.x,
+                y: inner.bottom() - 1,
+                width: inner.width,
+                height: 1,
+            };
+            Paragraph::new(help).render(help_area, buf);
+        }
+    }
+}
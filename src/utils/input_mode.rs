@@ -18,9 +18,9 @@ pub enum InputMode {
     /// - Camera can pan independently
     Look,
 
-    /// Targeting mode: select target for shooting
+    /// Targeting mode: select target for shooting or a grenade throw
     /// - hjkl/arrows move the cursor
-    /// - Enter shoots at target
+    /// - Enter shoots at target, or throws (twice if danger close)
     /// - ESC cancels and returns to Command mode
     /// - Camera can pan independently
     Targeting,
@@ -37,9 +37,17 @@ impl InputMode {
 
     pub fn help_text(&self) -> &'static str {
         match self {
-            InputMode::Command => "qweasdzxc: 8-dir move | s: wait | ,/.: rotate | Space: advance | f: fire | r: reload | l: look | v: center | Shift+Q/Ctrl+C: quit",
-            InputMode::Look => "hjkl/arrows: pan camera | c: center | Enter: select destination | ESC: exit",
-            InputMode::Targeting => "hjkl/arrows: pan camera | c: center | Enter: shoot target | ESC: cancel",
+            InputMode::Command => "qweasdzxc: 8-dir move | b: crawl/walk | s: wait | ,/.: rotate | m: step costs | Space: advance | T: end turn (all) | A: advance to contact | f: fire | G: grenade | r: reload | p: swap weapon | g: loot corpse | o: hold/open fire | R: retreat | l: look | v: center | 1-9/Shift+1-9: bookmarks | Shift+Q/Ctrl+C: quit",
+            InputMode::Look => "hjkl/arrows: pan camera | c: center | p: peek sightlines | Enter: select destination | o: squad to cursor (formation) | ESC: exit",
+            InputMode::Targeting => "hjkl/arrows: pan camera | c: center | Enter: shoot target / throw grenade | ESC: cancel",
+        }
+    }
+
+    /// Help text when playing as a commander with no soldier of their own
+    pub fn commander_help_text(&self) -> &'static str {
+        match self {
+            InputMode::Command => "qweasdzxc: move camera | Space/T: end turn | o: hold/open fire | R: retreat | l: look | 1-9/Shift+1-9: bookmarks | Shift+Q/Ctrl+C: quit",
+            _ => self.help_text(),
         }
     }
 }
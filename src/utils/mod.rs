@@ -2,6 +2,7 @@
 // Helper functions, constants, and shared utilities
 
 pub mod event_log;
+pub mod game_rng;
 pub mod input_mode;
 
 // Future utility submodules: